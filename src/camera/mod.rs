@@ -1,11 +1,143 @@
 pub use self::camera::Camera;
+pub use self::camera::Aperture;
+pub use self::camera::ApertureImage;
+pub use self::camera::Exposure;
 
 mod camera{
+    use std::sync::Arc;
     use crate::vector::vec3;
     use crate::ray::Ray;
+    use crate::geometry::{HitRecord, HittableList};
+    use crate::sampling::IndependentSampler;
+    use crate::image::PPM;
     use vec3 as point3;
 
+    // Shape of the lens opening. Determines the shape of out-of-focus highlights (bokeh).
+    #[derive(Clone, Debug)]
+    pub enum Aperture {
+        Circular,
+        // Regular polygon with `blades` sides, rotated by `rotation` radians
+        Polygon{blades: u32, rotation: f32},
+        // Arbitrary mask. Brighter pixels let through more light.
+        Image(Arc<ApertureImage>),
+    }
+
+    // Greyscale aperture mask, stored as a CDF over its pixels so it can be importance sampled
+    #[derive(Debug)]
+    pub struct ApertureImage {
+        width: usize,
+        height: usize,
+        cdf: Vec<f32>,
+    }
+
+    impl ApertureImage {
+        pub fn from_file(filename: &str) -> std::io::Result<Self> {
+            let img = PPM::read_file(filename)?;
+            let (width, height) = (img.width() as usize, img.height() as usize);
+
+            let mut cdf = Vec::with_capacity(width*height);
+            let mut total = 0.0;
+            for y in 0..height {
+                for x in 0..width {
+                    let px = img.get_pixel(x as u32, y as u32).unwrap();
+                    total += (px.r as f32 + px.g as f32 + px.b as f32)/(3.0*255.0);
+                    cdf.push(total);
+                }
+            }
+            if total <= 0.0 {
+                return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{}: aperture mask is completely black", filename)));
+            }
+            for c in cdf.iter_mut() { *c /= total; }
+
+            Ok(Self {width: width, height: height, cdf: cdf})
+        }
+
+        // Maps two uniform numbers to a point in [-1,1]^2, distributed according to the mask
+        fn sample(&self, u1: f32, u2: f32) -> (f32, f32) {
+            let idx = self.cdf.partition_point(|&c| c < u1).min(self.cdf.len()-1);
+
+            // Reuse the leftover of u1 within the chosen pixel as jitter along x
+            let lo = if idx == 0 {0.0} else {self.cdf[idx-1]};
+            let jx = if self.cdf[idx] > lo {(u1-lo)/(self.cdf[idx]-lo)} else {0.5};
+
+            let (px, py) = (idx % self.width, idx / self.width);
+            let x = (px as f32 + jx)/self.width as f32;
+            let y = (py as f32 + u2)/self.height as f32;
+
+            // Image rows go top to bottom, lens v axis goes bottom to top
+            (2.0*x - 1.0, 1.0 - 2.0*y)
+        }
+    }
+
+    impl Aperture {
+        // Takes a sample in the unit disc and returns the corresponding point on the aperture (also within [-1,1]^2).
+        // Circular apertures use the sample as-is, so the blue noise properties of the disc sample are kept.
+        pub fn sample(&self, disc_sample: (f32, f32)) -> (f32, f32) {
+            // Recover two uniform numbers from the disc sample (r^2 and the angle are uniform in [0,1))
+            let to_uniform = |(x, y): (f32, f32)| {
+                let u1 = (x*x + y*y).min(0.99999);
+                let u2 = (y.atan2(x)/(2.0*std::f32::consts::PI)).rem_euclid(1.0);
+                (u1, u2)
+            };
+
+            match self {
+                Aperture::Circular => disc_sample,
+                Aperture::Polygon{blades, rotation} => {
+                    let (u1, u2) = to_uniform(disc_sample);
+                    let n = (*blades).max(3) as f32;
+
+                    // Pick one of the n triangles fanning out from the center, then sample it uniformly
+                    let tri = (u2*n).floor();
+                    let u2 = u2*n - tri;
+                    let wedge = 2.0*std::f32::consts::PI/n;
+                    let a0 = rotation + tri*wedge;
+                    let a1 = a0 + wedge;
+
+                    let s = u1.sqrt();
+                    let (b0, b1) = (s*(1.0-u2), s*u2);
+                    (b0*a0.cos() + b1*a1.cos(), b0*a0.sin() + b1*a1.sin())
+                },
+                Aperture::Image(img) => {
+                    let (u1, u2) = to_uniform(disc_sample);
+                    img.sample(u1, u2)
+                },
+            }
+        }
+    }
+
+    // Physical camera settings. Scene radiance is taken to be in cd/m^2 and scene units in metres.
     #[derive(Copy, Clone, Debug)]
+    pub struct Exposure {
+        pub f_number: f32,
+        // Seconds. Only changes how bright the image is, rays have no time so nothing is motion blurred.
+        pub shutter_speed: f32,
+        pub iso: f32,
+        // In stops, added on top of what the f-number, shutter and ISO give
+        pub ev_compensation: f32,
+    }
+
+    impl Exposure {
+        // Exposure value normalised to ISO 100
+        pub fn ev100(&self) -> f32 {
+            (self.f_number*self.f_number/self.shutter_speed * 100.0/self.iso).log2()
+        }
+
+        // Multiplier taking scene luminance to film values, where 1.0 is sensor saturation.
+        // Uses the saturation based sensitivity from ISO 12232 (L_max = 78/(q*S) * N^2/t, with q = 0.65).
+        pub fn scale(&self) -> f32 {
+            let max_luminance = 1.2*2.0f32.powf(self.ev100());
+            2.0f32.powf(self.ev_compensation)/max_luminance
+        }
+    }
+
+    impl Default for Exposure {
+        // Sunny 16 rule
+        fn default() -> Self {
+            Exposure{f_number: 16.0, shutter_speed: 1.0/100.0, iso: 100.0, ev_compensation: 0.0}
+        }
+    }
+
+    #[derive(Clone, Debug)]
     pub struct Camera {
         pub v_fov: f32,
         pub aspect_ratio: f32,
@@ -14,6 +146,15 @@ mod camera{
         pub lens_radius: f32,
         pub focus_dist: f32,
 
+        pub aperture: Aperture,
+        // Optical (cat's eye) vignetting. 0.0 disables it. Larger values clip the bokeh more towards the image edges.
+        pub cat_eye: f32,
+        // If set, focus_dist is set by casting a ray through this point on the screen, in [0,1]^2 screen coordinates
+        pub autofocus: Option<(f32, f32)>,
+        // If set, lens_radius is derived from the f-number and the image is scaled by the exposure.
+        // If not, lens_radius is used as-is and the image is the plain sample mean.
+        pub exposure: Option<Exposure>,
+
         // u, v, w is the orthonormal vector defining camera orientation
         pub u: vec3,
         pub v: vec3,
@@ -34,7 +175,7 @@ mod camera{
     impl Camera {
         pub fn new(v_fov: f32, aspect_ratio: f32, aperture: f32, focus_dist: f32, origin: vec3,
             look_at: point3, v_up: point3, samples_per_px: u32) -> Self {
-            
+
             let theta = deg_to_rad(v_fov);
             let h = (theta/2.0).tan();
 
@@ -57,12 +198,17 @@ mod camera{
                 lens_radius: aperture/2.0,
                 focus_dist: focus_dist,
 
+                aperture: Aperture::Circular,
+                cat_eye: 0.0,
+                autofocus: None,
+                exposure: None,
+
                 w: w,
                 u: u,
                 v: v,
 
                 origin: origin,
-                
+
                 //TODO: Let user specify initial rotation of camera, and convert that to horizontal/vertical
                 horizontal: horizontal,
                 vertical: vertical,
@@ -72,10 +218,87 @@ mod camera{
             }
         }
 
-        pub fn get_ray(self, s: f32, t: f32, random_in_unit_disk: vec3) -> Ray {
-            let rd = random_in_unit_disk*self.lens_radius;
-            let offset = self.u*rd.x + self.v*rd.y;
-            Ray::new(self.origin + offset, self.lower_left_corner + self.horizontal*s + self.vertical*t - self.origin-offset)
+        // Returns None if the lens sample is blocked by the lens barrel (cat's eye vignetting)
+        pub fn get_ray(&self, s: f32, t: f32, lens_sample: (f32, f32)) -> Option<Ray> {
+            let (lx, ly) = self.aperture.sample(lens_sample);
+
+            if self.cat_eye > 0.0 {
+                // The exit pupil seen from off-axis points slides towards the image edge, so only the
+                // intersection of the aperture and the shifted pupil lets light through.
+                let cx = (2.0*s - 1.0)*self.aspect_ratio*self.cat_eye;
+                let cy = (2.0*t - 1.0)*self.cat_eye;
+                if (lx-cx)*(lx-cx) + (ly-cy)*(ly-cy) > 1.0 {
+                    return None;
+                }
+            }
+
+            let offset = self.u*lx*self.lens_radius + self.v*ly*self.lens_radius;
+            Some(Ray::new(self.origin + offset, self.lower_left_corner + self.horizontal*s + self.vertical*t - self.origin-offset))
+        }
+
+        // Focal length in metres, assuming a 35mm full frame sensor (24mm tall)
+        pub fn focal_length(&self) -> f32 {
+            0.024/self.viewport_height
+        }
+
+        pub fn set_exposure(&mut self, exposure: Exposure) {
+            self.lens_radius = self.focal_length()/(2.0*exposure.f_number);
+            self.exposure = Some(exposure);
+        }
+
+        // Factor applied to the mean radiance of a pixel before it is displayed
+        pub fn exposure_scale(&self) -> f32 {
+            match self.exposure {
+                Some(exposure) => exposure.scale(),
+                None => 1.0,
+            }
+        }
+
+        // Inverse of get_ray for a pinhole camera: screen coordinates (s, t) of a world point, or None if it is behind the camera
+        pub fn project(&self, p: point3) -> Option<(f32, f32)> {
+            let d = p - self.origin;
+            let z = vec3::dot(&d, &-self.w);
+            if z <= 0.0 {
+                return None;
+            }
+            // Point where the line towards p crosses the focus plane, relative to the origin
+            let q = d*(self.focus_dist/z);
+            let s = vec3::dot(&q, &self.u)/(self.viewport_width*self.focus_dist) + 0.5;
+            let t = vec3::dot(&q, &self.v)/(self.viewport_height*self.focus_dist) + 0.5;
+            Some((s, t))
+        }
+
+        // Whether both cameras see the scene the same way, so that images taken with them can be averaged
+        pub fn same_view(&self, other: &Camera) -> bool {
+            let same = |a: vec3, b: vec3| a.x == b.x && a.y == b.y && a.z == b.z;
+            same(self.origin, other.origin) && same(self.lower_left_corner, other.lower_left_corner)
+                && same(self.horizontal, other.horizontal) && same(self.vertical, other.vertical) && self.lens_radius == other.lens_radius
+        }
+
+        pub fn set_focus_dist(&mut self, focus_dist: f32) {
+            self.focus_dist = focus_dist;
+            self.horizontal = self.u*self.viewport_width*focus_dist;
+            self.vertical = self.v*self.viewport_height*focus_dist;
+            self.lower_left_corner = self.origin - self.horizontal/2.0 - self.vertical/2.0 - self.w*focus_dist;
+        }
+
+        // Sets the focus distance to whatever is visible through the autofocus point. Leaves it untouched if nothing is hit.
+        pub fn apply_autofocus(&mut self, scene: &HittableList) {
+            let (s, t) = match self.autofocus {
+                Some(st) => st,
+                None => return,
+            };
+
+            let pinhole = Ray::new(self.origin, self.lower_left_corner + self.horizontal*s + self.vertical*t - self.origin);
+            let mut hr = HitRecord::default();
+            let mut attenuation = vec3::new(0.0, 0.0, 0.0);
+            if scene.hit(&pinhole, &mut attenuation, 0.001, f32::INFINITY, &mut hr, &mut IndependentSampler::new(0)).is_some() {
+                // Focus plane is perpendicular to the viewing direction, so project onto it
+                let dist = vec3::dot(&(hr.p - self.origin), &-self.w);
+                if dist > 0.0 {
+                    self.set_focus_dist(dist);
+                }
+            }
         }
 
         pub fn position_camera(&mut self, look_from: point3, look_at: point3, v_up: vec3) {
@@ -118,6 +341,11 @@ mod camera{
                 lens_radius: 0.0, // Infinite depth of field
                 focus_dist: 1.0, //Parameter is not used when lens_radius = 0
 
+                aperture: Aperture::Circular,
+                cat_eye: 0.0,
+                autofocus: None,
+                exposure: None,
+
                 w: w,
                 u: u,
                 v: v,
@@ -131,4 +359,4 @@ mod camera{
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -1,38 +1,83 @@
 pub use self::denoising::BlueNoise;
+pub use self::denoising::BlueNoiseConfig;
 
 
 mod denoising{
+    use std::f32::consts::PI;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::sync::OnceLock;
+    use rand::Rng;
+    use spherical_blue_noise::BlueNoiseSphere;
     use crate::vector::vec3;
 
+    // Sizes of the generated blue noise. Everything is written to cache_dir the first time
+    // and read back on later runs, since the screen space mask takes a while.
+    #[derive(Clone, Debug)]
+    pub struct BlueNoiseConfig {
+        pub screenspace_size: usize,
+        pub sphere_points: usize,
+        pub disc_points: usize,
+        pub cache_dir: Option<PathBuf>,
+    }
+
+    impl Default for BlueNoiseConfig {
+        fn default() -> Self {
+            BlueNoiseConfig{
+                screenspace_size: 128,
+                sphere_points: 256,
+                disc_points: 64,
+                cache_dir: Some(PathBuf::from(".blue_noise_cache")),
+            }
+        }
+    }
+
+    struct Tables {
+        screenspace_size: usize,
+        screenspace: Vec<f32>,
+        sphere: Vec<(f32, f32, f32)>,
+        disc: Vec<(f32, f32)>,
+    }
+
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+
     pub struct BlueNoise {}
     impl BlueNoise{
-        pub fn get_disc() -> Vec<(f32, f32)>{
-            disc_blue_noise.to_vec()
+        // Generates (or loads) the tables. Has to be called before rendering starts,
+        // otherwise the first lookup initialises them with the default config.
+        pub fn init(config: &BlueNoiseConfig){
+            if TABLES.set(Tables::new(config)).is_err() {
+                panic!("Blue noise was already initialised");
+            }
+        }
+        fn tables() -> &'static Tables{
+            TABLES.get_or_init(|| Tables::new(&BlueNoiseConfig::default()))
         }
         pub fn random_in_disc() -> (f32, f32){
             let seed = unsafe{
                 counter_disc = counter_disc +1;
                 counter_disc
             };
-            disc_blue_noise[(seed%disc_blue_noise.len() as u32) as usize]
+            let disc = &BlueNoise::tables().disc;
+            disc[(seed%disc.len() as u32) as usize]
         }
-        pub fn get_screenspace(i: usize, j: usize, screen_width: usize)-> f32{
-            let (i,j) = (i%128, j%128);
-            let idx = 128*i + j;
-            screenspace_blue_noise_128_128[idx]
+        pub fn get_screenspace(i: usize, j: usize, _screen_width: usize)-> f32{
+            let tables = BlueNoise::tables();
+            let size = tables.screenspace_size;
+            let (i,j) = (i%size, j%size);
+            let idx = size*i + j;
+            tables.screenspace[idx]
         }
-        pub fn get_spherical_64() -> Vec<(f32, f32, f32)>{
-            spherical_blue_noise_64.to_vec()
+        pub fn get_spherical() -> Vec<(f32, f32, f32)>{
+            BlueNoise::tables().sphere.clone()
         }
         pub fn blue_noise_cleanup(pixel_data: (usize,usize,usize)) -> vec3{
             let sp_bn = BlueNoise::get_screenspace(pixel_data.0, pixel_data.1, pixel_data.2);
             // let sp_bn = rand::thread_rng().gen_range(0.0 .. 1.0);
+            let sphere = &BlueNoise::tables().sphere;
             let seed = (sp_bn*32.0) as u32;
-            vec3::new(
-                spherical_blue_noise_64[(seed%spherical_blue_noise_64.len() as u32) as usize].0,
-                spherical_blue_noise_64[(seed%spherical_blue_noise_64.len() as u32) as usize].1,
-                spherical_blue_noise_64[(seed%spherical_blue_noise_64.len() as u32) as usize].2
-            )*0.1*sp_bn
+            let p = sphere[(seed%sphere.len() as u32) as usize];
+            vec3::new(p.0, p.1, p.2)*0.1*sp_bn
         }
         pub fn random_in_unit_sphere(pixel_data: (usize,usize,usize)) -> vec3 {
             let seed = unsafe{
@@ -41,22 +86,569 @@ mod denoising{
             };
             let sp_bn = BlueNoise::get_screenspace(pixel_data.0, pixel_data.1, pixel_data.2);
 
-            vec3::new(
-                spherical_blue_noise_256[(seed%spherical_blue_noise_256.len() as u32) as usize].0,
-                spherical_blue_noise_256[(seed%spherical_blue_noise_256.len() as u32) as usize].1,
-                spherical_blue_noise_256[(seed%spherical_blue_noise_256.len() as u32) as usize].2
-            )*sp_bn*1.1
-            // vec3::new(0.0,0.0,0.0)
+            let sphere = &BlueNoise::tables().sphere;
+            let p = sphere[(seed%sphere.len() as u32) as usize];
+            vec3::new(p.0, p.1, p.2)*sp_bn*1.1
         }
     }
 
     static mut counter_sphere: u32 = 0;
     static mut counter_disc: u32 = 0;
-    static disc_blue_noise: &'static [(f32, f32)] = &[(0.478712,0.875764),(-0.337956,-0.793959),(-0.955259,-0.028164),(0.864527,0.325689),(0.209342,-0.395657),(-0.106779,0.672585),(0.156213,0.235113),(-0.413644,-0.082856),(-0.415667,0.323909),(0.141896,-0.939980),(0.954932,-0.182516),(-0.766184,0.410799),(-0.434912,-0.458845),(0.415242,-0.078724),(0.728335,-0.491777),(-0.058086,-0.066401),(0.202990,0.686837),(-0.808362,-0.556402),(0.507386,-0.640839),(-0.723494,-0.229240),(0.489740,0.317826),(-0.622663,0.765301),(-0.010640,0.929347),(0.663146,0.647618),(-0.096674,-0.413835),(0.525945,-0.321063),(-0.122533,0.366019),(0.195235,-0.687983),(-0.563203,0.098748),(0.418563,0.561335),(-0.378595,0.800367),(0.826922,0.001024),(-0.085372,-0.766651),(-0.921920,0.183673),(-0.590008,-0.721799),(0.167751,-0.164393),(0.032961,-0.562530),(0.632900,-0.107059),(-0.464080,0.569669),(-0.173676,-0.958758),(-0.242648,-0.234303),(-0.275362,0.157163),(0.382295,-0.795131),(0.562955,0.115562),(0.190586,0.470121),(0.770764,-0.297576),(0.237281,0.931050),(-0.666642,-0.455871),(-0.905649,-0.298379),(0.339520,0.157829),(0.701438,-0.704100),(-0.062758,0.160346),(-0.220674,0.957141),(0.642692,0.432706),(-0.773390,-0.015272),(-0.671467,0.246880),(0.158051,0.062859),(0.806009,0.527232),(-0.057620,-0.247071),(0.333436,-0.516710),(-0.550658,-0.315773),(-0.652078,0.589846),(0.008818,0.530556),(-0.210004,0.519896)];
-    static spherical_blue_noise_64: &'static [(f32, f32, f32)] = &[(0.06719841, -0.95855117, -0.27688232), (0.9578699, -0.0032531766, -0.2871842), (-0.8169513, 0.25040236, -0.5195082), (0.4437619, 0.6144255, 0.6523469), (-0.20522565, 0.9204008, -0.33278316), (-0.34534046, 0.54488707, 0.7640928), (0.12369734, -0.56490684, 0.81583023), (-0.25005588, -0.76166075, 0.5977833), (0.80913216, 0.41768932, 0.41332895), (-0.4232499, 0.7900235, 0.44353345), (0.7863261, -0.31219956, -0.53312534), (0.4667257, -0.2580736, 0.84591085), (0.06979886, 0.8567019, 0.5110681), (-0.58510876, 0.8097824, -0.043592248), (-0.778557, 0.5645306, 0.2741425), (0.9481215, 0.31560096, 0.038226053), (0.51279813, 0.015121469, -0.8583759), (-0.878782, -0.44322756, -0.17689466), (0.74198866, 0.23762214, -0.6268878), (-0.11276091, -0.47617903, -0.87208855), (0.4190057, -0.719198, 0.5542457), (0.13493076, -0.74644786, -0.6516202), (0.058858782, 0.5146765, 0.8553616), (-0.8842624, -0.36535546, 0.29085237), (0.6012128, 0.78297126, -0.15968505), (-0.61283344, -0.7594801, -0.21823186), (0.0847191, -0.07356083, -0.9936859), (-0.6315406, 0.64514667, -0.43004888), (0.27817443, 0.8790498, -0.38715684), (0.9603933, -0.0602955, 0.2720467), (-0.2927667, -0.344799, 0.89185274), (0.4228658, 0.51119643, -0.74823976), (0.027571838, -0.9268116, 0.37451282), (0.5846646, 0.76913804, 0.2580584), (-0.6920372, -0.10532027, 0.71413696), (-0.6880917, 0.32648668, 0.64802474), (-0.2763285, -0.96059257, 0.030070698), (0.5335626, -0.6788355, -0.50447303), (0.71934, 0.11597821, 0.6849076), (0.61804205, -0.7744751, -0.13495344), (0.23013581, 0.9704936, 0.071968265), (-0.61610675, -0.75332814, 0.23001897), (0.8029452, 0.49623176, -0.33020124), (-0.93354183, 0.1029665, 0.34336263), (0.06210029, -0.09567437, 0.99347365), (0.30318362, -0.9497823, 0.07741359), (0.7226901, -0.6446429, 0.24930875), (0.042306624, 0.7202276, -0.692447), (-0.31802693, 0.13362445, 0.93861777), (0.9220656, -0.3814953, -0.065236494), (-0.59034693, -0.4842521, -0.64574784), (-0.39631927, -0.15794349, -0.904425), (0.39526618, -0.39908296, -0.82734346), (-0.19609348, 0.97428733, 0.110957816), (-0.30288544, -0.79785925, -0.5212301), (-0.4875078, 0.22043341, -0.8448343), (-0.34082714, 0.5958962, -0.7271478), (0.76323843, -0.34081197, 0.5489207), (-0.9173129, 0.38252744, -0.110497974), (-0.5981418, -0.5180011, 0.6114746), (0.016898068, 0.31932566, -0.9474942), (-0.8171272, -0.14419654, -0.5581311), (-0.9961651, -0.054192837, -0.06869483), (0.34877515, 0.22429883, 0.9099705)];
-    static spherical_blue_noise_16: &'static [(f32, f32, f32)] = &[(0.2642377, 0.8333146, -0.4855569), (0.95293754, 0.2802258, -0.11568863), (0.41527894, 0.121686935, -0.9015185), (0.3184647, 0.8710724, 0.373916), (-0.75308466, -0.22111271, 0.6196553), (-0.026836863, -0.7566735, -0.6532417), (-0.00034593372, -0.21745783, 0.9760697), (-0.9792196, 0.1657653, -0.11683571), (0.64774984, -0.65245926, 0.3933401), (0.6681226, 0.1880825, 0.7198869), (-0.52438706, 0.81266195, -0.25416327), (-0.7322591, -0.63822496, -0.23762493), (-0.45351753, 0.07909719, -0.8877305), (-0.14146732, -0.93057716, 0.33765846), (-0.4267974, 0.60968375, 0.66792965), (0.73487806, -0.53804797, -0.41286623)];
 
-    static spherical_blue_noise_256: &'static [(f32,f32,f32)] = &[(-0.14333059, 0.9247781, -0.35247958), (0.5324883, 0.06498823, 0.8439387), (0.829217, 0.2511124, 0.49934134), (0.37614325, 0.90762115, -0.18638626), (-0.5677737, 0.31048623, -0.7623851), (0.8858844, -0.40494522, -0.22633678), (-0.3509272, -0.2593886, 0.8997597), (0.6903419, 0.7233998, -0.0109744435), (-0.5609558, -0.6809966, 0.47071403), (0.5921286, 0.53091943, -0.60622436), (0.09206964, -0.5696905, -0.8166858), (0.323161, -0.041149, 0.9454488), (0.06378261, 0.8613659, -0.5039645), (-0.6281874, 0.29637644, 0.71940327), (0.91396505, 0.034525346, -0.4043212), (0.023557669, -0.3545613, -0.93473595), (0.07220403, 0.9543122, -0.28995627), (-0.266181, 0.7785773, 0.56830007), (-0.97820836, 0.20446193, -0.03610498), (0.4775378, 0.3644193, -0.79947245), (-0.895772, -0.009054812, 0.44442135), (0.77862006, 0.18442427, -0.5997816), (-0.7125955, 0.4390608, 0.54720485), (0.9684023, -0.21690346, -0.123083405), (-0.026144968, 0.62467515, -0.78044665), (0.80979234, -0.40090644, 0.42838094), (0.27643156, 0.40370026, 0.8721304), (0.77187485, 0.6064297, 0.1909243), (0.564531, -0.338278, 0.75290924), (-0.11060494, -0.5727854, -0.8122088), (-0.2618072, -0.94055605, -0.21635802), (-0.15927029, 0.29832944, -0.9410803), (-0.19206731, -0.35692552, -0.91417396), (-0.6082942, 0.7903612, 0.07284987), (0.6452517, 0.6816886, 0.34489202), (-0.8952721, 0.2049193, 0.39559573), (0.48735976, -0.8659696, 0.11214813), (-0.7848706, 0.21939223, 0.57952136), (0.92491174, 0.22094972, 0.30938527), (-0.2976263, -0.7216546, 0.6250065), (0.2560163, -0.24407735, 0.9353511), (0.12251735, -0.96867365, 0.21601012), (-0.06168048, 0.7566392, 0.6509166), (0.31012598, 0.76056945, -0.5703998), (0.2890475, -0.1309036, -0.94832253), (0.6477138, -0.15716235, -0.7454975), (-0.43013182, 0.79087687, 0.43531585), (0.10239335, -0.017512787, 0.99458975), (0.62185663, 0.13013034, -0.77224386), (-0.4486028, 0.78377855, -0.42947155), (0.5993652, 0.5950324, 0.5354416), (-0.8610522, -0.43685168, 0.26028717), (0.22621569, -0.34212095, -0.9120195), (0.04028964, -0.4543478, 0.8899126), (-0.99622107, -0.061671894, 0.06115203), (0.40981874, -0.8475021, -0.33732548), (-0.43267614, -0.84821784, -0.3054788), (-0.35624525, 0.41327253, -0.8380304), (0.4070154, -0.63056713, -0.6608504), (0.33440888, 0.5792961, 0.74336165), (0.6917354, 0.37559038, 0.6167932), (-0.36540058, 0.21548374, -0.9055655), (-0.70398515, -0.24307394, -0.6673226), (0.10972186, -0.9908774, -0.07825153), (-0.24740998, -0.70846874, -0.6609537), (-0.4049464, -0.3438065, -0.84724), (0.50028014, 0.6911374, -0.5215827), (0.5129297, -0.15317072, 0.8446548), (0.1535721, 0.71469796, -0.68236494), (-0.608373, 0.7745672, -0.1729954), (-0.088981755, 0.7762424, -0.62412316), (0.6045468, -0.5880017, -0.5373794), (0.7715902, -0.61087483, -0.17742543), (0.974177, 0.18393931, -0.13094018), (0.34142345, -0.808866, 0.47871226), (-0.99205434, 0.00931767, -0.12546334), (-0.11192942, -0.61905915, 0.7773271), (0.4859388, 0.8739751, -0.0055460464), (0.80250436, 0.5577124, -0.21199827), (0.6973369, -0.06367442, 0.71390957), (-0.46245283, 0.2981602, 0.83500755), (-0.31888092, -0.5577318, 0.7663224), (0.9013798, 0.36138415, -0.2385701), (0.2003449, 0.9160144, 0.3475333), (0.75114155, -0.5378544, -0.38275173), (0.85614187, -0.1608153, 0.49107972), (0.32544252, -0.8964, 0.3009218), (0.4092728, 0.16361351, -0.8976223), (0.66387117, -0.3636057, -0.6535025), (0.83839315, 0.5450476, -0.004551873), (-0.2441351, 0.96102786, -0.12970574), (-0.8927456, -0.44970587, 0.02774035), (-0.043267854, -0.75886005, -0.64981455), (-0.66828316, -0.74058306, -0.07024281), (0.7371885, -0.27277702, 0.6181793), (-0.060780447, -0.9831204, -0.17256866), (-0.8100858, -0.56899226, -0.14145128), (0.9663731, -0.24912839, 0.06370099), (-0.03114256, 0.9934812, -0.10965886), (0.25702283, 0.88625884, -0.38533723), (-0.6801387, -0.67916065, 0.27595586), (0.22822824, -0.5193775, 0.82350385), (0.63041574, 0.7474511, -0.20950583), (-0.35452056, -0.81578326, 0.4569603), (-0.08736592, -0.9948016, 0.05231545), (0.110894755, -0.87906367, 0.463626), (-0.9727178, 0.03842593, 0.22878633), (-0.38591233, 0.8830936, -0.26686543), (0.23235784, 0.08375399, -0.9690175), (-0.44666308, -0.41214013, 0.79412353), (-0.31141606, -0.032391123, 0.94972134), (-0.53400934, 0.63781756, -0.55499786), (0.18860412, -0.9261301, -0.32666805), (0.45819432, -0.2534183, -0.8519605), (-0.12184949, -0.14271441, -0.9822348), (0.10508403, 0.84505236, 0.52425545), (-0.26809564, 0.9138541, 0.30495036), (-0.9222866, -0.21142896, 0.3235502), (0.4987338, -0.8581476, -0.12184917), (-0.5882752, 0.09000572, 0.8036362), (0.55586284, -0.77789146, 0.29308832), (-0.9419142, 0.093916625, -0.32245526), (0.1200727, 0.6601851, 0.741443), (0.2542975, -0.71573174, 0.65043104), (-0.789519, 0.5688112, -0.23046361), (-0.31056866, -0.9500927, 0.029508252), (0.6070608, -0.72157776, -0.33287007), (0.99445367, -0.03480359, 0.09924886), (0.58260506, 0.7945938, 0.170854), (-0.54676175, -0.8304096, 0.10710552), (0.9419597, -0.0019386364, 0.33571982), (0.51990014, 0.50641215, 0.68793184), (0.6761695, -0.61422974, 0.40683705), (-0.51053613, -0.09828852, 0.8542202), (0.31786838, 0.9398095, 0.1253692), (-0.7422043, -0.52418566, 0.41756707), (-0.1137295, 0.06693647, 0.99125403), (-0.33621502, 0.6370635, 0.69362026), (-0.17020059, 0.06875345, -0.9830077), (0.87997544, 0.23114184, -0.4149896), (-0.6779075, -0.13572372, 0.7225098), (-0.6337493, -0.7241566, -0.2719536), (-0.8361468, 0.41584378, 0.35767648), (-0.11654769, 0.5885128, 0.80004334), (-0.01681312, -0.9219072, -0.38704488), (0.3124819, -0.9397108, -0.13892038), (0.5058761, -0.44051805, -0.7416424), (-0.47683692, -0.6108034, -0.63209647), (0.6593555, -0.7484303, -0.071428224), (0.9794993, 0.16865157, 0.11017089), (-0.44701368, 0.015470978, -0.89439315), (-0.73044807, -0.04056504, -0.68176234), (-0.7481459, 0.20012224, -0.63263625), (-0.7347471, -0.42840284, -0.52594465), (-0.17883636, 0.5088218, -0.84209114), (-0.77108586, -0.5305771, -0.35201433), (-0.9528497, 0.2511891, 0.17023934), (-0.10554555, 0.8836352, 0.45612392), (-0.8846677, -0.3724031, -0.28049695), (-0.9533676, -0.27340922, 0.12781824), (0.4943647, -0.041601874, -0.8682585), (-0.3245076, -0.1421381, -0.93514234), (0.08948491, -0.6651829, 0.74129885), (0.7723147, 0.47016254, 0.42717293), (0.8270151, 0.047153626, 0.56019855), (-0.32344282, -0.535491, -0.7801498), (0.20693421, 0.9732201, -0.10010196), (-0.2672824, 0.81911653, -0.5075507), (-0.13869141, -0.17442694, 0.97485363), (-0.15831636, 0.98274064, 0.09569008), (0.265447, 0.3355938, -0.9038333), (-0.29246065, -0.92460537, 0.24407315), (-0.17836286, -0.40605202, 0.89627457), (0.14394273, -0.84195614, -0.5199905), (-0.32000446, 0.4662816, 0.8247291), (0.3712413, 0.19386812, 0.908072), (-0.5175316, 0.5029556, 0.6922402), (-0.3980159, -0.77114046, -0.49691582), (-0.16653614, 0.37530333, 0.91181874), (0.69167835, 0.6103498, -0.38607478), (0.64184433, -0.48694298, 0.5923868), (0.39663488, 0.56556636, -0.7230596), (0.078129776, -0.089021765, -0.99296016), (-0.9136583, 0.3124884, -0.25995985), (-0.64491194, 0.6732501, -0.3616937), (-0.75445133, 0.6314171, 0.17920795), (0.681891, 0.16515082, 0.71256536), (-0.59832597, 0.09058915, -0.7961151), (0.9212475, -0.19850257, -0.33451307), (-0.96254915, -0.25319532, -0.096906886), (-0.1394311, -0.87892795, 0.456119), (0.09628411, 0.99038506, 0.0993297), (-0.302069, 0.6566963, -0.6910167), (-0.062023494, -0.95911866, 0.27612382), (0.28599298, 0.7436483, 0.6043136), (-0.5481034, 0.7868193, 0.28372177), (-0.4942323, -0.81972516, 0.2894564), (-0.7611681, 0.64784414, -0.030346125), (0.27874297, -0.957579, 0.073105976), (-0.60163724, -0.6468544, -0.46862748), (0.057669986, -0.23619694, 0.9699921), (-0.70179033, 0.60444677, 0.37700656), (-0.44926682, 0.8914972, -0.058237582), (0.6536832, 0.34457457, -0.6737705), (0.9852074, -0.005159396, -0.17128782), (0.77167356, -0.01612356, -0.63581395), (0.080104046, 0.46958578, 0.8792451), (-0.8461377, -0.22461809, -0.4833193), (-0.898256, 0.43208173, -0.0802603), (-0.8252792, -0.22521311, 0.5178735), (-0.20147745, -0.8616629, -0.4657719), (-0.5575845, 0.63887423, 0.5300371), (-0.042791273, -0.7921832, 0.60878104), (0.50901735, 0.32293782, 0.7978799), (0.89639384, -0.41021255, 0.16793981), (-0.46886078, -0.8779824, -0.09651959), (0.9358983, 0.35226864, 0.00096970575), (-0.84978926, 0.25465167, -0.46153075), (-0.77487975, 0.4753726, -0.41663146), (0.40195653, -0.75735015, -0.5146372), (-0.6121085, -0.31829828, 0.7238848), (-0.5715711, -0.43041474, -0.698605), (-0.77905256, 0.016458778, 0.6267424), (-0.52545565, -0.5681588, 0.63331825), (0.83670396, -0.16447654, -0.522373), (0.48838696, 0.8008215, -0.34664533), (0.9268702, -0.23499581, 0.29272565), (0.41324762, 0.86032826, 0.29843143), (-0.7687592, -0.63292795, 0.09171396), (-0.48588285, 0.5199703, -0.7025301), (0.7028102, -0.7019974, 0.11513966), (0.044624474, 0.40455836, -0.91342276), (-0.54735583, -0.17927074, -0.8174737), (0.044195425, 0.18183774, -0.9823348), (0.30475375, -0.50584495, -0.806998), (0.8581332, -0.5129116, -0.022997212), (-0.011117771, 0.9598416, 0.280322), (0.19974937, 0.5341967, -0.8214216), (-0.6767164, 0.42997566, -0.59764165), (-0.72327894, -0.39868763, 0.5638403), (0.8817868, 0.41468197, 0.22470027), (-0.3350301, 0.1646479, 0.92771), (0.18967184, -0.7097526, -0.678436), (0.77342, 0.424602, -0.47067446), (0.44828862, 0.7510782, 0.48468408), (0.7895365, -0.37431747, -0.48633155), (-0.38157743, 0.9136037, 0.14045055), (-0.8617895, 0.0054569296, -0.5072363), (-0.02002572, 0.25511009, 0.9667044), (0.4323953, -0.56571025, 0.70214355), (0.50555193, -0.687686, 0.5210614), (-0.9400735, -0.14261298, -0.3097143), (-0.8813375, 0.45416614, 0.13029587), (0.3884187, -0.37484318, 0.84179723), (0.17375948, 0.20949906, 0.96224594), (0.7903673, -0.5591944, 0.25024226)];
+    impl Tables {
+        fn new(config: &BlueNoiseConfig) -> Self {
+            let size = config.screenspace_size;
+            let screenspace = cached(config, &format!("screenspace_{}x{}", size, size), size*size, 1, || {
+                void_and_cluster(size)
+            });
+            let sphere = cached(config, &format!("sphere_{}", config.sphere_points), config.sphere_points, 3, || {
+                BlueNoiseSphere::new(config.sphere_points as u32, &mut rand::thread_rng())
+                    .into_iter().flat_map(|(x, y, z)| vec![x, y, z]).collect()
+            });
+            let disc = cached(config, &format!("disc_{}", config.disc_points), config.disc_points, 2, || {
+                best_candidate_disc(config.disc_points)
+                    .into_iter().flat_map(|(x, y)| vec![x, y]).collect()
+            });
+
+            Tables{
+                screenspace_size: size,
+                screenspace,
+                sphere: sphere.chunks(3).map(|p| (p[0], p[1], p[2])).collect(),
+                disc: disc.chunks(2).map(|p| (p[0], p[1])).collect(),
+            }
+        }
+    }
+
+    // Cache files are raw little endian f32s. A file with the wrong length is regenerated.
+    fn cached<F: FnOnce() -> Vec<f32>>(config: &BlueNoiseConfig, name: &str, count: usize, components: usize, generate: F) -> Vec<f32> {
+        if count == 0 {
+            panic!("Blue noise '{}' needs at least one point", name);
+        }
+        let path = config.cache_dir.as_ref().map(|dir| dir.join(format!("{}.bin", name)));
+        if let Some(data) = path.as_ref().and_then(|p| read_f32s(p, count*components)) {
+            return data;
+        }
+
+        println!("Generating blue noise '{}'...", name);
+        let data = generate();
+        if let Some(path) = path {
+            let bytes: Vec<u8> = data.iter().flat_map(|v| v.to_le_bytes()).collect();
+            let written = path.parent().map_or(Ok(()), fs::create_dir_all).and_then(|_| fs::write(&path, bytes));
+            if let Err(e) = written {
+                println!("Could not cache blue noise to {}: {}", path.display(), e);
+            }
+        }
+        data
+    }
+
+    fn read_f32s(path: &Path, len: usize) -> Option<Vec<f32>> {
+        let bytes = fs::read(path).ok()?;
+        if bytes.len() != 4*len { return None; }
+        Some(bytes.chunks(4).map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect())
+    }
+
+    // Ulichney's void-and-cluster method. Returns the rank of every pixel of a tileable
+    // size x size mask, scaled to [0,1).
+    fn void_and_cluster(size: usize) -> Vec<f32> {
+        let n = size*size;
+        let sigma = 1.5f32;
+        let mut rng = rand::thread_rng();
+
+        // Gaussian energy for every wrapped offset, truncated to a window
+        let radius = ((4.0*sigma).ceil() as usize).min((size - 1)/2) as isize;
+        let mut kernel = Vec::new();
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                kernel.push((dx, dy, (-((dx*dx + dy*dy) as f32)/(2.0*sigma*sigma)).exp()));
+            }
+        }
+        let splat = |energy: &mut Vec<f32>, p: usize, sign: f32| {
+            let (px, py) = ((p % size) as isize, (p / size) as isize);
+            for &(dx, dy, w) in &kernel {
+                let x = (px + dx).rem_euclid(size as isize) as usize;
+                let y = (py + dy).rem_euclid(size as isize) as usize;
+                energy[y*size + x] += sign*w;
+            }
+        };
+        // Tightest cluster is the set pixel with the most energy, largest void the empty one with the least
+        let tightest_cluster = |pattern: &Vec<bool>, energy: &Vec<f32>| {
+            (0..n).filter(|&p| pattern[p]).max_by(|&a, &b| energy[a].partial_cmp(&energy[b]).unwrap()).unwrap()
+        };
+        let largest_void = |pattern: &Vec<bool>, energy: &Vec<f32>| {
+            (0..n).filter(|&p| !pattern[p]).min_by(|&a, &b| energy[a].partial_cmp(&energy[b]).unwrap()).unwrap()
+        };
 
-    static screenspace_blue_noise_128_128: &'static [f32] = &[0.3686274509803922, 0.6941176470588235, 0.6274509803921569, 0.7725490196078432, 0.058823529411764705, 0.8431372549019608, 0.27058823529411763, 0.1411764705882353, 0.4627450980392157, 0.996078431372549, 0.5764705882352941, 0.2823529411764706, 0.6196078431372549, 0.792156862745098, 0.3137254901960784, 0.6980392156862745, 0.9215686274509803, 0.5372549019607843, 0.7568627450980392, 0.24313725490196078, 0.1411764705882353, 0.5137254901960784, 0.7843137254901961, 0.2784313725490196, 0.9882352941176471, 0.14901960784313725, 0.2196078431372549, 0.44313725490196076, 0.023529411764705882, 0.7294117647058823, 0.3411764705882353, 0.9725490196078431, 0.5098039215686274, 0.2980392156862745, 0.07058823529411765, 0.8470588235294118, 0.5215686274509804, 0.2549019607843137, 0.8745098039215686, 0.792156862745098, 0.6549019607843137, 0.23921568627450981, 0.5372549019607843, 0.9450980392156862, 0.07450980392156863, 0.6666666666666666, 0.36470588235294116, 0.8470588235294118, 0.6941176470588235, 0.9215686274509803, 0.25882352941176473, 0.7843137254901961, 0.6627450980392157, 0.3607843137254902, 0.2235294117647059, 0.4980392156862745, 0.8313725490196079, 0.3254901960784314, 0.6431372549019608, 0.9019607843137255, 0.7294117647058823, 0.8156862745098039, 0.9490196078431372, 0.6, 0.10980392156862745, 0.7529411764705882, 0.8117647058823529, 0.03137254901960784, 0.6196078431372549, 0.47058823529411764, 0.09019607843137255, 0.5490196078431373, 0.33725490196078434, 0.2235294117647059, 0.45098039215686275, 0.0392156862745098, 0.7803921568627451, 0.5254901960784314, 0.6862745098039216, 0.2235294117647059, 0.7647058823529411, 0.11764705882352941, 0.5882352941176471, 0.3764705882352941, 0.023529411764705882, 0.8156862745098039, 0.9254901960784314, 0.6941176470588235, 0.41568627450980394, 0.32941176470588235, 0.8980392156862745, 0.6039215686274509, 0.2627450980392157, 0.9921568627450981, 0.5333333333333333, 0.09019607843137255, 0.3843137254901961, 0.7058823529411765, 0.12941176470588237, 0.5176470588235295, 0.18823529411764706, 0.6627450980392157, 0.32941176470588235, 0.41568627450980394, 0.6235294117647059, 0.27450980392156865, 0.36470588235294116, 0.6039215686274509, 0.7450980392156863, 0.06274509803921569, 0.5490196078431373, 0.9921568627450981, 0.3568627450980392, 0.7098039215686275, 0.9176470588235294, 0.3058823529411765, 0.9803921568627451, 0.5294117647058824, 0.20392156862745098, 0.07450980392156863, 0.49019607843137253, 0.22745098039215686, 0.8549019607843137, 0.9411764705882353, 0.08235294117647059, 0.43529411764705883, 0.9098039215686274, 0.5764705882352941, 0.11764705882352941, 0.9764705882352941, 0.01568627450980392, 0.47843137254901963, 0.3568627450980392, 0.6745098039215687, 0.9098039215686274, 0.5450980392156862, 0.3215686274509804, 0.8352941176470589, 0.16862745098039217, 0.6784313725490196, 0.09411764705882353, 0.49411764705882355, 0.8941176470588236, 0.22745098039215686, 0.47058823529411764, 0.07058823529411765, 0.29411764705882354, 0.615686274509804, 0.6823529411764706, 0.9254901960784314, 0.1843137254901961, 0.4627450980392157, 0.6588235294117647, 0.058823529411764705, 0.7607843137254902, 0.9294117647058824, 0.6392156862745098, 0.08235294117647059, 0.5647058823529412, 0.13725490196078433, 0.7686274509803922, 0.4549019607843137, 0.9294117647058824, 0.3843137254901961, 0.7372549019607844, 0.09019607843137255, 0.47058823529411764, 0.5882352941176471, 0.14901960784313725, 0.39215686274509803, 0.027450980392156862, 0.6313725490196078, 0.3176470588235294, 0.7764705882352941, 0.9725490196078431, 0.047058823529411764, 0.2980392156862745, 0.43529411764705883, 0.09803921568627451, 0.011764705882352941, 0.7333333333333333, 0.4196078431372549, 0.8823529411764706, 0.027450980392156862, 0.7725490196078432, 0.5411764705882353, 0.4117647058823529, 0.050980392156862744, 0.2549019607843137, 0.36470588235294116, 0.011764705882352941, 0.30980392156862746, 0.5058823529411764, 0.37254901960784315, 0.27058823529411763, 0.5647058823529412, 0.8941176470588236, 0.7176470588235294, 0.9803921568627451, 0.01568627450980392, 0.7607843137254902, 0.9411764705882353, 0.6705882352941176, 0.8313725490196079, 0.3215686274509804, 0.16470588235294117, 0.8823529411764706, 0.011764705882352941, 0.984313725490196, 0.4588235294117647, 0.7019607843137254, 0.9411764705882353, 0.3215686274509804, 0.5137254901960784, 0.4470588235294118, 0.08235294117647059, 0.5450980392156862, 0.2235294117647059, 0.12549019607843137, 0.796078431372549, 0.42745098039215684, 0.16470588235294117, 0.8705882352941177, 0.4666666666666667, 0.807843137254902, 0.2235294117647059, 0.8823529411764706, 0.42745098039215684, 0.6039215686274509, 0.10196078431372549, 0.7607843137254902, 0.058823529411764705, 0.8823529411764706, 0.7215686274509804, 0.47058823529411764, 0.9215686274509803, 0.13333333333333333, 0.3137254901960784, 0.8431372549019608, 0.4627450980392157, 0.2627450980392157, 0.1450980392156863, 0.5058823529411764, 0.09019607843137255, 0.788235294117647, 0.4470588235294118, 0.3333333333333333, 0.5764705882352941, 0.9647058823529412, 0.6823529411764706, 0.3568627450980392, 0.13333333333333333, 0.6392156862745098, 0.30196078431372547, 0.043137254901960784, 0.8627450980392157, 0.4117647058823529, 0.5176470588235295, 0.3058823529411765, 0.8823529411764706, 0.23137254901960785, 0.596078431372549, 0.09411764705882353, 0.7607843137254902, 0.2196078431372549, 0.7098039215686275, 0.41568627450980394, 0.8784313725490196, 0.37254901960784315, 0.7529411764705882, 0.050980392156862744, 0.5725490196078431, 0.6588235294117647, 0.8588235294117647, 1.0, 0.43529411764705883, 0.3686274509803922, 0.5607843137254902, 0.0392156862745098, 0.7411764705882353, 0.33725490196078434, 0.5764705882352941, 0.8313725490196079, 0.3843137254901961, 0.5176470588235295, 0.9058823529411765, 0.42745098039215684, 0.26666666666666666, 0.6666666666666666, 0.00784313725490196, 0.8117647058823529, 0.2196078431372549, 0.6274509803921569, 0.9921568627450981, 0.03529411764705882, 0.34901960784313724, 0.6941176470588235, 0.9686274509803922, 0.49411764705882355, 0.8117647058823529, 0.45098039215686275, 0.25098039215686274, 0.5607843137254902, 0.49019607843137253, 0.6431372549019608, 0.8196078431372549, 0.9803921568627451, 0.5568627450980392, 0.3137254901960784, 0.17647058823529413, 0.6196078431372549, 0.10980392156862745, 0.6901960784313725, 0.15294117647058825, 0.8705882352941177, 0.19607843137254902, 0.5254901960784314, 0.6666666666666666, 0.7843137254901961, 0.2, 0.7137254901960784, 0.8627450980392157, 0.4470588235294118, 0.17647058823529413, 0.12549019607843137, 0.4, 0.29411764705882354, 0.8156862745098039, 0.3843137254901961, 0.5215686274509804, 0.06666666666666667, 0.27450980392156865, 0.5803921568627451, 0.6352941176470588, 0.4392156862745098, 0.35294117647058826, 0.5568627450980392, 0.2627450980392157, 0.19215686274509805, 0.07058823529411765, 0.7568627450980392, 0.6313725490196078, 0.14901960784313725, 0.7843137254901961, 0.9686274509803922, 0.7254901960784313, 0.8588235294117647, 0.5058823529411764, 0.3568627450980392, 0.03137254901960784, 0.7411764705882353, 0.29411764705882354, 0.054901960784313725, 0.6470588235294118, 0.33725490196078434, 0.0, 0.8235294117647058, 0.2901960784313726, 0.9529411764705882, 0.2196078431372549, 0.5215686274509804, 0.8117647058823529, 0.00784313725490196, 0.25098039215686274, 0.4, 0.6823529411764706, 0.19607843137254902, 0.03529411764705882, 0.7607843137254902, 0.4117647058823529, 0.6784313725490196, 0.8470588235294118, 0.6039215686274509, 0.13725490196078433, 0.9058823529411765, 0.7607843137254902, 0.2823529411764706, 0.058823529411764705, 0.6, 0.4627450980392157, 0.7176470588235294, 0.792156862745098, 0.5450980392156862, 0.23529411764705882, 0.611764705882353, 0.8274509803921568, 0.19607843137254902, 0.7294117647058823, 0.12549019607843137, 0.984313725490196, 0.4, 0.0196078431372549, 0.6235294117647059, 0.9568627450980393, 0.11764705882352941, 0.5333333333333333, 0.25882352941176473, 0.1843137254901961, 0.9764705882352941, 0.403921568627451, 0.12941176470588237, 0.34509803921568627, 0.19215686274509805, 0.792156862745098, 0.10980392156862745, 0.8784313725490196, 0.8235294117647058, 0.23137254901960785, 0.9450980392156862, 0.49411764705882355, 0.12156862745098039, 0.25098039215686274, 0.16862745098039217, 0.6941176470588235, 0.8509803921568627, 0.20392156862745098, 0.9529411764705882, 0.592156862745098, 0.34509803921568627, 0.14901960784313725, 0.43529411764705883, 0.29411764705882354, 0.7686274509803922, 0.8431372549019608, 0.20784313725490197, 0.2784313725490196, 0.8784313725490196, 0.09019607843137255, 0.19607843137254902, 0.9058823529411765, 0.12156862745098039, 0.7490196078431373, 0.16862745098039217, 0.3764705882352941, 0.22745098039215686, 0.5058823529411764, 0.9019607843137255, 0.803921568627451, 0.47058823529411764, 0.996078431372549, 0.3843137254901961, 0.29411764705882354, 0.7529411764705882, 0.592156862745098, 0.9647058823529412, 0.13725490196078433, 0.4196078431372549, 0.9254901960784314, 0.6313725490196078, 0.043137254901960784, 0.9882352941176471, 0.6784313725490196, 0.7764705882352941, 0.4980392156862745, 0.23921568627450981, 0.615686274509804, 0.16470588235294117, 0.7098039215686275, 0.9058823529411765, 0.4117647058823529, 0.12156862745098039, 0.9647058823529412, 0.7333333333333333, 0.0784313725490196, 0.8392156862745098, 0.6549019607843137, 0.9058823529411765, 0.403921568627451, 0.8549019607843137, 0.23137254901960785, 0.5725490196078431, 0.36470588235294116, 0.2823529411764706, 0.1803921568627451, 0.047058823529411764, 0.6784313725490196, 0.9333333333333333, 0.5607843137254902, 0.6313725490196078, 0.19607843137254902, 0.9764705882352941, 0.7647058823529411, 0.5450980392156862, 0.9411764705882353, 0.7176470588235294, 0.4627450980392157, 0.5607843137254902, 0.6941176470588235, 0.34509803921568627, 0.16862745098039217, 0.6588235294117647, 0.5725490196078431, 0.8705882352941177, 0.9607843137254902, 0.5215686274509804, 0.6196078431372549, 0.9411764705882353, 0.8823529411764706, 0.2235294117647059, 0.00784313725490196, 0.36470588235294116, 0.25098039215686274, 0.7098039215686275, 0.023529411764705882, 0.8196078431372549, 0.17254901960784313, 0.9254901960784314, 0.396078431372549, 0.20784313725490197, 1.0, 0.15294117647058825, 0.6823529411764706, 0.9294117647058824, 0.08627450980392157, 0.7843137254901961, 0.5607843137254902, 0.44313725490196076, 0.6980392156862745, 0.5098039215686274, 0.8666666666666667, 0.28627450980392155, 0.48627450980392156, 0.058823529411764705, 0.9254901960784314, 0.6, 0.807843137254902, 0.7058823529411765, 0.2823529411764706, 0.8313725490196079, 0.592156862745098, 0.7254901960784313, 0.01568627450980392, 0.48627450980392156, 0.2901960784313726, 0.6313725490196078, 0.396078431372549, 0.08627450980392157, 0.7176470588235294, 0.3058823529411765, 0.9725490196078431, 0.7725490196078432, 0.3215686274509804, 0.047058823529411764, 0.3803921568627451, 0.4823529411764706, 0.10980392156862745, 0.8901960784313725, 0.6901960784313725, 0.5568627450980392, 0.9176470588235294, 0.49019607843137253, 0.12156862745098039, 0.5411764705882353, 0.7411764705882353, 0.592156862745098, 0.35294117647058826, 0.7176470588235294, 0.6196078431372549, 0.403921568627451, 0.0196078431372549, 0.8823529411764706, 0.6, 0.7137254901960784, 0.1411764705882353, 0.6509803921568628, 0.043137254901960784, 0.26666666666666666, 0.5686274509803921, 0.20784313725490197, 0.9294117647058824, 0.4588235294117647, 0.09019607843137255, 0.3333333333333333, 0.8352941176470589, 0.47843137254901963, 0.2627450980392157, 0.10196078431372549, 0.5803921568627451, 0.22745098039215686, 0.3411764705882353, 0.07450980392156863, 0.9529411764705882, 0.5607843137254902, 0.8627450980392157, 0.10980392156862745, 0.4666666666666667, 0.21176470588235294, 0.7843137254901961, 0.8549019607843137, 0.4980392156862745, 0.18823529411764706, 0.3058823529411765, 0.792156862745098, 0.1411764705882353, 0.6, 0.49019607843137253, 0.29411764705882354, 0.9882352941176471, 0.00784313725490196, 0.9137254901960784, 0.6470588235294118, 0.47058823529411764, 0.5882352941176471, 0.30196078431372547, 0.23137254901960785, 0.11764705882352941, 0.8352941176470589, 0.4, 0.4823529411764706, 0.13725490196078433, 0.24705882352941178, 0.3764705882352941, 0.16862745098039217, 0.8588235294117647, 0.12156862745098039, 0.4, 0.07058823529411765, 0.9764705882352941, 0.4392156862745098, 0.7647058823529411, 0.09803921568627451, 0.3607843137254902, 0.7294117647058823, 0.28627450980392155, 0.10588235294117647, 0.33725490196078434, 0.5647058823529412, 0.4666666666666667, 0.9450980392156862, 0.6313725490196078, 0.403921568627451, 0.5411764705882353, 0.47843137254901963, 0.32941176470588235, 0.8666666666666667, 0.5333333333333333, 0.011764705882352941, 0.8431372549019608, 0.4823529411764706, 0.34901960784313724, 0.26666666666666666, 0.03137254901960784, 0.39215686274509803, 0.9137254901960784, 0.32941176470588235, 0.2549019607843137, 0.8196078431372549, 0.1803921568627451, 0.35294117647058826, 0.7843137254901961, 0.43529411764705883, 0.6666666666666666, 0.3254901960784314, 0.011764705882352941, 0.5176470588235295, 0.4549019607843137, 0.0784313725490196, 0.9372549019607843, 0.2627450980392157, 0.5294117647058824, 0.9019607843137255, 0.6980392156862745, 0.1568627450980392, 0.4470588235294118, 0.592156862745098, 0.8980392156862745, 0.6666666666666666, 0.00784313725490196, 0.4627450980392157, 0.5686274509803921, 0.6274509803921569, 0.8156862745098039, 0.7372549019607844, 0.5294117647058824, 0.27450980392156865, 0.792156862745098, 0.058823529411764705, 0.18823529411764706, 0.6509803921568628, 0.4, 0.9372549019607843, 0.011764705882352941, 0.43529411764705883, 0.15294117647058825, 1.0, 0.5215686274509804, 0.803921568627451, 0.32941176470588235, 0.9568627450980393, 0.2823529411764706, 0.06274509803921569, 0.44313725490196076, 0.9411764705882353, 0.3411764705882353, 0.7372549019607844, 0.8509803921568627, 0.6705882352941176, 0.0, 0.8196078431372549, 0.6392156862745098, 0.7215686274509804, 0.0392156862745098, 0.5529411764705883, 0.8862745098039215, 0.38823529411764707, 0.7333333333333333, 0.5215686274509804, 0.8196078431372549, 0.43137254901960786, 0.6431372549019608, 0.047058823529411764, 0.35294117647058826, 0.9333333333333333, 0.3058823529411765, 0.6274509803921569, 0.0, 0.36470588235294116, 0.25098039215686274, 0.6941176470588235, 0.9215686274509803, 0.5215686274509804, 0.3686274509803922, 0.0392156862745098, 0.7411764705882353, 0.11372549019607843, 0.6823529411764706, 0.43137254901960786, 0.807843137254902, 0.10196078431372549, 0.39215686274509803, 0.7647058823529411, 0.9803921568627451, 0.43529411764705883, 0.7058823529411765, 0.9019607843137255, 0.3254901960784314, 0.5882352941176471, 0.9137254901960784, 0.6862745098039216, 0.5058823529411764, 0.06274509803921569, 0.6352941176470588, 0.26666666666666666, 0.9098039215686274, 0.6078431372549019, 0.8313725490196079, 0.30980392156862746, 0.22745098039215686, 0.49411764705882355, 0.0196078431372549, 0.17254901960784313, 0.4470588235294118, 0.807843137254902, 0.6588235294117647, 0.1568627450980392, 0.7372549019607844, 0.8313725490196079, 0.09803921568627451, 0.1843137254901961, 0.9882352941176471, 0.6509803921568628, 0.12156862745098039, 0.7529411764705882, 0.2549019607843137, 0.6274509803921569, 0.3137254901960784, 0.10196078431372549, 0.7411764705882353, 0.6470588235294118, 0.4666666666666667, 0.592156862745098, 0.1607843137254902, 0.047058823529411764, 0.6470588235294118, 0.0784313725490196, 0.9372549019607843, 0.5803921568627451, 0.7215686274509804, 0.22745098039215686, 0.8392156862745098, 0.12941176470588237, 0.3803921568627451, 0.8823529411764706, 0.6352941176470588, 0.1607843137254902, 0.7764705882352941, 0.41568627450980394, 0.6509803921568628, 0.21568627450980393, 0.3333333333333333, 0.984313725490196, 0.03137254901960784, 0.8431372549019608, 0.20392156862745098, 0.5333333333333333, 0.40784313725490196, 0.8666666666666667, 0.09411764705882353, 0.9411764705882353, 0.1450980392156863, 0.23137254901960785, 0.9882352941176471, 0.027450980392156862, 0.4117647058823529, 0.8666666666666667, 0.7215686274509804, 0.25098039215686274, 0.06666666666666667, 0.8, 0.3176470588235294, 0.6784313725490196, 0.8392156862745098, 0.043137254901960784, 0.25882352941176473, 0.6862745098039216, 0.20392156862745098, 0.47058823529411764, 0.5450980392156862, 0.7647058823529411, 0.8666666666666667, 0.39215686274509803, 0.19215686274509805, 0.5254901960784314, 0.0784313725490196, 0.4196078431372549, 0.34901960784313724, 0.5176470588235295, 0.27058823529411763, 0.2196078431372549, 0.9921568627450981, 0.16862745098039217, 0.7686274509803922, 0.3137254901960784, 0.9450980392156862, 0.13333333333333333, 0.8784313725490196, 0.2823529411764706, 0.7215686274509804, 0.20392156862745098, 0.8, 0.6823529411764706, 0.5372549019607843, 0.9607843137254902, 0.7490196078431373, 0.5686274509803921, 0.10196078431372549, 0.611764705882353, 0.054901960784313725, 0.42745098039215684, 0.2196078431372549, 0.9607843137254902, 0.8235294117647058, 0.5490196078431373, 0.34509803921568627, 0.2, 0.7215686274509804, 0.5215686274509804, 0.8823529411764706, 0.14901960784313725, 0.8196078431372549, 0.0784313725490196, 0.5098039215686274, 0.25882352941176473, 0.7843137254901961, 0.09411764705882353, 0.027450980392156862, 0.8431372549019608, 0.3058823529411765, 0.7843137254901961, 0.9725490196078431, 0.4470588235294118, 0.7294117647058823, 0.2, 0.5333333333333333, 0.13725490196078433, 0.8901960784313725, 0.6431372549019608, 0.788235294117647, 0.596078431372549, 0.9254901960784314, 0.5372549019607843, 0.06666666666666667, 0.9803921568627451, 0.26666666666666666, 0.5137254901960784, 0.30980392156862746, 0.7803921568627451, 0.8823529411764706, 0.23137254901960785, 0.4235294117647059, 0.6980392156862745, 0.08627450980392157, 0.9098039215686274, 0.7764705882352941, 0.42745098039215684, 0.9686274509803922, 0.2196078431372549, 0.8784313725490196, 0.7098039215686275, 0.796078431372549, 0.9529411764705882, 0.5411764705882353, 0.7529411764705882, 0.4588235294117647, 0.1450980392156863, 0.03137254901960784, 0.9921568627450981, 0.5490196078431373, 0.19607843137254902, 0.7333333333333333, 0.9647058823529412, 0.23137254901960785, 0.5568627450980392, 0.3568627450980392, 0.054901960784313725, 0.9568627450980393, 0.12156862745098039, 0.7490196078431373, 0.5529411764705883, 0.7764705882352941, 0.26666666666666666, 0.36470588235294116, 0.13333333333333333, 0.8, 0.18823529411764706, 0.28627450980392155, 0.7137254901960784, 0.3607843137254902, 0.44313725490196076, 0.6588235294117647, 0.3176470588235294, 0.615686274509804, 0.5098039215686274, 0.36470588235294116, 0.9803921568627451, 0.5686274509803921, 0.4588235294117647, 0.8980392156862745, 0.23137254901960785, 0.5019607843137255, 0.38823529411764707, 0.9254901960784314, 0.5803921568627451, 0.07450980392156863, 0.8509803921568627, 0.6588235294117647, 0.11764705882352941, 0.2549019607843137, 0.6039215686274509, 0.8, 0.13333333333333333, 0.9686274509803922, 0.615686274509804, 0.7764705882352941, 0.11764705882352941, 0.8980392156862745, 0.44313725490196076, 0.3764705882352941, 0.6039215686274509, 0.6705882352941176, 0.00784313725490196, 0.4588235294117647, 0.18823529411764706, 0.6, 0.023529411764705882, 0.9254901960784314, 0.4823529411764706, 0.4, 0.09411764705882353, 0.24313725490196078, 0.42745098039215684, 0.14901960784313725, 0.8941176470588236, 0.47058823529411764, 0.996078431372549, 0.7686274509803922, 0.6705882352941176, 0.8784313725490196, 0.26666666666666666, 0.4549019607843137, 0.16470588235294117, 0.6196078431372549, 0.8980392156862745, 0.2627450980392157, 0.03137254901960784, 0.32941176470588235, 0.21568627450980393, 0.6196078431372549, 0.3607843137254902, 0.00784313725490196, 0.6666666666666666, 0.1843137254901961, 0.45098039215686275, 0.7333333333333333, 0.615686274509804, 0.4196078431372549, 0.18823529411764706, 0.5686274509803921, 0.011764705882352941, 0.3568627450980392, 0.792156862745098, 0.043137254901960784, 0.6980392156862745, 0.38823529411764707, 0.996078431372549, 0.2549019607843137, 0.8588235294117647, 0.40784313725490196, 0.21176470588235294, 0.37254901960784315, 0.8509803921568627, 0.42745098039215684, 0.6823529411764706, 0.0392156862745098, 0.5725490196078431, 0.35294117647058826, 0.058823529411764705, 0.592156862745098, 0.9529411764705882, 0.49019607843137253, 0.37254901960784315, 0.5843137254901961, 0.16862745098039217, 0.5294117647058824, 0.12549019607843137, 0.29411764705882354, 0.3607843137254902, 0.4980392156862745, 0.20784313725490197, 0.2784313725490196, 0.3686274509803922, 0.8941176470588236, 0.6823529411764706, 0.3137254901960784, 0.4, 0.6235294117647059, 0.9058823529411765, 0.49019607843137253, 0.0392156862745098, 0.30980392156862746, 0.8549019607843137, 0.7137254901960784, 0.4745098039215686, 0.8274509803921568, 0.6078431372549019, 0.3843137254901961, 0.4823529411764706, 0.07058823529411765, 0.9254901960784314, 0.6352941176470588, 0.7254901960784313, 0.996078431372549, 0.6039215686274509, 0.49019607843137253, 0.8862745098039215, 0.5490196078431373, 0.0784313725490196, 0.7725490196078432, 0.9215686274509803, 0.1568627450980392, 0.09019607843137255, 0.8235294117647058, 0.3058823529411765, 0.12941176470588237, 0.611764705882353, 0.17254901960784313, 0.7529411764705882, 0.6470588235294118, 0.11764705882352941, 0.30196078431372547, 0.7764705882352941, 0.15294117647058825, 0.4117647058823529, 0.34509803921568627, 0.9882352941176471, 0.011764705882352941, 0.49019607843137253, 0.6823529411764706, 0.30196078431372547, 0.8862745098039215, 0.24313725490196078, 0.17647058823529413, 0.9411764705882353, 0.5764705882352941, 0.6941176470588235, 0.8588235294117647, 0.0784313725490196, 0.4980392156862745, 0.23921568627450981, 0.8470588235294118, 0.6901960784313725, 0.396078431372549, 0.3333333333333333, 0.7647058823529411, 0.1568627450980392, 0.5882352941176471, 0.8431372549019608, 0.0392156862745098, 0.7176470588235294, 0.33725490196078434, 0.8235294117647058, 0.2784313725490196, 0.39215686274509803, 0.16862745098039217, 0.3215686274509804, 0.5647058823529412, 0.01568627450980392, 0.6980392156862745, 0.9450980392156862, 0.06666666666666667, 0.4823529411764706, 0.8313725490196079, 0.5803921568627451, 0.6784313725490196, 0.9215686274509803, 0.5411764705882353, 0.7411764705882353, 0.9450980392156862, 0.8666666666666667, 0.5490196078431373, 0.984313725490196, 0.36470588235294116, 0.23137254901960785, 0.9411764705882353, 0.10196078431372549, 0.6745098039215687, 0.8823529411764706, 0.5019607843137255, 0.29411764705882354, 0.9490196078431372, 0.4549019607843137, 0.5725490196078431, 0.3333333333333333, 0.054901960784313725, 0.12156862745098039, 0.7098039215686275, 0.3058823529411765, 0.7725490196078432, 0.023529411764705882, 0.611764705882353, 0.20392156862745098, 0.9333333333333333, 0.7215686274509804, 0.4588235294117647, 0.8196078431372549, 0.16470588235294117, 0.3058823529411765, 0.796078431372549, 0.20392156862745098, 0.043137254901960784, 0.6901960784313725, 0.8392156862745098, 0.7607843137254902, 0.00784313725490196, 0.9921568627450981, 0.09803921568627451, 0.8509803921568627, 0.42745098039215684, 0.6274509803921569, 0.10588235294117647, 0.23921568627450981, 0.5058823529411764, 0.8117647058823529, 0.07058823529411765, 0.28627450980392155, 0.43137254901960786, 0.788235294117647, 0.6627450980392157, 0.08627450980392157, 0.9176470588235294, 0.2, 0.29411764705882354, 0.00392156862745098, 0.8745098039215686, 0.17647058823529413, 0.6823529411764706, 0.30980392156862746, 0.5098039215686274, 0.43529411764705883, 0.03137254901960784, 0.33725490196078434, 0.24313725490196078, 0.6705882352941176, 0.01568627450980392, 0.8313725490196079, 0.2, 0.5725490196078431, 0.4666666666666667, 0.8941176470588236, 0.2235294117647059, 0.6862745098039216, 0.7803921568627451, 0.9450980392156862, 0.37254901960784315, 0.0784313725490196, 0.5529411764705883, 0.8666666666666667, 0.44313725490196076, 0.7254901960784313, 0.9450980392156862, 0.5137254901960784, 0.8196078431372549, 0.2, 0.7254901960784313, 0.9137254901960784, 0.43529411764705883, 0.0392156862745098, 0.5568627450980392, 0.4627450980392157, 0.7411764705882353, 0.396078431372549, 0.0196078431372549, 0.3058823529411765, 0.1450980392156863, 0.7843137254901961, 0.3568627450980392, 0.9647058823529412, 0.5490196078431373, 0.792156862745098, 0.10588235294117647, 0.996078431372549, 0.5294117647058824, 0.2627450980392157, 0.9098039215686274, 0.6392156862745098, 0.984313725490196, 0.5058823529411764, 0.2, 0.6588235294117647, 0.023529411764705882, 0.5411764705882353, 0.8431372549019608, 0.12156862745098039, 0.6313725490196078, 0.788235294117647, 0.403921568627451, 0.3058823529411765, 0.7607843137254902, 0.3764705882352941, 0.13333333333333333, 1.0, 0.4470588235294118, 0.054901960784313725, 0.27058823529411763, 0.47058823529411764, 0.12156862745098039, 0.403921568627451, 0.6274509803921569, 0.28627450980392155, 0.1450980392156863, 0.8549019607843137, 0.5372549019607843, 0.3333333333333333, 0.7490196078431373, 0.2196078431372549, 0.12941176470588237, 0.6509803921568628, 0.8509803921568627, 0.08627450980392157, 0.16862745098039217, 0.7529411764705882, 0.5254901960784314, 0.47058823529411764, 0.6549019607843137, 0.9607843137254902, 0.5607843137254902, 0.1450980392156863, 0.9019607843137255, 0.49411764705882355, 0.3843137254901961, 0.13333333333333333, 0.25882352941176473, 0.9725490196078431, 0.6705882352941176, 0.0, 0.5450980392156862, 0.8588235294117647, 0.2784313725490196, 0.9372549019607843, 0.3843137254901961, 0.6078431372549019, 0.4392156862745098, 0.6588235294117647, 0.5725490196078431, 0.7019607843137254, 0.027450980392156862, 0.788235294117647, 0.9686274509803922, 0.596078431372549, 0.8745098039215686, 0.7450980392156863, 0.16470588235294117, 0.6980392156862745, 0.11764705882352941, 0.592156862745098, 0.3686274509803922, 0.5215686274509804, 0.14901960784313725, 0.5725490196078431, 0.6705882352941176, 0.44313725490196076, 0.25098039215686274, 0.9607843137254902, 0.8196078431372549, 0.10980392156862745, 0.2196078431372549, 0.8470588235294118, 0.08627450980392157, 0.7647058823529411, 0.9215686274509803, 0.15294117647058825, 0.403921568627451, 0.9686274509803922, 0.3411764705882353, 0.26666666666666666, 0.7490196078431373, 0.6549019607843137, 0.00784313725490196, 0.43137254901960786, 0.5254901960784314, 0.050980392156862744, 0.2823529411764706, 0.8156862745098039, 0.984313725490196, 0.20784313725490197, 0.0, 0.6039215686274509, 0.24705882352941178, 0.050980392156862744, 0.6274509803921569, 0.29411764705882354, 0.5490196078431373, 0.36470588235294116, 0.1607843137254902, 0.8313725490196079, 0.7137254901960784, 0.32941176470588235, 0.06666666666666667, 0.6549019607843137, 0.8156862745098039, 0.5333333333333333, 0.20784313725490197, 0.9098039215686274, 0.6235294117647059, 0.2823529411764706, 0.050980392156862744, 0.43137254901960786, 0.6352941176470588, 0.20784313725490197, 0.7019607843137254, 0.06274509803921569, 0.34901960784313724, 0.44313725490196076, 0.29411764705882354, 0.10980392156862745, 0.7764705882352941, 0.596078431372549, 0.9490196078431372, 0.23137254901960785, 0.7215686274509804, 0.4980392156862745, 0.9725490196078431, 0.20784313725490197, 0.09803921568627451, 0.5372549019607843, 0.9176470588235294, 0.6392156862745098, 0.23137254901960785, 0.7254901960784313, 0.4, 0.7764705882352941, 0.16470588235294117, 0.8117647058823529, 0.3215686274509804, 0.2235294117647059, 0.0392156862745098, 0.792156862745098, 0.6823529411764706, 0.47843137254901963, 0.047058823529411764, 0.807843137254902, 0.4392156862745098, 0.9921568627450981, 0.396078431372549, 0.7647058823529411, 0.24705882352941178, 0.6039215686274509, 0.9294117647058824, 0.2823529411764706, 0.8196078431372549, 0.9137254901960784, 0.19215686274509805, 0.09411764705882353, 0.45098039215686275, 0.7333333333333333, 0.33725490196078434, 0.792156862745098, 0.07450980392156863, 0.8588235294117647, 0.6313725490196078, 0.5098039215686274, 0.403921568627451, 0.8980392156862745, 0.7333333333333333, 0.4392156862745098, 0.6431372549019608, 0.06666666666666667, 0.48627450980392156, 0.24313725490196078, 0.8980392156862745, 0.18823529411764706, 0.3176470588235294, 0.9372549019607843, 0.1568627450980392, 0.47843137254901963, 0.3333333333333333, 0.19215686274509805, 0.0, 0.4470588235294118, 0.34901960784313724, 0.9764705882352941, 0.8431372549019608, 0.20784313725490197, 0.9568627450980393, 0.25882352941176473, 0.403921568627451, 0.807843137254902, 0.996078431372549, 0.7254901960784313, 0.5411764705882353, 0.35294117647058826, 0.615686274509804, 0.403921568627451, 0.5686274509803921, 0.9490196078431372, 0.6980392156862745, 0.5333333333333333, 0.45098039215686275, 0.803921568627451, 0.6235294117647059, 0.5058823529411764, 0.7176470588235294, 0.10196078431372549, 0.9450980392156862, 0.37254901960784315, 0.5882352941176471, 0.8627450980392157, 0.19215686274509805, 0.7254901960784313, 0.49019607843137253, 0.4117647058823529, 0.6941176470588235, 0.3411764705882353, 0.4823529411764706, 0.9058823529411765, 0.37254901960784315, 0.7019607843137254, 0.13333333333333333, 0.8901960784313725, 0.7843137254901961, 0.08235294117647059, 0.9490196078431372, 0.6352941176470588, 0.20784313725490197, 1.0, 0.8549019607843137, 0.4823529411764706, 0.2549019607843137, 0.9607843137254902, 0.7176470588235294, 0.4627450980392157, 0.10980392156862745, 0.7450980392156863, 0.17254901960784313, 0.8941176470588236, 0.3058823529411765, 0.49411764705882355, 0.8509803921568627, 0.803921568627451, 0.13725490196078433, 0.7450980392156863, 0.5490196078431373, 0.8862745098039215, 0.3764705882352941, 0.4549019607843137, 0.0784313725490196, 0.9019607843137255, 0.43529411764705883, 0.047058823529411764, 0.3607843137254902, 0.8823529411764706, 0.4666666666666667, 0.8431372549019608, 0.17647058823529413, 0.00392156862745098, 0.5098039215686274, 0.3137254901960784, 0.09803921568627451, 0.8745098039215686, 0.6392156862745098, 0.9725490196078431, 0.5764705882352941, 0.7176470588235294, 0.9137254901960784, 0.5176470588235295, 0.09411764705882353, 0.8941176470588236, 0.592156862745098, 0.27450980392156865, 0.1568627450980392, 0.6235294117647059, 0.5568627450980392, 0.023529411764705882, 0.47843137254901963, 0.3607843137254902, 0.7098039215686275, 0.42745098039215684, 0.0, 0.611764705882353, 0.3686274509803922, 0.8784313725490196, 0.2784313725490196, 0.6431372549019608, 0.23921568627450981, 1.0, 0.5529411764705883, 0.30196078431372547, 0.7529411764705882, 0.19607843137254902, 0.10196078431372549, 0.34509803921568627, 0.23529411764705882, 0.1411764705882353, 0.984313725490196, 0.3215686274509804, 0.807843137254902, 0.7176470588235294, 0.13725490196078433, 0.8274509803921568, 0.5333333333333333, 0.2549019607843137, 0.403921568627451, 0.7333333333333333, 0.8352941176470589, 0.5411764705882353, 0.6666666666666666, 0.9137254901960784, 0.24705882352941178, 0.5568627450980392, 0.5019607843137255, 0.01568627450980392, 0.6901960784313725, 0.8745098039215686, 0.7450980392156863, 0.050980392156862744, 0.3254901960784314, 0.08627450980392157, 0.1450980392156863, 0.7843137254901961, 0.0392156862745098, 0.7450980392156863, 0.8862745098039215, 0.27058823529411763, 0.1803921568627451, 0.37254901960784315, 0.10980392156862745, 0.2980392156862745, 0.2196078431372549, 0.047058823529411764, 0.8705882352941177, 0.4392156862745098, 0.5372549019607843, 0.1450980392156863, 0.2549019607843137, 0.996078431372549, 0.3254901960784314, 0.6392156862745098, 0.9215686274509803, 0.24313725490196078, 0.13333333333333333, 0.6313725490196078, 0.788235294117647, 0.16862745098039217, 0.5607843137254902, 0.9607843137254902, 0.4235294117647059, 0.47843137254901963, 0.22745098039215686, 0.6, 0.41568627450980394, 0.26666666666666666, 0.5176470588235295, 0.11372549019607843, 0.3764705882352941, 0.1568627450980392, 0.611764705882353, 0.34509803921568627, 0.0392156862745098, 0.4117647058823529, 0.5725490196078431, 0.8196078431372549, 0.3764705882352941, 0.6823529411764706, 0.9568627450980393, 0.00392156862745098, 0.5764705882352941, 0.39215686274509803, 0.6549019607843137, 0.2196078431372549, 0.01568627450980392, 0.8117647058823529, 0.17254901960784313, 0.6941176470588235, 0.3254901960784314, 0.796078431372549, 0.2823529411764706, 0.6784313725490196, 0.6039215686274509, 0.25098039215686274, 0.7333333333333333, 0.5843137254901961, 0.2784313725490196, 0.6705882352941176, 0.8196078431372549, 0.9372549019607843, 0.5450980392156862, 0.2, 0.3686274509803922, 0.4980392156862745, 0.08235294117647059, 0.8392156862745098, 0.4235294117647059, 0.34509803921568627, 0.19607843137254902, 0.7372549019607844, 0.3843137254901961, 0.9254901960784314, 0.7019607843137254, 0.06666666666666667, 0.30980392156862746, 0.9568627450980393, 0.807843137254902, 0.19607843137254902, 0.8784313725490196, 0.1411764705882353, 0.6745098039215687, 0.23529411764705882, 0.7843137254901961, 0.06274509803921569, 0.5098039215686274, 0.8313725490196079, 0.011764705882352941, 0.16862745098039217, 0.6823529411764706, 0.43529411764705883, 0.0392156862745098, 0.9176470588235294, 0.5686274509803921, 0.788235294117647, 0.47843137254901963, 0.7058823529411765, 0.592156862745098, 0.09803921568627451, 0.5450980392156862, 0.40784313725490196, 0.050980392156862744, 0.3568627450980392, 0.7686274509803922, 0.08235294117647059, 0.9098039215686274, 0.5803921568627451, 0.054901960784313725, 0.2901960784313726, 0.38823529411764707, 0.09019607843137255, 0.7568627450980392, 0.6313725490196078, 0.3137254901960784, 0.807843137254902, 0.4588235294117647, 0.12549019607843137, 0.6078431372549019, 0.4980392156862745, 0.2196078431372549, 0.6313725490196078, 0.9333333333333333, 0.5058823529411764, 0.20392156862745098, 0.4745098039215686, 0.32941176470588235, 0.054901960784313725, 0.6549019607843137, 0.592156862745098, 0.9607843137254902, 0.8901960784313725, 0.6823529411764706, 0.5607843137254902, 0.16862745098039217, 0.30980392156862746, 0.8156862745098039, 0.054901960784313725, 0.7647058823529411, 0.4, 0.09411764705882353, 0.8352941176470589, 0.023529411764705882, 0.5843137254901961, 0.8862745098039215, 0.06274509803921569, 0.2784313725490196, 0.8431372549019608, 0.07450980392156863, 0.30980392156862746, 0.7647058823529411, 0.027450980392156862, 0.6784313725490196, 0.3333333333333333, 0.807843137254902, 0.00392156862745098, 0.6745098039215687, 0.7529411764705882, 0.5686274509803921, 0.9137254901960784, 0.7843137254901961, 0.09411764705882353, 0.8509803921568627, 0.6745098039215687, 0.9882352941176471, 0.23529411764705882, 0.5176470588235295, 0.13725490196078433, 0.45098039215686275, 0.2549019607843137, 0.09803921568627451, 0.8745098039215686, 0.47058823529411764, 0.9568627450980393, 0.6039215686274509, 0.27058823529411763, 0.9725490196078431, 0.5176470588235295, 0.13333333333333333, 0.5686274509803921, 0.1843137254901961, 0.9294117647058824, 0.8156862745098039, 0.14901960784313725, 0.03529411764705882, 0.9803921568627451, 0.4392156862745098, 0.36470588235294116, 0.7529411764705882, 0.050980392156862744, 0.596078431372549, 0.7019607843137254, 0.2901960784313726, 0.011764705882352941, 0.6784313725490196, 0.15294117647058825, 0.2549019607843137, 0.996078431372549, 0.6431372549019608, 0.4549019607843137, 0.00392156862745098, 0.20784313725490197, 0.8392156862745098, 0.4627450980392157, 0.8862745098039215, 0.12156862745098039, 0.6549019607843137, 0.5333333333333333, 0.07450980392156863, 0.9882352941176471, 0.49411764705882355, 0.3176470588235294, 0.5686274509803921, 0.9725490196078431, 0.7058823529411765, 0.38823529411764707, 0.6, 0.4745098039215686, 0.9450980392156862, 0.36470588235294116, 0.8352941176470589, 0.6078431372549019, 0.2823529411764706, 0.9607843137254902, 0.03529411764705882, 0.8784313725490196, 0.3764705882352941, 0.17647058823529413, 0.9176470588235294, 0.6470588235294118, 0.5058823529411764, 0.9568627450980393, 0.4588235294117647, 0.6235294117647059, 0.6862745098039216, 0.21568627450980393, 0.13333333333333333, 1.0, 0.8627450980392157, 0.4745098039215686, 0.14901960784313725, 0.9450980392156862, 0.06666666666666667, 0.4117647058823529, 0.1843137254901961, 0.2901960784313726, 0.9372549019607843, 0.3607843137254902, 0.8901960784313725, 0.4392156862745098, 0.8313725490196079, 0.28627450980392155, 0.6941176470588235, 0.9137254901960784, 0.1411764705882353, 0.43137254901960786, 0.8235294117647058, 0.7372549019607844, 0.06274509803921569, 0.4745098039215686, 0.7568627450980392, 0.3803921568627451, 0.984313725490196, 0.6352941176470588, 0.7019607843137254, 0.9098039215686274, 0.47843137254901963, 0.6784313725490196, 0.5529411764705883, 0.16470588235294117, 0.4588235294117647, 0.7529411764705882, 0.5294117647058824, 0.9647058823529412, 0.43137254901960786, 0.7372549019607844, 0.5019607843137255, 0.6470588235294118, 0.19215686274509805, 0.8352941176470589, 0.9882352941176471, 0.12941176470588237, 0.49019607843137253, 0.9294117647058824, 0.8666666666666667, 0.30980392156862746, 0.047058823529411764, 0.2235294117647059, 0.43137254901960786, 0.5098039215686274, 0.2901960784313726, 0.1843137254901961, 0.3254901960784314, 0.03137254901960784, 0.8823529411764706, 0.592156862745098, 0.7215686274509804, 0.33725490196078434, 0.9372549019607843, 0.7568627450980392, 0.1607843137254902, 0.30980392156862746, 0.7137254901960784, 0.40784313725490196, 0.06274509803921569, 0.6431372549019608, 0.8627450980392157, 0.7568627450980392, 0.4117647058823529, 0.08235294117647059, 0.49019607843137253, 0.32941176470588235, 0.396078431372549, 0.7058823529411765, 0.11372549019607843, 0.8627450980392157, 0.1568627450980392, 0.4666666666666667, 0.25098039215686274, 0.9647058823529412, 0.7843137254901961, 0.45098039215686275, 0.9294117647058824, 0.6078431372549019, 0.5294117647058824, 0.7568627450980392, 0.12549019607843137, 0.3058823529411765, 0.9568627450980393, 0.6627450980392157, 0.5176470588235295, 0.3568627450980392, 0.7294117647058823, 0.24313725490196078, 0.4196078431372549, 0.32941176470588235, 0.5882352941176471, 0.7647058823529411, 0.396078431372549, 0.8627450980392157, 0.1568627450980392, 0.43529411764705883, 0.21176470588235294, 0.11764705882352941, 0.8980392156862745, 0.7764705882352941, 0.09019607843137255, 0.24705882352941178, 0.7215686274509804, 0.13725490196078433, 0.45098039215686275, 0.6745098039215687, 0.21568627450980393, 0.5254901960784314, 0.8274509803921568, 0.2627450980392157, 0.7529411764705882, 0.3058823529411765, 0.21176470588235294, 0.8627450980392157, 0.011764705882352941, 0.2823529411764706, 0.8235294117647058, 0.3686274509803922, 0.5176470588235295, 0.7803921568627451, 0.7019607843137254, 0.6, 0.2196078431372549, 0.36470588235294116, 0.8862745098039215, 0.6549019607843137, 0.7686274509803922, 0.5647058823529412, 0.027450980392156862, 0.6823529411764706, 0.7529411764705882, 0.16862745098039217, 0.38823529411764707, 0.011764705882352941, 0.5803921568627451, 0.807843137254902, 0.6274509803921569, 0.9921568627450981, 0.5098039215686274, 0.20392156862745098, 0.34509803921568627, 0.25882352941176473, 0.13333333333333333, 0.8392156862745098, 0.0, 0.24313725490196078, 0.35294117647058826, 0.1843137254901961, 0.6078431372549019, 0.28627450980392155, 0.9411764705882353, 0.796078431372549, 0.35294117647058826, 0.29411764705882354, 0.1843137254901961, 0.37254901960784315, 0.6901960784313725, 0.23137254901960785, 0.11372549019607843, 0.8862745098039215, 0.38823529411764707, 0.5411764705882353, 0.2784313725490196, 0.7215686274509804, 0.5647058823529412, 0.1843137254901961, 0.3843137254901961, 0.4588235294117647, 0.9764705882352941, 0.7098039215686275, 0.6431372549019608, 0.8980392156862745, 0.7490196078431373, 0.5490196078431373, 0.9294117647058824, 0.6313725490196078, 0.08235294117647059, 0.7725490196078432, 0.8352941176470589, 0.1803921568627451, 0.6274509803921569, 0.5254901960784314, 0.37254901960784315, 0.054901960784313725, 0.8313725490196079, 0.49019607843137253, 0.9215686274509803, 0.23921568627450981, 0.35294117647058826, 0.00392156862745098, 0.9921568627450981, 0.5294117647058824, 0.6588235294117647, 0.7686274509803922, 0.9333333333333333, 0.5568627450980392, 0.2235294117647059, 0.6235294117647059, 0.3215686274509804, 0.8901960784313725, 0.40784313725490196, 0.12156862745098039, 0.8470588235294118, 0.1803921568627451, 0.38823529411764707, 0.3137254901960784, 0.807843137254902, 0.03137254901960784, 0.8705882352941177, 0.5568627450980392, 0.8, 0.25882352941176473, 0.09803921568627451, 0.5764705882352941, 0.17254901960784313, 0.792156862745098, 0.9215686274509803, 0.0392156862745098, 0.8352941176470589, 0.26666666666666666, 0.10588235294117647, 0.7254901960784313, 0.0196078431372549, 0.6509803921568628, 0.9333333333333333, 0.34509803921568627, 0.5450980392156862, 0.2980392156862745, 0.6549019607843137, 0.18823529411764706, 0.5254901960784314, 0.396078431372549, 0.8549019607843137, 0.06274509803921569, 0.32941176470588235, 0.6274509803921569, 0.4235294117647059, 0.0196078431372549, 0.9725490196078431, 0.596078431372549, 0.10980392156862745, 0.7058823529411765, 0.5607843137254902, 0.17254901960784313, 0.43529411764705883, 0.9215686274509803, 0.6392156862745098, 0.25098039215686274, 0.42745098039215684, 0.043137254901960784, 0.8196078431372549, 0.5450980392156862, 0.7137254901960784, 0.24705882352941178, 0.9882352941176471, 0.47843137254901963, 0.8352941176470589, 0.10980392156862745, 0.2549019607843137, 0.5411764705882353, 0.6549019607843137, 0.9764705882352941, 0.11372549019607843, 0.23921568627450981, 0.3764705882352941, 0.023529411764705882, 0.2901960784313726, 0.8588235294117647, 0.5686274509803921, 0.9137254901960784, 0.42745098039215684, 0.5176470588235295, 0.596078431372549, 0.7764705882352941, 0.4549019607843137, 0.11764705882352941, 0.8705882352941177, 0.01568627450980392, 0.23137254901960785, 0.7137254901960784, 0.10588235294117647, 0.8666666666666667, 0.6549019607843137, 0.0392156862745098, 0.9254901960784314, 0.5725490196078431, 0.33725490196078434, 0.9686274509803922, 0.6196078431372549, 0.058823529411764705, 0.4392156862745098, 0.9058823529411765, 0.08235294117647059, 0.24313725490196078, 0.6235294117647059, 0.13725490196078433, 0.803921568627451, 0.2784313725490196, 0.16862745098039217, 0.00784313725490196, 0.396078431372549, 0.12941176470588237, 0.796078431372549, 0.4392156862745098, 0.36470588235294116, 0.27450980392156865, 0.5019607843137255, 0.41568627450980394, 0.027450980392156862, 0.23921568627450981, 0.8980392156862745, 0.6666666666666666, 0.12941176470588237, 0.5803921568627451, 0.19215686274509805, 0.7372549019607844, 0.4666666666666667, 0.6039215686274509, 0.30196078431372547, 0.21176470588235294, 0.8980392156862745, 0.26666666666666666, 0.06274509803921569, 0.47058823529411764, 0.788235294117647, 0.0196078431372549, 0.9450980392156862, 0.5058823529411764, 0.6627450980392157, 0.06274509803921569, 0.5372549019607843, 0.7333333333333333, 0.8901960784313725, 0.07058823529411765, 0.2196078431372549, 0.47843137254901963, 0.3686274509803922, 0.6196078431372549, 0.15294117647058825, 0.41568627450980394, 0.7647058823529411, 0.9803921568627451, 0.01568627450980392, 0.615686274509804, 0.4980392156862745, 0.7019607843137254, 0.21176470588235294, 0.47058823529411764, 0.9607843137254902, 0.6039215686274509, 0.5215686274509804, 0.807843137254902, 0.2627450980392157, 0.7411764705882353, 0.058823529411764705, 0.4235294117647059, 0.8705882352941177, 0.8, 0.027450980392156862, 0.9882352941176471, 0.7568627450980392, 0.16862745098039217, 0.9450980392156862, 0.7803921568627451, 0.08627450980392157, 0.6901960784313725, 0.4627450980392157, 0.8862745098039215, 0.38823529411764707, 0.803921568627451, 0.33725490196078434, 0.9803921568627451, 0.7529411764705882, 0.09411764705882353, 0.03137254901960784, 0.3411764705882353, 0.1607843137254902, 0.9019607843137255, 0.3058823529411765, 0.11372549019607843, 0.49411764705882355, 0.03529411764705882, 0.15294117647058825, 0.3254901960784314, 0.396078431372549, 0.596078431372549, 0.9607843137254902, 0.07058823529411765, 0.8549019607843137, 0.33725490196078434, 0.45098039215686275, 0.7647058823529411, 0.5490196078431373, 0.7215686274509804, 0.1607843137254902, 0.6784313725490196, 0.788235294117647, 0.0392156862745098, 0.6509803921568628, 0.19607843137254902, 0.9333333333333333, 0.08627450980392157, 0.8274509803921568, 0.5058823529411764, 0.9803921568627451, 0.3843137254901961, 0.5333333333333333, 0.43137254901960786, 0.6235294117647059, 0.9764705882352941, 0.5058823529411764, 0.803921568627451, 0.15294117647058825, 0.4549019607843137, 0.0196078431372549, 0.7490196078431373, 0.25098039215686274, 0.1607843137254902, 0.792156862745098, 0.35294117647058826, 0.6588235294117647, 0.7490196078431373, 0.8470588235294118, 0.5294117647058824, 0.09803921568627451, 0.5607843137254902, 0.3686274509803922, 0.9568627450980393, 0.6, 0.25098039215686274, 0.48627450980392156, 0.7019607843137254, 0.21176470588235294, 0.9019607843137255, 0.11372549019607843, 1.0, 0.6901960784313725, 0.792156862745098, 0.5568627450980392, 0.43137254901960786, 0.9803921568627451, 0.3215686274509804, 0.8, 0.10196078431372549, 0.8941176470588236, 0.6901960784313725, 0.8392156862745098, 0.3843137254901961, 0.12156862745098039, 0.6196078431372549, 0.17647058823529413, 0.8784313725490196, 0.30196078431372547, 0.6862745098039216, 0.5686274509803921, 0.19607843137254902, 0.7725490196078432, 0.35294117647058826, 0.6313725490196078, 0.24705882352941178, 0.5803921568627451, 0.9686274509803922, 0.6509803921568628, 0.7058823529411765, 0.9490196078431372, 0.054901960784313725, 0.5019607843137255, 0.9137254901960784, 0.32941176470588235, 0.6862745098039216, 0.45098039215686275, 0.28627450980392155, 0.3764705882352941, 0.9450980392156862, 0.15294117647058825, 0.6627450980392157, 0.3686274509803922, 0.2980392156862745, 0.18823529411764706, 0.8862745098039215, 0.4745098039215686, 0.6235294117647059, 0.15294117647058825, 0.9647058823529412, 0.5725490196078431, 0.4823529411764706, 0.2784313725490196, 0.6196078431372549, 0.34901960784313724, 0.5607843137254902, 0.48627450980392156, 0.2901960784313726, 0.9137254901960784, 0.5529411764705883, 0.1568627450980392, 0.7725490196078432, 0.047058823529411764, 0.6352941176470588, 0.23921568627450981, 0.12941176470588237, 0.49019607843137253, 0.5882352941176471, 0.8509803921568627, 0.9647058823529412, 0.5058823529411764, 0.7450980392156863, 0.6470588235294118, 0.9725490196078431, 0.43137254901960786, 0.8588235294117647, 0.6235294117647059, 0.9137254901960784, 0.21176470588235294, 0.788235294117647, 0.4470588235294118, 0.2980392156862745, 0.7137254901960784, 0.5137254901960784, 0.1843137254901961, 0.9372549019607843, 0.0784313725490196, 0.8784313725490196, 0.48627450980392156, 0.4117647058823529, 0.10588235294117647, 0.9725490196078431, 0.3137254901960784, 0.7294117647058823, 0.27450980392156865, 0.403921568627451, 0.5686274509803921, 0.3058823529411765, 0.7372549019607844, 0.6666666666666666, 0.14901960784313725, 0.8392156862745098, 0.06274509803921569, 0.21568627450980393, 0.4117647058823529, 0.26666666666666666, 0.6078431372549019, 0.8784313725490196, 0.8235294117647058, 0.5215686274509804, 0.6862745098039216, 0.4745098039215686, 0.8588235294117647, 0.5843137254901961, 0.043137254901960784, 0.4196078431372549, 0.9372549019607843, 0.3333333333333333, 0.6941176470588235, 0.8823529411764706, 0.47058823529411764, 0.8274509803921568, 0.6745098039215687, 0.06274509803921569, 0.8627450980392157, 0.9764705882352941, 0.01568627450980392, 0.6627450980392157, 0.5764705882352941, 0.30980392156862746, 0.8627450980392157, 0.07058823529411765, 0.19607843137254902, 0.27058823529411763, 0.7215686274509804, 0.0196078431372549, 0.3803921568627451, 0.5333333333333333, 0.2627450980392157, 0.1568627450980392, 0.0392156862745098, 0.5647058823529412, 0.7176470588235294, 0.8235294117647058, 0.4235294117647059, 0.5215686274509804, 0.9921568627450981, 0.37254901960784315, 0.08235294117647059, 0.8274509803921568, 0.28627450980392155, 0.9764705882352941, 0.0196078431372549, 0.4588235294117647, 0.13333333333333333, 0.3333333333333333, 0.4235294117647059, 0.17647058823529413, 0.28627450980392155, 0.7411764705882353, 0.23137254901960785, 0.8235294117647058, 0.07450980392156863, 0.20392156862745098, 0.8980392156862745, 0.8470588235294118, 0.11764705882352941, 0.7568627450980392, 0.5725490196078431, 0.06666666666666667, 0.8980392156862745, 0.7764705882352941, 0.08627450980392157, 0.403921568627451, 0.0392156862745098, 0.8431372549019608, 0.2196078431372549, 0.7019607843137254, 0.32941176470588235, 0.12549019607843137, 0.9098039215686274, 0.6941176470588235, 0.0784313725490196, 0.23529411764705882, 0.7254901960784313, 0.12156862745098039, 0.8392156862745098, 0.3568627450980392, 0.24705882352941178, 0.5058823529411764, 0.43137254901960786, 0.9294117647058824, 0.5372549019607843, 0.8823529411764706, 0.7215686274509804, 0.3137254901960784, 0.2, 0.6941176470588235, 0.5647058823529412, 0.3803921568627451, 0.26666666666666666, 0.18823529411764706, 0.5803921568627451, 0.35294117647058826, 0.7450980392156863, 0.09803921568627451, 0.5058823529411764, 0.6980392156862745, 0.1607843137254902, 0.9254901960784314, 0.047058823529411764, 0.8, 0.6078431372549019, 0.26666666666666666, 0.6509803921568628, 0.3254901960784314, 0.9529411764705882, 0.23137254901960785, 0.6078431372549019, 0.36470588235294116, 0.4666666666666667, 0.8823529411764706, 0.054901960784313725, 0.7058823529411765, 0.9529411764705882, 0.21176470588235294, 0.047058823529411764, 0.807843137254902, 0.2627450980392157, 0.9176470588235294, 0.5764705882352941, 0.32941176470588235, 0.7686274509803922, 0.09411764705882353, 0.7058823529411765, 0.30980392156862746, 0.20784313725490197, 0.3803921568627451, 0.12549019607843137, 0.9490196078431372, 0.3137254901960784, 0.20784313725490197, 0.9803921568627451, 0.28627450980392155, 0.49411764705882355, 0.023529411764705882, 0.22745098039215686, 0.7607843137254902, 0.0784313725490196, 0.19607843137254902, 0.33725490196078434, 0.7568627450980392, 0.16470588235294117, 0.3137254901960784, 0.5411764705882353, 0.39215686274509803, 0.7647058823529411, 0.14901960784313725, 0.47058823529411764, 0.36470588235294116, 0.9411764705882353, 0.5019607843137255, 0.8588235294117647, 0.6196078431372549, 0.9137254901960784, 0.6627450980392157, 0.4235294117647059, 0.9411764705882353, 0.796078431372549, 0.45098039215686275, 0.9686274509803922, 0.34509803921568627, 0.03137254901960784, 0.7450980392156863, 0.12941176470588237, 0.24313725490196078, 0.4470588235294118, 0.7176470588235294, 0.1411764705882353, 0.403921568627451, 0.8588235294117647, 0.6901960784313725, 0.5098039215686274, 0.8274509803921568, 0.7686274509803922, 0.09019607843137255, 0.8862745098039215, 0.4470588235294118, 0.6666666666666666, 0.5647058823529412, 0.38823529411764707, 0.6392156862745098, 0.5372549019607843, 0.054901960784313725, 0.23529411764705882, 0.5176470588235295, 0.3254901960784314, 0.8156862745098039, 0.43137254901960786, 0.23529411764705882, 0.6784313725490196, 0.5607843137254902, 0.9921568627450981, 0.3686274509803922, 0.5137254901960784, 0.7725490196078432, 0.00784313725490196, 0.40784313725490196, 0.21176470588235294, 0.8196078431372549, 0.4549019607843137, 0.8823529411764706, 0.596078431372549, 0.40784313725490196, 0.19215686274509805, 0.6549019607843137, 0.996078431372549, 0.09019607843137255, 0.2901960784313726, 0.1803921568627451, 0.6745098039215687, 0.0196078431372549, 0.40784313725490196, 0.2627450980392157, 0.796078431372549, 0.4392156862745098, 0.10196078431372549, 0.00784313725490196, 0.7764705882352941, 0.9333333333333333, 0.058823529411764705, 0.8117647058823529, 0.2784313725490196, 0.6666666666666666, 0.00392156862745098, 0.8823529411764706, 0.37254901960784315, 0.5843137254901961, 0.22745098039215686, 0.4117647058823529, 0.9019607843137255, 0.13725490196078433, 0.38823529411764707, 0.8274509803921568, 0.03529411764705882, 0.5568627450980392, 0.7647058823529411, 0.1450980392156863, 0.807843137254902, 0.5372549019607843, 0.16862745098039217, 0.6196078431372549, 0.34509803921568627, 0.13333333333333333, 0.6352941176470588, 0.45098039215686275, 0.3607843137254902, 0.49019607843137253, 0.7411764705882353, 0.00392156862745098, 0.8862745098039215, 0.5490196078431373, 0.996078431372549, 0.43137254901960786, 0.07450980392156863, 0.9215686274509803, 0.6313725490196078, 0.00784313725490196, 0.7686274509803922, 0.5333333333333333, 0.11372549019607843, 0.8, 0.17647058823529413, 0.6, 0.403921568627451, 0.996078431372549, 0.29411764705882354, 0.5803921568627451, 0.5215686274509804, 0.9411764705882353, 0.41568627450980394, 0.615686274509804, 0.8313725490196079, 0.08627450980392157, 0.9490196078431372, 0.25098039215686274, 0.6, 0.7411764705882353, 0.6470588235294118, 0.10588235294117647, 0.1568627450980392, 0.4549019607843137, 0.2196078431372549, 0.050980392156862744, 0.7529411764705882, 0.3176470588235294, 0.5058823529411764, 0.2, 0.07450980392156863, 0.6705882352941176, 0.2784313725490196, 0.6, 0.8549019607843137, 0.6509803921568628, 0.9137254901960784, 0.49411764705882355, 0.6039215686274509, 0.8980392156862745, 0.20784313725490197, 0.7490196078431373, 0.27450980392156865, 0.047058823529411764, 0.9137254901960784, 0.5411764705882353, 0.596078431372549, 0.34901960784313724, 0.011764705882352941, 0.9882352941176471, 0.13333333333333333, 0.30196078431372547, 0.7294117647058823, 0.803921568627451, 0.41568627450980394, 1.0, 0.6313725490196078, 0.03137254901960784, 0.49411764705882355, 0.9529411764705882, 0.7372549019607844, 0.3176470588235294, 0.11764705882352941, 0.6588235294117647, 0.27058823529411763, 0.8862745098039215, 0.6, 0.9411764705882353, 0.6549019607843137, 0.1568627450980392, 0.37254901960784315, 0.9803921568627451, 0.0392156862745098, 0.803921568627451, 0.5294117647058824, 0.00392156862745098, 0.7333333333333333, 0.6039215686274509, 0.8549019607843137, 0.807843137254902, 0.35294117647058826, 0.47058823529411764, 0.0784313725490196, 0.9490196078431372, 0.6509803921568628, 0.1450980392156863, 0.9098039215686274, 0.8352941176470589, 0.4666666666666667, 0.6784313725490196, 0.5176470588235295, 0.2196078431372549, 0.4196078431372549, 0.9568627450980393, 0.5529411764705883, 0.3176470588235294, 0.7686274509803922, 0.11372549019607843, 0.49411764705882355, 0.6784313725490196, 0.01568627450980392, 0.7294117647058823, 0.5294117647058824, 0.4549019607843137, 0.19215686274509805, 0.7098039215686275, 0.8980392156862745, 0.25882352941176473, 0.011764705882352941, 0.6666666666666666, 0.8509803921568627, 0.43529411764705883, 0.9058823529411765, 0.7686274509803922, 0.5411764705882353, 0.8705882352941177, 0.1843137254901961, 0.10980392156862745, 0.9568627450980393, 0.6666666666666666, 0.3764705882352941, 0.4823529411764706, 0.16862745098039217, 0.6549019607843137, 0.788235294117647, 0.5568627450980392, 0.27058823529411763, 0.7176470588235294, 0.396078431372549, 0.615686274509804, 0.4549019607843137, 0.7137254901960784, 0.9176470588235294, 0.6745098039215687, 0.8196078431372549, 0.1411764705882353, 0.6431372549019608, 0.9058823529411765, 0.0392156862745098, 0.4588235294117647, 0.11764705882352941, 0.27058823529411763, 0.7254901960784313, 0.19607843137254902, 0.5137254901960784, 0.8156862745098039, 0.4235294117647059, 0.0, 0.3254901960784314, 0.7764705882352941, 0.5647058823529412, 0.29411764705882354, 0.8156862745098039, 0.9686274509803922, 0.5843137254901961, 0.11372549019607843, 0.6392156862745098, 0.2549019607843137, 0.8705882352941177, 0.5372549019607843, 0.9294117647058824, 0.19215686274509805, 0.4117647058823529, 0.0, 0.7803921568627451, 0.1843137254901961, 0.050980392156862744, 0.5411764705882353, 0.3215686274509804, 0.10980392156862745, 0.9921568627450981, 0.6392156862745098, 0.3843137254901961, 0.23529411764705882, 0.1411764705882353, 0.8392156862745098, 0.7803921568627451, 0.19215686274509805, 0.8666666666666667, 0.4823529411764706, 0.9254901960784314, 0.596078431372549, 0.17254901960784313, 0.7058823529411765, 0.27450980392156865, 0.8509803921568627, 0.13333333333333333, 0.6078431372549019, 0.16862745098039217, 0.9137254901960784, 0.4666666666666667, 0.8235294117647058, 0.17647058823529413, 0.4392156862745098, 0.09803921568627451, 0.7490196078431373, 0.4980392156862745, 0.5529411764705883, 0.06666666666666667, 0.30980392156862746, 0.6392156862745098, 0.26666666666666666, 0.9254901960784314, 0.4470588235294118, 0.3333333333333333, 0.6941176470588235, 0.13725490196078433, 0.5568627450980392, 0.984313725490196, 0.7647058823529411, 0.6, 0.8588235294117647, 0.5411764705882353, 0.3568627450980392, 0.23921568627450981, 0.615686274509804, 0.16862745098039217, 0.3176470588235294, 0.11764705882352941, 0.6078431372549019, 0.8509803921568627, 0.14901960784313725, 0.4627450980392157, 0.24313725490196078, 0.6352941176470588, 0.8352941176470589, 0.996078431372549, 0.33725490196078434, 0.8627450980392157, 0.29411764705882354, 0.9725490196078431, 0.788235294117647, 0.07058823529411765, 0.6392156862745098, 0.3058823529411765, 0.4980392156862745, 1.0, 0.3843137254901961, 0.2196078431372549, 0.06666666666666667, 0.49019607843137253, 0.26666666666666666, 0.023529411764705882, 0.9921568627450981, 0.7058823529411765, 0.6, 0.29411764705882354, 0.21176470588235294, 0.12941176470588237, 0.8352941176470589, 0.25098039215686274, 0.043137254901960784, 0.34509803921568627, 0.8509803921568627, 0.4470588235294118, 0.1843137254901961, 0.8980392156862745, 0.050980392156862744, 0.8666666666666667, 0.3686274509803922, 0.25882352941176473, 0.06666666666666667, 0.5137254901960784, 0.4470588235294118, 0.3607843137254902, 0.24705882352941178, 0.7058823529411765, 0.796078431372549, 0.6588235294117647, 0.9098039215686274, 0.4745098039215686, 0.3568627450980392, 0.058823529411764705, 0.6784313725490196, 0.8980392156862745, 0.2196078431372549, 0.8392156862745098, 0.9921568627450981, 0.396078431372549, 0.7098039215686275, 0.07450980392156863, 0.3568627450980392, 0.1803921568627451, 0.8901960784313725, 0.7215686274509804, 0.3764705882352941, 0.7725490196078432, 0.1450980392156863, 0.4745098039215686, 0.803921568627451, 0.3411764705882353, 0.5764705882352941, 0.2901960784313726, 0.9490196078431372, 0.36470588235294116, 0.803921568627451, 0.6666666666666666, 0.4392156862745098, 0.5843137254901961, 0.1803921568627451, 0.47843137254901963, 0.7215686274509804, 0.9647058823529412, 0.29411764705882354, 0.4980392156862745, 0.6980392156862745, 0.43137254901960786, 0.25098039215686274, 0.09411764705882353, 0.33725490196078434, 0.00784313725490196, 0.47058823529411764, 0.9098039215686274, 0.21568627450980393, 0.3843137254901961, 0.5411764705882353, 0.2823529411764706, 0.011764705882352941, 0.788235294117647, 0.5843137254901961, 0.050980392156862744, 0.9764705882352941, 0.3568627450980392, 0.30196078431372547, 0.24313725490196078, 0.8627450980392157, 0.7764705882352941, 0.7098039215686275, 0.4823529411764706, 0.10588235294117647, 0.7529411764705882, 0.8745098039215686, 0.21568627450980393, 0.9607843137254902, 0.396078431372549, 0.26666666666666666, 0.09803921568627451, 0.23137254901960785, 0.17647058823529413, 0.3215686274509804, 0.03529411764705882, 0.4823529411764706, 0.996078431372549, 0.7019607843137254, 0.396078431372549, 0.8862745098039215, 0.7647058823529411, 0.984313725490196, 0.3607843137254902, 0.0392156862745098, 0.7294117647058823, 0.9372549019607843, 0.06274509803921569, 0.1843137254901961, 0.44313725490196076, 0.5647058823529412, 0.09411764705882353, 0.16862745098039217, 0.5882352941176471, 0.24705882352941178, 0.35294117647058826, 0.9450980392156862, 0.4196078431372549, 0.10196078431372549, 0.6, 0.7372549019607844, 0.3176470588235294, 0.9607843137254902, 0.592156862745098, 0.8235294117647058, 0.4117647058823529, 0.3254901960784314, 0.0784313725490196, 0.5137254901960784, 0.8, 0.4470588235294118, 0.9098039215686274, 0.7215686274509804, 0.5843137254901961, 0.9568627450980393, 0.7450980392156863, 0.5019607843137255, 0.1411764705882353, 0.9882352941176471, 0.3333333333333333, 0.23921568627450981, 0.6470588235294118, 0.15294117647058825, 0.5568627450980392, 0.3215686274509804, 0.8509803921568627, 0.0, 0.7450980392156863, 0.8705882352941177, 0.17254901960784313, 0.3803921568627451, 0.2235294117647059, 0.0196078431372549, 0.8549019607843137, 0.9882352941176471, 0.6235294117647059, 0.29411764705882354, 0.12549019607843137, 0.5450980392156862, 0.48627450980392156, 0.043137254901960784, 0.24313725490196078, 0.8666666666666667, 0.5176470588235295, 0.6549019607843137, 0.47843137254901963, 0.41568627450980394, 0.01568627450980392, 1.0, 0.6078431372549019, 0.3176470588235294, 0.6980392156862745, 0.09019607843137255, 0.9764705882352941, 0.5137254901960784, 0.11764705882352941, 0.7019607843137254, 0.42745098039215684, 0.24313725490196078, 0.9215686274509803, 0.00784313725490196, 0.8549019607843137, 0.34509803921568627, 0.796078431372549, 0.03529411764705882, 0.6509803921568628, 0.40784313725490196, 0.10588235294117647, 0.615686274509804, 0.047058823529411764, 0.7647058823529411, 0.6549019607843137, 0.9686274509803922, 0.396078431372549, 0.796078431372549, 0.6627450980392157, 0.08235294117647059, 0.7725490196078432, 0.7137254901960784, 0.8705882352941177, 0.4196078431372549, 0.3411764705882353, 0.22745098039215686, 0.7254901960784313, 0.5333333333333333, 0.8549019607843137, 0.6901960784313725, 0.03529411764705882, 0.9607843137254902, 0.41568627450980394, 0.2, 0.9490196078431372, 0.1568627450980392, 0.3764705882352941, 0.5764705882352941, 0.07058823529411765, 0.7803921568627451, 0.4549019607843137, 0.6509803921568628, 0.7333333333333333, 0.5215686274509804, 0.9215686274509803, 0.6784313725490196, 0.8235294117647058, 0.7411764705882353, 0.07058823529411765, 0.5686274509803921, 0.2823529411764706, 0.027450980392156862, 0.44313725490196076, 0.7098039215686275, 0.5647058823529412, 0.29411764705882354, 0.803921568627451, 0.5098039215686274, 0.3843137254901961, 0.7019607843137254, 0.26666666666666666, 0.8117647058823529, 0.6588235294117647, 0.40784313725490196, 0.9176470588235294, 0.6823529411764706, 0.13333333333333333, 0.8156862745098039, 0.5450980392156862, 0.8745098039215686, 0.17647058823529413, 0.4588235294117647, 0.796078431372549, 0.09411764705882353, 0.6862745098039216, 0.15294117647058825, 0.7450980392156863, 0.6470588235294118, 0.23921568627450981, 0.9372549019607843, 0.38823529411764707, 0.6392156862745098, 0.06666666666666667, 0.5215686274509804, 0.3176470588235294, 0.403921568627451, 0.21568627450980393, 0.08627450980392157, 0.6901960784313725, 0.5764705882352941, 0.8156862745098039, 0.5137254901960784, 0.09411764705882353, 0.7843137254901961, 0.9803921568627451, 0.7254901960784313, 0.20784313725490197, 0.9490196078431372, 0.5490196078431373, 0.09019607843137255, 0.6, 0.9725490196078431, 0.5294117647058824, 0.3254901960784314, 0.5647058823529412, 0.1568627450980392, 0.7686274509803922, 0.396078431372549, 0.9294117647058824, 0.7254901960784313, 0.17254901960784313, 0.6823529411764706, 0.611764705882353, 0.12549019607843137, 0.9254901960784314, 0.7686274509803922, 0.2627450980392157, 0.8392156862745098, 0.5568627450980392, 0.09803921568627451, 0.22745098039215686, 0.4392156862745098, 0.03529411764705882, 0.8666666666666667, 0.25098039215686274, 0.7529411764705882, 0.6431372549019608, 0.8549019607843137, 0.07058823529411765, 0.611764705882353, 0.7647058823529411, 0.14901960784313725, 0.5254901960784314, 0.9411764705882353, 0.26666666666666666, 0.08235294117647059, 0.9058823529411765, 0.5333333333333333, 0.2235294117647059, 0.9529411764705882, 0.3686274509803922, 0.8980392156862745, 0.5058823529411764, 0.20784313725490197, 0.5490196078431373, 0.1450980392156863, 0.5764705882352941, 0.34901960784313724, 0.9803921568627451, 0.47843137254901963, 0.10196078431372549, 0.6235294117647059, 0.6784313725490196, 0.9333333333333333, 0.4823529411764706, 0.13333333333333333, 0.6352941176470588, 0.19215686274509805, 0.5803921568627451, 0.12549019607843137, 0.3411764705882353, 0.6196078431372549, 0.5372549019607843, 0.8392156862745098, 0.6705882352941176, 0.30196078431372547, 0.4980392156862745, 0.1843137254901961, 0.9098039215686274, 0.01568627450980392, 0.8784313725490196, 0.3803921568627451, 0.4470588235294118, 0.12549019607843137, 0.27450980392156865, 0.41568627450980394, 0.19215686274509805, 0.7803921568627451, 0.9490196078431372, 0.5333333333333333, 0.09803921568627451, 0.25098039215686274, 0.1803921568627451, 0.6509803921568628, 0.8980392156862745, 0.12941176470588237, 0.592156862745098, 0.8784313725490196, 0.027450980392156862, 0.4823529411764706, 0.21176470588235294, 0.7686274509803922, 0.5058823529411764, 0.0, 0.4627450980392157, 0.7490196078431373, 0.21568627450980393, 0.03529411764705882, 0.6980392156862745, 0.8470588235294118, 0.25882352941176473, 0.5333333333333333, 0.37254901960784315, 0.20784313725490197, 0.9137254901960784, 0.5529411764705883, 0.8431372549019608, 0.01568627450980392, 0.7294117647058823, 0.1607843137254902, 0.2784313725490196, 0.8156862745098039, 0.023529411764705882, 0.9176470588235294, 0.6352941176470588, 0.8745098039215686, 0.2980392156862745, 0.03529411764705882, 0.4235294117647059, 0.7372549019607844, 0.9294117647058824, 0.4627450980392157, 0.3843137254901961, 0.596078431372549, 0.11764705882352941, 0.6627450980392157, 0.4117647058823529, 0.2980392156862745, 0.47843137254901963, 0.8156862745098039, 0.07058823529411765, 0.7529411764705882, 0.6941176470588235, 0.44313725490196076, 0.23529411764705882, 0.023529411764705882, 0.5882352941176471, 0.33725490196078434, 0.45098039215686275, 0.9607843137254902, 0.30196078431372547, 0.43137254901960786, 0.2, 0.06274509803921569, 0.32941176470588235, 0.1568627450980392, 0.9137254901960784, 0.49019607843137253, 0.8196078431372549, 0.9411764705882353, 0.6313725490196078, 0.5490196078431373, 0.38823529411764707, 0.20784313725490197, 0.4549019607843137, 0.16470588235294117, 0.984313725490196, 0.30196078431372547, 0.49019607843137253, 0.2235294117647059, 0.7176470588235294, 0.41568627450980394, 0.6823529411764706, 0.5764705882352941, 0.8588235294117647, 0.33725490196078434, 0.7294117647058823, 0.8156862745098039, 0.5686274509803921, 0.12156862745098039, 0.3137254901960784, 0.8352941176470589, 0.7372549019607844, 0.8862745098039215, 0.2901960784313726, 0.4392156862745098, 0.03137254901960784, 0.24313725490196078, 0.9176470588235294, 0.19607843137254902, 0.8392156862745098, 0.06274509803921569, 0.27450980392156865, 0.3803921568627451, 0.803921568627451, 0.42745098039215684, 0.9137254901960784, 0.47843137254901963, 0.8235294117647058, 0.27058823529411763, 0.0196078431372549, 0.4392156862745098, 0.23529411764705882, 0.9333333333333333, 0.043137254901960784, 0.5529411764705883, 0.34901960784313724, 0.6196078431372549, 0.29411764705882354, 0.07058823529411765, 0.788235294117647, 0.9725490196078431, 0.6078431372549019, 0.5176470588235295, 0.8666666666666667, 0.13725490196078433, 0.34509803921568627, 0.6313725490196078, 0.8235294117647058, 0.9215686274509803, 0.4823529411764706, 0.06666666666666667, 0.41568627450980394, 0.22745098039215686, 0.3215686274509804, 0.9568627450980393, 0.7372549019607844, 0.3568627450980392, 0.9764705882352941, 0.058823529411764705, 0.3137254901960784, 0.8745098039215686, 0.6196078431372549, 0.3764705882352941, 0.2784313725490196, 0.9254901960784314, 0.34509803921568627, 0.12156862745098039, 0.6352941176470588, 0.00784313725490196, 0.8666666666666667, 0.47843137254901963, 0.2901960784313726, 0.11764705882352941, 0.4392156862745098, 0.34901960784313724, 0.8745098039215686, 0.48627450980392156, 0.984313725490196, 0.596078431372549, 0.6980392156862745, 0.12941176470588237, 0.47058823529411764, 0.792156862745098, 0.3764705882352941, 0.9490196078431372, 0.6627450980392157, 0.27450980392156865, 0.19607843137254902, 0.01568627450980392, 0.29411764705882354, 0.8823529411764706, 0.49411764705882355, 0.2549019607843137, 0.7803921568627451, 0.9137254901960784, 0.14901960784313725, 0.6352941176470588, 0.42745098039215684, 0.2784313725490196, 0.11764705882352941, 0.8431372549019608, 0.9450980392156862, 0.49411764705882355, 0.803921568627451, 0.8627450980392157, 0.06666666666666667, 0.7607843137254902, 0.37254901960784315, 0.5764705882352941, 0.807843137254902, 0.9803921568627451, 0.6196078431372549, 0.7411764705882353, 0.6745098039215687, 0.28627450980392155, 0.1803921568627451, 0.35294117647058826, 0.7294117647058823, 0.1411764705882353, 0.9058823529411765, 0.796078431372549, 0.01568627450980392, 0.34509803921568627, 0.5411764705882353, 0.8941176470588236, 0.3843137254901961, 0.058823529411764705, 0.8352941176470589, 0.29411764705882354, 0.12156862745098039, 0.20392156862745098, 0.4470588235294118, 0.1607843137254902, 0.0, 0.2784313725490196, 0.4549019607843137, 0.17647058823529413, 0.7058823529411765, 0.0392156862745098, 0.2549019607843137, 0.07450980392156863, 0.9490196078431372, 0.6196078431372549, 0.8156862745098039, 0.6980392156862745, 0.5176470588235295, 0.3764705882352941, 0.14901960784313725, 0.5568627450980392, 0.7568627450980392, 0.9568627450980393, 0.0, 0.25098039215686274, 0.7333333333333333, 0.07058823529411765, 0.6745098039215687, 0.7607843137254902, 0.8862745098039215, 0.09411764705882353, 0.7254901960784313, 0.611764705882353, 0.8235294117647058, 0.6980392156862745, 0.22745098039215686, 0.8431372549019608, 0.14901960784313725, 0.7098039215686275, 0.5686274509803921, 0.32941176470588235, 0.00392156862745098, 0.23529411764705882, 0.9176470588235294, 0.6666666666666666, 0.4627450980392157, 0.21568627450980393, 0.7294117647058823, 0.38823529411764707, 0.5882352941176471, 0.9686274509803922, 0.6941176470588235, 0.7725490196078432, 0.5450980392156862, 0.09019607843137255, 0.6274509803921569, 0.13725490196078433, 0.8274509803921568, 0.5686274509803921, 0.25882352941176473, 0.7098039215686275, 0.1607843137254902, 0.984313725490196, 0.5725490196078431, 0.6588235294117647, 0.5098039215686274, 0.42745098039215684, 0.9803921568627451, 0.5803921568627451, 0.7607843137254902, 0.9450980392156862, 0.043137254901960784, 0.615686274509804, 0.7764705882352941, 0.19215686274509805, 0.5686274509803921, 0.09019607843137255, 0.23529411764705882, 0.4235294117647059, 0.3607843137254902, 0.19215686274509805, 0.5411764705882353, 0.24705882352941178, 0.6039215686274509, 0.17254901960784313, 0.11372549019607843, 0.5490196078431373, 0.8392156862745098, 0.6274509803921569, 0.6941176470588235, 0.054901960784313725, 0.17647058823529413, 0.8352941176470589, 0.35294117647058826, 0.0392156862745098, 0.7019607843137254, 0.21568627450980393, 0.8823529411764706, 0.9647058823529412, 0.37254901960784315, 0.6078431372549019, 0.08235294117647059, 0.6588235294117647, 0.19215686274509805, 0.27058823529411763, 0.11372549019607843, 0.6352941176470588, 0.8901960784313725, 0.00784313725490196, 0.7058823529411765, 0.5333333333333333, 0.45098039215686275, 0.047058823529411764, 0.39215686274509803, 0.592156862745098, 0.7843137254901961, 0.5137254901960784, 0.07058823529411765, 0.27058823529411763, 0.6705882352941176, 0.48627450980392156, 0.592156862745098, 0.8235294117647058, 0.7137254901960784, 0.10588235294117647, 0.6666666666666666, 0.5686274509803921, 0.9607843137254902, 0.6313725490196078, 0.7568627450980392, 0.49411764705882355, 1.0, 0.611764705882353, 0.7803921568627451, 0.6627450980392157, 0.8823529411764706, 0.984313725490196, 0.6235294117647059, 0.40784313725490196, 0.4745098039215686, 0.6784313725490196, 0.5215686274509804, 0.18823529411764706, 0.13333333333333333, 0.3176470588235294, 0.5882352941176471, 0.7803921568627451, 0.9921568627450981, 0.45098039215686275, 0.3215686274509804, 0.592156862745098, 0.8666666666666667, 0.5137254901960784, 0.30980392156862746, 1.0, 0.3686274509803922, 0.14901960784313725, 0.5843137254901961, 0.9686274509803922, 0.39215686274509803, 0.32941176470588235, 0.12156862745098039, 0.996078431372549, 0.4117647058823529, 0.5294117647058824, 0.9568627450980393, 0.4745098039215686, 0.1843137254901961, 0.8313725490196079, 0.6392156862745098, 0.7215686274509804, 0.3803921568627451, 0.0784313725490196, 0.8431372549019608, 0.0196078431372549, 0.30196078431372547, 0.14901960784313725, 0.8627450980392157, 0.3333333333333333, 0.00392156862745098, 0.8392156862745098, 0.17647058823529413, 0.43529411764705883, 0.28627450980392155, 0.5215686274509804, 0.38823529411764707, 0.6509803921568628, 0.9058823529411765, 0.43137254901960786, 0.10196078431372549, 0.8352941176470589, 0.050980392156862744, 0.7764705882352941, 0.19607843137254902, 0.8901960784313725, 0.2980392156862745, 0.1450980392156863, 0.40784313725490196, 0.33725490196078434, 0.7176470588235294, 0.5058823529411764, 0.9725490196078431, 0.6745098039215687, 0.30196078431372547, 0.9176470588235294, 0.6509803921568628, 0.7607843137254902, 0.8431372549019608, 0.9686274509803922, 0.7333333333333333, 0.0, 0.8941176470588236, 0.49019607843137253, 0.7686274509803922, 0.34901960784313724, 0.9098039215686274, 0.403921568627451, 0.5215686274509804, 0.9647058823529412, 0.7411764705882353, 0.4392156862745098, 0.5882352941176471, 0.996078431372549, 0.5176470588235295, 0.33725490196078434, 0.00784313725490196, 0.6705882352941176, 0.1803921568627451, 0.5254901960784314, 0.3176470588235294, 0.7176470588235294, 0.3843137254901961, 0.5490196078431373, 0.9215686274509803, 0.2235294117647059, 0.5058823529411764, 0.15294117647058825, 0.27450980392156865, 0.3607843137254902, 0.22745098039215686, 0.8941176470588236, 0.12941176470588237, 0.9647058823529412, 0.00784313725490196, 0.8784313725490196, 0.43137254901960786, 0.9882352941176471, 0.3254901960784314, 0.09411764705882353, 0.9450980392156862, 0.2784313725490196, 0.21568627450980393, 0.44313725490196076, 0.7725490196078432, 0.17254901960784313, 0.33725490196078434, 0.01568627450980392, 0.8941176470588236, 0.36470588235294116, 0.8274509803921568, 0.06274509803921569, 0.4, 0.5137254901960784, 0.08627450980392157, 0.34509803921568627, 0.5411764705882353, 0.7686274509803922, 0.9294117647058824, 0.32941176470588235, 0.8470588235294118, 0.7529411764705882, 0.4196078431372549, 0.8784313725490196, 0.08235294117647059, 0.2627450980392157, 0.023529411764705882, 0.6666666666666666, 0.21176470588235294, 0.7019607843137254, 0.09019607843137255, 0.16862745098039217, 0.6431372549019608, 0.5529411764705883, 0.21568627450980393, 0.4549019607843137, 0.29411764705882354, 0.5098039215686274, 0.17647058823529413, 0.7686274509803922, 0.4666666666666667, 0.08627450980392157, 0.796078431372549, 0.027450980392156862, 0.6549019607843137, 0.25882352941176473, 0.403921568627451, 0.8823529411764706, 0.11372549019607843, 0.4549019607843137, 0.29411764705882354, 0.5843137254901961, 0.9568627450980393, 0.4980392156862745, 0.788235294117647, 0.6666666666666666, 0.10196078431372549, 0.5254901960784314, 0.2627450980392157, 0.47058823529411764, 0.9411764705882353, 0.6705882352941176, 0.8588235294117647, 0.9254901960784314, 0.1843137254901961, 0.07450980392156863, 0.788235294117647, 0.21176470588235294, 0.5333333333333333, 0.32941176470588235, 0.4823529411764706, 0.39215686274509803, 0.09803921568627451, 0.7137254901960784, 0.803921568627451, 0.23921568627450981, 0.6745098039215687, 0.09411764705882353, 0.8352941176470589, 0.25098039215686274, 0.1411764705882353, 0.3843137254901961, 0.796078431372549, 0.027450980392156862, 0.5254901960784314, 0.11372549019607843, 0.3254901960784314, 0.06666666666666667, 0.44313725490196076, 0.8235294117647058, 0.30980392156862746, 0.6901960784313725, 1.0, 0.06666666666666667, 0.24705882352941178, 0.13333333333333333, 0.796078431372549, 0.3254901960784314, 0.08627450980392157, 0.6470588235294118, 0.22745098039215686, 0.10588235294117647, 0.7568627450980392, 0.8588235294117647, 0.5725490196078431, 0.47058823529411764, 0.7764705882352941, 0.25098039215686274, 0.9058823529411765, 0.043137254901960784, 1.0, 0.4666666666666667, 0.7843137254901961, 0.6862745098039216, 0.41568627450980394, 0.8470588235294118, 0.9411764705882353, 0.0784313725490196, 0.8274509803921568, 0.7607843137254902, 0.5450980392156862, 0.3215686274509804, 0.23921568627450981, 0.6941176470588235, 0.615686274509804, 0.807843137254902, 0.19215686274509805, 0.7450980392156863, 0.4, 0.6470588235294118, 0.03137254901960784, 0.9725490196078431, 0.8470588235294118, 0.2549019607843137, 0.4666666666666667, 0.5372549019607843, 0.09019607843137255, 0.23529411764705882, 0.6745098039215687, 0.30196078431372547, 0.9450980392156862, 0.2, 0.25882352941176473, 0.8509803921568627, 0.023529411764705882, 0.1568627450980392, 0.2235294117647059, 0.11372549019607843, 0.3843137254901961, 0.00784313725490196, 0.6470588235294118, 0.9568627450980393, 0.4745098039215686, 0.7254901960784313, 0.8901960784313725, 0.403921568627451, 0.11764705882352941, 0.9098039215686274, 0.4666666666666667, 0.788235294117647, 0.396078431372549, 0.8470588235294118, 0.03137254901960784, 0.9019607843137255, 0.803921568627451, 0.6470588235294118, 0.00784313725490196, 0.8666666666666667, 0.26666666666666666, 0.592156862745098, 0.2, 0.3607843137254902, 0.7450980392156863, 0.9176470588235294, 0.06666666666666667, 0.5098039215686274, 0.7725490196078432, 0.9882352941176471, 0.17647058823529413, 0.7490196078431373, 0.24313725490196078, 0.4196078431372549, 0.5607843137254902, 1.0, 0.20392156862745098, 0.7529411764705882, 0.6352941176470588, 0.3764705882352941, 0.054901960784313725, 0.5725490196078431, 0.23921568627450981, 0.01568627450980392, 0.7450980392156863, 0.4745098039215686, 0.34509803921568627, 0.6, 0.9294117647058824, 0.7372549019607844, 0.25098039215686274, 0.9647058823529412, 0.6235294117647059, 0.0196078431372549, 0.5294117647058824, 0.4627450980392157, 0.8823529411764706, 0.2, 0.5607843137254902, 0.42745098039215684, 0.058823529411764705, 0.8627450980392157, 0.596078431372549, 0.4549019607843137, 0.1803921568627451, 0.8784313725490196, 0.26666666666666666, 0.6313725490196078, 0.5725490196078431, 0.38823529411764707, 0.10196078431372549, 0.21568627450980393, 0.42745098039215684, 0.5843137254901961, 0.7333333333333333, 0.4745098039215686, 0.20392156862745098, 0.9215686274509803, 0.5568627450980392, 0.3764705882352941, 0.8980392156862745, 0.4588235294117647, 0.16470588235294117, 0.26666666666666666, 0.396078431372549, 0.9333333333333333, 0.09803921568627451, 0.8274509803921568, 0.4196078431372549, 0.7450980392156863, 0.1450980392156863, 0.596078431372549, 0.2980392156862745, 0.027450980392156862, 0.3411764705882353, 0.7411764705882353, 0.5686274509803921, 0.4666666666666667, 0.6588235294117647, 0.18823529411764706, 0.4235294117647059, 0.7176470588235294, 0.47843137254901963, 0.1607843137254902, 0.3764705882352941, 0.5215686274509804, 0.047058823529411764, 0.5686274509803921, 0.1450980392156863, 0.8784313725490196, 0.5176470588235295, 0.3686274509803922, 0.12941176470588237, 0.6235294117647059, 0.9372549019607843, 0.796078431372549, 0.7254901960784313, 0.42745098039215684, 0.5607843137254902, 0.1450980392156863, 0.7529411764705882, 0.596078431372549, 0.7098039215686275, 0.4392156862745098, 0.796078431372549, 0.8980392156862745, 0.6588235294117647, 0.5882352941176471, 0.9882352941176471, 0.2784313725490196, 0.5529411764705883, 0.2196078431372549, 0.3568627450980392, 0.16862745098039217, 0.5333333333333333, 0.6235294117647059, 0.8196078431372549, 0.3568627450980392, 0.2784313725490196, 0.9529411764705882, 0.058823529411764705, 0.7137254901960784, 0.6, 0.11764705882352941, 0.6980392156862745, 0.23137254901960785, 0.9490196078431372, 0.5450980392156862, 0.6784313725490196, 0.9215686274509803, 0.49411764705882355, 0.8627450980392157, 0.13333333333333333, 0.3176470588235294, 0.5843137254901961, 0.21568627450980393, 0.3411764705882353, 0.03529411764705882, 0.5333333333333333, 0.6823529411764706, 0.12156862745098039, 0.8784313725490196, 0.054901960784313725, 0.3568627450980392, 0.44313725490196076, 0.9176470588235294, 0.8156862745098039, 0.15294117647058825, 0.7137254901960784, 0.3215686274509804, 0.7803921568627451, 0.41568627450980394, 0.6901960784313725, 0.9921568627450981, 0.12941176470588237, 0.8588235294117647, 0.011764705882352941, 0.6705882352941176, 0.15294117647058825, 0.8235294117647058, 0.4470588235294118, 0.3215686274509804, 0.9450980392156862, 0.6, 0.047058823529411764, 0.9921568627450981, 0.6431372549019608, 0.7568627450980392, 0.9411764705882353, 0.27450980392156865, 0.7098039215686275, 0.34509803921568627, 0.996078431372549, 0.6823529411764706, 0.5019607843137255, 0.9215686274509803, 0.7176470588235294, 0.16470588235294117, 0.9490196078431372, 0.6392156862745098, 0.8117647058823529, 0.0196078431372549, 0.8745098039215686, 0.6784313725490196, 0.6078431372549019, 0.2823529411764706, 0.00784313725490196, 0.8431372549019608, 0.6941176470588235, 0.3058823529411765, 0.8117647058823529, 0.6313725490196078, 0.054901960784313725, 0.6862745098039216, 0.1607843137254902, 0.5607843137254902, 0.6392156862745098, 0.34901960784313724, 0.21568627450980393, 0.8745098039215686, 0.08627450980392157, 0.9686274509803922, 0.1803921568627451, 0.6196078431372549, 0.11372549019607843, 0.30980392156862746, 0.996078431372549, 0.023529411764705882, 0.6313725490196078, 0.9333333333333333, 0.8470588235294118, 0.10196078431372549, 0.9176470588235294, 0.22745098039215686, 0.8392156862745098, 0.9333333333333333, 0.4666666666666667, 0.7254901960784313, 0.3058823529411765, 0.5843137254901961, 0.7019607843137254, 0.054901960784313725, 0.38823529411764707, 0.2901960784313726, 0.1843137254901961, 0.9764705882352941, 0.8784313725490196, 0.0196078431372549, 0.4588235294117647, 0.9215686274509803, 0.10588235294117647, 0.3803921568627451, 0.5647058823529412, 0.27450980392156865, 0.49411764705882355, 0.7333333333333333, 0.44313725490196076, 0.807843137254902, 0.06274509803921569, 0.7058823529411765, 0.8588235294117647, 0.792156862745098, 0.30196078431372547, 0.9372549019607843, 0.03529411764705882, 0.5764705882352941, 0.7529411764705882, 0.5294117647058824, 0.22745098039215686, 0.3333333333333333, 0.9764705882352941, 0.48627450980392156, 0.4117647058823529, 0.3568627450980392, 0.07058823529411765, 0.42745098039215684, 0.30980392156862746, 0.7254901960784313, 0.050980392156862744, 0.6235294117647059, 0.43529411764705883, 0.8156862745098039, 0.7058823529411765, 0.9450980392156862, 0.6588235294117647, 0.4117647058823529, 0.8980392156862745, 0.8156862745098039, 0.32941176470588235, 0.6352941176470588, 0.16470588235294117, 0.7098039215686275, 0.596078431372549, 0.29411764705882354, 0.08235294117647059, 0.5058823529411764, 0.9803921568627451, 0.8862745098039215, 0.10980392156862745, 0.5137254901960784, 0.2901960784313726, 0.6235294117647059, 0.23529411764705882, 0.40784313725490196, 0.5098039215686274, 0.2980392156862745, 0.5803921568627451, 0.8980392156862745, 0.2196078431372549, 0.7607843137254902, 0.17254901960784313, 0.37254901960784315, 0.7058823529411765, 0.28627450980392155, 0.3568627450980392, 0.47843137254901963, 0.17254901960784313, 0.5411764705882353, 0.10196078431372549, 0.2196078431372549, 0.8156862745098039, 0.4, 0.1411764705882353, 0.054901960784313725, 0.23921568627450981, 0.8627450980392157, 0.5372549019607843, 0.32941176470588235, 0.5098039215686274, 0.27058823529411763, 0.36470588235294116, 0.09411764705882353, 0.9764705882352941, 0.41568627450980394, 0.7568627450980392, 0.1411764705882353, 0.5019607843137255, 0.03529411764705882, 0.9137254901960784, 0.5333333333333333, 0.7333333333333333, 0.3137254901960784, 0.9725490196078431, 0.45098039215686275, 0.023529411764705882, 0.8509803921568627, 0.5019607843137255, 0.6666666666666666, 0.43137254901960786, 0.5372549019607843, 0.8196078431372549, 0.8980392156862745, 0.24705882352941178, 0.5176470588235295, 0.796078431372549, 0.3803921568627451, 0.26666666666666666, 0.06666666666666667, 0.7764705882352941, 0.2980392156862745, 0.6666666666666666, 0.6039215686274509, 0.4196078431372549, 0.33725490196078434, 0.24705882352941178, 0.796078431372549, 0.07450980392156863, 0.19607843137254902, 0.9137254901960784, 0.48627450980392156, 0.8705882352941177, 0.6588235294117647, 0.11372549019607843, 0.6078431372549019, 0.34901960784313724, 0.5176470588235295, 0.807843137254902, 0.3254901960784314, 0.6431372549019608, 0.17254901960784313, 0.9647058823529412, 0.054901960784313725, 0.3411764705882353, 0.08235294117647059, 0.19607843137254902, 0.9058823529411765, 0.6313725490196078, 0.4980392156862745, 0.12156862745098039, 0.050980392156862744, 0.43529411764705883, 0.24313725490196078, 0.49019607843137253, 0.1843137254901961, 0.6549019607843137, 0.12549019607843137, 0.44313725490196076, 0.796078431372549, 0.16470588235294117, 0.26666666666666666, 0.7568627450980392, 0.9137254901960784, 0.6274509803921569, 0.8352941176470589, 0.1568627450980392, 0.3843137254901961, 0.2235294117647059, 0.984313725490196, 0.27450980392156865, 0.5294117647058824, 0.01568627450980392, 0.1568627450980392, 0.796078431372549, 0.6078431372549019, 0.27058823529411763, 0.07058823529411765, 0.39215686274509803, 0.5176470588235295, 0.9411764705882353, 0.2549019607843137, 0.01568627450980392, 0.8705882352941177, 0.40784313725490196, 0.23137254901960785, 0.6078431372549019, 0.3607843137254902, 0.19607843137254902, 0.8352941176470589, 0.5647058823529412, 0.043137254901960784, 0.8, 0.7137254901960784, 0.9607843137254902, 0.06666666666666667, 0.3686274509803922, 0.10588235294117647, 0.5411764705882353, 0.6627450980392157, 0.0784313725490196, 0.8588235294117647, 0.5058823529411764, 0.12941176470588237, 0.807843137254902, 0.00392156862745098, 0.8901960784313725, 0.40784313725490196, 0.7450980392156863, 0.6196078431372549, 0.01568627450980392, 0.5647058823529412, 0.7647058823529411, 0.3607843137254902, 0.4666666666666667, 0.788235294117647, 0.0392156862745098, 0.6745098039215687, 0.13725490196078433, 0.9333333333333333, 0.4549019607843137, 0.8274509803921568, 0.16862745098039217, 0.5254901960784314, 0.6627450980392157, 0.24313725490196078, 0.984313725490196, 0.596078431372549, 0.40784313725490196, 0.2196078431372549, 0.12549019607843137, 0.3686274509803922, 0.7843137254901961, 0.19607843137254902, 0.2823529411764706, 0.9254901960784314, 0.11764705882352941, 0.8, 0.25882352941176473, 0.7058823529411765, 0.3686274509803922, 0.4470588235294118, 0.0392156862745098, 0.7294117647058823, 0.8627450980392157, 0.1450980392156863, 0.596078431372549, 0.5098039215686274, 0.3568627450980392, 0.5529411764705883, 0.027450980392156862, 0.7568627450980392, 0.12156862745098039, 0.6901960784313725, 0.0, 0.6313725490196078, 0.996078431372549, 0.42745098039215684, 0.7647058823529411, 0.23921568627450981, 0.5568627450980392, 0.027450980392156862, 0.4588235294117647, 0.8392156862745098, 0.2627450980392157, 0.6862745098039216, 0.07450980392156863, 0.23921568627450981, 0.8392156862745098, 0.48627450980392156, 0.7568627450980392, 0.6823529411764706, 0.8588235294117647, 0.9372549019607843, 0.5333333333333333, 0.1450980392156863, 0.30980392156862746, 0.3764705882352941, 0.9803921568627451, 0.6, 0.6784313725490196, 0.7647058823529411, 0.9686274509803922, 0.08235294117647059, 0.8784313725490196, 0.38823529411764707, 0.9333333333333333, 0.6745098039215687, 0.5490196078431373, 0.8627450980392157, 0.043137254901960784, 0.19607843137254902, 0.5137254901960784, 0.11372549019607843, 0.7803921568627451, 0.4745098039215686, 0.5607843137254902, 0.6823529411764706, 0.09411764705882353, 0.8941176470588236, 0.38823529411764707, 0.48627450980392156, 0.23921568627450981, 0.11764705882352941, 0.4627450980392157, 0.9215686274509803, 0.19607843137254902, 0.8431372549019608, 0.7372549019607844, 0.47058823529411764, 0.788235294117647, 0.5372549019607843, 0.6627450980392157, 0.7568627450980392, 0.027450980392156862, 0.44313725490196076, 0.6509803921568628, 0.9529411764705882, 0.1568627450980392, 0.9058823529411765, 0.3333333333333333, 0.45098039215686275, 0.1803921568627451, 0.8509803921568627, 0.6941176470588235, 0.7803921568627451, 0.4117647058823529, 0.27058823529411763, 0.9254901960784314, 0.43137254901960786, 0.7490196078431373, 0.23529411764705882, 0.9176470588235294, 0.596078431372549, 0.6745098039215687, 0.3137254901960784, 0.8431372549019608, 0.9529411764705882, 0.4823529411764706, 0.30196078431372547, 0.8705882352941177, 0.9647058823529412, 0.6078431372549019, 0.28627450980392155, 0.4117647058823529, 0.7372549019607844, 0.8901960784313725, 0.19607843137254902, 0.5686274509803921, 0.7686274509803922, 0.3058823529411765, 0.054901960784313725, 0.8823529411764706, 0.45098039215686275, 0.3411764705882353, 0.18823529411764706, 0.7686274509803922, 0.9529411764705882, 0.4823529411764706, 0.8941176470588236, 0.611764705882353, 0.5137254901960784, 0.7137254901960784, 0.38823529411764707, 0.5647058823529412, 0.3254901960784314, 0.9450980392156862, 0.058823529411764705, 0.14901960784313725, 0.6470588235294118, 0.9254901960784314, 0.21176470588235294, 0.4117647058823529, 0.6862745098039216, 0.9490196078431372, 0.2, 0.8823529411764706, 0.4470588235294118, 0.984313725490196, 0.27450980392156865, 0.4980392156862745, 0.9450980392156862, 0.8823529411764706, 0.16862745098039217, 0.5372549019607843, 0.35294117647058826, 0.8313725490196079, 0.14901960784313725, 0.3215686274509804, 0.7098039215686275, 0.9529411764705882, 0.20784313725490197, 0.7607843137254902, 0.4117647058823529, 0.1568627450980392, 0.9803921568627451, 0.5450980392156862, 0.00392156862745098, 0.30196078431372547, 0.21176470588235294, 0.403921568627451, 0.6, 0.25882352941176473, 0.7803921568627451, 0.42745098039215684, 0.8862745098039215, 0.2235294117647059, 0.5490196078431373, 0.1568627450980392, 0.32941176470588235, 0.8431372549019608, 0.27450980392156865, 0.7176470588235294, 0.592156862745098, 0.01568627450980392, 0.2980392156862745, 0.09019607843137255, 0.6352941176470588, 0.3411764705882353, 0.5803921568627451, 0.7058823529411765, 0.2549019607843137, 0.9568627450980393, 0.0, 0.3333333333333333, 0.807843137254902, 0.1803921568627451, 0.7490196078431373, 0.30980392156862746, 0.8509803921568627, 0.984313725490196, 0.5529411764705883, 0.7647058823529411, 0.7019607843137254, 0.5882352941176471, 0.047058823529411764, 0.2980392156862745, 0.10980392156862745, 0.984313725490196, 0.19607843137254902, 0.13725490196078433, 0.9411764705882353, 0.2980392156862745, 0.807843137254902, 0.09019607843137255, 0.47843137254901963, 0.3843137254901961, 0.7490196078431373, 0.08235294117647059, 0.5490196078431373, 0.27450980392156865, 0.615686274509804, 0.47058823529411764, 1.0, 0.1450980392156863, 0.8196078431372549, 0.5843137254901961, 0.023529411764705882, 0.6313725490196078, 0.3333333333333333, 0.5333333333333333, 0.08627450980392157, 0.20784313725490197, 0.4549019607843137, 0.14901960784313725, 0.0784313725490196, 0.25098039215686274, 0.6549019607843137, 0.1803921568627451, 0.10980392156862745, 0.6980392156862745, 0.20784313725490197, 0.9882352941176471, 0.08627450980392157, 0.35294117647058826, 0.6196078431372549, 0.03137254901960784, 0.7058823529411765, 0.39215686274509803, 0.9450980392156862, 0.615686274509804, 0.8, 0.10196078431372549, 0.7098039215686275, 0.06274509803921569, 0.6549019607843137, 0.26666666666666666, 0.00392156862745098, 0.8196078431372549, 0.09019607843137255, 0.984313725490196, 0.6509803921568628, 0.047058823529411764, 0.19215686274509805, 0.7450980392156863, 0.6078431372549019, 0.49411764705882355, 0.7803921568627451, 0.2980392156862745, 0.5725490196078431, 0.0784313725490196, 0.47058823529411764, 0.3215686274509804, 0.807843137254902, 0.11372549019607843, 0.7058823529411765, 0.1568627450980392, 0.8549019607843137, 0.20392156862745098, 0.3764705882352941, 0.5764705882352941, 0.4549019607843137, 0.2784313725490196, 0.6823529411764706, 0.09019607843137255, 0.611764705882353, 0.9254901960784314, 0.788235294117647, 0.3764705882352941, 0.5333333333333333, 0.0392156862745098, 0.9098039215686274, 0.5803921568627451, 0.7137254901960784, 0.3686274509803922, 0.8823529411764706, 0.6235294117647059, 0.11764705882352941, 0.4745098039215686, 0.8235294117647058, 0.01568627450980392, 0.7137254901960784, 0.6509803921568628, 0.09411764705882353, 0.7333333333333333, 0.8196078431372549, 0.396078431372549, 0.00392156862745098, 0.6196078431372549, 0.47058823529411764, 0.34901960784313724, 0.20392156862745098, 0.8235294117647058, 0.5058823529411764, 0.9176470588235294, 0.4235294117647059, 0.807843137254902, 0.9882352941176471, 0.4470588235294118, 0.3058823529411765, 0.8784313725490196, 0.6588235294117647, 0.13333333333333333, 0.9411764705882353, 0.4470588235294118, 0.5764705882352941, 0.6392156862745098, 0.08235294117647059, 0.6784313725490196, 0.3568627450980392, 0.0196078431372549, 0.3176470588235294, 0.9686274509803922, 0.43529411764705883, 0.6705882352941176, 0.5647058823529412, 0.34509803921568627, 0.38823529411764707, 0.8470588235294118, 0.48627450980392156, 0.5764705882352941, 0.7254901960784313, 0.8980392156862745, 0.2627450980392157, 0.6862745098039216, 0.2196078431372549, 0.6392156862745098, 0.8235294117647058, 0.9372549019607843, 0.023529411764705882, 0.22745098039215686, 0.33725490196078434, 0.06274509803921569, 0.49411764705882355, 0.30980392156862746, 0.9686274509803922, 0.1803921568627451, 0.8313725490196079, 0.396078431372549, 0.7215686274509804, 0.984313725490196, 0.796078431372549, 0.3686274509803922, 0.5568627450980392, 0.9176470588235294, 0.7294117647058823, 0.4392156862745098, 0.5294117647058824, 0.023529411764705882, 0.8392156862745098, 0.5647058823529412, 0.48627450980392156, 0.8, 0.2549019607843137, 0.9686274509803922, 0.49411764705882355, 0.2196078431372549, 0.12941176470588237, 0.5607843137254902, 0.27450980392156865, 0.3803921568627451, 0.8745098039215686, 0.5411764705882353, 0.8392156862745098, 0.43137254901960786, 0.5725490196078431, 0.3411764705882353, 0.23921568627450981, 0.15294117647058825, 0.7725490196078432, 0.4745098039215686, 0.9019607843137255, 0.4117647058823529, 0.23529411764705882, 0.9882352941176471, 0.34901960784313724, 0.12549019607843137, 0.7137254901960784, 0.9686274509803922, 0.6235294117647059, 0.00392156862745098, 0.7411764705882353, 0.24705882352941178, 0.5843137254901961, 0.403921568627451, 0.6352941176470588, 0.7686274509803922, 0.06274509803921569, 0.8196078431372549, 0.10980392156862745, 0.7450980392156863, 0.9607843137254902, 0.01568627450980392, 0.5019607843137255, 0.4196078431372549, 0.17254901960784313, 0.09803921568627451, 0.2980392156862745, 0.6274509803921569, 0.4666666666666667, 0.8, 0.2784313725490196, 0.09411764705882353, 0.4392156862745098, 0.7803921568627451, 1.0, 0.6941176470588235, 0.36470588235294116, 0.1843137254901961, 0.9647058823529412, 0.48627450980392156, 0.043137254901960784, 0.28627450980392155, 0.9333333333333333, 0.5137254901960784, 0.6941176470588235, 0.8980392156862745, 0.13725490196078433, 0.5568627450980392, 0.984313725490196, 0.7529411764705882, 0.13333333333333333, 0.6980392156862745, 0.23529411764705882, 0.1568627450980392, 0.023529411764705882, 0.7686274509803922, 0.09803921568627451, 0.6078431372549019, 0.5333333333333333, 0.4117647058823529, 0.7215686274509804, 0.24313725490196078, 0.050980392156862744, 0.9294117647058824, 0.2, 0.4196078431372549, 0.8117647058823529, 0.16470588235294117, 0.5058823529411764, 0.23529411764705882, 0.8313725490196079, 0.14901960784313725, 0.9058823529411765, 0.6235294117647059, 0.0392156862745098, 0.6941176470588235, 0.24313725490196078, 0.06274509803921569, 0.17254901960784313, 0.3411764705882353, 0.5529411764705883, 0.00392156862745098, 0.9764705882352941, 0.4980392156862745, 0.1411764705882353, 0.39215686274509803, 0.7176470588235294, 0.5764705882352941, 0.9098039215686274, 0.6431372549019608, 0.7333333333333333, 0.21568627450980393, 0.6862745098039216, 0.09803921568627451, 0.4666666666666667, 0.9333333333333333, 0.2549019607843137, 0.047058823529411764, 0.5098039215686274, 0.6313725490196078, 0.8588235294117647, 0.03529411764705882, 0.32941176470588235, 0.8117647058823529, 0.9333333333333333, 0.38823529411764707, 0.3137254901960784, 0.6509803921568628, 0.1568627450980392, 0.42745098039215684, 0.8627450980392157, 0.10588235294117647, 0.6666666666666666, 0.33725490196078434, 0.8549019607843137, 0.7215686274509804, 0.011764705882352941, 0.9294117647058824, 0.47058823529411764, 0.3137254901960784, 0.12156862745098039, 0.1803921568627451, 0.7450980392156863, 0.6862745098039216, 0.8705882352941177, 0.4392156862745098, 0.27450980392156865, 0.596078431372549, 0.8509803921568627, 0.09411764705882353, 0.5411764705882353, 0.8313725490196079, 0.0196078431372549, 0.8862745098039215, 0.5215686274509804, 0.3764705882352941, 0.16862745098039217, 0.9058823529411765, 0.5333333333333333, 0.36470588235294116, 0.9529411764705882, 0.09019607843137255, 0.30196078431372547, 0.5333333333333333, 0.6705882352941176, 0.32941176470588235, 0.2235294117647059, 0.8588235294117647, 0.39215686274509803, 0.6392156862745098, 0.25882352941176473, 0.8509803921568627, 0.5725490196078431, 0.6745098039215687, 0.9921568627450981, 0.8588235294117647, 0.12549019607843137, 0.18823529411764706, 0.6666666666666666, 0.9490196078431372, 0.2235294117647059, 0.5333333333333333, 0.1607843137254902, 0.06666666666666667, 0.5764705882352941, 0.8901960784313725, 0.3176470588235294, 0.5529411764705883, 0.8549019607843137, 0.3568627450980392, 0.18823529411764706, 0.10196078431372549, 0.43529411764705883, 0.23137254901960785, 0.792156862745098, 0.06666666666666667, 0.4196078431372549, 0.2627450980392157, 0.47843137254901963, 0.3686274509803922, 0.8784313725490196, 0.5254901960784314, 0.6666666666666666, 0.3843137254901961, 0.21568627450980393, 0.8274509803921568, 0.17647058823529413, 0.2823529411764706, 0.8549019607843137, 0.5019607843137255, 0.34509803921568627, 0.7803921568627451, 0.5254901960784314, 0.27450980392156865, 0.9450980392156862, 0.6196078431372549, 0.8862745098039215, 0.08235294117647059, 0.4, 0.7686274509803922, 0.26666666666666666, 0.4627450980392157, 0.796078431372549, 0.9137254901960784, 0.3176470588235294, 0.6431372549019608, 0.996078431372549, 0.4117647058823529, 0.8470588235294118, 0.7764705882352941, 0.43137254901960786, 0.3137254901960784, 0.8784313725490196, 0.09803921568627451, 0.7686274509803922, 0.42745098039215684, 0.17647058823529413, 0.8549019607843137, 0.5450980392156862, 0.38823529411764707, 0.8862745098039215, 0.7764705882352941, 0.5607843137254902, 0.1411764705882353, 0.6588235294117647, 0.30196078431372547, 0.7607843137254902, 0.17647058823529413, 0.6980392156862745, 0.41568627450980394, 0.21568627450980393, 0.596078431372549, 0.13725490196078433, 0.7529411764705882, 0.23921568627450981, 0.9019607843137255, 0.058823529411764705, 0.7215686274509804, 0.2980392156862745, 0.5450980392156862, 0.9137254901960784, 0.44313725490196076, 0.7803921568627451, 0.19607843137254902, 0.5058823529411764, 0.6745098039215687, 0.22745098039215686, 0.6313725490196078, 0.996078431372549, 0.3843137254901961, 0.9176470588235294, 0.0392156862745098, 0.5372549019607843, 0.9529411764705882, 0.3568627450980392, 0.011764705882352941, 0.6901960784313725, 0.3137254901960784, 0.16470588235294117, 0.6705882352941176, 0.4549019607843137, 0.2627450980392157, 0.8, 0.2235294117647059, 0.8431372549019608, 0.2901960784313726, 0.6627450980392157, 0.058823529411764705, 0.8313725490196079, 0.47843137254901963, 0.8862745098039215, 0.023529411764705882, 0.4392156862745098, 0.984313725490196, 0.6, 0.4823529411764706, 0.1411764705882353, 0.9098039215686274, 0.3137254901960784, 0.7254901960784313, 0.047058823529411764, 0.44313725490196076, 0.24313725490196078, 0.7411764705882353, 0.3411764705882353, 0.396078431372549, 0.5098039215686274, 0.03137254901960784, 0.7333333333333333, 0.33725490196078434, 0.8509803921568627, 0.27058823529411763, 0.7490196078431373, 0.44313725490196076, 0.23529411764705882, 0.1411764705882353, 0.6078431372549019, 0.7725490196078432, 0.6431372549019608, 0.996078431372549, 0.7450980392156863, 0.30980392156862746, 0.9254901960784314, 0.6549019607843137, 0.8588235294117647, 0.043137254901960784, 0.6235294117647059, 0.9568627450980393, 0.0784313725490196, 0.3176470588235294, 0.5686274509803921, 0.9411764705882353, 0.47843137254901963, 0.9098039215686274, 0.7019607843137254, 0.03137254901960784, 0.996078431372549, 0.11372549019607843, 0.6078431372549019, 0.8862745098039215, 0.027450980392156862, 0.7137254901960784, 0.10980392156862745, 0.45098039215686275, 0.7411764705882353, 0.6470588235294118, 0.5411764705882353, 0.0, 0.7098039215686275, 0.21176470588235294, 0.08627450980392157, 0.5294117647058824, 0.43137254901960786, 0.7529411764705882, 0.5019607843137255, 0.12941176470588237, 0.6078431372549019, 0.07058823529411765, 0.1843137254901961, 0.5843137254901961, 0.6784313725490196, 0.25098039215686274, 0.5294117647058824, 0.2980392156862745, 0.8, 0.00784313725490196, 0.11764705882352941, 0.45098039215686275, 0.2784313725490196, 0.03529411764705882, 0.3411764705882353, 0.8666666666666667, 0.5882352941176471, 0.4235294117647059, 0.9058823529411765, 0.11372549019607843, 0.27450980392156865, 0.49411764705882355, 1.0, 0.07058823529411765, 0.6823529411764706, 0.4666666666666667, 0.5215686274509804, 0.9529411764705882, 0.6039215686274509, 0.7803921568627451, 0.38823529411764707, 0.1803921568627451, 0.0196078431372549, 0.592156862745098, 0.07450980392156863, 0.4, 0.9686274509803922, 0.1450980392156863, 0.7607843137254902, 0.050980392156862744, 0.5882352941176471, 0.788235294117647, 0.28627450980392155, 0.48627450980392156, 0.12549019607843137, 0.6313725490196078, 0.2, 0.8, 0.5058823529411764, 0.9607843137254902, 0.3843137254901961, 0.7568627450980392, 0.6235294117647059, 0.07058823529411765, 0.41568627450980394, 0.6823529411764706, 0.09411764705882353, 0.49411764705882355, 0.4235294117647059, 0.7764705882352941, 0.18823529411764706, 0.6980392156862745, 0.23137254901960785, 0.9254901960784314, 0.796078431372549, 0.17647058823529413, 0.7137254901960784, 0.03529411764705882, 0.5490196078431373, 0.7803921568627451, 0.20392156862745098, 0.9568627450980393, 0.3607843137254902, 0.8117647058823529, 0.49411764705882355, 0.011764705882352941, 0.9411764705882353, 0.5647058823529412, 0.8901960784313725, 0.8117647058823529, 0.6, 0.4117647058823529, 0.9215686274509803, 0.4980392156862745, 0.6274509803921569, 0.03137254901960784, 0.803921568627451, 0.9137254901960784, 0.4, 0.4666666666666667, 0.2549019607843137, 0.5450980392156862, 0.027450980392156862, 0.592156862745098, 0.3843137254901961, 0.17254901960784313, 0.5294117647058824, 0.3254901960784314, 0.7294117647058823, 0.19215686274509805, 0.788235294117647, 0.8470588235294118, 0.26666666666666666, 0.7372549019607844, 0.050980392156862744, 0.33725490196078434, 0.8, 0.5686274509803921, 0.3803921568627451, 0.7529411764705882, 0.17254901960784313, 0.4666666666666667, 0.39215686274509803, 0.8470588235294118, 0.5843137254901961, 0.20392156862745098, 0.36470588235294116, 0.28627450980392155, 0.9372549019607843, 0.33725490196078434, 0.9764705882352941, 0.596078431372549, 0.8705882352941177, 0.1607843137254902, 0.9568627450980393, 0.027450980392156862, 0.21568627450980393, 0.8823529411764706, 0.2901960784313726, 0.7215686274509804, 0.9490196078431372, 0.807843137254902, 0.3568627450980392, 0.9176470588235294, 0.054901960784313725, 0.9803921568627451, 0.4823529411764706, 0.3607843137254902, 0.9215686274509803, 0.7529411764705882, 0.6235294117647059, 0.984313725490196, 0.5019607843137255, 0.2, 0.8117647058823529, 0.01568627450980392, 0.9686274509803922, 0.34509803921568627, 0.5725490196078431, 0.8862745098039215, 0.7686274509803922, 0.37254901960784315, 0.26666666666666666, 0.8745098039215686, 0.0, 0.34901960784313724, 0.11764705882352941, 0.2235294117647059, 0.6823529411764706, 0.996078431372549, 0.8117647058823529, 0.26666666666666666, 0.6431372549019608, 0.8941176470588236, 0.29411764705882354, 0.5490196078431373, 0.3568627450980392, 0.8627450980392157, 0.45098039215686275, 0.0784313725490196, 0.21568627450980393, 0.6705882352941176, 0.8313725490196079, 0.4, 0.7254901960784313, 0.9058823529411765, 0.24313725490196078, 0.12156862745098039, 0.5725490196078431, 0.8941176470588236, 0.19607843137254902, 0.9764705882352941, 0.5490196078431373, 0.7490196078431373, 0.9411764705882353, 0.592156862745098, 0.13725490196078433, 1.0, 0.615686274509804, 0.34509803921568627, 0.5647058823529412, 0.12156862745098039, 0.28627450980392155, 0.40784313725490196, 0.8745098039215686, 0.24705882352941178, 0.35294117647058826, 0.6901960784313725, 0.06274509803921569, 0.5215686274509804, 0.11764705882352941, 0.8941176470588236, 0.6078431372549019, 0.21176470588235294, 0.6901960784313725, 0.07450980392156863, 0.3058823529411765, 0.14901960784313725, 0.2549019607843137, 0.050980392156862744, 0.6627450980392157, 0.11372549019607843, 0.3803921568627451, 0.9764705882352941, 0.7019607843137254, 0.08627450980392157, 0.6705882352941176, 0.054901960784313725, 0.8823529411764706, 0.14901960784313725, 0.8431372549019608, 0.47843137254901963, 0.7098039215686275, 0.11372549019607843, 0.7843137254901961, 0.9333333333333333, 0.5607843137254902, 0.027450980392156862, 0.4392156862745098, 0.6039215686274509, 0.10980392156862745, 0.1607843137254902, 0.4235294117647059, 0.6235294117647059, 0.10196078431372549, 0.2196078431372549, 0.6549019607843137, 0.3058823529411765, 0.9254901960784314, 0.6901960784313725, 0.23137254901960785, 0.3215686274509804, 0.9607843137254902, 0.06274509803921569, 0.788235294117647, 0.12941176470588237, 0.8392156862745098, 0.17647058823529413, 0.49411764705882355, 0.40784313725490196, 0.30196078431372547, 0.6666666666666666, 0.36470588235294116, 0.5803921568627451, 0.792156862745098, 0.6862745098039216, 0.3764705882352941, 0.5137254901960784, 0.23921568627450981, 0.027450980392156862, 0.4588235294117647, 0.15294117647058825, 0.6039215686274509, 0.6588235294117647, 0.20784313725490197, 0.7019607843137254, 0.5803921568627451, 0.15294117647058825, 0.22745098039215686, 0.6745098039215687, 0.07450980392156863, 0.3843137254901961, 0.6980392156862745, 0.23921568627450981, 0.4745098039215686, 0.6235294117647059, 0.7254901960784313, 0.03137254901960784, 0.16470588235294117, 0.5450980392156862, 0.6392156862745098, 0.8, 0.19215686274509805, 0.44313725490196076, 0.8313725490196079, 0.5647058823529412, 0.4666666666666667, 0.3215686274509804, 0.5176470588235295, 0.7372549019607844, 0.12156862745098039, 0.4627450980392157, 0.8274509803921568, 0.03137254901960784, 0.7019607843137254, 0.2549019607843137, 0.9019607843137255, 0.5254901960784314, 0.7294117647058823, 0.9764705882352941, 0.3137254901960784, 0.054901960784313725, 0.5490196078431373, 0.08627450980392157, 0.4392156862745098, 0.7764705882352941, 0.2784313725490196, 0.027450980392156862, 0.49019607843137253, 0.30196078431372547, 0.1450980392156863, 0.3333333333333333, 0.0392156862745098, 0.8784313725490196, 0.25098039215686274, 0.3137254901960784, 0.011764705882352941, 0.45098039215686275, 0.7450980392156863, 0.6549019607843137, 0.5098039215686274, 0.06666666666666667, 0.6352941176470588, 0.9490196078431372, 0.45098039215686275, 0.8274509803921568, 0.596078431372549, 0.41568627450980394, 0.6588235294117647, 0.16470588235294117, 0.29411764705882354, 0.7843137254901961, 0.42745098039215684, 0.8392156862745098, 0.6352941176470588, 0.4666666666666667, 0.7176470588235294, 0.9647058823529412, 0.20392156862745098, 0.8274509803921568, 0.2980392156862745, 0.17254901960784313, 0.5254901960784314, 0.3333333333333333, 0.21176470588235294, 0.9568627450980393, 0.29411764705882354, 0.796078431372549, 0.35294117647058826, 0.21176470588235294, 0.9725490196078431, 0.2784313725490196, 0.43529411764705883, 0.23921568627450981, 0.6823529411764706, 0.39215686274509803, 0.996078431372549, 0.49411764705882355, 0.6745098039215687, 0.8862745098039215, 0.9686274509803922, 0.5098039215686274, 0.8627450980392157, 0.9490196078431372, 0.45098039215686275, 0.5411764705882353, 0.07058823529411765, 0.796078431372549, 0.1411764705882353, 0.5529411764705883, 0.6705882352941176, 0.5019607843137255, 0.9019607843137255, 0.6, 0.43137254901960786, 0.6784313725490196, 0.09411764705882353, 0.807843137254902, 0.7294117647058823, 0.12156862745098039, 0.8392156862745098, 0.26666666666666666, 0.08235294117647059, 0.4588235294117647, 0.9098039215686274, 0.10980392156862745, 0.6470588235294118, 0.5490196078431373, 0.7529411764705882, 0.8470588235294118, 0.396078431372549, 0.8745098039215686, 0.27450980392156865, 0.07450980392156863, 0.9607843137254902, 0.3176470588235294, 0.8313725490196079, 0.43137254901960786, 0.9098039215686274, 0.7725490196078432, 0.13333333333333333, 0.5294117647058824, 0.8431372549019608, 0.08627450980392157, 0.21176470588235294, 0.44313725490196076, 0.8235294117647058, 0.9568627450980393, 0.09411764705882353, 0.30980392156862746, 0.7254901960784313, 0.9803921568627451, 0.6588235294117647, 0.03529411764705882, 0.8745098039215686, 0.08627450980392157, 0.1607843137254902, 0.9490196078431372, 0.34901960784313724, 0.2196078431372549, 0.5803921568627451, 0.7843137254901961, 0.41568627450980394, 0.1803921568627451, 0.6470588235294118, 0.3411764705882353, 0.1411764705882353, 0.43137254901960786, 0.592156862745098, 0.17647058823529413, 0.9490196078431372, 0.3411764705882353, 0.6588235294117647, 0.6078431372549019, 0.8509803921568627, 0.36470588235294116, 0.7294117647058823, 0.9176470588235294, 0.8156862745098039, 0.6392156862745098, 0.4627450980392157, 0.3803921568627451, 0.7254901960784313, 0.5411764705882353, 0.9019607843137255, 0.8235294117647058, 0.1607843137254902, 0.9764705882352941, 0.3686274509803922, 0.7725490196078432, 0.5686274509803921, 0.10588235294117647, 0.2980392156862745, 0.17254901960784313, 0.8705882352941177, 0.23921568627450981, 0.9803921568627451, 0.7333333333333333, 0.3803921568627451, 0.5490196078431373, 0.10980392156862745, 0.9882352941176471, 0.1843137254901961, 0.37254901960784315, 0.8980392156862745, 0.5294117647058824, 0.43137254901960786, 0.7647058823529411, 0.5686274509803921, 0.8745098039215686, 0.4549019607843137, 0.7843137254901961, 0.5764705882352941, 0.7254901960784313, 0.5058823529411764, 0.4235294117647059, 0.6784313725490196, 0.06666666666666667, 0.611764705882353, 0.8705882352941177, 0.5058823529411764, 0.08627450980392157, 0.9019607843137255, 0.1411764705882353, 0.29411764705882354, 0.20392156862745098, 0.3568627450980392, 0.7725490196078432, 0.23529411764705882, 0.2980392156862745, 0.7294117647058823, 0.1568627450980392, 0.0196078431372549, 0.8313725490196079, 0.26666666666666666, 0.6313725490196078, 0.9882352941176471, 0.00784313725490196, 0.40784313725490196, 0.25882352941176473, 0.7215686274509804, 0.0392156862745098, 0.23529411764705882, 0.5568627450980392, 0.9137254901960784, 0.023529411764705882, 0.22745098039215686, 0.5450980392156862, 0.47843137254901963, 0.9254901960784314, 0.6235294117647059, 0.32941176470588235, 0.16862745098039217, 0.8313725490196079, 0.4117647058823529, 0.984313725490196, 0.2, 0.3254901960784314, 0.11372549019607843, 0.7333333333333333, 0.5333333333333333, 0.4235294117647059, 0.788235294117647, 0.49411764705882355, 0.10588235294117647, 0.5529411764705883, 0.28627450980392155, 0.6, 0.9568627450980393, 0.3137254901960784, 0.7568627450980392, 0.39215686274509803, 0.9372549019607843, 0.6705882352941176, 0.3333333333333333, 0.23921568627450981, 0.4235294117647059, 0.611764705882353, 0.5372549019607843, 0.13725490196078433, 0.4, 0.27058823529411763, 0.9294117647058824, 0.7058823529411765, 0.615686274509804, 0.39215686274509803, 0.8431372549019608, 0.6784313725490196, 0.08235294117647059, 0.9764705882352941, 0.5019607843137255, 0.10980392156862745, 0.9411764705882353, 0.796078431372549, 0.027450980392156862, 0.8470588235294118, 0.25882352941176473, 0.7490196078431373, 0.8666666666666667, 0.4980392156862745, 0.1450980392156863, 0.996078431372549, 0.06274509803921569, 0.4235294117647059, 0.6862745098039216, 0.10196078431372549, 0.5803921568627451, 0.2196078431372549, 0.8549019607843137, 0.17254901960784313, 0.6627450980392157, 0.788235294117647, 0.0784313725490196, 0.396078431372549, 0.26666666666666666, 0.050980392156862744, 0.8392156862745098, 0.21568627450980393, 0.9019607843137255, 0.7215686274509804, 0.4980392156862745, 0.027450980392156862, 0.7607843137254902, 0.32941176470588235, 0.08627450980392157, 0.47843137254901963, 0.027450980392156862, 0.9137254901960784, 0.25098039215686274, 0.5019607843137255, 0.7490196078431373, 0.00392156862745098, 0.5843137254901961, 0.09019607843137255, 0.3254901960784314, 0.6941176470588235, 0.0196078431372549, 0.24705882352941178, 0.10196078431372549, 0.9372549019607843, 0.38823529411764707, 0.0, 0.6313725490196078, 0.10980392156862745, 0.9098039215686274, 0.5607843137254902, 0.7411764705882353, 0.011764705882352941, 0.8117647058823529, 0.35294117647058826, 0.7607843137254902, 0.6235294117647059, 0.8352941176470589, 0.7215686274509804, 0.00392156862745098, 0.5490196078431373, 0.07450980392156863, 0.396078431372549, 0.47843137254901963, 0.6745098039215687, 0.35294117647058826, 0.9098039215686274, 0.4196078431372549, 0.49411764705882355, 0.3411764705882353, 0.7490196078431373, 0.8784313725490196, 0.16862745098039217, 0.4627450980392157, 0.9803921568627451, 0.37254901960784315, 0.7725490196078432, 0.2823529411764706, 0.6313725490196078, 0.9921568627450981, 0.38823529411764707, 0.7607843137254902, 0.19215686274509805, 0.011764705882352941, 0.9725490196078431, 0.5647058823529412, 0.7647058823529411, 0.043137254901960784, 0.2784313725490196, 0.6901960784313725, 0.5058823529411764, 0.011764705882352941, 0.9450980392156862, 0.17647058823529413, 0.6470588235294118, 0.0392156862745098, 0.8784313725490196, 0.37254901960784315, 0.7333333333333333, 0.0, 0.1843137254901961, 0.4549019607843137, 0.06274509803921569, 0.6509803921568628, 0.26666666666666666, 0.8627450980392157, 0.12549019607843137, 0.5137254901960784, 0.7098039215686275, 0.8823529411764706, 0.043137254901960784, 0.9137254901960784, 0.23137254901960785, 0.7529411764705882, 0.5098039215686274, 0.3411764705882353, 0.2, 0.7725490196078432, 0.01568627450980392, 0.4823529411764706, 0.9176470588235294, 0.16862745098039217, 0.3215686274509804, 0.7490196078431373, 0.6039215686274509, 0.2901960784313726, 0.39215686274509803, 0.5607843137254902, 0.9294117647058824, 0.6431372549019608, 0.4549019607843137, 0.00392156862745098, 0.7019607843137254, 0.29411764705882354, 0.2235294117647059, 0.796078431372549, 0.1803921568627451, 0.5254901960784314, 0.2549019607843137, 0.396078431372549, 0.00392156862745098, 0.5137254901960784, 0.9490196078431372, 0.10980392156862745, 0.2235294117647059, 0.49411764705882355, 0.9333333333333333, 0.611764705882353, 0.6862745098039216, 0.4745098039215686, 0.32941176470588235, 0.14901960784313725, 0.403921568627451, 0.9725490196078431, 0.6431372549019608, 0.5333333333333333, 0.9254901960784314, 0.5803921568627451, 0.803921568627451, 0.6901960784313725, 0.8588235294117647, 0.611764705882353, 0.3176470588235294, 0.6745098039215687, 0.8, 0.2784313725490196, 0.8509803921568627, 0.1450980392156863, 0.49019607843137253, 0.996078431372549, 0.6078431372549019, 0.6588235294117647, 0.2823529411764706, 0.1450980392156863, 0.8549019607843137, 0.19215686274509805, 0.984313725490196, 0.25098039215686274, 0.3215686274509804, 0.16470588235294117, 0.4, 0.6509803921568628, 0.18823529411764706, 0.5725490196078431, 0.058823529411764705, 0.5215686274509804, 0.9254901960784314, 0.4470588235294118, 0.6392156862745098, 0.8196078431372549, 0.592156862745098, 0.050980392156862744, 0.2549019607843137, 0.5607843137254902, 0.7568627450980392, 0.08627450980392157, 0.17647058823529413, 0.592156862745098, 0.807843137254902, 0.10196078431372549, 0.30196078431372547, 0.6392156862745098, 0.8235294117647058, 0.1450980392156863, 0.5098039215686274, 0.8666666666666667, 0.45098039215686275, 0.32941176470588235, 0.058823529411764705, 0.8901960784313725, 0.6588235294117647, 0.4235294117647059, 0.7137254901960784, 0.24313725490196078, 0.49019607843137253, 0.35294117647058826, 0.8941176470588236, 0.6196078431372549, 0.8, 0.45098039215686275, 0.5725490196078431, 0.8274509803921568, 0.35294117647058826, 0.25882352941176473, 0.6196078431372549, 0.21568627450980393, 0.9882352941176471, 0.6666666666666666, 0.8549019607843137, 0.3568627450980392, 0.9019607843137255, 0.5607843137254902, 0.1607843137254902, 0.4666666666666667, 0.592156862745098, 0.00784313725490196, 0.7725490196078432, 0.1803921568627451, 0.48627450980392156, 0.3607843137254902, 0.8235294117647058, 0.592156862745098, 0.054901960784313725, 0.8509803921568627, 0.4392156862745098, 0.5725490196078431, 0.2901960784313726, 0.5372549019607843, 0.25098039215686274, 0.6313725490196078, 0.4470588235294118, 0.0, 0.8235294117647058, 0.21176470588235294, 0.6980392156862745, 0.48627450980392156, 0.10196078431372549, 0.3607843137254902, 0.21568627450980393, 0.8156862745098039, 0.4, 0.8980392156862745, 0.5647058823529412, 0.4627450980392157, 0.9294117647058824, 0.6431372549019608, 0.8666666666666667, 0.984313725490196, 0.7686274509803922, 0.7098039215686275, 0.2980392156862745, 0.43529411764705883, 0.5843137254901961, 0.3254901960784314, 0.7176470588235294, 0.18823529411764706, 0.5411764705882353, 0.8862745098039215, 0.0, 0.6, 0.2627450980392157, 0.8117647058823529, 0.19607843137254902, 0.37254901960784315, 0.13725490196078433, 0.27450980392156865, 0.4392156862745098, 0.19215686274509805, 0.34509803921568627, 0.14901960784313725, 0.06666666666666667, 0.4196078431372549, 0.9686274509803922, 0.2196078431372549, 0.6470588235294118, 0.9098039215686274, 0.396078431372549, 0.19607843137254902, 0.8156862745098039, 0.4235294117647059, 0.9058823529411765, 0.7411764705882353, 0.47843137254901963, 0.3568627450980392, 0.7686274509803922, 0.5215686274509804, 0.4470588235294118, 0.8862745098039215, 0.9647058823529412, 0.2901960784313726, 0.4666666666666667, 0.9490196078431372, 0.23137254901960785, 0.33725490196078434, 0.11764705882352941, 0.26666666666666666, 0.9803921568627451, 0.18823529411764706, 0.8980392156862745, 0.792156862745098, 0.9647058823529412, 0.20784313725490197, 0.7019607843137254, 0.8588235294117647, 0.9372549019607843, 0.23921568627450981, 0.4470588235294118, 0.6941176470588235, 0.9176470588235294, 0.5686274509803921, 0.33725490196078434, 0.06666666666666667, 0.6980392156862745, 0.19215686274509805, 0.11764705882352941, 0.7137254901960784, 0.5843137254901961, 0.14901960784313725, 0.5215686274509804, 0.29411764705882354, 0.8156862745098039, 0.13333333333333333, 0.8705882352941177, 0.5882352941176471, 0.08627450980392157, 0.14901960784313725, 0.9647058823529412, 0.23137254901960785, 0.2980392156862745, 0.9098039215686274, 0.12941176470588237, 0.7686274509803922, 0.9372549019607843, 0.4392156862745098, 0.14901960784313725, 0.5098039215686274, 0.796078431372549, 0.23137254901960785, 0.7176470588235294, 0.03529411764705882, 0.9725490196078431, 0.792156862745098, 0.2980392156862745, 0.3803921568627451, 0.9921568627450981, 0.6470588235294118, 0.27058823529411763, 0.07058823529411765, 0.6823529411764706, 0.16470588235294117, 0.9686274509803922, 0.6431372549019608, 0.10980392156862745, 0.807843137254902, 0.9882352941176471, 0.06274509803921569, 0.8705882352941177, 0.7294117647058823, 0.3803921568627451, 0.8941176470588236, 0.5372549019607843, 1.0, 0.058823529411764705, 0.7764705882352941, 0.16470588235294117, 0.8862745098039215, 0.5333333333333333, 0.07058823529411765, 0.6235294117647059, 0.11372549019607843, 0.34509803921568627, 0.7372549019607844, 0.03529411764705882, 0.3215686274509804, 0.13333333333333333, 0.4549019607843137, 0.34901960784313724, 0.06274509803921569, 0.6196078431372549, 0.8274509803921568, 0.9725490196078431, 0.027450980392156862, 0.8705882352941177, 0.12549019607843137, 0.2901960784313726, 0.4196078431372549, 0.7647058823529411, 0.9333333333333333, 0.7058823529411765, 0.07058823529411765, 0.4588235294117647, 0.6862745098039216, 0.8901960784313725, 0.6235294117647059, 0.054901960784313725, 0.9725490196078431, 0.5254901960784314, 0.7607843137254902, 0.9215686274509803, 0.4823529411764706, 0.12549019607843137, 0.36470588235294116, 0.5372549019607843, 0.7803921568627451, 0.047058823529411764, 0.7215686274509804, 0.34509803921568627, 0.06274509803921569, 0.5450980392156862, 0.2235294117647059, 0.6705882352941176, 0.5882352941176471, 0.07058823529411765, 0.8274509803921568, 0.6862745098039216, 0.043137254901960784, 0.5450980392156862, 0.10980392156862745, 0.8470588235294118, 0.6823529411764706, 0.4235294117647059, 0.7764705882352941, 0.8588235294117647, 0.6980392156862745, 0.3803921568627451, 0.13333333333333333, 0.3215686274509804, 0.6313725490196078, 0.5137254901960784, 0.403921568627451, 0.12941176470588237, 0.3137254901960784, 0.6588235294117647, 0.03529411764705882, 0.37254901960784315, 0.5294117647058824, 0.21176470588235294, 0.0196078431372549, 0.7450980392156863, 0.9333333333333333, 0.611764705882353, 0.4117647058823529, 0.9490196078431372, 0.803921568627451, 0.25882352941176473, 0.8549019607843137, 0.35294117647058826, 0.9450980392156862, 0.050980392156862744, 0.4549019607843137, 0.6784313725490196, 0.21176470588235294, 0.7490196078431373, 0.43137254901960786, 0.37254901960784315, 0.7176470588235294, 0.050980392156862744, 0.6745098039215687, 0.4627450980392157, 0.5254901960784314, 0.7019607843137254, 0.027450980392156862, 0.5803921568627451, 0.29411764705882354, 0.10980392156862745, 0.6274509803921569, 0.40784313725490196, 0.5215686274509804, 0.20392156862745098, 0.6941176470588235, 0.9176470588235294, 0.07450980392156863, 0.5529411764705883, 0.8509803921568627, 0.4117647058823529, 0.9411764705882353, 0.788235294117647, 0.45098039215686275, 0.3058823529411765, 0.38823529411764707, 0.23921568627450981, 0.6862745098039216, 0.15294117647058825, 0.4196078431372549, 0.792156862745098, 0.2, 0.11372549019607843, 0.6666666666666666, 0.14901960784313725, 0.26666666666666666, 0.42745098039215684, 0.596078431372549, 0.3176470588235294, 0.6784313725490196, 0.9803921568627451, 0.2823529411764706, 0.7568627450980392, 0.9372549019607843, 0.5058823529411764, 0.8392156862745098, 0.09411764705882353, 0.6, 0.7019607843137254, 0.1843137254901961, 0.5568627450980392, 0.9137254901960784, 0.23529411764705882, 0.1568627450980392, 0.7529411764705882, 0.36470588235294116, 0.4588235294117647, 0.792156862745098, 0.9921568627450981, 0.6352941176470588, 0.09019607843137255, 0.49411764705882355, 0.34901960784313724, 0.5607843137254902, 0.8588235294117647, 0.01568627450980392, 0.23529411764705882, 0.7333333333333333, 0.39215686274509803, 0.8196078431372549, 0.6549019607843137, 0.22745098039215686, 0.5764705882352941, 0.8666666666666667, 0.7058823529411765, 0.03137254901960784, 0.6039215686274509, 0.4470588235294118, 0.25882352941176473, 0.9294117647058824, 0.5098039215686274, 0.12941176470588237, 0.8431372549019608, 0.3215686274509804, 0.03137254901960784, 0.9411764705882353, 0.41568627450980394, 0.13725490196078433, 0.2196078431372549, 0.6039215686274509, 0.788235294117647, 0.24705882352941178, 0.7333333333333333, 0.01568627450980392, 0.1607843137254902, 0.6039215686274509, 0.48627450980392156, 0.03137254901960784, 0.5372549019607843, 0.7490196078431373, 0.4392156862745098, 0.0784313725490196, 0.8666666666666667, 0.0, 0.615686274509804, 0.4627450980392157, 0.5450980392156862, 0.8274509803921568, 0.9607843137254902, 0.1411764705882353, 0.8549019607843137, 0.4235294117647059, 0.47843137254901963, 0.23529411764705882, 0.29411764705882354, 0.5294117647058824, 0.00392156862745098, 0.6470588235294118, 0.4745098039215686, 0.08235294117647059, 0.7450980392156863, 0.2, 0.611764705882353, 0.3803921568627451, 1.0, 0.3058823529411765, 0.9098039215686274, 0.5411764705882353, 0.07058823529411765, 0.8627450980392157, 0.592156862745098, 0.396078431372549, 0.2, 0.09019607843137255, 0.3254901960784314, 0.8313725490196079, 0.39215686274509803, 0.8784313725490196, 0.4745098039215686, 0.9294117647058824, 0.7647058823529411, 0.32941176470588235, 0.10196078431372549, 0.43137254901960786, 0.6352941176470588, 0.22745098039215686, 0.1411764705882353, 0.7372549019607844, 0.3215686274509804, 0.10588235294117647, 0.5686274509803921, 0.5019607843137255, 0.00784313725490196, 0.8823529411764706, 0.9411764705882353, 0.5176470588235295, 0.3568627450980392, 0.611764705882353, 0.3058823529411765, 0.5607843137254902, 0.9568627450980393, 0.3333333333333333, 0.4666666666666667, 0.8588235294117647, 0.7254901960784313, 0.9254901960784314, 0.23137254901960785, 0.803921568627451, 0.41568627450980394, 0.5803921568627451, 0.1568627450980392, 0.20392156862745098, 0.6745098039215687, 0.26666666666666666, 0.4117647058823529, 0.788235294117647, 0.9450980392156862, 0.2823529411764706, 0.8392156862745098, 0.6666666666666666, 0.4980392156862745, 0.4117647058823529, 0.08627450980392157, 0.6549019607843137, 0.5725490196078431, 0.24313725490196078, 0.047058823529411764, 0.37254901960784315, 0.8313725490196079, 0.22745098039215686, 0.13333333333333333, 0.9803921568627451, 0.2901960784313726, 0.7725490196078432, 0.5137254901960784, 0.9529411764705882, 0.3137254901960784, 0.09411764705882353, 0.4627450980392157, 0.023529411764705882, 0.3803921568627451, 0.29411764705882354, 0.18823529411764706, 0.8196078431372549, 0.9490196078431372, 0.3137254901960784, 0.16470588235294117, 0.6549019607843137, 0.7647058823529411, 0.596078431372549, 0.9803921568627451, 0.4470588235294118, 0.8, 0.2627450980392157, 0.8784313725490196, 0.30980392156862746, 0.7215686274509804, 0.3803921568627451, 0.9058823529411765, 0.3333333333333333, 0.5137254901960784, 0.39215686274509803, 0.9882352941176471, 0.30196078431372547, 0.9137254901960784, 0.21568627450980393, 0.6705882352941176, 0.9450980392156862, 0.2823529411764706, 0.17254901960784313, 0.7215686274509804, 0.3686274509803922, 1.0, 0.7647058823529411, 0.26666666666666666, 0.06666666666666667, 0.7333333333333333, 0.2901960784313726, 0.6196078431372549, 0.9921568627450981, 0.796078431372549, 0.10588235294117647, 0.8431372549019608, 0.9019607843137255, 0.3686274509803922, 0.21568627450980393, 0.984313725490196, 0.4, 0.6862745098039216, 0.5607843137254902, 0.788235294117647, 0.10980392156862745, 0.5215686274509804, 0.0, 0.6470588235294118, 0.2549019607843137, 0.7764705882352941, 0.16470588235294117, 0.4980392156862745, 0.9921568627450981, 0.792156862745098, 0.9019607843137255, 0.611764705882353, 0.23921568627450981, 0.9647058823529412, 0.7372549019607844, 0.047058823529411764, 0.16862745098039217, 0.26666666666666666, 0.8196078431372549, 0.5803921568627451, 0.8901960784313725, 0.35294117647058826, 0.8352941176470589, 0.4549019607843137, 0.5176470588235295, 0.6235294117647059, 0.20784313725490197, 0.8627450980392157, 0.2549019607843137, 0.7372549019607844, 0.5882352941176471, 0.19215686274509805, 0.7568627450980392, 0.03529411764705882, 0.9215686274509803, 0.6980392156862745, 0.09019607843137255, 0.5019607843137255, 0.7607843137254902, 0.6235294117647059, 0.0784313725490196, 0.3686274509803922, 0.01568627450980392, 0.5098039215686274, 0.12941176470588237, 0.047058823529411764, 0.47843137254901963, 0.8666666666666667, 0.3764705882352941, 0.011764705882352941, 0.9882352941176471, 0.5450980392156862, 0.2235294117647059, 0.48627450980392156, 0.3843137254901961, 0.0196078431372549, 0.11764705882352941, 0.792156862745098, 0.27058823529411763, 0.8901960784313725, 0.5176470588235295, 0.2, 0.8549019607843137, 0.6941176470588235, 0.5254901960784314, 0.17254901960784313, 0.7450980392156863, 0.6588235294117647, 0.41568627450980394, 0.6078431372549019, 0.16862745098039217, 0.44313725490196076, 0.11764705882352941, 0.5882352941176471, 0.9058823529411765, 0.27058823529411763, 0.792156862745098, 1.0, 0.6666666666666666, 0.5098039215686274, 0.7372549019607844, 0.09019607843137255, 0.8745098039215686, 0.5607843137254902, 0.07058823529411765, 0.3843137254901961, 0.28627450980392155, 0.18823529411764706, 0.6901960784313725, 0.09803921568627451, 0.5647058823529412, 0.6392156862745098, 0.5019607843137255, 1.0, 0.0784313725490196, 0.47843137254901963, 0.1411764705882353, 0.6313725490196078, 0.8784313725490196, 0.5568627450980392, 0.08235294117647059, 0.792156862745098, 0.34901960784313724, 0.10196078431372549, 0.5764705882352941, 0.8117647058823529, 0.47843137254901963, 0.9254901960784314, 0.5686274509803921, 0.2235294117647059, 0.1568627450980392, 0.9019607843137255, 0.40784313725490196, 0.48627450980392156, 0.6784313725490196, 0.08235294117647059, 0.3607843137254902, 0.17254901960784313, 0.6588235294117647, 0.5764705882352941, 0.7294117647058823, 0.15294117647058825, 0.7803921568627451, 0.5450980392156862, 0.2901960784313726, 0.027450980392156862, 0.9294117647058824, 0.2549019607843137, 0.8431372549019608, 0.7137254901960784, 0.1843137254901961, 0.8156862745098039, 0.4627450980392157, 0.9294117647058824, 0.3215686274509804, 0.6313725490196078, 0.011764705882352941, 0.2784313725490196, 0.7254901960784313, 0.15294117647058825, 0.5411764705882353, 0.0784313725490196, 0.34509803921568627, 0.6784313725490196, 0.6, 1.0, 0.01568627450980392, 0.49019607843137253, 0.15294117647058825, 0.7529411764705882, 0.054901960784313725, 0.2627450980392157, 0.9294117647058824, 0.0196078431372549, 0.9764705882352941, 0.6901960784313725, 0.35294117647058826, 0.12941176470588237, 0.6588235294117647, 0.0784313725490196, 0.47058823529411764, 0.8196078431372549, 0.2627450980392157, 0.4470588235294118, 0.8470588235294118, 0.03137254901960784, 0.23921568627450981, 0.9098039215686274, 0.18823529411764706, 0.5647058823529412, 0.8431372549019608, 0.6470588235294118, 0.9647058823529412, 0.7333333333333333, 0.3254901960784314, 0.792156862745098, 0.6274509803921569, 0.4470588235294118, 0.7215686274509804, 0.1450980392156863, 0.8588235294117647, 0.0784313725490196, 0.7568627450980392, 0.6039215686274509, 0.996078431372549, 0.3333333333333333, 0.7019607843137254, 0.9490196078431372, 0.01568627450980392, 0.7411764705882353, 0.3058823529411765, 0.43529411764705883, 0.9529411764705882, 0.5843137254901961, 0.8941176470588236, 0.3215686274509804, 0.09803921568627451, 0.9215686274509803, 0.8392156862745098, 0.3568627450980392, 0.6627450980392157, 0.8627450980392157, 0.17647058823529413, 0.7215686274509804, 0.615686274509804, 0.1411764705882353, 0.06666666666666667, 0.4235294117647059, 0.23921568627450981, 0.3568627450980392, 0.4745098039215686, 0.6941176470588235, 0.8352941176470589, 0.9568627450980393, 0.011764705882352941, 0.49019607843137253, 0.8941176470588236, 0.34901960784313724, 0.1607843137254902, 0.7490196078431373, 0.00784313725490196, 0.8313725490196079, 0.1843137254901961, 0.6666666666666666, 0.9568627450980393, 0.054901960784313725, 0.7490196078431373, 0.26666666666666666, 0.1803921568627451, 0.6235294117647059, 0.4627450980392157, 0.8862745098039215, 0.40784313725490196, 0.24313725490196078, 0.6588235294117647, 0.33725490196078434, 0.8274509803921568, 0.047058823529411764, 0.6509803921568628, 0.803921568627451, 0.3254901960784314, 0.18823529411764706, 0.5607843137254902, 0.7764705882352941, 0.2549019607843137, 0.5098039215686274, 0.403921568627451, 0.043137254901960784, 0.32941176470588235, 0.4549019607843137, 0.09411764705882353, 0.6196078431372549, 0.8823529411764706, 0.49411764705882355, 0.13725490196078433, 0.4392156862745098, 0.32941176470588235, 0.9568627450980393, 0.40784313725490196, 0.5803921568627451, 0.3568627450980392, 0.11764705882352941, 0.6901960784313725, 0.21176470588235294, 0.8509803921568627, 0.3686274509803922, 0.49019607843137253, 0.43137254901960786, 0.6588235294117647, 0.803921568627451, 0.20392156862745098, 0.4470588235294118, 0.5333333333333333, 0.3764705882352941, 0.8627450980392157, 0.6980392156862745, 0.2901960784313726, 0.9725490196078431, 0.5568627450980392, 0.6745098039215687, 0.803921568627451, 0.396078431372549, 0.16862745098039217, 0.7686274509803922, 0.43529411764705883, 0.9176470588235294, 0.8352941176470589, 0.403921568627451, 0.3215686274509804, 0.6352941176470588, 0.13725490196078433, 0.20784313725490197, 0.984313725490196, 0.596078431372549, 0.4, 0.8, 0.6901960784313725, 0.30196078431372547, 0.4588235294117647, 0.2549019607843137, 0.3843137254901961, 0.6, 0.2196078431372549, 0.9529411764705882, 0.06274509803921569, 0.30196078431372547, 0.9058823529411765, 0.5882352941176471, 0.35294117647058826, 0.6509803921568628, 0.8941176470588236, 0.42745098039215684, 0.20784313725490197, 0.5529411764705883, 0.4627450980392157, 0.16862745098039217, 0.6196078431372549, 0.35294117647058826, 0.9058823529411765, 0.12941176470588237, 0.06274509803921569, 0.2549019607843137, 0.4666666666666667, 0.8117647058823529, 0.5450980392156862, 0.0392156862745098, 0.6980392156862745, 0.2235294117647059, 0.06274509803921569, 0.5450980392156862, 0.4, 0.4980392156862745, 0.34509803921568627, 0.8431372549019608, 0.5529411764705883, 0.9137254901960784, 0.6313725490196078, 0.9803921568627451, 0.12156862745098039, 0.2, 0.4235294117647059, 0.24313725490196078, 0.7843137254901961, 0.6235294117647059, 0.7294117647058823, 0.4, 0.9607843137254902, 0.4588235294117647, 0.24313725490196078, 0.5568627450980392, 0.4196078431372549, 0.2823529411764706, 0.807843137254902, 0.21568627450980393, 0.44313725490196076, 0.6862745098039216, 0.3764705882352941, 0.8352941176470589, 0.7098039215686275, 0.14901960784313725, 0.984313725490196, 0.027450980392156862, 0.5254901960784314, 0.11372549019607843, 0.29411764705882354, 0.43529411764705883, 0.5019607843137255, 0.09019607843137255, 0.6, 0.9450980392156862, 0.8666666666666667, 0.00784313725490196, 0.9215686274509803, 0.7058823529411765, 0.8705882352941177, 0.9764705882352941, 0.27058823529411763, 0.6745098039215687, 0.9333333333333333, 0.8352941176470589, 0.1843137254901961, 0.34509803921568627, 0.7725490196078432, 0.6431372549019608, 0.047058823529411764, 0.5019607843137255, 0.1450980392156863, 0.8901960784313725, 0.03529411764705882, 0.7372549019607844, 0.9529411764705882, 0.4235294117647059, 0.5647058823529412, 0.12549019607843137, 0.9686274509803922, 0.03529411764705882, 0.8705882352941177, 0.2901960784313726, 0.9490196078431372, 0.11764705882352941, 0.7843137254901961, 0.22745098039215686, 0.08235294117647059, 0.6392156862745098, 0.2, 0.47058823529411764, 0.3686274509803922, 0.12156862745098039, 0.596078431372549, 0.30980392156862746, 0.48627450980392156, 0.09019607843137255, 0.5568627450980392, 0.2901960784313726, 0.2235294117647059, 0.5294117647058824, 0.9529411764705882, 0.8823529411764706, 0.5568627450980392, 0.7372549019607844, 0.3686274509803922, 0.29411764705882354, 0.12549019607843137, 0.5254901960784314, 0.9725490196078431, 0.14901960784313725, 0.7529411764705882, 0.09411764705882353, 0.8823529411764706, 0.17254901960784313, 0.5411764705882353, 0.6980392156862745, 0.11764705882352941, 0.5215686274509804, 0.807843137254902, 0.19607843137254902, 0.0392156862745098, 0.29411764705882354, 0.5137254901960784, 0.13725490196078433, 0.7215686274509804, 0.043137254901960784, 0.8470588235294118, 0.38823529411764707, 0.10196078431372549, 0.803921568627451, 0.49019607843137253, 0.6627450980392157, 0.7725490196078432, 0.4, 0.00784313725490196, 0.7137254901960784, 0.19215686274509805, 0.2784313725490196, 0.48627450980392156, 0.8, 0.9882352941176471, 0.7568627450980392, 0.24705882352941178, 0.0, 0.9568627450980393, 0.20784313725490197, 0.7686274509803922, 0.3215686274509804, 0.023529411764705882, 0.8, 0.5882352941176471, 0.7490196078431373, 0.5294117647058824, 0.3333333333333333, 0.8705882352941177, 0.13725490196078433, 0.5490196078431373, 0.2235294117647059, 0.08235294117647059, 0.788235294117647, 0.3333333333333333, 0.9254901960784314, 0.7019607843137254, 0.596078431372549, 0.3568627450980392, 0.5372549019607843, 0.8627450980392157, 0.023529411764705882, 0.9647058823529412, 0.5019607843137255, 0.058823529411764705, 0.3137254901960784, 0.6, 0.7725490196078432, 0.1843137254901961, 0.6980392156862745, 0.8901960784313725, 0.7490196078431373, 0.9725490196078431, 0.24313725490196078, 0.7058823529411765, 0.37254901960784315, 0.13725490196078433, 0.43137254901960786, 0.3137254901960784, 0.611764705882353, 0.12941176470588237, 0.20784313725490197, 0.7686274509803922, 0.5058823529411764, 0.0196078431372549, 0.24705882352941178, 0.4196078431372549, 0.7019607843137254, 0.5764705882352941, 0.9098039215686274, 0.2196078431372549, 0.6823529411764706, 0.7607843137254902, 0.2901960784313726, 0.611764705882353, 0.23921568627450981, 0.5254901960784314, 0.058823529411764705, 0.8156862745098039, 0.6705882352941176, 0.25098039215686274, 0.7490196078431373, 0.5803921568627451, 0.396078431372549, 0.5058823529411764, 0.9058823529411765, 0.7098039215686275, 0.3137254901960784, 0.42745098039215684, 0.9294117647058824, 0.788235294117647, 0.027450980392156862, 0.9098039215686274, 0.7137254901960784, 0.21568627450980393, 0.8431372549019608, 0.9019607843137255, 0.6431372549019608, 0.03137254901960784, 0.996078431372549, 0.792156862745098, 0.054901960784313725, 0.16862745098039217, 0.7098039215686275, 0.00392156862745098, 0.49411764705882355, 0.788235294117647, 0.6588235294117647, 0.8862745098039215, 0.44313725490196076, 0.023529411764705882, 0.3568627450980392, 0.9372549019607843, 0.6666666666666666, 0.8156862745098039, 0.00392156862745098, 0.42745098039215684, 0.9215686274509803, 0.25098039215686274, 0.6588235294117647, 0.396078431372549, 0.47843137254901963, 0.7411764705882353, 0.9568627450980393, 0.24705882352941178, 0.8, 0.9215686274509803, 0.3137254901960784, 0.6470588235294118, 0.2549019607843137, 0.5725490196078431, 0.9803921568627451, 0.19215686274509805, 0.2823529411764706, 0.6078431372549019, 0.8627450980392157, 0.9921568627450981, 0.34901960784313724, 0.6274509803921569, 0.9372549019607843, 0.39215686274509803, 0.5803921568627451, 0.30980392156862746, 0.13725490196078433, 0.9215686274509803, 0.6901960784313725, 0.43137254901960786, 0.10588235294117647, 0.47843137254901963, 0.7058823529411765, 0.1607843137254902, 0.4, 0.28627450980392155, 0.8941176470588236, 0.043137254901960784, 0.6745098039215687, 0.4627450980392157, 0.06666666666666667, 0.2980392156862745, 0.9137254901960784, 0.6784313725490196, 0.6078431372549019, 0.8588235294117647, 0.12941176470588237, 0.0392156862745098, 0.7647058823529411, 0.09411764705882353, 0.9333333333333333, 0.3058823529411765, 0.13725490196078433, 0.5725490196078431, 0.21176470588235294, 0.7607843137254902, 0.25882352941176473, 0.4470588235294118, 0.8470588235294118, 0.9607843137254902, 0.39215686274509803, 0.611764705882353, 0.1411764705882353, 0.5529411764705883, 0.8431372549019608, 0.03529411764705882, 0.4666666666666667, 0.7411764705882353, 0.5333333333333333, 0.8117647058823529, 0.054901960784313725, 0.47058823529411764, 0.5568627450980392, 0.8235294117647058, 0.3843137254901961, 0.592156862745098, 0.7411764705882353, 0.11372549019607843, 0.9490196078431372, 0.07058823529411765, 0.2784313725490196, 0.8588235294117647, 0.5490196078431373, 0.08627450980392157, 0.9764705882352941, 0.47843137254901963, 0.8705882352941177, 0.3764705882352941, 0.7725490196078432, 0.30980392156862746, 0.9058823529411765, 0.18823529411764706, 0.35294117647058826, 0.07450980392156863, 0.6431372549019608, 0.16470588235294117, 0.00392156862745098, 0.611764705882353, 0.8392156862745098, 0.5450980392156862, 0.13725490196078433, 0.5882352941176471, 0.3254901960784314, 0.8549019607843137, 0.4392156862745098, 0.07450980392156863, 0.5372549019607843, 0.25098039215686274, 0.7254901960784313, 0.3803921568627451, 0.611764705882353, 0.45098039215686275, 0.6784313725490196, 0.3607843137254902, 0.2784313725490196, 0.42745098039215684, 0.10196078431372549, 0.9372549019607843, 0.1803921568627451, 0.07058823529411765, 0.7254901960784313, 0.6274509803921569, 0.21568627450980393, 0.5176470588235295, 0.5764705882352941, 0.3137254901960784, 0.47843137254901963, 0.7725490196078432, 0.3607843137254902, 0.8509803921568627, 0.1568627450980392, 0.9725490196078431, 0.09803921568627451, 0.8666666666666667, 0.6862745098039216, 0.3686274509803922, 0.5764705882352941, 0.07058823529411765, 0.4823529411764706, 0.7764705882352941, 0.8745098039215686, 0.6941176470588235, 0.42745098039215684, 0.07058823529411765, 0.9294117647058824, 0.5411764705882353, 0.14901960784313725, 0.22745098039215686, 0.5058823529411764, 0.7607843137254902, 0.10588235294117647, 0.8784313725490196, 0.047058823529411764, 0.4588235294117647, 0.6392156862745098, 0.5254901960784314, 0.8156862745098039, 0.29411764705882354, 0.6039215686274509, 0.9019607843137255, 0.25098039215686274, 0.8509803921568627, 0.5098039215686274, 0.6509803921568628, 0.10196078431372549, 0.9372549019607843, 0.1803921568627451, 0.5803921568627451, 0.9921568627450981, 0.8274509803921568, 0.43529411764705883, 0.023529411764705882, 0.5215686274509804, 0.19607843137254902, 0.38823529411764707, 0.5019607843137255, 0.8431372549019608, 0.16862745098039217, 0.4745098039215686, 0.6196078431372549, 0.796078431372549, 0.403921568627451, 0.8823529411764706, 0.6509803921568628, 0.9372549019607843, 0.36470588235294116, 0.0784313725490196, 0.2235294117647059, 0.47843137254901963, 0.00784313725490196, 0.27058823529411763, 0.3607843137254902, 0.19607843137254902, 0.6392156862745098, 0.28627450980392155, 0.9882352941176471, 0.17647058823529413, 0.23137254901960785, 0.6549019607843137, 0.9568627450980393, 0.34901960784313724, 0.07058823529411765, 0.1607843137254902, 0.9686274509803922, 0.30980392156862746, 0.5333333333333333, 0.8, 0.37254901960784315, 0.4745098039215686, 0.16470588235294117, 0.39215686274509803, 0.8274509803921568, 0.3411764705882353, 0.19215686274509805, 0.6549019607843137, 0.10980392156862745, 0.17254901960784313, 0.4588235294117647, 0.615686274509804, 0.5176470588235295, 0.9411764705882353, 0.8235294117647058, 0.23137254901960785, 0.7647058823529411, 0.4666666666666667, 0.26666666666666666, 0.058823529411764705, 0.9686274509803922, 0.39215686274509803, 0.6862745098039216, 0.24705882352941178, 0.5215686274509804, 0.17647058823529413, 0.7529411764705882, 0.9568627450980393, 0.3411764705882353, 0.14901960784313725, 0.8313725490196079, 0.20392156862745098, 0.12156862745098039, 0.7607843137254902, 0.5843137254901961, 0.9725490196078431, 0.8117647058823529, 0.6196078431372549, 0.3333333333333333, 0.24705882352941178, 0.5725490196078431, 0.8274509803921568, 0.27450980392156865, 0.8666666666666667, 0.40784313725490196, 0.058823529411764705, 0.9137254901960784, 0.27058823529411763, 0.6274509803921569, 0.0784313725490196, 0.7450980392156863, 0.5686274509803921, 0.3254901960784314, 0.611764705882353, 0.43529411764705883, 0.0, 0.17647058823529413, 0.6352941176470588, 0.40784313725490196, 0.9725490196078431, 0.2, 0.1411764705882353, 0.00784313725490196, 0.5215686274509804, 0.3333333333333333, 0.7333333333333333, 0.3803921568627451, 0.6823529411764706, 0.08235294117647059, 0.8313725490196079, 0.43137254901960786, 0.1607843137254902, 0.6666666666666666, 0.7333333333333333, 0.19607843137254902, 0.37254901960784315, 0.08235294117647059, 0.8823529411764706, 0.17254901960784313, 0.6705882352941176, 0.3686274509803922, 0.07058823529411765, 0.5725490196078431, 0.9607843137254902, 0.2235294117647059, 0.3607843137254902, 0.8117647058823529, 0.403921568627451, 0.25882352941176473, 0.7607843137254902, 0.36470588235294116, 0.15294117647058825, 0.7333333333333333, 0.3137254901960784, 0.984313725490196, 0.6470588235294118, 0.27450980392156865, 0.9607843137254902, 0.41568627450980394, 0.6705882352941176, 0.23529411764705882, 0.7254901960784313, 0.33725490196078434, 0.11372549019607843, 0.5294117647058824, 0.01568627450980392, 0.7294117647058823, 0.5647058823529412, 0.8823529411764706, 0.807843137254902, 0.6745098039215687, 0.9411764705882353, 0.4235294117647059, 0.9058823529411765, 0.8, 0.10588235294117647, 0.5882352941176471, 0.8901960784313725, 0.38823529411764707, 0.7607843137254902, 0.2901960784313726, 0.7215686274509804, 0.9019607843137255, 0.6470588235294118, 0.44313725490196076, 0.8509803921568627, 0.21568627450980393, 0.615686274509804, 0.00784313725490196, 0.9921568627450981, 0.7333333333333333, 0.6352941176470588, 0.45098039215686275, 0.0196078431372549, 0.7176470588235294, 0.8, 0.5843137254901961, 0.996078431372549, 0.00784313725490196, 0.7294117647058823, 0.09803921568627451, 0.43529411764705883, 0.6980392156862745, 0.3254901960784314, 0.9921568627450981, 0.8823529411764706, 0.3607843137254902, 0.7333333333333333, 0.1803921568627451, 0.4980392156862745, 0.7647058823529411, 0.043137254901960784, 0.9921568627450981, 0.615686274509804, 0.40784313725490196, 0.0, 0.6705882352941176, 0.4627450980392157, 0.9411764705882353, 0.5254901960784314, 0.3215686274509804, 0.8666666666666667, 0.48627450980392156, 0.23529411764705882, 0.1450980392156863, 0.8941176470588236, 0.5333333333333333, 0.7647058823529411, 0.38823529411764707, 0.9882352941176471, 0.49019607843137253, 0.11372549019607843, 0.7803921568627451, 0.18823529411764706, 0.6901960784313725, 0.13333333333333333, 0.996078431372549, 0.21176470588235294, 0.4980392156862745, 0.047058823529411764, 0.27450980392156865, 0.7843137254901961, 0.2235294117647059, 0.5450980392156862, 0.8235294117647058, 0.9058823529411765, 0.10980392156862745, 0.27058823529411763, 0.5411764705882353, 0.3607843137254902, 0.7568627450980392, 0.8941176470588236, 0.23921568627450981, 0.8431372549019608, 0.03529411764705882, 0.4666666666666667, 0.30980392156862746, 0.9607843137254902, 0.5607843137254902, 0.2549019607843137, 0.33725490196078434, 0.9568627450980393, 0.8431372549019608, 0.26666666666666666, 0.7607843137254902, 0.5686274509803921, 0.03529411764705882, 0.5019607843137255, 0.9921568627450981, 0.7411764705882353, 0.4392156862745098, 0.01568627450980392, 0.7254901960784313, 0.47843137254901963, 0.6078431372549019, 0.7058823529411765, 0.00392156862745098, 0.4980392156862745, 0.6509803921568628, 0.592156862745098, 0.24705882352941178, 0.8784313725490196, 0.45098039215686275, 0.08627450980392157, 0.7176470588235294, 0.21568627450980393, 0.5529411764705883, 0.0, 0.9098039215686274, 0.08235294117647059, 0.996078431372549, 0.47058823529411764, 0.1803921568627451, 0.8274509803921568, 0.6235294117647059, 0.2901960784313726, 0.3411764705882353, 0.09803921568627451, 0.5176470588235295, 0.7607843137254902, 0.058823529411764705, 0.7137254901960784, 0.48627450980392156, 0.33725490196078434, 0.6823529411764706, 0.01568627450980392, 0.8470588235294118, 0.45098039215686275, 0.09803921568627451, 0.19215686274509805, 0.5019607843137255, 0.2627450980392157, 0.03529411764705882, 0.6980392156862745, 0.1411764705882353, 0.4980392156862745, 0.6784313725490196, 0.3254901960784314, 0.23529411764705882, 0.12156862745098039, 0.9058823529411765, 0.5607843137254902, 0.2627450980392157, 0.41568627450980394, 0.9137254901960784, 0.4980392156862745, 0.2901960784313726, 0.38823529411764707, 0.8901960784313725, 0.15294117647058825, 0.5450980392156862, 0.03137254901960784, 0.4117647058823529, 0.5686274509803921, 0.6627450980392157, 0.10196078431372549, 0.8235294117647058, 0.29411764705882354, 0.8784313725490196, 0.11372549019607843, 0.6588235294117647, 0.8117647058823529, 0.27450980392156865, 0.8627450980392157, 0.5803921568627451, 0.09803921568627451, 0.7725490196078432, 0.26666666666666666, 0.01568627450980392, 0.9215686274509803, 0.08235294117647059, 0.396078431372549, 0.6627450980392157, 0.023529411764705882, 0.4549019607843137, 0.6901960784313725, 0.047058823529411764, 0.1568627450980392, 0.3215686274509804, 0.7137254901960784, 0.9607843137254902, 0.6039215686274509, 0.3764705882352941, 0.8470588235294118, 0.5490196078431373, 0.4235294117647059, 0.6705882352941176, 0.9372549019607843, 0.8941176470588236, 0.6980392156862745, 0.12941176470588237, 0.9921568627450981, 0.3333333333333333, 0.4627450980392157, 0.6784313725490196, 0.7372549019607844, 0.6, 0.9372549019607843, 0.45098039215686275, 0.6274509803921569, 0.16470588235294117, 0.5764705882352941, 0.7843137254901961, 0.9176470588235294, 0.615686274509804, 0.7215686274509804, 0.027450980392156862, 0.807843137254902, 0.5137254901960784, 0.592156862745098, 0.011764705882352941, 0.48627450980392156, 0.9764705882352941, 0.40784313725490196, 0.23137254901960785, 0.788235294117647, 0.32941176470588235, 0.1450980392156863, 0.8235294117647058, 0.19215686274509805, 0.9254901960784314, 0.30196078431372547, 0.1411764705882353, 0.8588235294117647, 0.9647058823529412, 0.20392156862745098, 0.10588235294117647, 0.9450980392156862, 0.7803921568627451, 0.054901960784313725, 0.5372549019607843, 0.8156862745098039, 0.34509803921568627, 0.8941176470588236, 0.7529411764705882, 0.37254901960784315, 0.8313725490196079, 0.28627450980392155, 0.5882352941176471, 0.6980392156862745, 0.25098039215686274, 0.9137254901960784, 0.4196078431372549, 0.14901960784313725, 0.6431372549019608, 0.984313725490196, 0.17647058823529413, 0.592156862745098, 0.3058823529411765, 0.13333333333333333, 0.5647058823529412, 0.9647058823529412, 0.2549019607843137, 0.5098039215686274, 0.14901960784313725, 0.5764705882352941, 0.9294117647058824, 0.6274509803921569, 0.403921568627451, 0.8745098039215686, 0.7725490196078432, 0.35294117647058826, 0.9215686274509803, 0.4196078431372549, 0.8156862745098039, 0.8862745098039215, 0.5843137254901961, 0.7803921568627451, 0.30196078431372547, 0.9647058823529412, 0.15294117647058825, 0.07450980392156863, 0.33725490196078434, 0.2235294117647059, 0.8470588235294118, 0.6666666666666666, 0.7843137254901961, 0.26666666666666666, 0.6313725490196078, 0.8549019607843137, 0.12549019607843137, 0.19607843137254902, 0.5058823529411764, 0.9529411764705882, 0.6, 0.4470588235294118, 0.2196078431372549, 0.3764705882352941, 0.47843137254901963, 0.3333333333333333, 0.1411764705882353, 0.5058823529411764, 0.21176470588235294, 0.8941176470588236, 0.4117647058823529, 0.6274509803921569, 0.6941176470588235, 0.5529411764705883, 0.1843137254901961, 0.7411764705882353, 0.8549019607843137, 0.29411764705882354, 0.9490196078431372, 0.21176470588235294, 0.8823529411764706, 0.43529411764705883, 0.6509803921568628, 0.5411764705882353, 0.0196078431372549, 0.4588235294117647, 0.23137254901960785, 0.7294117647058823, 0.03529411764705882, 0.803921568627451, 0.3411764705882353, 0.17254901960784313, 0.4588235294117647, 0.3803921568627451, 0.5176470588235295, 0.7294117647058823, 0.25098039215686274, 0.058823529411764705, 0.8666666666666667, 0.3176470588235294, 0.027450980392156862, 0.8196078431372549, 0.09019607843137255, 0.29411764705882354, 0.9882352941176471, 0.10980392156862745, 0.4196078431372549, 0.19215686274509805, 0.27058823529411763, 0.13333333333333333, 0.39215686274509803, 0.8941176470588236, 0.21568627450980393, 0.4196078431372549, 0.1411764705882353, 0.6666666666666666, 0.3137254901960784, 0.7098039215686275, 0.11764705882352941, 0.9254901960784314, 0.6352941176470588, 0.27450980392156865, 0.396078431372549, 0.6666666666666666, 0.5215686274509804, 0.788235294117647, 0.058823529411764705, 0.3411764705882353, 0.4470588235294118, 0.5450980392156862, 0.8352941176470589, 0.32941176470588235, 0.4, 0.1803921568627451, 0.6901960784313725, 0.5882352941176471, 0.13725490196078433, 0.06274509803921569, 0.615686274509804, 0.1607843137254902, 0.5176470588235295, 0.43529411764705883, 0.03529411764705882, 0.7725490196078432, 0.3686274509803922, 0.5058823529411764, 0.047058823529411764, 0.7843137254901961, 0.44313725490196076, 0.25098039215686274, 0.3843137254901961, 0.8470588235294118, 0.4588235294117647, 0.2196078431372549, 0.8666666666666667, 0.07450980392156863, 0.37254901960784315, 0.7843137254901961, 0.7019607843137254, 0.3176470588235294, 0.03137254901960784, 0.8156862745098039, 0.5372549019607843, 0.11372549019607843, 0.5803921568627451, 0.984313725490196, 0.054901960784313725, 0.27058823529411763, 0.18823529411764706, 0.0784313725490196, 0.5137254901960784, 0.03529411764705882, 0.6705882352941176, 0.4823529411764706, 0.8196078431372549, 0.6980392156862745, 0.6313725490196078, 0.7568627450980392, 0.5568627450980392, 0.0392156862745098, 0.20392156862745098, 0.9764705882352941, 0.48627450980392156, 0.3058823529411765, 0.7372549019607844, 0.803921568627451, 0.2549019607843137, 0.3411764705882353, 0.01568627450980392, 0.7176470588235294, 0.9098039215686274, 0.5607843137254902, 0.9372549019607843, 0.058823529411764705, 0.7411764705882353, 0.9686274509803922, 0.30980392156862746, 0.7058823529411765, 0.06666666666666667, 0.35294117647058826, 0.9764705882352941, 0.4392156862745098, 0.8, 0.3411764705882353, 0.592156862745098, 0.49411764705882355, 0.12156862745098039, 0.35294117647058826, 0.6039215686274509, 0.807843137254902, 0.08627450980392157, 0.25098039215686274, 0.8274509803921568, 0.34509803921568627, 0.8980392156862745, 0.5137254901960784, 0.2901960784313726, 0.09411764705882353, 0.8705882352941177, 0.5843137254901961, 0.023529411764705882, 0.8313725490196079, 0.08627450980392157, 0.6196078431372549, 0.41568627450980394, 0.792156862745098, 0.1568627450980392, 0.5098039215686274, 0.22745098039215686, 0.4, 0.6745098039215687, 0.4980392156862745, 0.7176470588235294, 0.3568627450980392, 0.6549019607843137, 0.5294117647058824, 0.8745098039215686, 0.49019607843137253, 0.6745098039215687, 0.09803921568627451, 0.6274509803921569, 0.9333333333333333, 0.792156862745098, 0.8666666666666667, 0.1843137254901961, 0.611764705882353, 0.4627450980392157, 0.8431372549019608, 0.5254901960784314, 0.07450980392156863, 0.5843137254901961, 0.8823529411764706, 0.10980392156862745, 0.25098039215686274, 0.5686274509803921, 0.9137254901960784, 0.6823529411764706, 0.2823529411764706, 0.03529411764705882, 0.7098039215686275, 0.4745098039215686, 0.9176470588235294, 0.28627450980392155, 0.9568627450980393, 0.4235294117647059, 0.49019607843137253, 0.3137254901960784, 0.9764705882352941, 0.788235294117647, 0.6352941176470588, 0.2, 0.8588235294117647, 0.12156862745098039, 0.9686274509803922, 0.7098039215686275, 0.5450980392156862, 0.8705882352941177, 0.7372549019607844, 0.03529411764705882, 0.9294117647058824, 0.6666666666666666, 0.7725490196078432, 0.40784313725490196, 0.6313725490196078, 0.19215686274509805, 0.9019607843137255, 0.4235294117647059, 1.0, 0.21568627450980393, 0.7490196078431373, 0.33725490196078434, 0.24313725490196078, 0.16862745098039217, 0.4588235294117647, 0.6352941176470588, 0.5411764705882353, 0.7529411764705882, 0.3686274509803922, 0.9529411764705882, 0.4235294117647059, 0.20784313725490197, 0.35294117647058826, 0.8784313725490196, 0.5294117647058824, 0.054901960784313725, 0.9568627450980393, 0.13333333333333333, 0.4666666666666667, 0.36470588235294116, 0.5882352941176471, 0.07058823529411765, 0.8980392156862745, 0.6823529411764706, 0.050980392156862744, 0.9176470588235294, 0.4196078431372549, 0.15294117647058825, 0.6313725490196078, 0.0784313725490196, 0.7725490196078432, 0.1843137254901961, 0.6823529411764706, 0.6039215686274509, 0.39215686274509803, 0.803921568627451, 0.5450980392156862, 0.16862745098039217, 0.8274509803921568, 0.22745098039215686, 0.043137254901960784, 0.6470588235294118, 0.25882352941176473, 0.996078431372549, 0.058823529411764705, 0.7215686274509804, 0.7764705882352941, 0.5098039215686274, 0.9254901960784314, 0.29411764705882354, 0.7529411764705882, 0.9450980392156862, 0.16470588235294117, 0.10196078431372549, 0.6352941176470588, 0.9607843137254902, 0.4, 0.7098039215686275, 0.24705882352941178, 0.6509803921568628, 0.3058823529411765, 0.9490196078431372, 0.2, 0.9019607843137255, 0.5568627450980392, 0.6470588235294118, 0.9803921568627451, 0.7686274509803922, 0.8901960784313725, 0.1843137254901961, 0.0392156862745098, 0.8352941176470589, 0.25098039215686274, 0.9333333333333333, 0.011764705882352941, 0.8, 0.3411764705882353, 1.0, 0.7568627450980392, 0.2980392156862745, 0.054901960784313725, 0.3607843137254902, 0.5372549019607843, 0.08627450980392157, 0.9019607843137255, 0.36470588235294116, 0.00392156862745098, 0.20784313725490197, 0.7803921568627451, 0.45098039215686275, 0.9529411764705882, 0.3568627450980392, 0.7176470588235294, 0.6313725490196078, 0.4117647058823529, 0.1568627450980392, 0.792156862745098, 0.9019607843137255, 0.5764705882352941, 0.12941176470588237, 0.6352941176470588, 0.00784313725490196, 0.23529411764705882, 0.7764705882352941, 0.8745098039215686, 0.7058823529411765, 0.25098039215686274, 0.10588235294117647, 0.9333333333333333, 0.33725490196078434, 0.5568627450980392, 0.6549019607843137, 0.30196078431372547, 0.21176470588235294, 0.0784313725490196, 0.3254901960784314, 0.6078431372549019, 0.12156862745098039, 0.5372549019607843, 0.27058823529411763, 0.00392156862745098, 0.9490196078431372, 0.7294117647058823, 0.5254901960784314, 0.047058823529411764, 0.27058823529411763, 0.6470588235294118, 0.47058823529411764, 0.07058823529411765, 0.9490196078431372, 0.6627450980392157, 0.7333333333333333, 0.8352941176470589, 0.3058823529411765, 0.9058823529411765, 0.6941176470588235, 0.12941176470588237, 0.8392156862745098, 0.6274509803921569, 0.7294117647058823, 0.09411764705882353, 0.25098039215686274, 0.38823529411764707, 0.19215686274509805, 0.42745098039215684, 0.2823529411764706, 0.9215686274509803, 0.8196078431372549, 0.7490196078431373, 0.16862745098039217, 0.44313725490196076, 0.3764705882352941, 0.6078431372549019, 0.5411764705882353, 0.7529411764705882, 0.8705882352941177, 0.5098039215686274, 0.3215686274509804, 0.27058823529411763, 0.41568627450980394, 0.8588235294117647, 0.23921568627450981, 0.11764705882352941, 0.023529411764705882, 0.4549019607843137, 0.9254901960784314, 0.6, 0.5019607843137255, 0.8745098039215686, 0.10588235294117647, 0.5372549019607843, 0.1607843137254902, 0.8313725490196079, 0.4117647058823529, 0.24313725490196078, 0.16862745098039217, 0.027450980392156862, 0.39215686274509803, 0.5803921568627451, 0.49019607843137253, 0.6745098039215687, 0.4235294117647059, 0.796078431372549, 0.5568627450980392, 0.19215686274509805, 0.7686274509803922, 0.9764705882352941, 0.5372549019607843, 0.14901960784313725, 0.7490196078431373, 0.4745098039215686, 0.011764705882352941, 0.28627450980392155, 0.3686274509803922, 0.12156862745098039, 0.4470588235294118, 0.5803921568627451, 0.3333333333333333, 0.9568627450980393, 0.5607843137254902, 0.4627450980392157, 0.7490196078431373, 0.09019607843137255, 0.596078431372549, 0.22745098039215686, 0.45098039215686275, 0.1607843137254902, 0.5607843137254902, 0.7137254901960784, 0.23921568627450981, 0.44313725490196076, 0.7686274509803922, 0.2823529411764706, 0.5764705882352941, 0.7372549019607844, 0.984313725490196, 0.6941176470588235, 0.3137254901960784, 0.043137254901960784, 0.17254901960784313, 0.47058823529411764, 0.9921568627450981, 0.21568627450980393, 0.08627450980392157, 0.5098039215686274, 0.3686274509803922, 0.22745098039215686, 0.7568627450980392, 0.8549019607843137, 0.5176470588235295, 0.3803921568627451, 0.6470588235294118, 0.18823529411764706, 0.027450980392156862, 0.4627450980392157, 0.39215686274509803, 0.6901960784313725, 0.06666666666666667, 0.8901960784313725, 0.4627450980392157, 0.403921568627451, 0.807843137254902, 0.9372549019607843, 0.49411764705882355, 0.6941176470588235, 0.19607843137254902, 0.8117647058823529, 0.3568627450980392, 0.48627450980392156, 0.1607843137254902, 0.3137254901960784, 0.8117647058823529, 0.6039215686274509, 0.12156862745098039, 0.8627450980392157, 0.5568627450980392, 0.3803921568627451, 0.8941176470588236, 0.49411764705882355, 0.023529411764705882, 0.38823529411764707, 0.09803921568627451, 0.21568627450980393, 0.5764705882352941, 0.47058823529411764, 0.2627450980392157, 0.16470588235294117, 0.9137254901960784, 0.5725490196078431, 0.9882352941176471, 0.7686274509803922, 0.6078431372549019, 0.8588235294117647, 0.7137254901960784, 0.6352941176470588, 0.08627450980392157, 0.3176470588235294, 0.5333333333333333, 0.9607843137254902, 0.2235294117647059, 0.13333333333333333, 0.29411764705882354, 0.9882352941176471, 0.2, 0.6784313725490196, 0.8274509803921568, 0.9764705882352941, 0.00784313725490196, 0.5372549019607843, 0.4823529411764706, 0.8862745098039215, 0.6392156862745098, 0.27450980392156865, 0.7529411764705882, 0.12549019607843137, 0.3176470588235294, 0.4, 0.7098039215686275, 0.9137254901960784, 0.4627450980392157, 0.6745098039215687, 0.5725490196078431, 0.9647058823529412, 0.6470588235294118, 0.8470588235294118, 0.7058823529411765, 0.12549019607843137, 0.21568627450980393, 0.8666666666666667, 0.0, 0.26666666666666666, 0.32941176470588235, 0.12941176470588237, 0.4823529411764706, 0.07450980392156863, 0.43137254901960786, 0.8784313725490196, 0.34509803921568627, 0.5843137254901961, 0.807843137254902, 0.7137254901960784, 0.9333333333333333, 0.047058823529411764, 0.25882352941176473, 0.6274509803921569, 0.10588235294117647, 0.792156862745098, 0.21568627450980393, 0.13725490196078433, 0.403921568627451, 0.3137254901960784, 0.7098039215686275, 0.8509803921568627, 0.047058823529411764, 0.3843137254901961, 0.8901960784313725, 0.4823529411764706, 0.9803921568627451, 0.6431372549019608, 0.050980392156862744, 0.9450980392156862, 0.1607843137254902, 0.41568627450980394, 0.2627450980392157, 0.13725490196078433, 0.8627450980392157, 0.6196078431372549, 0.7607843137254902, 0.8274509803921568, 0.01568627450980392, 0.8705882352941177, 0.7450980392156863, 0.6509803921568628, 0.9647058823529412, 0.06274509803921569, 0.43137254901960786, 0.3176470588235294, 1.0, 0.09803921568627451, 0.8274509803921568, 0.5450980392156862, 0.9176470588235294, 0.596078431372549, 0.8431372549019608, 0.5294117647058824, 0.2823529411764706, 0.16470588235294117, 0.7490196078431373, 0.00784313725490196, 0.5843137254901961, 0.15294117647058825, 0.37254901960784315, 0.9058823529411765, 0.4235294117647059, 0.9921568627450981, 0.6431372549019608, 0.8745098039215686, 0.5843137254901961, 0.08627450980392157, 0.43529411764705883, 0.9725490196078431, 0.3568627450980392, 0.7607843137254902, 0.1803921568627451, 0.6941176470588235, 0.13725490196078433, 0.2823529411764706, 0.6078431372549019, 0.9607843137254902, 0.7803921568627451, 0.4196078431372549, 0.0, 0.9803921568627451, 0.788235294117647, 0.30980392156862746, 0.5019607843137255, 0.03137254901960784, 0.4470588235294118, 0.1450980392156863, 0.3215686274509804, 0.011764705882352941, 0.49411764705882355, 0.23529411764705882, 0.403921568627451, 0.6901960784313725, 0.8509803921568627, 0.0, 0.6509803921568628, 0.8313725490196079, 0.4823529411764706, 0.06666666666666667, 0.39215686274509803, 0.10980392156862745, 0.4588235294117647, 0.5882352941176471, 0.1450980392156863, 0.796078431372549, 0.34509803921568627, 0.7176470588235294, 1.0, 0.3803921568627451, 0.20392156862745098, 0.6745098039215687, 0.9607843137254902, 0.7803921568627451, 0.2784313725490196, 0.2, 0.36470588235294116, 0.00392156862745098, 0.2901960784313726, 0.10196078431372549, 0.33725490196078434, 0.5490196078431373, 0.45098039215686275, 0.9921568627450981, 0.36470588235294116, 0.6078431372549019, 0.7450980392156863, 0.9411764705882353, 0.6901960784313725, 0.8941176470588236, 0.6196078431372549, 0.36470588235294116, 0.8196078431372549, 0.21568627450980393, 0.6901960784313725, 0.10980392156862745, 0.403921568627451, 0.17254901960784313, 0.49411764705882355, 0.6784313725490196, 0.8588235294117647, 0.7450980392156863, 0.5137254901960784, 0.3764705882352941, 0.6901960784313725, 0.8745098039215686, 0.6352941176470588, 0.9725490196078431, 0.17647058823529413, 0.5176470588235295, 0.9411764705882353, 0.6549019607843137, 0.796078431372549, 0.19215686274509805, 0.12549019607843137, 0.8274509803921568, 0.32941176470588235, 0.6862745098039216, 0.4980392156862745, 0.8823529411764706, 0.6549019607843137, 0.5529411764705883, 0.3803921568627451, 0.2235294117647059, 0.5098039215686274, 0.2784313725490196, 0.39215686274509803, 0.5529411764705883, 0.32941176470588235, 0.25882352941176473, 0.4666666666666667, 0.8196078431372549, 0.19215686274509805, 0.615686274509804, 0.7019607843137254, 0.16470588235294117, 0.29411764705882354, 0.7372549019607844, 0.34901960784313724, 0.13725490196078433, 0.20784313725490197, 0.7686274509803922, 0.9921568627450981, 0.6196078431372549, 0.24313725490196078, 0.9568627450980393, 0.6745098039215687, 0.27058823529411763, 0.043137254901960784, 0.7764705882352941, 0.09411764705882353, 0.3058823529411765, 0.054901960784313725, 0.23921568627450981, 0.7019607843137254, 0.9137254901960784, 0.21176470588235294, 0.6784313725490196, 0.5019607843137255, 0.011764705882352941, 0.3058823529411765, 0.796078431372549, 0.4392156862745098, 0.8509803921568627, 0.2, 0.7058823529411765, 0.5176470588235295, 0.8823529411764706, 0.33725490196078434, 0.6627450980392157, 0.07058823529411765, 0.403921568627451, 0.8627450980392157, 0.7058823529411765, 0.8117647058823529, 0.6666666666666666, 0.9333333333333333, 0.5450980392156862, 0.7843137254901961, 0.12549019607843137, 1.0, 0.19607843137254902, 0.4745098039215686, 0.7725490196078432, 0.2627450980392157, 0.9254901960784314, 0.7058823529411765, 0.5725490196078431, 0.796078431372549, 0.24705882352941178, 0.3607843137254902, 0.7294117647058823, 0.6588235294117647, 0.9058823529411765, 0.2901960784313726, 0.17647058823529413, 0.043137254901960784, 0.8235294117647058, 0.5725490196078431, 0.08235294117647059, 0.4666666666666667, 0.027450980392156862, 0.5607843137254902, 0.8509803921568627, 0.7450980392156863, 0.9372549019607843, 0.807843137254902, 0.48627450980392156, 0.8784313725490196, 0.19607843137254902, 0.047058823529411764, 0.7725490196078432, 0.30980392156862746, 0.07450980392156863, 0.5058823529411764, 0.1803921568627451, 0.44313725490196076, 0.8392156862745098, 0.027450980392156862, 0.28627450980392155, 0.6588235294117647, 0.047058823529411764, 1.0, 0.25882352941176473, 0.9098039215686274, 0.8431372549019608, 0.32941176470588235, 0.21568627450980393, 0.9725490196078431, 0.41568627450980394, 0.06274509803921569, 0.9176470588235294, 0.27450980392156865, 0.0196078431372549, 0.7764705882352941, 0.4823529411764706, 0.25882352941176473, 0.5803921568627451, 0.10980392156862745, 0.29411764705882354, 0.00784313725490196, 0.6039215686274509, 0.27058823529411763, 0.4, 0.5490196078431373, 0.20784313725490197, 0.8117647058823529, 0.09803921568627451, 0.023529411764705882, 0.47843137254901963, 0.796078431372549, 0.9137254901960784, 0.06666666666666667, 0.596078431372549, 0.6980392156862745, 0.11372549019607843, 0.9254901960784314, 0.6, 0.1450980392156863, 0.7215686274509804, 0.8980392156862745, 0.03137254901960784, 0.5647058823529412, 0.47843137254901963, 0.41568627450980394, 0.07058823529411765, 0.9686274509803922, 0.6588235294117647, 0.4392156862745098, 0.03137254901960784, 0.36470588235294116, 0.4980392156862745, 0.8196078431372549, 0.10980392156862745, 0.5294117647058824, 0.8431372549019608, 0.22745098039215686, 0.5686274509803921, 0.6235294117647059, 0.7450980392156863, 0.4588235294117647, 0.796078431372549, 0.5333333333333333, 0.38823529411764707, 0.13725490196078433, 0.8470588235294118, 0.25098039215686274, 0.9058823529411765, 0.6196078431372549, 0.9647058823529412, 0.08235294117647059, 0.5647058823529412, 0.3568627450980392, 0.11764705882352941, 0.25882352941176473, 0.596078431372549, 0.17647058823529413, 0.7411764705882353, 0.5333333333333333, 0.615686274509804, 0.12156862745098039, 0.36470588235294116, 0.2823529411764706, 0.2235294117647059, 0.0784313725490196, 0.8823529411764706, 0.34901960784313724, 0.6, 0.8980392156862745, 0.043137254901960784, 0.5647058823529412, 0.3686274509803922, 0.10196078431372549, 0.43137254901960786, 0.33725490196078434, 0.03137254901960784, 0.6431372549019608, 0.9450980392156862, 0.8509803921568627, 0.0392156862745098, 0.21176470588235294, 0.43529411764705883, 0.07450980392156863, 0.615686274509804, 0.5098039215686274, 0.42745098039215684, 0.8666666666666667, 0.33725490196078434, 0.8980392156862745, 0.6352941176470588, 0.1450980392156863, 0.4235294117647059, 0.07058823529411765, 0.6, 0.2235294117647059, 0.40784313725490196, 0.7333333333333333, 0.9176470588235294, 0.27450980392156865, 0.6392156862745098, 0.8352941176470589, 0.9098039215686274, 0.39215686274509803, 0.11764705882352941, 0.592156862745098, 0.24313725490196078, 0.5294117647058824, 0.9450980392156862, 0.788235294117647, 0.5686274509803921, 0.4549019607843137, 0.5176470588235295, 0.6392156862745098, 0.08627450980392157, 0.6039215686274509, 0.5529411764705883, 0.14901960784313725, 0.30196078431372547, 0.8274509803921568, 0.17254901960784313, 0.5450980392156862, 0.596078431372549, 0.33725490196078434, 0.07058823529411765, 0.8352941176470589, 0.4235294117647059, 0.6901960784313725, 0.34901960784313724, 0.7529411764705882, 0.5254901960784314, 0.8588235294117647, 0.9215686274509803, 0.03137254901960784, 0.7490196078431373, 0.36470588235294116, 0.615686274509804, 0.3137254901960784, 0.9529411764705882, 0.11764705882352941, 0.6627450980392157, 0.3411764705882353, 0.9686274509803922, 0.44313725490196076, 0.18823529411764706, 0.8431372549019608, 0.023529411764705882, 0.39215686274509803, 0.5333333333333333, 0.2784313725490196, 0.35294117647058826, 0.9372549019607843, 0.7607843137254902, 0.8784313725490196, 0.25098039215686274, 0.4980392156862745, 0.803921568627451, 0.3058823529411765, 0.9137254901960784, 0.6941176470588235, 0.058823529411764705, 0.41568627450980394, 0.33725490196078434, 0.7333333333333333, 0.4470588235294118, 0.8666666666666667, 0.9607843137254902, 0.5137254901960784, 0.1803921568627451, 0.12941176470588237, 0.9686274509803922, 0.3254901960784314, 0.03529411764705882, 0.7725490196078432, 0.47058823529411764, 0.5764705882352941, 0.058823529411764705, 0.40784313725490196, 0.5137254901960784, 0.24313725490196078, 0.9137254901960784, 0.6431372549019608, 0.807843137254902, 0.058823529411764705, 0.4470588235294118, 0.9490196078431372, 0.8352941176470589, 0.23921568627450981, 0.9098039215686274, 0.19215686274509805, 0.9647058823529412, 0.5176470588235295, 0.592156862745098, 0.4, 0.17254901960784313, 0.45098039215686275, 0.27450980392156865, 0.7411764705882353, 0.6627450980392157, 0.30196078431372547, 0.9529411764705882, 0.6196078431372549, 0.17254901960784313, 0.7490196078431373, 0.9019607843137255, 0.49411764705882355, 0.16470588235294117, 0.5294117647058824, 0.3137254901960784, 0.9647058823529412, 0.5686274509803921, 0.7529411764705882, 0.9294117647058824, 0.6862745098039216, 0.1411764705882353, 0.22745098039215686, 0.7294117647058823, 0.5254901960784314, 0.2549019607843137, 0.9764705882352941, 0.6862745098039216, 0.30980392156862746, 0.5098039215686274, 0.6588235294117647, 0.15294117647058825, 0.058823529411764705, 0.5882352941176471, 0.5176470588235295, 0.13725490196078433, 0.2196078431372549, 0.6823529411764706, 0.5529411764705883, 0.803921568627451, 0.9882352941176471, 0.09411764705882353, 0.7411764705882353, 0.17254901960784313, 0.38823529411764707, 0.13333333333333333, 0.3176470588235294, 0.7725490196078432, 0.027450980392156862, 0.7411764705882353, 0.37254901960784315, 0.8, 0.0, 0.7215686274509804, 0.47843137254901963, 0.6588235294117647, 0.9921568627450981, 0.4392156862745098, 0.8941176470588236, 0.15294117647058825, 0.7450980392156863, 0.9176470588235294, 0.20392156862745098, 0.8196078431372549, 0.9647058823529412, 0.43529411764705883, 0.06666666666666667, 0.6666666666666666, 0.47058823529411764, 0.9686274509803922, 0.24705882352941178, 0.8980392156862745, 0.7215686274509804, 0.20392156862745098, 0.42745098039215684, 0.7411764705882353, 0.1568627450980392, 0.8784313725490196, 0.24705882352941178, 0.7294117647058823, 0.49019607843137253, 0.7764705882352941, 0.9882352941176471, 0.6588235294117647, 0.08235294117647059, 0.796078431372549, 0.2235294117647059, 0.12941176470588237, 0.6313725490196078, 0.01568627450980392, 0.5803921568627451, 0.7215686274509804, 0.09411764705882353, 0.23137254901960785, 0.5607843137254902, 0.8745098039215686, 0.1803921568627451, 0.6, 0.9254901960784314, 0.1411764705882353, 0.396078431372549, 0.3333333333333333, 0.25882352941176473, 0.6862745098039216, 0.4117647058823529, 0.8392156862745098, 0.6196078431372549, 0.26666666666666666, 0.9450980392156862, 0.6549019607843137, 0.33725490196078434, 0.7411764705882353, 0.20784313725490197, 0.6823529411764706, 0.7647058823529411, 0.01568627450980392, 0.47843137254901963, 0.984313725490196, 0.7333333333333333, 0.2980392156862745, 0.13725490196078433, 0.37254901960784315, 0.0392156862745098, 0.47058823529411764, 0.7803921568627451, 0.6470588235294118, 0.0196078431372549, 0.7529411764705882, 0.9490196078431372, 0.7098039215686275, 0.8274509803921568, 0.09019607843137255, 0.5098039215686274, 0.41568627450980394, 0.1411764705882353, 0.8588235294117647, 0.5215686274509804, 0.8156862745098039, 0.2196078431372549, 0.2980392156862745, 0.11372549019607843, 0.41568627450980394, 0.7647058823529411, 0.6274509803921569, 0.09411764705882353, 0.48627450980392156, 0.24313725490196078, 0.35294117647058826, 0.7843137254901961, 0.2980392156862745, 0.9568627450980393, 0.050980392156862744, 0.803921568627451, 0.3803921568627451, 0.1843137254901961, 0.7607843137254902, 0.8745098039215686, 0.10980392156862745, 0.9882352941176471, 0.7803921568627451, 0.3764705882352941, 0.6901960784313725, 0.9686274509803922, 0.4235294117647059, 0.47843137254901963, 0.0392156862745098, 0.3411764705882353, 0.2823529411764706, 0.6509803921568628, 0.4196078431372549, 0.3254901960784314, 0.48627450980392156, 0.7019607843137254, 0.8980392156862745, 0.20392156862745098, 0.9607843137254902, 0.42745098039215684, 0.23921568627450981, 0.9294117647058824, 0.4470588235294118, 0.8705882352941177, 0.6235294117647059, 0.34901960784313724, 0.23921568627450981, 0.11372549019607843, 0.7098039215686275, 0.2196078431372549, 0.5294117647058824, 0.3764705882352941, 0.023529411764705882, 0.6235294117647059, 0.47843137254901963, 0.14901960784313725, 0.23137254901960785, 0.7176470588235294, 0.3176470588235294, 0.17647058823529413, 0.592156862745098, 0.13725490196078433, 0.45098039215686275, 0.5372549019607843, 0.8274509803921568, 0.2784313725490196, 0.5725490196078431, 0.00784313725490196, 0.5176470588235295, 0.6313725490196078, 0.0784313725490196, 0.36470588235294116, 0.29411764705882354, 0.21568627450980393, 0.8745098039215686, 0.44313725490196076, 0.5137254901960784, 0.6745098039215687, 0.3843137254901961, 0.8392156862745098, 0.9490196078431372, 0.3411764705882353, 0.16470588235294117, 0.8588235294117647, 0.6352941176470588, 0.4666666666666667, 0.7725490196078432, 0.9686274509803922, 0.28627450980392155, 0.6509803921568628, 0.0196078431372549, 0.7647058823529411, 0.07058823529411765, 0.8156862745098039, 0.9294117647058824, 0.01568627450980392, 0.5686274509803921, 0.19215686274509805, 0.7215686274509804, 0.43137254901960786, 0.10588235294117647, 0.16470588235294117, 0.9921568627450981, 0.8392156862745098, 0.12941176470588237, 0.3803921568627451, 0.30980392156862746, 0.1803921568627451, 0.403921568627451, 0.8666666666666667, 0.5098039215686274, 0.6941176470588235, 0.6352941176470588, 0.5647058823529412, 0.27058823529411763, 0.40784313725490196, 0.10588235294117647, 0.32941176470588235, 0.8470588235294118, 0.4823529411764706, 0.050980392156862744, 0.6313725490196078, 0.20784313725490197, 0.9764705882352941, 0.796078431372549, 0.24313725490196078, 0.7137254901960784, 0.023529411764705882, 0.4588235294117647, 0.9921568627450981, 0.6823529411764706, 0.596078431372549, 0.8823529411764706, 0.25882352941176473, 0.3843137254901961, 0.8, 0.8627450980392157, 0.12549019607843137, 0.40784313725490196, 0.0, 0.5411764705882353, 0.6431372549019608, 0.44313725490196076, 0.11764705882352941, 0.6078431372549019, 0.4745098039215686, 0.011764705882352941, 0.5450980392156862, 0.3411764705882353, 0.45098039215686275, 0.2549019607843137, 0.8666666666666667, 0.3137254901960784, 0.00784313725490196, 0.8156862745098039, 0.7372549019607844, 0.9411764705882353, 0.8784313725490196, 0.19607843137254902, 0.7764705882352941, 0.01568627450980392, 0.9176470588235294, 0.8470588235294118, 0.058823529411764705, 0.6039215686274509, 0.8235294117647058, 0.10196078431372549, 0.6588235294117647, 0.2980392156862745, 0.12941176470588237, 0.5058823529411764, 0.2, 0.08235294117647059, 0.9411764705882353, 0.7843137254901961, 0.4, 0.050980392156862744, 0.8117647058823529, 0.9529411764705882, 0.6745098039215687, 0.3176470588235294, 0.5568627450980392, 0.07450980392156863, 0.8705882352941177, 0.3803921568627451, 0.5725490196078431, 0.10196078431372549, 0.788235294117647, 0.8431372549019608, 0.396078431372549, 0.07450980392156863, 0.6862745098039216, 0.047058823529411764, 1.0, 0.39215686274509803, 0.7803921568627451, 0.3137254901960784, 0.8156862745098039, 0.9333333333333333, 0.6784313725490196, 0.5725490196078431, 0.11372549019607843, 0.611764705882353, 0.16862745098039217, 0.9686274509803922, 0.043137254901960784, 0.2980392156862745, 0.4627450980392157, 0.19607843137254902, 0.5372549019607843, 0.42745098039215684, 0.9803921568627451, 0.3843137254901961, 0.13725490196078433, 0.3215686274509804, 0.08235294117647059, 0.21568627450980393, 0.5019607843137255, 0.7058823529411765, 0.17254901960784313, 0.5568627450980392, 0.6392156862745098, 0.47843137254901963, 0.34901960784313724, 0.8941176470588236, 0.07450980392156863, 0.5019607843137255, 0.8784313725490196, 0.803921568627451, 0.5529411764705883, 0.2901960784313726, 0.4588235294117647, 0.9254901960784314, 0.6, 0.8823529411764706, 0.6745098039215687, 0.5529411764705883, 0.03137254901960784, 0.2235294117647059, 0.9333333333333333, 0.08235294117647059, 0.8156862745098039, 0.9882352941176471, 0.7254901960784313, 0.8862745098039215, 0.5764705882352941, 0.14901960784313725, 0.24313725490196078, 0.30980392156862746, 0.5568627450980392, 0.8784313725490196, 0.3568627450980392, 0.054901960784313725, 0.592156862745098, 0.9215686274509803, 0.39215686274509803, 0.09019607843137255, 0.5529411764705883, 0.3568627450980392, 0.00784313725490196, 0.7176470588235294, 0.9568627450980393, 0.050980392156862744, 0.6941176470588235, 0.18823529411764706, 0.9803921568627451, 0.5882352941176471, 0.8156862745098039, 0.8901960784313725, 0.2, 0.7176470588235294, 0.3215686274509804, 0.9176470588235294, 0.8431372549019608, 0.27450980392156865, 0.9450980392156862, 0.2, 0.8196078431372549, 0.6235294117647059, 0.09803921568627451, 0.5529411764705883, 0.1803921568627451, 0.3607843137254902, 0.25882352941176473, 0.596078431372549, 0.1450980392156863, 0.7137254901960784, 0.47058823529411764, 0.5490196078431373, 0.6235294117647059, 0.2196078431372549, 0.27450980392156865, 0.5254901960784314, 0.34509803921568627, 0.7176470588235294, 0.5490196078431373, 0.8862745098039215, 0.592156862745098, 0.9882352941176471, 0.6862745098039216, 0.28627450980392155, 0.5647058823529412, 0.8862745098039215, 0.5176470588235295, 0.3058823529411765, 0.6039215686274509, 0.4666666666666667, 0.24705882352941178, 0.12941176470588237, 0.996078431372549, 0.7333333333333333, 0.27450980392156865, 0.6509803921568628, 0.9019607843137255, 0.984313725490196, 0.5137254901960784, 0.2901960784313726, 0.6352941176470588, 0.9411764705882353, 0.32941176470588235, 0.17647058823529413, 0.6235294117647059, 0.8862745098039215, 0.4666666666666667, 0.20784313725490197, 0.13333333333333333, 0.4196078431372549, 0.054901960784313725, 0.8588235294117647, 0.7490196078431373, 0.403921568627451, 0.3254901960784314, 0.7176470588235294, 0.9019607843137255, 0.592156862745098, 0.7450980392156863, 0.10196078431372549, 0.6862745098039216, 0.7803921568627451, 0.27058823529411763, 0.00392156862745098, 0.5215686274509804, 0.7294117647058823, 0.807843137254902, 0.5764705882352941, 0.9058823529411765, 0.4549019607843137, 0.3058823529411765, 0.996078431372549, 0.10980392156862745, 0.21176470588235294, 0.7843137254901961, 0.28627450980392155, 0.6745098039215687, 0.3843137254901961, 0.23137254901960785, 0.0, 0.36470588235294116, 0.6392156862745098, 0.0784313725490196, 0.5372549019607843, 0.043137254901960784, 0.792156862745098, 0.2627450980392157, 0.1450980392156863, 0.6196078431372549, 0.34901960784313724, 0.7725490196078432, 0.43137254901960786, 0.3176470588235294, 0.1607843137254902, 0.6666666666666666, 0.21568627450980393, 0.4470588235294118, 0.9764705882352941, 0.6941176470588235, 0.38823529411764707, 0.7686274509803922, 0.11764705882352941, 0.6823529411764706, 0.4745098039215686, 0.17254901960784313, 0.3215686274509804, 0.6705882352941176, 0.26666666666666666, 0.7568627450980392, 0.8431372549019608, 0.20392156862745098, 0.4745098039215686, 0.13725490196078433, 0.5647058823529412, 0.33725490196078434, 0.5176470588235295, 0.6470588235294118, 0.2784313725490196, 0.47058823529411764, 0.0784313725490196, 0.3686274509803922, 0.9921568627450981, 0.5647058823529412, 0.06666666666666667, 0.6588235294117647, 0.41568627450980394, 0.6980392156862745, 0.5843137254901961, 0.054901960784313725, 0.7254901960784313, 0.49411764705882355, 0.9372549019607843, 0.7686274509803922, 0.6431372549019608, 0.10588235294117647, 0.5294117647058824, 0.403921568627451, 0.050980392156862744, 0.9647058823529412, 0.36470588235294116, 0.12549019607843137, 0.6823529411764706, 0.9803921568627451, 0.40784313725490196, 0.00784313725490196, 0.47058823529411764, 0.1803921568627451, 0.7803921568627451, 0.03529411764705882, 0.3568627450980392, 0.7529411764705882, 0.4235294117647059, 0.14901960784313725, 0.023529411764705882, 0.7411764705882353, 0.19215686274509805, 0.8588235294117647, 0.08627450980392157, 0.7686274509803922, 0.8941176470588236, 0.4117647058823529, 0.4980392156862745, 0.803921568627451, 0.027450980392156862, 0.4549019607843137, 0.34901960784313724, 0.2, 0.0, 0.7294117647058823, 0.8666666666666667, 0.4745098039215686, 0.7686274509803922, 0.25098039215686274, 0.10588235294117647, 0.7058823529411765, 0.592156862745098, 0.9803921568627451, 0.5372549019607843, 0.2627450980392157, 0.4745098039215686, 0.9490196078431372, 0.0, 0.8235294117647058, 0.2549019607843137, 0.5450980392156862, 0.14901960784313725, 0.8117647058823529, 0.23921568627450981, 0.9137254901960784, 0.06666666666666667, 0.6039215686274509, 0.8352941176470589, 0.9372549019607843, 0.6745098039215687, 0.4235294117647059, 0.050980392156862744, 0.3607843137254902, 0.8313725490196079, 0.23921568627450981, 0.8705882352941177, 0.42745098039215684, 0.7294117647058823, 0.5333333333333333, 0.14901960784313725, 0.9803921568627451, 0.7568627450980392, 0.592156862745098, 0.9529411764705882, 0.7098039215686275, 0.8274509803921568, 0.24705882352941178, 0.7294117647058823, 0.3411764705882353, 0.4392156862745098, 0.9333333333333333, 0.7098039215686275, 0.8313725490196079, 0.47843137254901963, 0.11372549019607843, 0.9019607843137255, 0.5411764705882353, 0.00392156862745098, 0.4980392156862745, 0.3568627450980392, 0.06274509803921569, 0.792156862745098, 0.5254901960784314, 0.023529411764705882, 0.9294117647058824, 0.43137254901960786, 0.27058823529411763, 0.9490196078431372, 0.5333333333333333, 0.7843137254901961, 0.8941176470588236, 0.6274509803921569, 0.15294117647058825, 0.4196078431372549, 0.9254901960784314, 0.3176470588235294, 0.8156862745098039, 0.22745098039215686, 0.44313725490196076, 0.9058823529411765, 0.09019607843137255, 0.7490196078431373, 0.16470588235294117, 0.6901960784313725, 0.25882352941176473, 0.8274509803921568, 0.49411764705882355, 0.1607843137254902, 0.23137254901960785, 0.788235294117647, 0.12941176470588237, 0.36470588235294116, 0.8980392156862745, 0.1607843137254902, 0.40784313725490196, 0.22745098039215686, 0.8862745098039215, 0.44313725490196076, 0.996078431372549, 0.8352941176470589, 0.6705882352941176, 0.24313725490196078, 0.8666666666666667, 0.29411764705882354, 0.788235294117647, 0.45098039215686275, 0.1607843137254902, 0.7607843137254902, 0.8627450980392157, 0.9450980392156862, 0.2549019607843137, 0.39215686274509803, 0.8235294117647058, 0.07058823529411765, 0.22745098039215686, 0.8509803921568627, 0.4823529411764706, 0.615686274509804, 0.9764705882352941, 0.3607843137254902, 0.6588235294117647, 0.43137254901960786, 0.5725490196078431, 0.1803921568627451, 0.6823529411764706, 0.3411764705882353, 0.21176470588235294, 0.6078431372549019, 0.1568627450980392, 0.7568627450980392, 0.6823529411764706, 0.42745098039215684, 0.23529411764705882, 0.5607843137254902, 0.13333333333333333, 0.3764705882352941, 0.5294117647058824, 0.9254901960784314, 0.0392156862745098, 0.35294117647058826, 0.8392156862745098, 0.7333333333333333, 0.1607843137254902, 0.3411764705882353, 0.5529411764705883, 0.20392156862745098, 0.6392156862745098, 0.49019607843137253, 0.08235294117647059, 0.34509803921568627, 0.9764705882352941, 0.396078431372549, 0.48627450980392156, 0.3137254901960784, 0.6588235294117647, 0.20784313725490197, 0.34901960784313724, 0.1607843137254902, 0.2549019607843137, 0.9764705882352941, 0.12156862745098039, 0.6588235294117647, 0.011764705882352941, 0.5882352941176471, 0.37254901960784315, 0.0392156862745098, 0.9215686274509803, 0.6274509803921569, 0.44313725490196076, 0.11372549019607843, 0.3176470588235294, 0.17647058823529413, 0.48627450980392156, 0.41568627450980394, 0.1450980392156863, 0.9764705882352941, 0.18823529411764706, 0.5176470588235295, 0.09803921568627451, 0.39215686274509803, 1.0, 0.28627450980392155, 0.2, 0.7294117647058823, 0.6039215686274509, 0.25882352941176473, 0.9450980392156862, 0.8588235294117647, 0.6196078431372549, 0.2784313725490196, 0.9019607843137255, 0.6509803921568628, 0.1568627450980392, 0.8392156862745098, 0.6039215686274509, 0.7450980392156863, 0.03529411764705882, 0.2196078431372549, 0.11764705882352941, 0.49411764705882355, 0.9607843137254902, 0.07450980392156863, 0.5254901960784314, 0.6627450980392157, 0.611764705882353, 0.8666666666666667, 0.7333333333333333, 0.03137254901960784, 0.39215686274509803, 0.8392156862745098, 0.9490196078431372, 0.611764705882353, 0.4235294117647059, 0.0392156862745098, 0.7529411764705882, 0.30196078431372547, 0.8823529411764706, 0.5254901960784314, 0.027450980392156862, 0.47058823529411764, 0.984313725490196, 0.3058823529411765, 0.6705882352941176, 0.023529411764705882, 0.5882352941176471, 0.2901960784313726, 0.06274509803921569, 0.3333333333333333, 0.16862745098039217, 0.5019607843137255, 0.7372549019607844, 0.07450980392156863, 0.5725490196078431, 0.8901960784313725, 0.09019607843137255, 0.6352941176470588, 0.3058823529411765, 0.5882352941176471, 0.13725490196078433, 0.6705882352941176, 0.4549019607843137, 0.5372549019607843, 0.6392156862745098, 0.9215686274509803, 0.3176470588235294, 0.6901960784313725, 0.25098039215686274, 0.12549019607843137, 0.9294117647058824, 0.00392156862745098, 0.28627450980392155, 0.8352941176470589, 0.050980392156862744, 0.9294117647058824, 0.10980392156862745, 0.8549019607843137, 0.5529411764705883, 0.9490196078431372, 0.09411764705882353, 0.8941176470588236, 0.792156862745098, 0.043137254901960784, 0.9725490196078431, 0.6666666666666666, 0.8196078431372549, 0.30196078431372547, 0.4392156862745098, 0.6470588235294118, 0.23529411764705882, 0.0196078431372549, 0.8, 0.9019607843137255, 0.6901960784313725, 0.12941176470588237, 0.7607843137254902, 0.8705882352941177, 0.4235294117647059, 0.7019607843137254, 0.6274509803921569, 0.023529411764705882, 0.5686274509803921, 0.8784313725490196, 0.4470588235294118, 0.10588235294117647, 0.5568627450980392, 0.49019607843137253, 0.8509803921568627, 0.611764705882353, 0.7529411764705882, 0.5176470588235295, 0.792156862745098, 0.9529411764705882, 0.7019607843137254, 0.3137254901960784, 0.8274509803921568, 0.24705882352941178, 0.054901960784313725, 0.796078431372549, 0.5254901960784314, 0.8509803921568627, 0.0392156862745098, 0.9019607843137255, 0.5686274509803921, 0.7725490196078432, 0.6627450980392157, 0.8588235294117647, 0.23137254901960785, 0.011764705882352941, 0.5686274509803921, 0.6549019607843137, 0.06274509803921569, 0.38823529411764707, 0.8313725490196079, 0.6980392156862745, 0.12549019607843137, 0.7647058823529411, 0.403921568627451, 0.08627450980392157, 0.2, 0.34901960784313724, 0.4980392156862745, 0.23529411764705882, 0.07450980392156863, 0.3843137254901961, 0.8549019607843137, 0.4470588235294118, 0.34509803921568627, 0.8196078431372549, 0.5882352941176471, 0.2549019607843137, 0.7843137254901961, 0.043137254901960784, 0.3764705882352941, 0.10980392156862745, 0.996078431372549, 0.30196078431372547, 0.5725490196078431, 0.2235294117647059, 0.32941176470588235, 0.5333333333333333, 0.12156862745098039, 0.9254901960784314, 0.6313725490196078, 0.3843137254901961, 0.9686274509803922, 0.6039215686274509, 0.7372549019607844, 0.8274509803921568, 0.24705882352941178, 0.5411764705882353, 0.803921568627451, 0.7411764705882353, 0.13725490196078433, 0.4823529411764706, 0.7019607843137254, 0.7803921568627451, 0.9176470588235294, 0.615686274509804, 0.43529411764705883, 0.20392156862745098, 0.9333333333333333, 0.33725490196078434, 0.7058823529411765, 0.23529411764705882, 0.5058823529411764, 0.06274509803921569, 0.7411764705882353, 0.9058823529411765, 0.3215686274509804, 0.9686274509803922, 0.10196078431372549, 0.17254901960784313, 0.7764705882352941, 0.06666666666666667, 0.4, 0.5529411764705883, 0.792156862745098, 0.4980392156862745, 0.7333333333333333, 0.3843137254901961, 0.6313725490196078, 0.5254901960784314, 0.7803921568627451, 0.2627450980392157, 0.396078431372549, 0.3058823529411765, 0.6274509803921569, 0.5215686274509804, 0.3607843137254902, 0.2823529411764706, 0.6, 0.20784313725490197, 0.06666666666666667, 0.7411764705882353, 0.17254901960784313, 0.8666666666666667, 0.5019607843137255, 0.396078431372549, 0.6235294117647059, 0.2901960784313726, 0.44313725490196076, 0.996078431372549, 0.3686274509803922, 0.047058823529411764, 0.9372549019607843, 0.1843137254901961, 0.2784313725490196, 0.8392156862745098, 0.7568627450980392, 0.1568627450980392, 1.0, 0.6941176470588235, 0.7725490196078432, 0.9019607843137255, 0.0392156862745098, 0.2980392156862745, 0.39215686274509803, 0.16470588235294117, 0.2784313725490196, 0.4588235294117647, 0.12549019607843137, 0.19215686274509805, 0.49019607843137253, 0.5764705882352941, 0.396078431372549, 0.9333333333333333, 0.6470588235294118, 0.21568627450980393, 0.6823529411764706, 0.3333333333333333, 0.27450980392156865, 0.058823529411764705, 0.4666666666666667, 0.615686274509804, 0.3137254901960784, 0.7450980392156863, 0.8941176470588236, 0.5058823529411764, 0.788235294117647, 0.9647058823529412, 0.4549019607843137, 0.3254901960784314, 0.18823529411764706, 0.5725490196078431, 0.47843137254901963, 0.7215686274509804, 0.8274509803921568, 0.5647058823529412, 0.9882352941176471, 0.7254901960784313, 0.3176470588235294, 0.6431372549019608, 0.9686274509803922, 0.5568627450980392, 0.7137254901960784, 0.011764705882352941, 0.396078431372549, 0.7019607843137254, 0.18823529411764706, 0.8941176470588236, 0.2784313725490196, 0.49019607843137253, 0.17254901960784313, 0.6784313725490196, 0.7803921568627451, 0.5019607843137255, 0.01568627450980392, 0.8823529411764706, 0.796078431372549, 0.18823529411764706, 0.7058823529411765, 0.4588235294117647, 0.09411764705882353, 0.3333333333333333, 0.20392156862745098, 0.4196078431372549, 0.6274509803921569, 0.08627450980392157, 0.34509803921568627, 0.9529411764705882, 0.3843137254901961, 0.8509803921568627, 0.20784313725490197, 0.5568627450980392, 0.0, 0.37254901960784315, 0.8196078431372549, 0.6549019607843137, 0.03137254901960784, 0.48627450980392156, 0.3843137254901961, 0.803921568627451, 0.996078431372549, 0.43137254901960786, 0.8431372549019608, 0.2, 0.6078431372549019, 0.27058823529411763, 0.7176470588235294, 0.3764705882352941, 0.5725490196078431, 0.8352941176470589, 0.45098039215686275, 0.8901960784313725, 0.20784313725490197, 0.3333333333333333, 0.9725490196078431, 0.09803921568627451, 0.23137254901960785, 0.4392156862745098, 0.9803921568627451, 0.7176470588235294, 0.48627450980392156, 0.06666666666666667, 0.8117647058823529, 0.1411764705882353, 0.4588235294117647, 0.7137254901960784, 0.9137254901960784, 0.4117647058823529, 0.4823529411764706, 0.9568627450980393, 0.5568627450980392, 0.11764705882352941, 0.6784313725490196, 0.9411764705882353, 0.09411764705882353, 0.5803921568627451, 0.06274509803921569, 0.2235294117647059, 0.5254901960784314, 0.30196078431372547, 0.788235294117647, 0.4588235294117647, 0.5333333333333333, 0.09803921568627451, 0.2235294117647059, 0.3764705882352941, 0.28627450980392155, 0.0196078431372549, 0.40784313725490196, 0.22745098039215686, 0.7137254901960784, 0.47058823529411764, 0.9294117647058824, 0.6823529411764706, 0.06274509803921569, 0.5490196078431373, 0.9019607843137255, 0.6470588235294118, 0.7529411764705882, 0.8588235294117647, 0.1607843137254902, 0.28627450980392155, 0.4627450980392157, 0.7450980392156863, 0.10588235294117647, 0.6039215686274509, 0.9372549019607843, 0.3843137254901961, 0.12941176470588237, 0.9647058823529412, 0.8156862745098039, 0.43137254901960786, 0.12156862745098039, 0.35294117647058826, 0.16470588235294117, 0.23921568627450981, 0.023529411764705882, 0.5294117647058824, 0.9176470588235294, 0.047058823529411764, 0.9686274509803922, 0.30980392156862746, 0.11372549019607843, 0.4196078431372549, 0.0, 0.796078431372549, 0.4627450980392157, 0.18823529411764706, 0.10588235294117647, 0.27450980392156865, 0.9176470588235294, 0.16470588235294117, 0.30980392156862746, 0.9803921568627451, 0.4549019607843137, 0.5529411764705883, 0.7176470588235294, 0.807843137254902, 0.596078431372549, 0.40784313725490196, 0.12549019607843137, 0.9647058823529412, 0.6549019607843137, 0.3764705882352941, 0.2901960784313726, 0.5803921568627451, 0.24313725490196078, 0.023529411764705882, 0.8392156862745098, 0.14901960784313725, 0.6862745098039216, 0.9294117647058824, 0.043137254901960784, 0.8666666666666667, 0.18823529411764706, 0.5098039215686274, 0.6509803921568628, 0.2627450980392157, 0.9686274509803922, 0.4235294117647059, 0.11764705882352941, 0.30196078431372547, 0.984313725490196, 0.14901960784313725, 0.27058823529411763, 0.5529411764705883, 0.8509803921568627, 0.1803921568627451, 0.0196078431372549, 0.5647058823529412, 0.36470588235294116, 0.11372549019607843, 0.5137254901960784, 0.00392156862745098, 0.8784313725490196, 0.4745098039215686, 0.2196078431372549, 1.0, 0.03137254901960784, 0.7137254901960784, 0.611764705882353, 0.14901960784313725, 0.6666666666666666, 0.9019607843137255, 0.5843137254901961, 0.3215686274509804, 0.1607843137254902, 0.011764705882352941, 0.6549019607843137, 0.9176470588235294, 0.24705882352941178, 0.9882352941176471, 0.18823529411764706, 0.8627450980392157, 0.10588235294117647, 0.32941176470588235, 0.7764705882352941, 0.6196078431372549, 0.27058823529411763, 0.8941176470588236, 0.3333333333333333, 0.2, 0.47843137254901963, 0.7254901960784313, 0.8862745098039215, 0.8313725490196079, 0.6666666666666666, 0.6, 0.1411764705882353, 0.9098039215686274, 0.6784313725490196, 0.9647058823529412, 0.7215686274509804, 0.5019607843137255, 0.803921568627451, 0.9254901960784314, 0.6313725490196078, 0.3333333333333333, 0.08235294117647059, 0.5725490196078431, 0.2, 0.8392156862745098, 0.615686274509804, 0.40784313725490196, 0.23529411764705882, 0.984313725490196, 0.09411764705882353, 0.34509803921568627, 0.7098039215686275, 0.027450980392156862, 0.996078431372549, 0.36470588235294116, 0.8705882352941177, 0.4392156862745098, 0.803921568627451, 0.7058823529411765, 0.21568627450980393, 0.5450980392156862, 0.043137254901960784, 0.6862745098039216, 0.27058823529411763, 0.6313725490196078, 0.8666666666666667, 0.5882352941176471, 0.6784313725490196, 0.8470588235294118, 0.3686274509803922, 0.6509803921568628, 0.22745098039215686, 0.8745098039215686, 0.6274509803921569, 0.6941176470588235, 0.9137254901960784, 0.1411764705882353, 0.5843137254901961, 0.8941176470588236, 0.6784313725490196, 0.42745098039215684, 0.7764705882352941, 0.6078431372549019, 0.8509803921568627, 0.6470588235294118, 0.12156862745098039, 0.34509803921568627, 0.9450980392156862, 0.00392156862745098, 0.24705882352941178, 0.9176470588235294, 0.4627450980392157, 0.20784313725490197, 0.09019607843137255, 0.7372549019607844, 0.43529411764705883, 0.9411764705882353, 0.5176470588235295, 0.7647058823529411, 0.9019607843137255, 0.5607843137254902, 0.49411764705882355, 0.2901960784313726, 0.7686274509803922, 0.5803921568627451, 0.44313725490196076, 0.9019607843137255, 0.10196078431372549, 0.0392156862745098, 0.6196078431372549, 0.7411764705882353, 0.8745098039215686, 0.596078431372549, 0.5176470588235295, 0.7568627450980392, 0.6941176470588235, 0.9568627450980393, 0.10196078431372549, 0.615686274509804, 0.7294117647058823, 0.28627450980392155, 0.6901960784313725, 0.9607843137254902, 0.7843137254901961, 0.4235294117647059, 0.807843137254902, 0.6705882352941176, 0.13333333333333333, 0.30196078431372547, 0.5254901960784314, 0.10588235294117647, 0.27450980392156865, 0.8509803921568627, 0.4666666666666667, 0.047058823529411764, 0.7725490196078432, 0.6941176470588235, 0.8862745098039215, 0.3686274509803922, 0.20392156862745098, 0.7490196078431373, 0.41568627450980394, 0.5843137254901961, 0.6823529411764706, 0.011764705882352941, 0.5490196078431373, 0.8352941176470589, 0.1568627450980392, 0.027450980392156862, 0.3803921568627451, 0.7137254901960784, 0.050980392156862744, 0.7843137254901961, 0.9764705882352941, 0.2549019607843137, 0.34901960784313724, 0.1607843137254902, 0.40784313725490196, 0.7372549019607844, 0.023529411764705882, 0.24705882352941178, 0.3411764705882353, 0.4117647058823529, 0.615686274509804, 0.058823529411764705, 0.5843137254901961, 0.12549019607843137, 0.5294117647058824, 0.8666666666666667, 0.7803921568627451, 0.9882352941176471, 0.11372549019607843, 0.34901960784313724, 0.7372549019607844, 0.8196078431372549, 0.32941176470588235, 0.00784313725490196, 0.5215686274509804, 0.42745098039215684, 0.8980392156862745, 0.5843137254901961, 0.08235294117647059, 0.5333333333333333, 0.24705882352941178, 0.16862745098039217, 0.00784313725490196, 0.5019607843137255, 0.8980392156862745, 0.33725490196078434, 0.17254901960784313, 0.9254901960784314, 0.4666666666666667, 0.9529411764705882, 0.07058823529411765, 0.41568627450980394, 0.7568627450980392, 0.1450980392156863, 0.2784313725490196, 0.8, 0.4392156862745098, 0.16470588235294117, 0.5215686274509804, 0.24705882352941178, 0.36470588235294116, 0.2823529411764706, 0.5098039215686274, 0.8313725490196079, 0.0392156862745098, 0.23529411764705882, 0.5215686274509804, 0.08627450980392157, 0.4745098039215686, 0.047058823529411764, 0.23137254901960785, 0.7607843137254902, 0.42745098039215684, 0.08627450980392157, 0.8745098039215686, 0.3176470588235294, 0.7058823529411765, 0.8235294117647058, 0.6078431372549019, 0.8627450980392157, 0.1568627450980392, 0.058823529411764705, 0.34901960784313724, 0.6392156862745098, 0.40784313725490196, 0.2549019607843137, 0.9764705882352941, 0.3686274509803922, 0.6627450980392157, 0.13333333333333333, 0.24313725490196078, 0.6980392156862745, 0.3254901960784314, 0.8196078431372549, 0.5254901960784314, 0.35294117647058826, 0.18823529411764706, 0.24705882352941178, 0.043137254901960784, 0.8980392156862745, 0.4117647058823529, 0.2196078431372549, 0.4588235294117647, 0.3215686274509804, 0.9215686274509803, 0.48627450980392156, 0.23137254901960785, 0.1568627450980392, 0.6313725490196078, 0.07450980392156863, 0.33725490196078434, 0.9529411764705882, 0.592156862745098, 0.7568627450980392, 0.36470588235294116, 0.9372549019607843, 0.7843137254901961, 0.4117647058823529, 0.5490196078431373, 0.19607843137254902, 0.2823529411764706, 0.5058823529411764, 0.10588235294117647, 0.6, 0.8274509803921568, 0.5372549019607843, 0.054901960784313725, 0.3215686274509804, 0.7725490196078432, 0.3843137254901961, 0.6431372549019608, 0.5019607843137255, 0.24705882352941178, 0.996078431372549, 0.4627450980392157, 0.8156862745098039, 0.5254901960784314, 0.4117647058823529, 0.12941176470588237, 0.5450980392156862, 0.6313725490196078, 0.050980392156862744, 0.9607843137254902, 0.49019607843137253, 0.8588235294117647, 0.5647058823529412, 0.07450980392156863, 0.8784313725490196, 0.17647058823529413, 0.3176470588235294, 0.8470588235294118, 0.45098039215686275, 0.2627450980392157, 0.16862745098039217, 0.6509803921568628, 0.42745098039215684, 0.5215686274509804, 0.8862745098039215, 0.043137254901960784, 0.1568627450980392, 0.47058823529411764, 0.6784313725490196, 0.796078431372549, 0.25882352941176473, 0.6392156862745098, 0.20784313725490197, 0.8274509803921568, 0.7764705882352941, 0.6274509803921569, 0.9686274509803922, 0.2980392156862745, 0.5843137254901961, 0.7490196078431373, 0.4117647058823529, 0.8, 0.5764705882352941, 0.2196078431372549, 0.7372549019607844, 0.5137254901960784, 0.30196078431372547, 0.9725490196078431, 0.09411764705882353, 0.49411764705882355, 0.7254901960784313, 0.5882352941176471, 0.03137254901960784, 0.7607843137254902, 0.9568627450980393, 0.0784313725490196, 0.6627450980392157, 0.7529411764705882, 0.396078431372549, 0.3254901960784314, 1.0, 0.7294117647058823, 0.3568627450980392, 0.807843137254902, 0.9333333333333333, 0.5725490196078431, 0.6705882352941176, 0.1607843137254902, 0.5137254901960784, 0.6352941176470588, 0.5607843137254902, 0.050980392156862744, 0.3568627450980392, 0.27058823529411763, 0.48627450980392156, 0.6823529411764706, 0.9921568627450981, 0.8156862745098039, 0.11764705882352941, 0.1843137254901961, 0.7137254901960784, 0.01568627450980392, 0.09803921568627451, 0.8509803921568627, 0.5294117647058824, 1.0, 0.4, 0.7529411764705882, 0.1607843137254902, 0.4588235294117647, 0.9450980392156862, 0.6901960784313725, 0.8, 0.4745098039215686, 0.3137254901960784, 0.07450980392156863, 0.6392156862745098, 0.7803921568627451, 0.1411764705882353, 0.8705882352941177, 0.050980392156862744, 0.7647058823529411, 0.3803921568627451, 0.9176470588235294, 0.5411764705882353, 0.24313725490196078, 0.1803921568627451, 0.0392156862745098, 0.6431372549019608, 0.49411764705882355, 0.22745098039215686, 0.6901960784313725, 0.011764705882352941, 0.34509803921568627, 0.9647058823529412, 0.8117647058823529, 0.396078431372549, 0.9411764705882353, 0.4588235294117647, 0.27058823529411763, 0.8745098039215686, 0.13333333333333333, 0.48627450980392156, 0.9568627450980393, 0.27450980392156865, 0.20392156862745098, 0.9254901960784314, 0.7333333333333333, 0.6705882352941176, 0.11372549019607843, 0.2196078431372549, 0.5882352941176471, 0.2980392156862745, 0.8627450980392157, 0.7490196078431373, 0.4470588235294118, 0.8117647058823529, 0.28627450980392155, 0.21176470588235294, 0.3764705882352941, 0.8, 0.6431372549019608, 0.4666666666666667, 0.7607843137254902, 0.9490196078431372, 0.21568627450980393, 0.7372549019607844, 0.9647058823529412, 0.3764705882352941, 0.0, 0.3058823529411765, 0.2196078431372549, 0.6666666666666666, 0.27450980392156865, 0.9647058823529412, 0.592156862745098, 0.8705882352941177, 0.1803921568627451, 0.9607843137254902, 0.12156862745098039, 0.48627450980392156, 0.3176470588235294, 0.40784313725490196, 0.13725490196078433, 0.4666666666666667, 0.6705882352941176, 0.8352941176470589, 0.09803921568627451, 0.2627450980392157, 0.6549019607843137, 0.10196078431372549, 0.3803921568627451, 0.0, 0.8117647058823529, 0.1843137254901961, 0.5607843137254902, 0.396078431372549, 0.9019607843137255, 0.06274509803921569, 0.9921568627450981, 0.3254901960784314, 0.39215686274509803, 0.8196078431372549, 0.4549019607843137, 0.20392156862745098, 0.9411764705882353, 0.09411764705882353, 0.1607843137254902, 0.592156862745098, 0.8784313725490196, 0.2, 0.27058823529411763, 0.40784313725490196, 0.8705882352941177, 0.3058823529411765, 0.9882352941176471, 0.8313725490196079, 0.3803921568627451, 0.7764705882352941, 0.1803921568627451, 0.9764705882352941, 0.5450980392156862, 0.00784313725490196, 0.23529411764705882, 0.5686274509803921, 0.30196078431372547, 0.4549019607843137, 0.8705882352941177, 0.596078431372549, 0.796078431372549, 0.47058823529411764, 0.19607843137254902, 0.3058823529411765, 0.792156862745098, 0.00784313725490196, 0.5882352941176471, 0.9137254901960784, 0.2784313725490196, 0.054901960784313725, 0.10588235294117647, 0.6352941176470588, 0.3803921568627451, 0.8431372549019608, 0.5803921568627451, 0.9411764705882353, 0.3607843137254902, 0.5294117647058824, 0.6549019607843137, 0.41568627450980394, 0.5725490196078431, 0.8352941176470589, 0.2980392156862745, 0.4666666666666667, 0.7333333333333333, 0.8509803921568627, 0.40784313725490196, 0.9176470588235294, 0.8235294117647058, 0.1607843137254902, 0.5764705882352941, 0.8784313725490196, 0.6352941176470588, 0.06666666666666667, 0.7176470588235294, 0.1450980392156863, 0.6627450980392157, 0.03529411764705882, 0.34509803921568627, 0.6352941176470588, 0.7215686274509804, 0.8980392156862745, 0.08235294117647059, 0.807843137254902, 0.4470588235294118, 0.0392156862745098, 0.5647058823529412, 0.34509803921568627, 0.8509803921568627, 0.9176470588235294, 0.6470588235294118, 0.01568627450980392, 0.6862745098039216, 0.19215686274509805, 0.08627450980392157, 0.9294117647058824, 0.5882352941176471, 0.6980392156862745, 0.10588235294117647, 0.9921568627450981, 0.14901960784313725, 0.27450980392156865, 0.011764705882352941, 0.6588235294117647, 0.41568627450980394, 0.050980392156862744, 0.6941176470588235, 0.5764705882352941, 0.8156862745098039, 0.7529411764705882, 0.9333333333333333, 0.48627450980392156, 0.3803921568627451, 0.7725490196078432, 0.10980392156862745, 0.2980392156862745, 0.5568627450980392, 0.37254901960784315, 0.7607843137254902, 0.6901960784313725, 0.9411764705882353, 0.023529411764705882, 0.7254901960784313, 0.8901960784313725, 0.20392156862745098, 0.3686274509803922, 0.0392156862745098, 0.9803921568627451, 0.48627450980392156, 0.8549019607843137, 0.6980392156862745, 0.9058823529411765, 0.3411764705882353, 0.6627450980392157, 0.8627450980392157, 0.611764705882353, 0.25098039215686274, 0.6901960784313725, 0.19607843137254902, 0.5411764705882353, 0.8901960784313725, 0.611764705882353, 0.01568627450980392, 0.5568627450980392, 0.8549019607843137, 0.6392156862745098, 0.48627450980392156, 0.6901960784313725, 0.01568627450980392, 0.5529411764705883, 0.13333333333333333, 0.7098039215686275, 0.4980392156862745, 0.09411764705882353, 0.21176470588235294, 0.7294117647058823, 0.2627450980392157, 0.12941176470588237, 0.8823529411764706, 0.42745098039215684, 0.7450980392156863, 0.9176470588235294, 0.3843137254901961, 0.7843137254901961, 0.0784313725490196, 0.6627450980392157, 0.9215686274509803, 0.3254901960784314, 0.38823529411764707, 0.9529411764705882, 0.7333333333333333, 0.611764705882353, 0.08627450980392157, 0.49019607843137253, 0.2196078431372549, 0.6549019607843137, 0.8470588235294118, 0.41568627450980394, 0.5490196078431373, 0.9921568627450981, 0.1411764705882353, 0.7294117647058823, 0.19215686274509805, 0.0196078431372549, 0.8235294117647058, 0.24313725490196078, 0.08627450980392157, 0.984313725490196, 0.19607843137254902, 0.023529411764705882, 0.6784313725490196, 0.10196078431372549, 0.8901960784313725, 0.5647058823529412, 0.27450980392156865, 0.07058823529411765, 0.3215686274509804, 0.44313725490196076, 0.9882352941176471, 0.09411764705882353, 0.49019607843137253, 0.2549019607843137, 0.5686274509803921, 0.8627450980392157, 0.22745098039215686, 0.7568627450980392, 0.9921568627450981, 0.1803921568627451, 0.4235294117647059, 0.22745098039215686, 0.6705882352941176, 0.596078431372549, 0.13725490196078433, 0.8705882352941177, 0.403921568627451, 0.2823529411764706, 0.06666666666666667, 0.43529411764705883, 0.16470588235294117, 0.9607843137254902, 0.37254901960784315, 0.8941176470588236, 0.3215686274509804, 0.5137254901960784, 0.00392156862745098, 0.43529411764705883, 0.7686274509803922, 0.30980392156862746, 0.4980392156862745, 0.9215686274509803, 0.5529411764705883, 0.8274509803921568, 0.34901960784313724, 0.5098039215686274, 0.10588235294117647, 0.9019607843137255, 0.4588235294117647, 0.14901960784313725, 0.07450980392156863, 0.5647058823529412, 0.19607843137254902, 0.8941176470588236, 0.4196078431372549, 0.7254901960784313, 0.03137254901960784, 0.4549019607843137, 0.07058823529411765, 0.27058823529411763, 0.8470588235294118, 0.5725490196078431, 0.35294117647058826, 0.06666666666666667, 0.5490196078431373, 0.9294117647058824, 0.43529411764705883, 0.6, 0.1568627450980392, 0.2980392156862745, 0.5411764705882353, 0.12549019607843137, 0.24705882352941178, 0.4588235294117647, 0.0392156862745098, 0.1568627450980392, 0.35294117647058826, 0.8352941176470589, 0.4745098039215686, 0.10980392156862745, 0.27058823529411763, 0.15294117647058825, 0.7333333333333333, 0.3176470588235294, 0.41568627450980394, 0.24313725490196078, 0.7843137254901961, 0.37254901960784315, 0.9607843137254902, 0.45098039215686275, 0.6235294117647059, 0.7686274509803922, 0.03137254901960784, 0.3607843137254902, 0.6039215686274509, 0.45098039215686275, 0.0196078431372549, 0.49411764705882355, 0.6666666666666666, 0.30980392156862746, 0.10588235294117647, 0.6235294117647059, 0.16862745098039217, 0.7176470588235294, 0.5098039215686274, 0.21176470588235294, 0.050980392156862744, 0.5254901960784314, 0.1450980392156863, 0.23921568627450981, 0.6509803921568628, 0.43137254901960786, 0.9411764705882353, 0.8705882352941177, 0.37254901960784315, 0.12549019607843137, 0.7176470588235294, 0.3137254901960784, 0.7686274509803922, 0.23529411764705882, 0.8823529411764706, 0.5137254901960784, 0.2823529411764706, 0.6784313725490196, 0.45098039215686275, 0.7568627450980392, 0.3058823529411765, 0.7098039215686275, 0.4980392156862745, 0.6039215686274509, 0.9647058823529412, 0.14901960784313725, 0.36470588235294116, 0.6980392156862745, 0.4823529411764706, 0.788235294117647, 0.12156862745098039, 0.7254901960784313, 0.20784313725490197, 0.3803921568627451, 0.7647058823529411, 0.9176470588235294, 0.4196078431372549, 0.3176470588235294, 0.5215686274509804, 0.0784313725490196, 0.796078431372549, 0.5647058823529412, 0.00392156862745098, 0.5215686274509804, 0.3607843137254902, 0.3058823529411765, 0.9803921568627451, 0.7607843137254902, 0.1843137254901961, 0.6313725490196078, 0.788235294117647, 0.5137254901960784, 0.7372549019607844, 0.2627450980392157, 0.47058823529411764, 0.611764705882353, 0.792156862745098, 0.24313725490196078, 0.6627450980392157, 0.8784313725490196, 0.1843137254901961, 0.611764705882353, 0.7254901960784313, 0.396078431372549, 0.23137254901960785, 0.12156862745098039, 0.6039215686274509, 0.8705882352941177, 0.19215686274509805, 0.3254901960784314, 0.25098039215686274, 0.6274509803921569, 0.8470588235294118, 0.7058823529411765, 0.011764705882352941, 0.5215686274509804, 0.23921568627450981, 0.6274509803921569, 0.8549019607843137, 0.9215686274509803, 0.6078431372549019, 0.5215686274509804, 0.1568627450980392, 0.22745098039215686, 0.6549019607843137, 0.803921568627451, 0.28627450980392155, 0.7647058823529411, 0.7058823529411765, 0.2235294117647059, 0.7843137254901961, 0.9372549019607843, 0.4235294117647059, 0.6235294117647059, 0.996078431372549, 0.7764705882352941, 0.7098039215686275, 0.9411764705882353, 0.43529411764705883, 0.7647058823529411, 0.6509803921568628, 0.9333333333333333, 0.792156862745098, 0.6823529411764706, 0.5019607843137255, 0.984313725490196, 0.12549019607843137, 0.9098039215686274, 0.28627450980392155, 0.06666666666666667, 0.8117647058823529, 0.3176470588235294, 0.9058823529411765, 0.1843137254901961, 0.9686274509803922, 0.803921568627451, 0.6588235294117647, 0.9254901960784314, 0.8549019607843137, 0.5764705882352941, 0.9372549019607843, 0.21568627450980393, 0.803921568627451, 0.47058823529411764, 0.8901960784313725, 0.33725490196078434, 0.9607843137254902, 0.8313725490196079, 0.2823529411764706, 0.7058823529411765, 0.5764705882352941, 0.8666666666666667, 0.043137254901960784, 0.34509803921568627, 0.16862745098039217, 0.2627450980392157, 0.5607843137254902, 0.9764705882352941, 0.5058823529411764, 0.1803921568627451, 0.6, 0.00392156862745098, 0.34901960784313724, 0.43137254901960786, 0.9686274509803922, 0.12156862745098039, 0.5647058823529412, 0.9254901960784314, 0.15294117647058825, 0.8784313725490196, 0.34509803921568627, 0.2549019607843137, 0.7803921568627451, 0.43137254901960786, 0.2196078431372549, 0.00784313725490196, 0.9803921568627451, 0.5882352941176471, 0.6705882352941176, 0.5215686274509804, 0.8470588235294118, 0.28627450980392155, 0.6588235294117647, 0.11764705882352941, 0.1843137254901961, 0.9568627450980393, 0.615686274509804, 0.4745098039215686, 0.37254901960784315, 0.29411764705882354, 0.8509803921568627, 0.9450980392156862, 0.7450980392156863, 0.4666666666666667, 0.058823529411764705, 0.6941176470588235, 0.49019607843137253, 0.9411764705882353, 0.12549019607843137, 0.5764705882352941, 0.33725490196078434, 0.8313725490196079, 0.09803921568627451, 0.5529411764705883, 0.14901960784313725, 0.40784313725490196, 0.9529411764705882, 0.35294117647058826, 0.5333333333333333, 0.07450980392156863, 0.8941176470588236, 0.0392156862745098, 0.6862745098039216, 0.9686274509803922, 0.28627450980392155, 0.7647058823529411, 0.6705882352941176, 0.9882352941176471, 0.5294117647058824, 0.4, 0.9529411764705882, 0.3176470588235294, 0.6627450980392157, 0.8117647058823529, 1.0, 0.35294117647058826, 0.09411764705882353, 0.21568627450980393, 0.8, 0.38823529411764707, 0.9882352941176471, 0.44313725490196076, 0.9058823529411765, 0.49411764705882355, 0.17254901960784313, 0.10980392156862745, 0.5176470588235295, 0.8784313725490196, 0.023529411764705882, 0.3607843137254902, 0.7333333333333333, 0.054901960784313725, 0.2, 0.5098039215686274, 0.2901960784313726, 0.11372549019607843, 0.5490196078431373, 0.0196078431372549, 0.30196078431372547, 0.40784313725490196, 0.06274509803921569, 0.35294117647058826, 0.23137254901960785, 0.8274509803921568, 0.043137254901960784, 0.5803921568627451, 0.7058823529411765, 0.5294117647058824, 0.21568627450980393, 0.10980392156862745, 0.6745098039215687, 0.3843137254901961, 0.2549019607843137, 0.5372549019607843, 0.1411764705882353, 0.3254901960784314, 0.0784313725490196, 0.396078431372549, 0.7176470588235294, 0.058823529411764705, 0.5333333333333333, 0.27450980392156865, 0.027450980392156862, 0.4117647058823529, 0.12156862745098039, 0.615686274509804, 0.44313725490196076, 0.984313725490196, 0.7490196078431373, 0.10980392156862745, 0.4980392156862745, 0.8274509803921568, 0.7686274509803922, 0.7019607843137254, 0.07450980392156863, 0.796078431372549, 0.043137254901960784, 0.9294117647058824, 0.4588235294117647, 0.8156862745098039, 0.7019607843137254, 0.08627450980392157, 0.6274509803921569, 0.8549019607843137, 0.3803921568627451, 0.050980392156862744, 0.615686274509804, 0.4117647058823529, 0.807843137254902, 0.06274509803921569, 0.9098039215686274, 0.5176470588235295, 0.6274509803921569, 0.8274509803921568, 0.17647058823529413, 0.34901960784313724, 0.24313725490196078, 0.9490196078431372, 0.027450980392156862, 0.596078431372549, 0.807843137254902, 0.4627450980392157, 0.01568627450980392, 0.6862745098039216, 0.8274509803921568, 0.13333333333333333, 0.9137254901960784, 0.7098039215686275, 0.6274509803921569, 0.10980392156862745, 0.16470588235294117, 0.8313725490196079, 0.2196078431372549, 0.5490196078431373, 0.3686274509803922, 0.00784313725490196, 0.8745098039215686, 0.23529411764705882, 0.984313725490196, 0.03137254901960784, 0.7019607843137254, 0.9333333333333333, 0.7490196078431373, 0.050980392156862744, 0.8313725490196079, 0.11764705882352941, 0.2627450980392157, 0.8196078431372549, 0.44313725490196076, 0.3333333333333333, 0.8, 0.16470588235294117, 0.4745098039215686, 0.4235294117647059, 0.01568627450980392, 0.8, 0.06666666666666667, 0.7333333333333333, 0.12156862745098039, 0.4470588235294118, 0.596078431372549, 0.0784313725490196, 0.1607843137254902, 0.7019607843137254, 0.5019607843137255, 0.30196078431372547, 0.6705882352941176, 0.03529411764705882, 0.7450980392156863, 0.30980392156862746, 0.08627450980392157, 0.6196078431372549, 0.9490196078431372, 0.403921568627451, 0.32941176470588235, 0.6431372549019608, 0.09803921568627451, 0.47843137254901963, 0.27058823529411763, 0.8470588235294118, 0.592156862745098, 0.3843137254901961, 0.8784313725490196, 0.6392156862745098, 0.22745098039215686, 0.9764705882352941, 0.596078431372549, 0.1803921568627451, 0.8823529411764706, 0.5686274509803921, 0.43529411764705883, 0.6274509803921569, 0.7568627450980392, 0.47058823529411764, 0.16470588235294117, 0.8862745098039215, 0.41568627450980394, 0.8392156862745098, 0.5803921568627451, 0.48627450980392156, 0.07058823529411765, 0.8784313725490196, 0.4196078431372549, 0.7568627450980392, 0.2823529411764706, 0.17647058823529413, 0.6313725490196078, 0.3568627450980392, 1.0, 0.8470588235294118, 0.6901960784313725, 0.5568627450980392, 0.7725490196078432, 0.17647058823529413, 0.36470588235294116, 0.00392156862745098, 0.25882352941176473, 0.403921568627451, 0.9254901960784314, 0.5450980392156862, 0.30196078431372547, 0.4666666666666667, 0.615686274509804, 0.40784313725490196, 0.3333333333333333, 0.6509803921568628, 0.27058823529411763, 0.9019607843137255, 0.5372549019607843, 0.21176470588235294, 0.7843137254901961, 0.3254901960784314, 0.4980392156862745, 0.7333333333333333, 0.2235294117647059, 0.47058823529411764, 0.11372549019607843, 0.5607843137254902, 0.6627450980392157, 0.30980392156862746, 0.7607843137254902, 0.9215686274509803, 0.403921568627451, 0.058823529411764705, 0.8745098039215686, 0.4235294117647059, 0.1450980392156863, 0.3333333333333333, 0.8941176470588236, 0.5372549019607843, 0.36470588235294116, 0.7529411764705882, 0.2784313725490196, 0.21176470588235294, 0.44313725490196076, 0.06274509803921569, 0.25098039215686274, 0.4117647058823529, 0.5803921568627451, 0.9058823529411765, 0.6549019607843137, 0.2627450980392157, 0.8196078431372549, 0.30980392156862746, 0.7137254901960784, 0.4196078431372549, 0.6431372549019608, 0.19607843137254902, 0.38823529411764707, 0.30980392156862746, 0.2235294117647059, 0.49411764705882355, 0.6313725490196078, 0.7137254901960784, 0.5803921568627451, 0.9764705882352941, 0.2, 0.6352941176470588, 0.5176470588235295, 0.9137254901960784, 0.09019607843137255, 0.5686274509803921, 0.2235294117647059, 0.35294117647058826, 0.6078431372549019, 0.1803921568627451, 0.28627450980392155, 0.9098039215686274, 0.3686274509803922, 0.48627450980392156, 0.27450980392156865, 0.7764705882352941, 0.4235294117647059, 0.9568627450980393, 0.5490196078431373, 0.13725490196078433, 0.8705882352941177, 0.6941176470588235, 0.3686274509803922, 0.00392156862745098, 0.8470588235294118, 0.6823529411764706, 0.25098039215686274, 0.9882352941176471, 0.5803921568627451, 0.8196078431372549, 0.6745098039215687, 0.16470588235294117, 0.9176470588235294, 0.08627450980392157, 0.803921568627451, 0.33725490196078434, 0.7411764705882353, 0.1411764705882353, 0.8431372549019608, 0.5137254901960784, 0.7019607843137254, 0.9686274509803922, 0.09019607843137255, 0.19607843137254902, 0.3803921568627451, 0.9529411764705882, 0.3333333333333333, 0.611764705882353, 0.7490196078431373, 0.00392156862745098, 0.2823529411764706, 0.9372549019607843, 0.7372549019607844, 0.596078431372549, 0.20392156862745098, 0.5137254901960784, 0.8274509803921568, 0.9529411764705882, 0.7843137254901961, 0.42745098039215684, 0.1450980392156863, 0.6, 0.09019607843137255, 0.23137254901960785, 0.9450980392156862, 0.49019607843137253, 0.6823529411764706, 0.8862745098039215, 0.792156862745098, 0.1568627450980392, 0.6666666666666666, 0.20784313725490197, 0.01568627450980392, 0.9019607843137255, 0.1411764705882353, 0.8549019607843137, 0.21176470588235294, 0.7490196078431373, 0.10588235294117647, 0.15294117647058825, 0.39215686274509803, 0.9529411764705882, 0.03137254901960784, 0.2627450980392157, 0.17254901960784313, 1.0, 0.8352941176470589, 0.6941176470588235, 0.9568627450980393, 0.18823529411764706, 0.38823529411764707, 0.03529411764705882, 0.12156862745098039, 0.26666666666666666, 0.5372549019607843, 0.7372549019607844, 0.6431372549019608, 0.7803921568627451, 0.49019607843137253, 0.19607843137254902, 0.7058823529411765, 0.24705882352941178, 0.996078431372549, 0.08627450980392157, 0.5843137254901961, 0.8901960784313725, 0.6666666666666666, 0.9725490196078431, 0.5098039215686274, 0.7803921568627451, 0.3411764705882353, 0.03137254901960784, 0.43137254901960786, 0.10196078431372549, 0.9568627450980393, 0.611764705882353, 0.16470588235294117, 0.47843137254901963, 0.807843137254902, 0.5215686274509804, 0.8823529411764706, 0.6666666666666666, 0.4392156862745098, 1.0, 0.17254901960784313, 0.2980392156862745, 0.38823529411764707, 0.47843137254901963, 0.058823529411764705, 0.7725490196078432, 0.25098039215686274, 0.37254901960784315, 0.7254901960784313, 0.6431372549019608, 0.9372549019607843, 0.8862745098039215, 0.49411764705882355, 0.8352941176470589, 0.5529411764705883, 0.7607843137254902, 0.03529411764705882, 0.8588235294117647, 0.9254901960784314, 0.5764705882352941, 0.11764705882352941, 0.8431372549019608, 0.19607843137254902, 0.47058823529411764, 0.25882352941176473, 0.5803921568627451, 0.7725490196078432, 0.20784313725490197, 0.4627450980392157, 0.5607843137254902, 0.1411764705882353, 0.7568627450980392, 0.19607843137254902, 0.3803921568627451, 0.9647058823529412, 0.3176470588235294, 0.49411764705882355, 0.4392156862745098, 0.03137254901960784, 0.5333333333333333, 0.9098039215686274, 0.47058823529411764, 0.3803921568627451, 0.24705882352941178, 0.00392156862745098, 0.7686274509803922, 0.2901960784313726, 0.8549019607843137, 0.6745098039215687, 0.03137254901960784, 0.25882352941176473, 0.12941176470588237, 0.9921568627450981, 0.4549019607843137, 0.6941176470588235, 0.1607843137254902, 0.3411764705882353, 0.0392156862745098, 0.9882352941176471, 0.6823529411764706, 0.09803921568627451, 0.5607843137254902, 0.0, 0.25098039215686274, 0.5058823529411764, 0.7607843137254902, 0.32941176470588235, 0.8666666666666667, 0.6392156862745098, 0.2901960784313726, 0.08235294117647059, 0.5843137254901961, 0.4549019607843137, 0.32941176470588235, 0.6235294117647059, 0.9921568627450981, 0.3803921568627451, 0.6823529411764706, 0.25098039215686274, 0.9450980392156862, 0.44313725490196076, 0.5725490196078431, 0.8392156862745098, 0.49019607843137253, 0.6705882352941176, 0.592156862745098, 0.7450980392156863, 0.8941176470588236, 0.5529411764705883, 0.6509803921568628, 0.011764705882352941, 0.28627450980392155, 0.33725490196078434, 0.8901960784313725, 0.7176470588235294, 0.4823529411764706, 0.9647058823529412, 0.6039215686274509, 0.45098039215686275, 0.08627450980392157, 0.2901960784313726, 0.5607843137254902, 0.10196078431372549, 0.9215686274509803, 0.6196078431372549, 0.054901960784313725, 0.8431372549019608, 0.4117647058823529, 0.4980392156862745, 0.33725490196078434, 0.0196078431372549, 0.7411764705882353, 0.1843137254901961, 0.8705882352941177, 0.2901960784313726, 1.0, 0.7294117647058823, 0.5058823529411764, 0.7725490196078432, 0.39215686274509803, 0.054901960784313725, 0.9215686274509803, 0.11372549019607843, 0.5882352941176471, 0.047058823529411764, 0.7764705882352941, 0.10196078431372549, 0.5647058823529412, 0.8627450980392157, 0.023529411764705882, 0.9176470588235294, 0.7450980392156863, 0.1411764705882353, 0.8745098039215686, 0.5568627450980392, 0.023529411764705882, 0.8392156862745098, 0.3058823529411765, 0.1450980392156863, 0.40784313725490196, 0.6784313725490196, 0.050980392156862744, 0.9803921568627451, 0.23137254901960785, 0.6745098039215687, 0.40784313725490196, 0.18823529411764706, 0.6431372549019608, 0.054901960784313725, 0.7294117647058823, 0.3411764705882353, 0.6313725490196078, 0.9372549019607843, 0.10588235294117647, 0.5254901960784314, 0.9725490196078431, 0.2823529411764706, 0.807843137254902, 0.06274509803921569, 0.8941176470588236, 0.45098039215686275, 0.011764705882352941, 0.5490196078431373, 0.11764705882352941, 0.7607843137254902, 0.6980392156862745, 0.2627450980392157, 0.1843137254901961, 0.6745098039215687, 0.054901960784313725, 0.615686274509804, 0.8117647058823529, 0.33725490196078434, 0.4588235294117647, 0.14901960784313725, 0.5098039215686274, 0.9294117647058824, 0.5490196078431373, 0.788235294117647, 0.6470588235294118, 0.36470588235294116, 0.5215686274509804, 0.22745098039215686, 0.796078431372549, 0.8509803921568627, 0.6431372549019608, 0.45098039215686275, 0.23921568627450981, 0.34901960784313724, 0.47058823529411764, 0.8901960784313725, 0.7019607843137254, 0.9215686274509803, 0.19215686274509805, 0.4588235294117647, 0.39215686274509803, 0.047058823529411764, 0.807843137254902, 0.9294117647058824, 0.2196078431372549, 0.5254901960784314, 0.8392156862745098, 0.058823529411764705, 0.7372549019607844, 0.09411764705882353, 0.5686274509803921, 0.5058823529411764, 0.7254901960784313, 0.023529411764705882, 0.36470588235294116, 0.9882352941176471, 0.2901960784313726, 0.058823529411764705, 0.33725490196078434, 0.4627450980392157, 0.12941176470588237, 0.4235294117647059, 0.3607843137254902, 0.10196078431372549, 0.5254901960784314, 0.6039215686274509, 0.7803921568627451, 0.23921568627450981, 0.8352941176470589, 0.16470588235294117, 0.6862745098039216, 0.8941176470588236, 0.20784313725490197, 0.9882352941176471, 0.8274509803921568, 0.38823529411764707, 0.7490196078431373, 0.4470588235294118, 0.3137254901960784, 0.17254901960784313, 0.6509803921568628, 0.792156862745098, 0.15294117647058825, 0.5411764705882353, 0.3803921568627451, 0.6078431372549019, 0.07450980392156863, 0.47843137254901963, 0.6392156862745098, 0.1450980392156863, 0.20392156862745098, 0.8588235294117647, 0.5529411764705883, 0.23921568627450981, 0.7529411764705882, 0.28627450980392155, 0.3568627450980392, 0.1607843137254902, 0.9137254901960784, 0.25882352941176473, 0.3411764705882353, 0.792156862745098, 0.6862745098039216, 0.23137254901960785, 0.6078431372549019, 0.3215686274509804, 0.6666666666666666, 0.4196078431372549, 0.996078431372549, 0.20392156862745098, 0.4666666666666667, 0.7803921568627451, 0.09803921568627451, 0.25882352941176473, 0.3333333333333333, 0.4392156862745098, 0.14901960784313725, 0.7372549019607844, 0.5372549019607843, 0.32941176470588235, 0.9803921568627451, 0.24705882352941178, 0.39215686274509803, 0.8980392156862745, 0.0196078431372549, 0.803921568627451, 0.4, 0.16862745098039217, 0.8784313725490196, 0.33725490196078434, 0.7137254901960784, 0.40784313725490196, 0.5215686274509804, 0.2627450980392157, 0.7294117647058823, 0.6392156862745098, 0.23529411764705882, 0.9333333333333333, 0.611764705882353, 0.8313725490196079, 0.9882352941176471, 0.41568627450980394, 0.2980392156862745, 0.11764705882352941, 0.9607843137254902, 0.6470588235294118, 0.9098039215686274, 0.5882352941176471, 0.7254901960784313, 0.10196078431372549, 0.4117647058823529, 0.20784313725490197, 0.9058823529411765, 0.06666666666666667, 0.8666666666666667, 0.10588235294117647, 0.403921568627451, 0.5450980392156862, 0.30196078431372547, 0.13333333333333333, 0.9058823529411765, 0.7294117647058823, 0.6078431372549019, 0.12549019607843137, 0.3137254901960784, 0.6509803921568628, 0.03137254901960784, 0.9764705882352941, 0.7294117647058823, 0.5372549019607843, 0.16470588235294117, 0.35294117647058826, 0.7098039215686275, 0.12941176470588237, 0.9568627450980393, 0.2823529411764706, 0.4823529411764706, 0.8745098039215686, 0.807843137254902, 0.1803921568627451, 0.3137254901960784, 0.6274509803921569, 0.0784313725490196, 0.6941176470588235, 0.19215686274509805, 0.796078431372549, 0.8705882352941177, 0.22745098039215686, 0.6980392156862745, 0.8117647058823529, 0.9137254901960784, 0.7647058823529411, 0.45098039215686275, 0.1450980392156863, 0.06274509803921569, 0.4196078431372549, 0.5686274509803921, 0.3137254901960784, 0.37254901960784315, 0.8, 0.03137254901960784, 0.35294117647058826, 0.6745098039215687, 0.23921568627450981, 0.0, 0.9647058823529412, 0.5568627450980392, 0.7254901960784313, 0.8745098039215686, 0.10196078431372549, 0.9529411764705882, 0.2980392156862745, 0.8392156862745098, 0.9372549019607843, 0.23137254901960785, 0.6980392156862745, 0.8156862745098039, 0.3607843137254902, 0.596078431372549, 0.07450980392156863, 0.3215686274509804, 0.6941176470588235, 0.9764705882352941, 0.6549019607843137, 0.8313725490196079, 0.4627450980392157, 0.7137254901960784, 0.611764705882353, 0.4117647058823529, 0.5176470588235295, 0.07450980392156863, 0.45098039215686275, 0.5372549019607843, 0.9490196078431372, 0.09803921568627451, 0.2823529411764706, 0.6941176470588235, 0.06666666666666667, 0.6, 0.5333333333333333, 0.9647058823529412, 0.7098039215686275, 0.807843137254902, 0.6313725490196078, 0.8901960784313725, 0.2901960784313726, 0.796078431372549, 0.0, 0.4627450980392157, 0.8313725490196079, 0.5176470588235295, 0.596078431372549, 0.7019607843137254, 0.2980392156862745, 0.4549019607843137, 0.7411764705882353, 0.6470588235294118, 0.03529411764705882, 0.12549019607843137, 0.6078431372549019, 0.9254901960784314, 0.34901960784313724, 0.8627450980392157, 0.796078431372549, 0.054901960784313725, 0.4, 0.1450980392156863, 0.3568627450980392, 0.5607843137254902, 0.7764705882352941, 0.8666666666666667, 0.7294117647058823, 0.5294117647058824, 0.20392156862745098, 0.06666666666666667, 0.3764705882352941, 0.23921568627450981, 0.3176470588235294, 0.8352941176470589, 0.49019607843137253, 0.7098039215686275, 0.3058823529411765, 0.5725490196078431, 0.9647058823529412, 0.6274509803921569, 0.0196078431372549, 0.9333333333333333, 0.7803921568627451, 0.38823529411764707, 0.050980392156862744, 0.8588235294117647, 0.21568627450980393, 0.40784313725490196, 0.8352941176470589, 0.5647058823529412, 0.27058823529411763, 0.12156862745098039, 0.8980392156862745, 0.611764705882353, 0.43137254901960786, 0.7647058823529411, 0.0196078431372549, 0.39215686274509803, 0.596078431372549, 0.2235294117647059, 0.43137254901960786, 0.34509803921568627, 0.10980392156862745, 0.9647058823529412, 0.8196078431372549, 0.5333333333333333, 0.9137254901960784, 0.41568627450980394, 0.611764705882353, 0.5254901960784314, 0.9450980392156862, 0.08627450980392157, 0.2980392156862745, 0.5843137254901961, 0.1803921568627451, 0.9333333333333333, 0.8549019607843137, 0.6431372549019608, 0.9921568627450981, 0.00392156862745098, 0.7450980392156863, 0.10980392156862745, 0.5176470588235295, 0.6235294117647059, 0.4745098039215686, 0.15294117647058825, 0.8862745098039215, 0.5137254901960784, 0.12941176470588237, 0.3686274509803922, 0.25882352941176473, 0.47058823529411764, 0.2196078431372549, 0.615686274509804, 0.6901960784313725, 0.4470588235294118, 0.11372549019607843, 0.5647058823529412, 0.403921568627451, 0.00392156862745098, 0.27058823529411763, 0.9490196078431372, 0.4392156862745098, 0.8980392156862745, 0.48627450980392156, 0.01568627450980392, 0.4, 0.21176470588235294, 0.5372549019607843, 0.9450980392156862, 0.011764705882352941, 0.2, 0.9686274509803922, 0.13725490196078433, 0.8313725490196079, 0.36470588235294116, 0.1843137254901961, 0.8156862745098039, 0.5019607843137255, 0.9058823529411765, 0.7568627450980392, 0.3411764705882353, 0.8666666666666667, 0.1843137254901961, 0.011764705882352941, 0.5764705882352941, 0.4980392156862745, 0.10196078431372549, 0.9647058823529412, 0.6, 0.13725490196078433, 0.6823529411764706, 0.7607843137254902, 0.10196078431372549, 0.21568627450980393, 0.15294117647058825, 0.996078431372549, 0.07450980392156863, 0.23137254901960785, 0.5725490196078431, 0.49019607843137253, 0.9529411764705882, 0.21568627450980393, 0.6627450980392157, 0.09411764705882353, 0.16862745098039217, 0.4823529411764706, 0.30196078431372547, 0.8784313725490196, 0.5176470588235295, 0.00784313725490196, 0.2196078431372549, 0.09019607843137255, 0.48627450980392156, 0.16862745098039217, 0.27450980392156865, 0.4235294117647059, 0.8745098039215686, 0.796078431372549, 0.6588235294117647, 0.984313725490196, 0.01568627450980392, 0.6, 0.15294117647058825, 0.4392156862745098, 0.7529411764705882, 0.19215686274509805, 0.26666666666666666, 0.4823529411764706, 0.7019607843137254, 0.1843137254901961, 0.5176470588235295, 0.27450980392156865, 0.796078431372549, 0.5411764705882353, 0.9607843137254902, 0.07058823529411765, 0.37254901960784315, 0.8, 0.6901960784313725, 0.47843137254901963, 0.23921568627450981, 0.996078431372549, 0.30196078431372547, 0.6627450980392157, 0.8862745098039215, 0.792156862745098, 0.15294117647058825, 0.9333333333333333, 0.6509803921568628, 0.7607843137254902, 0.2627450980392157, 0.4588235294117647, 0.12941176470588237, 0.23529411764705882, 0.7686274509803922, 0.0, 0.1607843137254902, 0.38823529411764707, 0.6431372549019608, 0.03137254901960784, 0.5019607843137255, 0.22745098039215686, 0.6862745098039216, 0.27450980392156865, 0.34901960784313724, 0.4980392156862745, 0.21176470588235294, 0.8627450980392157, 0.9294117647058824, 0.25882352941176473, 0.7294117647058823, 0.9568627450980393, 0.30980392156862746, 0.592156862745098, 0.7725490196078432, 0.6431372549019608, 0.8235294117647058, 0.9294117647058824, 0.027450980392156862, 0.4, 0.7647058823529411, 0.06666666666666667, 0.2627450980392157, 0.792156862745098, 0.16862745098039217, 0.9254901960784314, 0.8745098039215686, 0.5098039215686274, 0.7176470588235294, 0.7843137254901961, 0.17647058823529413, 0.6274509803921569, 0.13725490196078433, 0.5764705882352941, 0.8941176470588236, 0.09019607843137255, 0.3176470588235294, 0.7568627450980392, 0.8549019607843137, 0.6392156862745098, 0.28627450980392155, 0.8941176470588236, 0.7647058823529411, 0.0, 0.6274509803921569, 0.38823529411764707, 0.15294117647058825, 0.23921568627450981, 0.4470588235294118, 0.6509803921568628, 0.396078431372549, 0.28627450980392155, 0.9294117647058824, 0.20392156862745098, 0.3686274509803922, 0.47843137254901963, 0.23921568627450981, 0.9019607843137255, 0.4196078431372549, 0.28627450980392155, 0.9411764705882353, 0.37254901960784315, 0.4823529411764706, 0.5529411764705883, 0.8313725490196079, 0.9137254901960784, 0.36470588235294116, 0.792156862745098, 0.43137254901960786, 0.8352941176470589, 0.30980392156862746, 0.5647058823529412, 1.0, 0.6862745098039216, 0.43137254901960786, 0.592156862745098, 0.9607843137254902, 0.7137254901960784, 0.6352941176470588, 0.3333333333333333, 0.9450980392156862, 0.6784313725490196, 0.5764705882352941, 0.7568627450980392, 0.027450980392156862, 0.4980392156862745, 0.13333333333333333, 0.45098039215686275, 0.7686274509803922, 0.27058823529411763, 0.9490196078431372, 0.050980392156862744, 0.8196078431372549, 0.6705882352941176, 0.35294117647058826, 0.8431372549019608, 0.08235294117647059, 0.5843137254901961, 0.984313725490196, 0.6588235294117647, 0.43529411764705883, 0.16862745098039217, 0.7137254901960784, 0.49411764705882355, 0.6313725490196078, 0.1568627450980392, 0.3254901960784314, 0.027450980392156862, 0.8352941176470589, 0.09019607843137255, 0.5607843137254902, 0.19215686274509805, 0.5019607843137255, 0.07058823529411765, 0.6980392156862745, 0.5254901960784314, 0.00784313725490196, 0.403921568627451, 0.5764705882352941, 0.6745098039215687, 0.8549019607843137, 0.3254901960784314, 0.49411764705882355, 0.9764705882352941, 0.2784313725490196, 0.7254901960784313, 0.8313725490196079, 0.34901960784313724, 0.9725490196078431, 0.7333333333333333, 0.4, 0.09411764705882353, 0.5529411764705883, 0.8117647058823529, 0.6705882352941176, 0.44313725490196076, 0.5843137254901961, 0.4, 0.18823529411764706, 0.047058823529411764, 0.8627450980392157, 0.4117647058823529, 0.21176470588235294, 0.06274509803921569, 0.2980392156862745, 0.6980392156862745, 0.5882352941176471, 0.984313725490196, 0.5254901960784314, 0.8901960784313725, 0.3607843137254902, 0.49411764705882355, 0.7372549019607844, 0.3254901960784314, 0.6549019607843137, 0.12941176470588237, 0.2235294117647059, 0.03529411764705882, 0.37254901960784315, 0.2784313725490196, 0.8470588235294118, 0.7686274509803922, 0.4392156862745098, 0.2627450980392157, 0.6784313725490196, 0.15294117647058825, 0.4823529411764706, 0.34901960784313724, 0.7019607843137254, 0.5803921568627451, 0.43137254901960786, 0.25882352941176473, 0.7215686274509804, 0.9686274509803922, 0.5725490196078431, 0.027450980392156862, 0.8235294117647058, 0.12549019607843137, 0.8980392156862745, 0.7294117647058823, 0.42745098039215684, 0.8392156862745098, 0.06666666666666667, 0.7019607843137254, 0.8549019607843137, 0.19215686274509805, 0.5450980392156862, 0.07058823529411765, 0.6196078431372549, 0.8588235294117647, 0.7764705882352941, 0.6705882352941176, 0.3215686274509804, 0.00784313725490196, 0.7137254901960784, 0.26666666666666666, 0.15294117647058825, 0.047058823529411764, 0.7529411764705882, 0.396078431372549, 0.06274509803921569, 0.8235294117647058, 0.20392156862745098, 0.10588235294117647, 0.27450980392156865, 0.7843137254901961, 0.44313725490196076, 0.8980392156862745, 0.043137254901960784, 0.3803921568627451, 0.10980392156862745, 0.9725490196078431, 0.34509803921568627, 0.615686274509804, 0.9176470588235294, 0.1843137254901961, 0.3607843137254902, 0.8705882352941177, 0.6509803921568628, 0.39215686274509803, 0.5098039215686274, 0.12941176470588237, 0.9019607843137255, 0.42745098039215684, 0.7686274509803922, 0.23529411764705882, 0.32941176470588235, 0.011764705882352941, 0.9058823529411765, 0.09803921568627451, 0.29411764705882354, 0.7725490196078432, 0.22745098039215686, 0.9686274509803922, 0.8666666666666667, 0.5294117647058824, 0.38823529411764707, 0.6392156862745098, 0.7843137254901961, 0.44313725490196076, 0.9490196078431372, 0.3254901960784314, 0.24313725490196078, 0.611764705882353, 0.8392156862745098, 0.09019607843137255, 0.9019607843137255, 0.16470588235294117, 0.3803921568627451, 0.7372549019607844, 0.08235294117647059, 0.6627450980392157, 0.43529411764705883, 0.8666666666666667, 0.5568627450980392, 0.47058823529411764, 0.13725490196078433, 0.796078431372549, 0.615686274509804, 0.023529411764705882, 0.9529411764705882, 0.17254901960784313, 0.2980392156862745, 0.07058823529411765, 0.1411764705882353, 0.6509803921568628, 0.8235294117647058, 0.5450980392156862, 0.10588235294117647, 0.6862745098039216, 0.4627450980392157, 0.9098039215686274, 0.16862745098039217, 0.4980392156862745, 0.11764705882352941, 0.32941176470588235, 0.17647058823529413, 0.6627450980392157, 0.01568627450980392, 0.9725490196078431, 0.6196078431372549, 0.0784313725490196, 0.4549019607843137, 0.5529411764705883, 0.8509803921568627, 0.6745098039215687, 0.9882352941176471, 0.5254901960784314, 0.7215686274509804, 0.3333333333333333, 0.047058823529411764, 0.9803921568627451, 0.807843137254902, 0.39215686274509803, 0.5529411764705883, 0.03529411764705882, 0.2235294117647059, 0.08627450980392157, 0.9333333333333333, 0.12549019607843137, 0.3333333333333333, 0.4627450980392157, 0.8784313725490196, 0.6862745098039216, 0.5176470588235295, 0.3215686274509804, 0.050980392156862744, 0.615686274509804, 0.14901960784313725, 0.5294117647058824, 0.7686274509803922, 0.027450980392156862, 0.39215686274509803, 0.6470588235294118, 0.9882352941176471, 0.33725490196078434, 0.7215686274509804, 0.023529411764705882, 0.2627450980392157, 0.12156862745098039, 0.4235294117647059, 0.18823529411764706, 0.6274509803921569, 0.9803921568627451, 0.5372549019607843, 0.6901960784313725, 0.8980392156862745, 0.5058823529411764, 0.2549019607843137, 0.6274509803921569, 0.7568627450980392, 0.9098039215686274, 0.3803921568627451, 0.1607843137254902, 0.8470588235294118, 0.2549019607843137, 0.6, 0.807843137254902, 0.4666666666666667, 0.8509803921568627, 0.23529411764705882, 0.7019607843137254, 0.2901960784313726, 0.5647058823529412, 0.7294117647058823, 0.10588235294117647, 0.5372549019607843, 0.2, 0.3254901960784314, 1.0, 0.027450980392156862, 0.6078431372549019, 0.15294117647058825, 0.8823529411764706, 0.4666666666666667, 0.7372549019607844, 0.6196078431372549, 0.35294117647058826, 0.9372549019607843, 0.4549019607843137, 0.043137254901960784, 0.5882352941176471, 0.4235294117647059, 0.7450980392156863, 0.17647058823529413, 0.9176470588235294, 0.27058823529411763, 0.13333333333333333, 0.8470588235294118, 0.3764705882352941, 0.7490196078431373, 0.9725490196078431, 0.30196078431372547, 0.21176470588235294, 0.5137254901960784, 1.0, 0.03529411764705882, 0.596078431372549, 0.8901960784313725, 0.5411764705882353, 0.11372549019607843, 0.24705882352941178, 0.2, 0.07058823529411765, 0.8901960784313725, 0.30980392156862746, 0.44313725490196076, 0.9058823529411765, 0.7607843137254902, 0.38823529411764707, 0.7176470588235294, 0.8901960784313725, 0.984313725490196, 0.3176470588235294, 0.7568627450980392, 0.3686274509803922, 0.26666666666666666, 0.792156862745098, 0.9921568627450981, 0.5607843137254902, 0.3843137254901961, 0.7450980392156863, 0.8117647058823529, 0.24313725490196078, 0.8549019607843137, 0.42745098039215684, 0.5803921568627451, 0.20392156862745098, 0.8274509803921568, 0.25098039215686274, 0.38823529411764707, 0.9607843137254902, 0.3058823529411765, 0.5882352941176471, 0.4196078431372549, 0.06666666666666667, 0.9176470588235294, 0.19607843137254902, 0.6431372549019608, 0.5019607843137255, 0.596078431372549, 0.10588235294117647, 0.8705882352941177, 0.7372549019607844, 0.984313725490196, 0.4745098039215686, 0.6627450980392157, 0.5490196078431373, 0.8, 0.20392156862745098, 0.09019607843137255, 0.2784313725490196, 0.9490196078431372, 0.7647058823529411, 0.5647058823529412, 0.9882352941176471, 0.2549019607843137, 0.6627450980392157, 0.32941176470588235, 0.5803921568627451, 0.27450980392156865, 0.12156862745098039, 0.8, 0.4980392156862745, 0.17254901960784313, 0.45098039215686275, 0.8941176470588236, 0.9686274509803922, 0.592156862745098, 0.5019607843137255, 0.8862745098039215, 0.10196078431372549, 0.3254901960784314, 0.4549019607843137, 0.20784313725490197, 0.9450980392156862, 0.13333333333333333, 0.33725490196078434, 0.5450980392156862, 0.0196078431372549, 0.48627450980392156, 0.6666666666666666, 0.08627450980392157, 0.5254901960784314, 0.2, 0.7098039215686275, 0.30196078431372547, 0.1568627450980392, 0.5333333333333333, 0.0784313725490196, 0.8156862745098039, 0.047058823529411764, 0.4745098039215686, 0.9372549019607843, 0.24313725490196078, 0.6980392156862745, 0.792156862745098, 0.5607843137254902, 0.2549019607843137, 0.7215686274509804, 0.3764705882352941, 0.5411764705882353, 0.10588235294117647, 0.8156862745098039, 0.20784313725490197, 0.5725490196078431, 0.8470588235294118, 0.6745098039215687, 0.12941176470588237, 0.8901960784313725, 0.2823529411764706, 0.07450980392156863, 0.6823529411764706, 0.34901960784313724, 0.0, 0.7176470588235294, 0.5725490196078431, 0.054901960784313725, 0.4745098039215686, 0.12549019607843137, 0.43529411764705883, 0.7098039215686275, 0.34901960784313724, 0.7803921568627451, 0.4470588235294118, 0.2901960784313726, 0.1843137254901961, 0.8117647058823529, 0.3411764705882353, 0.9529411764705882, 0.7764705882352941, 0.5843137254901961, 0.6666666666666666, 0.17254901960784313, 0.5294117647058824, 0.2549019607843137, 0.12156862745098039, 0.5764705882352941, 0.4745098039215686, 0.23529411764705882, 0.5215686274509804, 0.011764705882352941, 0.4588235294117647, 0.9411764705882353, 0.615686274509804, 0.14901960784313725, 0.3333333333333333, 0.03529411764705882, 0.8666666666666667, 0.4392156862745098, 0.6352941176470588, 0.043137254901960784, 0.7137254901960784, 0.9215686274509803, 0.12156862745098039, 0.30980392156862746, 0.7098039215686275, 0.9019607843137255, 0.043137254901960784, 0.7450980392156863, 0.17254901960784313, 0.10196078431372549, 0.8, 0.24705882352941178, 0.47058823529411764, 0.12941176470588237, 0.8784313725490196, 0.3607843137254902, 0.22745098039215686, 0.9411764705882353, 0.2980392156862745, 0.4196078431372549, 0.7843137254901961, 0.17647058823529413, 0.37254901960784315, 0.8470588235294118, 0.047058823529411764, 0.6196078431372549, 0.7372549019607844, 0.3764705882352941, 0.17254901960784313, 0.47843137254901963, 0.21568627450980393, 0.36470588235294116, 0.803921568627451, 0.10980392156862745, 0.9568627450980393, 0.8941176470588236, 0.4627450980392157, 0.7450980392156863, 0.0392156862745098, 0.9137254901960784, 0.5764705882352941, 0.6509803921568628, 0.21176470588235294, 0.3607843137254902, 0.7254901960784313, 0.06274509803921569, 0.7647058823529411, 0.3843137254901961, 0.803921568627451, 0.5882352941176471, 0.023529411764705882, 0.6509803921568628, 0.8470588235294118, 0.4470588235294118, 0.9686274509803922, 0.7019607843137254, 0.3137254901960784, 0.5803921568627451, 0.9921568627450981, 0.7450980392156863, 0.41568627450980394, 0.9372549019607843, 0.011764705882352941, 0.6549019607843137, 0.8901960784313725, 0.43529411764705883, 0.9921568627450981, 0.4, 0.6313725490196078, 0.8352941176470589, 0.0, 0.43529411764705883, 0.8745098039215686, 0.06666666666666667, 0.4745098039215686, 0.9294117647058824, 0.6627450980392157, 0.28627450980392155, 0.9725490196078431, 0.403921568627451, 0.5058823529411764, 0.03137254901960784, 0.25882352941176473, 0.396078431372549, 0.7333333333333333, 0.5098039215686274, 0.20392156862745098, 0.796078431372549, 0.5529411764705883, 0.9803921568627451, 0.48627450980392156, 0.6196078431372549, 0.8941176470588236, 0.2, 0.6705882352941176, 0.9137254901960784, 0.807843137254902, 0.5843137254901961, 0.07058823529411765, 0.6431372549019608, 0.23137254901960785, 0.9450980392156862, 0.7019607843137254, 0.403921568627451, 0.6235294117647059, 0.011764705882352941, 0.7058823529411765, 0.39215686274509803, 0.996078431372549, 0.054901960784313725, 0.8627450980392157, 0.6980392156862745, 0.3411764705882353, 0.803921568627451, 0.047058823529411764, 0.6274509803921569, 0.8392156862745098, 0.6941176470588235, 0.19607843137254902, 0.8745098039215686, 0.06666666666666667, 0.5058823529411764, 0.7176470588235294, 0.22745098039215686, 0.6, 0.09411764705882353, 0.9568627450980393, 0.27450980392156865, 0.5490196078431373, 0.37254901960784315, 0.8, 0.4745098039215686, 0.5294117647058824, 0.15294117647058825, 0.6039215686274509, 0.8117647058823529, 0.4980392156862745, 0.3568627450980392, 0.9372549019607843, 0.615686274509804, 0.6901960784313725, 0.5490196078431373, 0.7803921568627451, 0.03137254901960784, 0.7058823529411765, 0.17254901960784313, 0.6392156862745098, 0.07058823529411765, 0.6039215686274509, 0.2549019607843137, 0.9098039215686274, 0.5098039215686274, 0.30196078431372547, 1.0, 0.42745098039215684, 0.5882352941176471, 0.8509803921568627, 0.6745098039215687, 0.08235294117647059, 0.4470588235294118, 0.8627450980392157, 0.5019607843137255, 0.1803921568627451, 0.41568627450980394, 0.615686274509804, 0.8352941176470589, 0.22745098039215686, 0.30196078431372547, 0.403921568627451, 0.08627450980392157, 0.8117647058823529, 0.5294117647058824, 0.8470588235294118, 0.29411764705882354, 0.22745098039215686, 0.6431372549019608, 0.16862745098039217, 0.8705882352941177, 0.28627450980392155, 0.7215686274509804, 0.3686274509803922, 0.050980392156862744, 0.18823529411764706, 0.807843137254902, 0.8705882352941177, 0.23529411764705882, 0.047058823529411764, 0.3411764705882353, 0.13333333333333333, 0.4980392156862745, 0.7764705882352941, 0.3686274509803922, 0.5843137254901961, 0.2, 0.7647058823529411, 0.2549019607843137, 0.12941176470588237, 0.33725490196078434, 0.5803921568627451, 0.7450980392156863, 0.14901960784313725, 0.6235294117647059, 0.3176470588235294, 0.18823529411764706, 0.7803921568627451, 0.043137254901960784, 0.8509803921568627, 0.1450980392156863, 0.7019607843137254, 0.7843137254901961, 0.17254901960784313, 0.996078431372549, 0.32941176470588235, 0.6274509803921569, 0.9254901960784314, 0.45098039215686275, 0.1450980392156863, 0.8313725490196079, 0.23529411764705882, 0.4, 0.2980392156862745, 0.788235294117647, 0.5411764705882353, 0.0196078431372549, 0.2627450980392157, 0.15294117647058825, 0.8823529411764706, 0.5372549019607843, 0.8352941176470589, 0.13333333333333333, 0.4823529411764706, 0.06274509803921569, 0.9058823529411765, 0.5176470588235295, 0.2627450980392157, 0.4627450980392157, 0.32941176470588235, 0.2235294117647059, 0.596078431372549, 0.49019607843137253, 0.9686274509803922, 0.2, 0.9333333333333333, 0.4196078431372549, 0.34901960784313724, 0.09803921568627451, 0.5843137254901961, 0.24705882352941178, 0.43137254901960786, 0.6627450980392157, 0.8352941176470589, 0.29411764705882354, 0.9058823529411765, 0.19215686274509805, 0.34901960784313724, 0.48627450980392156, 0.7686274509803922, 0.08235294117647059, 0.22745098039215686, 0.996078431372549, 0.058823529411764705, 0.6745098039215687, 0.42745098039215684, 0.26666666666666666, 0.8901960784313725, 0.21176470588235294, 0.7254901960784313, 0.00392156862745098, 0.3176470588235294, 0.4, 0.9647058823529412, 0.2784313725490196, 0.4588235294117647, 0.8352941176470589, 0.5254901960784314, 0.9215686274509803, 0.3333333333333333, 0.03137254901960784, 0.7215686274509804, 0.1450980392156863, 0.6705882352941176, 0.23529411764705882, 0.8980392156862745, 0.0196078431372549, 0.13725490196078433, 0.7843137254901961, 0.9137254901960784, 0.6235294117647059, 0.00392156862745098, 0.28627450980392155, 0.7333333333333333, 0.058823529411764705, 0.14901960784313725, 0.35294117647058826, 0.5333333333333333, 0.6745098039215687, 0.9529411764705882, 0.7647058823529411, 0.2823529411764706, 0.043137254901960784, 0.14901960784313725, 0.4470588235294118, 0.9568627450980393, 0.48627450980392156, 0.9294117647058824, 0.09411764705882353, 0.41568627450980394, 0.7843137254901961, 0.5176470588235295, 0.6039215686274509, 0.2784313725490196, 0.10588235294117647, 0.40784313725490196, 0.47058823529411764, 0.6352941176470588, 0.9058823529411765, 0.8313725490196079, 0.615686274509804, 0.26666666666666666, 0.9647058823529412, 0.10980392156862745, 0.3137254901960784, 0.6627450980392157, 0.5176470588235295, 0.9019607843137255, 0.1803921568627451, 0.9725490196078431, 0.2784313725490196, 0.403921568627451, 0.9529411764705882, 0.8117647058823529, 0.08235294117647059, 0.44313725490196076, 0.5843137254901961, 0.23137254901960785, 0.6274509803921569, 0.30980392156862746, 0.9019607843137255, 0.4745098039215686, 0.5607843137254902, 0.09411764705882353, 0.011764705882352941, 0.3764705882352941, 0.6627450980392157, 0.30980392156862746, 0.0392156862745098, 0.7568627450980392, 0.08627450980392157, 0.9568627450980393, 0.1568627450980392, 0.35294117647058826, 0.4117647058823529, 0.9803921568627451, 0.7333333333333333, 0.3137254901960784, 0.396078431372549, 0.023529411764705882, 0.7529411764705882, 0.5686274509803921, 0.3137254901960784, 0.7647058823529411, 0.14901960784313725, 0.8470588235294118, 0.10196078431372549, 0.7411764705882353, 0.8196078431372549, 0.41568627450980394, 0.00392156862745098, 0.6549019607843137, 0.10588235294117647, 0.2823529411764706, 0.7294117647058823, 0.15294117647058825, 0.7843137254901961, 0.9215686274509803, 0.39215686274509803, 0.7450980392156863, 0.9725490196078431, 0.00392156862745098, 0.3764705882352941, 0.7764705882352941, 0.5254901960784314, 0.6901960784313725, 0.8431372549019608, 0.1607843137254902, 0.6549019607843137, 0.596078431372549, 0.396078431372549, 0.8627450980392157, 0.3215686274509804, 0.9490196078431372, 0.023529411764705882, 0.6509803921568628, 0.5411764705882353, 0.4392156862745098, 0.8627450980392157, 0.16470588235294117, 0.8313725490196079, 0.0784313725490196, 0.5843137254901961, 0.7529411764705882, 0.3764705882352941, 0.13333333333333333, 0.6901960784313725, 0.4392156862745098, 0.803921568627451, 0.5686274509803921, 0.4, 0.09411764705882353, 0.4823529411764706, 0.807843137254902, 0.5450980392156862, 0.3176470588235294, 0.25882352941176473, 0.4, 0.7137254901960784, 0.33725490196078434, 0.5529411764705883, 0.9372549019607843, 0.24313725490196078, 0.7019607843137254, 0.984313725490196, 0.09803921568627451, 0.8549019607843137, 0.1607843137254902, 0.4823529411764706, 0.6980392156862745, 0.6196078431372549, 0.9019607843137255, 0.5647058823529412, 0.6862745098039216, 0.011764705882352941, 0.34901960784313724, 0.5450980392156862, 0.23921568627450981, 0.9764705882352941, 0.1411764705882353, 0.9098039215686274, 0.7490196078431373, 0.6588235294117647, 0.9490196078431372, 0.16470588235294117, 0.788235294117647, 0.2901960784313726, 0.5529411764705883, 0.18823529411764706, 0.050980392156862744, 0.7058823529411765, 0.4823529411764706, 0.8588235294117647, 0.0196078431372549, 0.7254901960784313, 0.37254901960784315, 0.803921568627451, 0.6470588235294118, 0.5019607843137255, 0.10196078431372549, 0.6784313725490196, 0.5333333333333333, 0.9058823529411765, 0.34901960784313724, 0.49411764705882355, 0.7450980392156863, 0.9490196078431372, 0.36470588235294116, 0.058823529411764705, 0.6627450980392157, 0.8627450980392157, 0.7490196078431373, 0.8117647058823529, 0.24705882352941178, 0.9529411764705882, 0.5882352941176471, 0.8705882352941177, 0.5333333333333333, 0.6901960784313725, 0.4588235294117647, 0.6470588235294118, 0.8392156862745098, 0.6039215686274509, 0.49019607843137253, 0.6627450980392157, 0.18823529411764706, 0.4666666666666667, 0.9058823529411765, 0.26666666666666666, 0.6784313725490196, 0.9882352941176471, 0.20784313725490197, 0.3803921568627451, 0.6470588235294118, 0.9450980392156862, 0.5450980392156862, 0.9176470588235294, 0.1411764705882353, 0.7254901960784313, 0.3843137254901961, 0.8549019607843137, 0.5529411764705883, 0.8901960784313725, 0.49019607843137253, 0.6509803921568628, 0.2980392156862745, 0.5450980392156862, 0.17254901960784313, 0.11764705882352941, 0.5725490196078431, 0.4666666666666667, 0.6509803921568628, 0.13333333333333333, 0.9803921568627451, 0.03529411764705882, 0.43529411764705883, 0.9372549019607843, 0.28627450980392155, 0.7333333333333333, 0.18823529411764706, 0.7764705882352941, 0.5647058823529412, 0.37254901960784315, 0.13333333333333333, 0.7803921568627451, 0.09019607843137255, 1.0, 0.25098039215686274, 0.6509803921568628, 0.5137254901960784, 0.20392156862745098, 0.9372549019607843, 0.011764705882352941, 0.23921568627450981, 0.8549019607843137, 0.49019607843137253, 0.20392156862745098, 0.9607843137254902, 0.8627450980392157, 0.7607843137254902, 0.35294117647058826, 0.19215686274509805, 0.6470588235294118, 0.9607843137254902, 0.5137254901960784, 0.06666666666666667, 0.984313725490196, 0.20392156862745098, 0.796078431372549, 0.6470588235294118, 0.3803921568627451, 0.49411764705882355, 0.7843137254901961, 0.4470588235294118, 0.6039215686274509, 0.00392156862745098, 0.37254901960784315, 0.23921568627450981, 0.9921568627450981, 0.4, 0.3333333333333333, 0.12156862745098039, 0.7372549019607844, 0.8235294117647058, 0.611764705882353, 0.6745098039215687, 0.07058823529411765, 0.4627450980392157, 0.20784313725490197, 0.5529411764705883, 0.3254901960784314, 0.5019607843137255, 0.0, 0.6980392156862745, 0.3803921568627451, 0.07450980392156863, 0.4549019607843137, 0.9019607843137255, 0.396078431372549, 0.788235294117647, 0.1607843137254902, 0.9529411764705882, 0.5725490196078431, 0.09411764705882353, 0.4627450980392157, 0.047058823529411764, 0.8901960784313725, 0.20392156862745098, 0.3686274509803922, 0.24313725490196078, 0.7137254901960784, 0.1411764705882353, 0.8627450980392157, 0.00392156862745098, 0.4235294117647059, 0.5411764705882353, 0.12156862745098039, 0.2784313725490196, 0.21176470588235294, 0.41568627450980394, 0.5215686274509804, 0.11764705882352941, 0.7137254901960784, 0.1843137254901961, 0.42745098039215684, 0.3411764705882353, 0.12549019607843137, 0.8862745098039215, 0.27058823529411763, 0.050980392156862744, 0.2196078431372549, 0.10980392156862745, 0.8196078431372549, 0.058823529411764705, 0.9568627450980393, 0.6078431372549019, 0.36470588235294116, 0.09411764705882353, 0.4392156862745098, 0.8117647058823529, 0.592156862745098, 0.2980392156862745, 0.043137254901960784, 0.19215686274509805, 0.6274509803921569, 0.25882352941176473, 0.7803921568627451, 0.30980392156862746, 0.4549019607843137, 0.611764705882353, 0.23921568627450981, 0.0196078431372549, 1.0, 0.06274509803921569, 0.8509803921568627, 0.3411764705882353, 0.788235294117647, 0.2627450980392157, 0.9294117647058824, 0.07450980392156863, 0.41568627450980394, 0.24705882352941178, 0.3215686274509804, 0.5529411764705883, 0.8862745098039215, 0.0, 0.5137254901960784, 0.10588235294117647, 0.4666666666666667, 0.23137254901960785, 0.7137254901960784, 0.9254901960784314, 0.29411764705882354, 0.48627450980392156, 0.592156862745098, 0.35294117647058826, 0.7411764705882353, 0.42745098039215684, 0.8823529411764706, 0.3254901960784314, 0.6666666666666666, 0.5529411764705883, 0.9882352941176471, 0.10196078431372549, 0.30980392156862746, 0.6196078431372549, 0.00784313725490196, 0.27450980392156865, 0.9372549019607843, 0.7098039215686275, 0.043137254901960784, 0.4392156862745098, 0.7529411764705882, 0.596078431372549, 0.1607843137254902, 0.4549019607843137, 0.11372549019607843, 0.8509803921568627, 0.5843137254901961, 0.023529411764705882, 0.19215686274509805, 0.27058823529411763, 0.32941176470588235, 0.8941176470588236, 0.5568627450980392, 0.8196078431372549, 0.09803921568627451, 0.7764705882352941, 0.20392156862745098, 0.8588235294117647, 0.26666666666666666, 0.43529411764705883, 0.1803921568627451, 0.8941176470588236, 0.30196078431372547, 0.8392156862745098, 0.6901960784313725, 0.39215686274509803, 0.06274509803921569, 0.8509803921568627, 0.5803921568627451, 0.22745098039215686, 0.9725490196078431, 0.7372549019607844, 0.8470588235294118, 0.6470588235294118, 0.23137254901960785, 0.5411764705882353, 0.34901960784313724, 0.27450980392156865, 0.43137254901960786, 0.21568627450980393, 0.6980392156862745, 0.3137254901960784, 0.7725490196078432, 0.5568627450980392, 0.8392156862745098, 0.03137254901960784, 0.611764705882353, 0.2980392156862745, 0.9921568627450981, 0.20392156862745098, 0.6862745098039216, 0.8274509803921568, 0.9294117647058824, 0.4588235294117647, 0.615686274509804, 0.9764705882352941, 0.3058823529411765, 0.8980392156862745, 0.47843137254901963, 0.050980392156862744, 0.6392156862745098, 0.9921568627450981, 0.21176470588235294, 0.7764705882352941, 0.5686274509803921, 0.9411764705882353, 0.7490196078431373, 0.8980392156862745, 0.34509803921568627, 0.5490196078431373, 0.6980392156862745, 0.2235294117647059, 0.8627450980392157, 0.16470588235294117, 0.5450980392156862, 0.0196078431372549, 0.8823529411764706, 0.7176470588235294, 0.4980392156862745, 0.43529411764705883, 0.3607843137254902, 0.0784313725490196, 0.5215686274509804, 0.9803921568627451, 0.1607843137254902, 0.08627450980392157, 0.7647058823529411, 0.36470588235294116, 0.43529411764705883, 0.21568627450980393, 0.7019607843137254, 0.4823529411764706, 0.6274509803921569, 0.8705882352941177, 0.1843137254901961, 0.7137254901960784, 0.8274509803921568, 0.615686274509804, 0.7450980392156863, 0.2, 0.807843137254902, 0.35294117647058826, 0.6352941176470588, 0.8509803921568627, 0.9647058823529412, 0.06274509803921569, 0.611764705882353, 0.8313725490196079, 0.4, 0.19607843137254902, 0.6941176470588235, 0.047058823529411764, 0.8156862745098039, 0.10196078431372549, 0.15294117647058825, 0.6078431372549019, 0.8, 0.28627450980392155, 0.7411764705882353, 0.38823529411764707, 0.1607843137254902, 0.6941176470588235, 0.5254901960784314, 0.4588235294117647, 0.596078431372549, 0.15294117647058825, 0.8705882352941177, 0.36470588235294116, 0.2235294117647059, 0.8274509803921568, 0.6705882352941176, 0.8901960784313725, 0.5215686274509804, 0.30196078431372547, 0.9607843137254902, 0.8745098039215686, 0.7215686274509804, 0.9254901960784314, 0.6431372549019608, 0.7490196078431373, 0.1843137254901961, 0.42745098039215684, 0.5176470588235295, 0.03529411764705882, 0.6588235294117647, 0.5882352941176471, 0.9803921568627451, 0.047058823529411764, 0.7607843137254902, 0.5019607843137255, 0.3607843137254902, 0.0196078431372549, 0.796078431372549, 0.996078431372549, 0.2627450980392157, 0.7647058823529411, 0.9176470588235294, 0.43137254901960786, 0.10980392156862745, 0.5137254901960784, 0.3254901960784314, 0.13333333333333333, 1.0, 0.03137254901960784, 0.6196078431372549, 0.7450980392156863, 0.9294117647058824, 0.8509803921568627, 0.596078431372549, 0.9921568627450981, 0.1568627450980392, 0.4470588235294118, 0.7411764705882353, 0.9607843137254902, 0.4196078431372549, 0.5176470588235295, 0.6509803921568628, 0.09019607843137255, 0.26666666666666666, 0.5803921568627451, 0.17254901960784313, 0.788235294117647, 0.7019607843137254, 0.043137254901960784, 0.1607843137254902, 0.5647058823529412, 0.7725490196078432, 0.8509803921568627, 0.2784313725490196, 0.7294117647058823, 0.5019607843137255, 0.011764705882352941, 0.3176470588235294, 0.38823529411764707, 0.5215686274509804, 0.4392156862745098, 0.2823529411764706, 0.788235294117647, 0.13333333333333333, 0.4235294117647059, 0.7725490196078432, 0.6509803921568628, 0.9529411764705882, 0.4823529411764706, 0.24705882352941178, 0.13725490196078433, 0.9686274509803922, 0.8, 0.8745098039215686, 0.6784313725490196, 0.9098039215686274, 0.20784313725490197, 0.8235294117647058, 0.7058823529411765, 0.9294117647058824, 0.5333333333333333, 0.8196078431372549, 0.596078431372549, 0.10588235294117647, 0.9529411764705882, 0.043137254901960784, 0.4235294117647059, 0.2980392156862745, 0.5607843137254902, 0.35294117647058826, 0.8980392156862745, 0.06666666666666667, 0.4823529411764706, 0.6980392156862745, 0.12941176470588237, 0.4196078431372549, 0.2627450980392157, 0.6705882352941176, 0.32941176470588235, 0.1568627450980392, 0.5215686274509804, 0.03529411764705882, 0.8784313725490196, 0.9411764705882353, 0.5490196078431373, 0.2980392156862745, 0.9725490196078431, 0.4980392156862745, 0.4, 0.07058823529411765, 0.4588235294117647, 0.047058823529411764, 0.6431372549019608, 0.7843137254901961, 0.9294117647058824, 0.23529411764705882, 0.07058823529411765, 0.8352941176470589, 0.30196078431372547, 0.5490196078431373, 0.10588235294117647, 0.9176470588235294, 0.28627450980392155, 0.01568627450980392, 0.34901960784313724, 0.7411764705882353, 0.07058823529411765, 0.6666666666666666, 0.1411764705882353, 0.5372549019607843, 0.40784313725490196, 0.054901960784313725, 0.12549019607843137, 0.9647058823529412, 0.28627450980392155, 0.7254901960784313, 0.9372549019607843, 0.47058823529411764, 0.3137254901960784, 0.5333333333333333, 0.38823529411764707, 0.13725490196078433, 0.9568627450980393, 0.6392156862745098, 0.5843137254901961, 0.4392156862745098, 0.16862745098039217, 0.11372549019607843, 0.6274509803921569, 0.3568627450980392, 0.17647058823529413, 0.6705882352941176, 0.8, 0.27058823529411763, 0.5882352941176471, 0.4235294117647059, 0.6901960784313725, 0.8235294117647058, 0.12549019607843137, 0.5098039215686274, 0.058823529411764705, 0.25882352941176473, 0.396078431372549, 0.011764705882352941, 0.6352941176470588, 0.2784313725490196, 0.11372549019607843, 0.1803921568627451, 0.8117647058823529, 0.7647058823529411, 0.3333333333333333, 0.47843137254901963, 0.7294117647058823, 0.38823529411764707, 0.08235294117647059, 0.3411764705882353, 0.8431372549019608, 0.396078431372549, 0.6705882352941176, 0.23137254901960785, 0.3607843137254902, 0.0784313725490196, 0.403921568627451, 0.8117647058823529, 0.6235294117647059, 0.17647058823529413, 0.7137254901960784, 0.08235294117647059, 0.1607843137254902, 0.6392156862745098, 0.996078431372549, 0.00784313725490196, 0.5058823529411764, 0.3176470588235294, 0.0784313725490196, 0.7372549019607844, 0.34509803921568627, 0.40784313725490196, 0.6666666666666666, 0.06274509803921569, 0.3176470588235294, 0.5882352941176471, 0.01568627450980392, 0.4666666666666667, 0.5725490196078431, 0.40784313725490196, 0.047058823529411764, 0.3215686274509804, 0.6627450980392157, 0.16862745098039217, 0.9058823529411765, 0.396078431372549, 0.23921568627450981, 0.5294117647058824, 0.7490196078431373, 0.9921568627450981, 0.01568627450980392, 0.5058823529411764, 0.15294117647058825, 0.9411764705882353, 0.2784313725490196, 0.5882352941176471, 0.9882352941176471, 0.17647058823529413, 0.5411764705882353, 0.792156862745098, 0.8901960784313725, 0.43529411764705883, 0.7450980392156863, 0.24313725490196078, 0.6470588235294118, 0.12941176470588237, 0.45098039215686275, 0.2235294117647059, 0.6352941176470588, 0.9019607843137255, 0.7294117647058823, 0.1843137254901961, 0.9490196078431372, 0.8705882352941177, 0.5019607843137255, 0.3411764705882353, 0.8862745098039215, 0.42745098039215684, 0.7490196078431373, 0.3803921568627451, 0.9764705882352941, 0.6823529411764706, 0.788235294117647, 0.49019607843137253, 0.40784313725490196, 0.5607843137254902, 0.9411764705882353, 0.21568627450980393, 0.4196078431372549, 0.27450980392156865, 0.8235294117647058, 0.3607843137254902, 0.23529411764705882, 0.5019607843137255, 0.6745098039215687, 0.8470588235294118, 0.5725490196078431, 0.35294117647058826, 0.15294117647058825, 0.8862745098039215, 0.06666666666666667, 0.2235294117647059, 0.8156862745098039, 0.7137254901960784, 0.2784313725490196, 0.09019607843137255, 0.23137254901960785, 0.9019607843137255, 0.5254901960784314, 0.7254901960784313, 0.4745098039215686, 0.30196078431372547, 0.8901960784313725, 0.5647058823529412, 0.0196078431372549, 0.9333333333333333, 0.19215686274509805, 0.8705882352941177, 0.0784313725490196, 0.3058823529411765, 0.3843137254901961, 0.1843137254901961, 0.6627450980392157, 0.8156862745098039, 0.5333333333333333, 0.9254901960784314, 0.8705882352941177, 0.34901960784313724, 0.5882352941176471, 0.9333333333333333, 0.0392156862745098, 0.3764705882352941, 0.9098039215686274, 0.8392156862745098, 0.0196078431372549, 0.9725490196078431, 0.24705882352941178, 0.5137254901960784, 0.596078431372549, 0.9450980392156862, 0.00392156862745098, 0.4588235294117647, 0.9686274509803922, 0.6039215686274509, 0.1450980392156863, 0.9333333333333333, 0.45098039215686275, 0.8549019607843137, 0.9725490196078431, 0.6745098039215687, 0.8235294117647058, 0.23529411764705882, 0.5882352941176471, 0.3686274509803922, 0.8392156862745098, 0.9294117647058824, 0.26666666666666666, 0.6196078431372549, 0.19607843137254902, 0.9137254901960784, 0.7764705882352941, 0.5411764705882353, 0.21568627450980393, 0.7411764705882353, 0.27450980392156865, 0.13333333333333333, 0.34509803921568627, 0.6392156862745098, 0.24705882352941178, 0.5058823529411764, 0.12549019607843137, 0.2823529411764706, 0.7843137254901961, 0.7294117647058823, 0.3215686274509804, 0.6666666666666666, 0.1411764705882353, 0.803921568627451, 0.2235294117647059, 0.6549019607843137, 0.403921568627451, 0.7725490196078432, 0.45098039215686275, 0.0392156862745098, 0.8627450980392157, 0.7294117647058823, 0.08627450980392157, 0.3803921568627451, 0.023529411764705882, 0.5686274509803921, 0.9803921568627451, 0.30980392156862746, 0.803921568627451, 0.3686274509803922, 0.7725490196078432, 0.7137254901960784, 0.01568627450980392, 0.36470588235294116, 0.25882352941176473, 0.8156862745098039, 0.592156862745098, 0.2235294117647059, 0.13333333333333333, 0.5647058823529412, 0.03529411764705882, 0.18823529411764706, 0.6627450980392157, 0.12549019607843137, 0.4666666666666667, 0.21176470588235294, 0.050980392156862744, 0.6352941176470588, 0.7215686274509804, 0.13333333333333333, 0.792156862745098, 0.6078431372549019, 0.4745098039215686, 0.7607843137254902, 0.08235294117647059, 0.6274509803921569, 0.9921568627450981, 0.7764705882352941, 0.4588235294117647, 0.2196078431372549, 0.011764705882352941, 0.6274509803921569, 0.25098039215686274, 0.6941176470588235, 0.7686274509803922, 0.6039215686274509, 0.9294117647058824, 0.4470588235294118, 0.5490196078431373, 0.8666666666666667, 0.7529411764705882, 0.3215686274509804, 0.6627450980392157, 0.9450980392156862, 0.027450980392156862, 0.8196078431372549, 0.08235294117647059, 0.49019607843137253, 0.36470588235294116, 0.7176470588235294, 0.4627450980392157, 0.7607843137254902, 0.5294117647058824, 0.9568627450980393, 0.4745098039215686, 0.8941176470588236, 0.7607843137254902, 0.3333333333333333, 0.10196078431372549, 0.7098039215686275, 0.49019607843137253, 0.21176470588235294, 0.6862745098039216, 0.4627450980392157, 0.5529411764705883, 0.23529411764705882, 0.6196078431372549, 0.1450980392156863, 0.5254901960784314, 0.6470588235294118, 0.8862745098039215, 0.7490196078431373, 0.12549019607843137, 0.2823529411764706, 0.8, 0.18823529411764706, 0.7019607843137254, 0.8823529411764706, 0.5490196078431373, 0.22745098039215686, 0.058823529411764705, 0.3568627450980392, 0.25882352941176473, 0.5450980392156862, 0.03137254901960784, 0.47058823529411764, 0.9098039215686274, 0.7215686274509804, 0.17647058823529413, 0.5607843137254902, 0.0392156862745098, 0.8235294117647058, 0.5215686274509804, 0.10196078431372549, 0.8588235294117647, 0.1568627450980392, 0.3764705882352941, 0.996078431372549, 0.807843137254902, 0.6941176470588235, 0.8666666666666667, 0.9529411764705882, 0.7568627450980392, 0.8431372549019608, 0.9764705882352941, 0.4588235294117647, 0.0, 0.5647058823529412, 0.08627450980392157, 0.8470588235294118, 0.37254901960784315, 0.4588235294117647, 0.5843137254901961, 0.8705882352941177, 0.09019607843137255, 0.6823529411764706, 0.21176470588235294, 0.36470588235294116, 0.6431372549019608, 0.3058823529411765, 0.49411764705882355, 0.9490196078431372, 0.20784313725490197, 0.7058823529411765, 0.10980392156862745, 0.4823529411764706, 0.6, 0.06274509803921569, 0.5215686274509804, 0.16470588235294117, 0.8392156862745098, 0.5607843137254902, 0.11372549019607843, 0.6784313725490196, 0.3333333333333333, 0.4196078431372549, 0.7098039215686275, 0.2823529411764706, 0.9921568627450981, 0.611764705882353, 0.8196078431372549, 0.2627450980392157, 0.5803921568627451, 0.9333333333333333, 0.3254901960784314, 0.17647058823529413, 0.996078431372549, 0.2549019607843137, 0.03529411764705882, 0.8627450980392157, 0.16862745098039217, 0.3254901960784314, 0.5686274509803921, 0.027450980392156862, 0.8705882352941177, 0.3058823529411765, 0.10196078431372549, 0.3843137254901961, 0.9019607843137255, 0.803921568627451, 0.45098039215686275, 0.403921568627451, 0.11372549019607843, 0.33725490196078434, 0.18823529411764706, 0.00392156862745098, 0.6784313725490196, 0.15294117647058825, 0.41568627450980394, 0.054901960784313725, 0.20784313725490197, 0.3764705882352941, 0.6039215686274509, 0.25098039215686274, 0.6901960784313725, 0.9882352941176471, 0.2235294117647059, 0.6313725490196078, 0.050980392156862744, 0.33725490196078434, 0.1450980392156863, 0.6470588235294118, 0.2235294117647059, 0.023529411764705882, 0.592156862745098, 0.4392156862745098, 0.23921568627450981, 0.1450980392156863, 0.792156862745098, 0.06274509803921569, 0.8470588235294118, 0.30980392156862746, 0.7254901960784313, 0.09803921568627451, 0.43529411764705883, 0.9529411764705882, 0.20784313725490197, 0.3176470588235294, 0.4196078431372549, 0.17647058823529413, 0.48627450980392156, 0.9058823529411765, 0.6392156862745098, 0.3333333333333333, 0.5137254901960784, 0.09803921568627451, 0.30196078431372547, 0.6666666666666666, 0.7411764705882353, 0.5882352941176471, 0.12549019607843137, 0.8745098039215686, 0.4, 0.7607843137254902, 0.30196078431372547, 0.10980392156862745, 0.45098039215686275, 0.6862745098039216, 0.39215686274509803, 0.984313725490196, 0.44313725490196076, 0.2901960784313726, 0.6078431372549019, 0.4745098039215686, 0.6470588235294118, 0.42745098039215684, 0.09019607843137255, 0.5176470588235295, 0.17647058823529413, 0.027450980392156862, 0.43529411764705883, 0.09803921568627451, 0.6039215686274509, 0.3568627450980392, 0.8745098039215686, 0.49019607843137253, 0.9254901960784314, 0.19607843137254902, 0.9607843137254902, 0.050980392156862744, 0.7254901960784313, 0.2627450980392157, 0.32941176470588235, 0.9725490196078431, 0.8274509803921568, 0.5647058823529412, 0.9019607843137255, 0.23921568627450981, 0.7686274509803922, 0.6039215686274509, 0.8196078431372549, 0.2784313725490196, 0.403921568627451, 0.9176470588235294, 0.1843137254901961, 0.8666666666666667, 0.9882352941176471, 0.41568627450980394, 0.30196078431372547, 0.9372549019607843, 0.4392156862745098, 0.5098039215686274, 0.9725490196078431, 0.0, 0.792156862745098, 0.8549019607843137, 0.36470588235294116, 0.10196078431372549, 0.49411764705882355, 0.9019607843137255, 0.011764705882352941, 0.7450980392156863, 0.5333333333333333, 0.8156862745098039, 0.4549019607843137, 0.3686274509803922, 0.6784313725490196, 0.5137254901960784, 0.9686274509803922, 0.7098039215686275, 0.9294117647058824, 0.4392156862745098, 0.18823529411764706, 0.6941176470588235, 0.6, 0.9529411764705882, 0.7372549019607844, 0.09019607843137255, 0.5098039215686274, 1.0, 0.8313725490196079, 0.6588235294117647, 0.48627450980392156, 0.8862745098039215, 0.3686274509803922, 0.788235294117647, 0.49411764705882355, 0.984313725490196, 0.807843137254902, 0.13333333333333333, 0.43137254901960786, 0.8745098039215686, 0.7725490196078432, 0.11764705882352941, 0.41568627450980394, 0.8509803921568627, 0.9137254901960784, 0.25882352941176473, 0.5607843137254902, 0.8392156862745098, 0.7215686274509804, 0.2901960784313726, 0.9215686274509803, 0.5450980392156862, 0.9647058823529412, 0.3686274509803922, 0.6078431372549019, 0.4117647058823529, 0.9882352941176471, 0.1568627450980392, 0.8901960784313725, 0.8, 0.28627450980392155, 0.6901960784313725, 0.5764705882352941, 0.03529411764705882, 0.8156862745098039, 0.6823529411764706, 0.058823529411764705, 0.5568627450980392, 0.4117647058823529, 0.07450980392156863, 0.8392156862745098, 0.7647058823529411, 0.38823529411764707, 0.4823529411764706, 0.9098039215686274, 0.792156862745098, 0.3137254901960784, 0.4980392156862745, 0.9411764705882353, 0.2, 0.06666666666666667, 0.6352941176470588, 0.8862745098039215, 0.23137254901960785, 0.7843137254901961, 0.15294117647058825, 0.7294117647058823, 0.00784313725490196, 0.6941176470588235, 0.9450980392156862, 0.054901960784313725, 0.24313725490196078, 0.9176470588235294, 0.30980392156862746, 0.615686274509804, 0.37254901960784315, 0.5490196078431373, 0.2196078431372549, 0.7137254901960784, 0.1803921568627451, 0.6588235294117647, 0.25882352941176473, 0.611764705882353, 0.4235294117647059, 0.2901960784313726, 0.6352941176470588, 0.12549019607843137, 0.9176470588235294, 0.5372549019607843, 0.17647058823529413, 0.0196078431372549, 0.5019607843137255, 0.1411764705882353, 0.07058823529411765, 0.4549019607843137, 0.34509803921568627, 0.1450980392156863, 0.6588235294117647, 0.8431372549019608, 0.00392156862745098, 0.6862745098039216, 0.3411764705882353, 0.24705882352941178, 0.5843137254901961, 0.6666666666666666, 0.043137254901960784, 0.8784313725490196, 0.17254901960784313, 0.7372549019607844, 0.09019607843137255, 0.6431372549019608, 0.4549019607843137, 0.5372549019607843, 0.7647058823529411, 0.3176470588235294, 0.7058823529411765, 0.43137254901960786, 0.3764705882352941, 0.8784313725490196, 0.06666666666666667, 0.6039215686274509, 0.9019607843137255, 0.3058823529411765, 0.09411764705882353, 0.396078431372549, 0.6470588235294118, 0.12549019607843137, 0.2549019607843137, 0.5490196078431373, 0.8313725490196079, 0.15294117647058825, 0.3333333333333333, 0.5333333333333333, 0.2, 0.29411764705882354, 0.027450980392156862, 0.5568627450980392, 0.24313725490196078, 0.9607843137254902, 0.06274509803921569, 0.2980392156862745, 0.6196078431372549, 0.24705882352941178, 0.6980392156862745, 0.5725490196078431, 0.9176470588235294, 0.5137254901960784, 0.1803921568627451, 0.3333333333333333, 0.5372549019607843, 0.592156862745098, 0.16470588235294117, 0.788235294117647, 0.39215686274509803, 0.9803921568627451, 0.09803921568627451, 0.4196078431372549, 0.8, 0.16470588235294117, 0.043137254901960784, 0.8431372549019608, 0.7333333333333333, 0.27450980392156865, 0.5176470588235295, 0.00392156862745098, 0.6392156862745098, 0.38823529411764707, 0.5019607843137255, 0.054901960784313725, 0.7529411764705882, 0.8705882352941177, 0.47058823529411764, 0.9921568627450981, 0.3607843137254902, 0.788235294117647, 0.2235294117647059, 0.7254901960784313, 0.9568627450980393, 0.25882352941176473, 0.1568627450980392, 1.0, 0.00784313725490196, 0.19215686274509805, 0.43137254901960786, 0.043137254901960784, 0.7058823529411765, 0.6509803921568628, 0.3568627450980392, 0.5764705882352941, 0.9607843137254902, 0.5176470588235295, 0.3254901960784314, 0.06274509803921569, 0.592156862745098, 0.36470588235294116, 0.8980392156862745, 0.2, 0.3333333333333333, 0.7764705882352941, 0.5803921568627451, 0.8352941176470589, 0.4823529411764706, 0.7450980392156863, 0.8862745098039215, 0.2823529411764706, 0.8, 0.9372549019607843, 0.403921568627451, 0.0392156862745098, 0.807843137254902, 0.14901960784313725, 0.7098039215686275, 0.5490196078431373, 0.7803921568627451, 0.4823529411764706, 0.8156862745098039, 0.43137254901960786, 0.6235294117647059, 0.7490196078431373, 0.3843137254901961, 0.8, 0.984313725490196, 0.6941176470588235, 0.0392156862745098, 0.9333333333333333, 0.4980392156862745, 0.5529411764705883, 0.23137254901960785, 0.44313725490196076, 0.7333333333333333, 0.12156862745098039, 0.803921568627451, 0.08627450980392157, 0.7607843137254902, 0.23529411764705882, 0.611764705882353, 0.30980392156862746, 0.9098039215686274, 0.2549019607843137, 0.2, 0.1411764705882353, 0.9254901960784314, 0.058823529411764705, 0.22745098039215686, 0.1568627450980392, 0.6431372549019608, 0.2823529411764706, 0.12549019607843137, 0.2235294117647059, 0.7411764705882353, 0.5568627450980392, 0.8431372549019608, 0.21176470588235294, 0.4666666666666667, 0.8862745098039215, 0.3764705882352941, 0.7607843137254902, 0.4823529411764706, 0.047058823529411764, 0.4196078431372549, 0.6549019607843137, 0.8588235294117647, 0.7137254901960784, 0.9215686274509803, 0.1607843137254902, 0.38823529411764707, 0.7372549019607844, 0.5803921568627451, 0.8156862745098039, 0.9333333333333333, 0.10196078431372549, 0.34901960784313724, 0.03529411764705882, 0.2901960784313726, 0.6470588235294118, 0.7450980392156863, 0.00392156862745098, 0.9372549019607843, 0.28627450980392155, 0.07058823529411765, 0.4823529411764706, 0.6862745098039216, 0.011764705882352941, 0.615686274509804, 0.5058823529411764, 0.35294117647058826, 0.6941176470588235, 0.6392156862745098, 0.4627450980392157, 0.07450980392156863, 0.9019607843137255, 0.6705882352941176, 0.7764705882352941, 0.24705882352941178, 0.5686274509803921, 0.18823529411764706, 0.9333333333333333, 0.33725490196078434, 0.13725490196078433, 0.25882352941176473, 0.09411764705882353, 0.611764705882353, 0.2980392156862745, 0.9254901960784314, 0.03529411764705882, 0.6, 0.44313725490196076, 0.6862745098039216, 0.5764705882352941, 0.8117647058823529, 0.2784313725490196, 0.6196078431372549, 0.9764705882352941, 0.1568627450980392, 0.25098039215686274, 0.8509803921568627, 0.7803921568627451, 0.13725490196078433, 0.41568627450980394, 0.8274509803921568, 0.6588235294117647, 0.9529411764705882, 0.25098039215686274, 0.4627450980392157, 0.807843137254902, 0.5294117647058824, 0.13333333333333333, 0.7098039215686275, 0.011764705882352941, 0.21176470588235294, 0.1411764705882353, 0.984313725490196, 0.6823529411764706, 0.054901960784313725, 0.5137254901960784, 0.3176470588235294, 0.7529411764705882, 0.996078431372549, 0.0784313725490196, 0.3333333333333333, 0.9019607843137255, 0.011764705882352941, 0.23137254901960785, 0.3607843137254902, 0.058823529411764705, 0.2980392156862745, 0.9490196078431372, 0.25882352941176473, 0.6549019607843137, 0.4196078431372549, 0.5450980392156862, 0.2, 0.8627450980392157, 0.37254901960784315, 0.09803921568627451, 0.7843137254901961, 0.615686274509804, 0.9490196078431372, 0.5058823529411764, 0.9098039215686274, 0.47058823529411764, 0.34901960784313724, 0.5411764705882353, 0.8235294117647058, 0.38823529411764707, 0.49019607843137253, 0.5803921568627451, 0.9647058823529412, 0.40784313725490196, 0.6745098039215687, 0.6, 0.8470588235294118, 0.984313725490196, 0.7803921568627451, 0.5137254901960784, 0.6941176470588235, 0.9372549019607843, 0.41568627450980394, 0.1607843137254902, 0.043137254901960784, 0.796078431372549, 0.5333333333333333, 0.7254901960784313, 0.011764705882352941, 0.9803921568627451, 0.2784313725490196, 0.9098039215686274, 0.792156862745098, 0.23921568627450981, 0.06666666666666667, 0.611764705882353, 0.3254901960784314, 0.7803921568627451, 0.6431372549019608, 0.09019607843137255, 0.4392156862745098, 0.20392156862745098, 0.1450980392156863, 0.5372549019607843, 0.7568627450980392, 0.4588235294117647, 0.8392156862745098, 0.08627450980392157, 0.3843137254901961, 0.6823529411764706, 0.4549019607843137, 0.8274509803921568, 0.6392156862745098, 0.7411764705882353, 0.23137254901960785, 0.3215686274509804, 0.8901960784313725, 0.20392156862745098, 0.11764705882352941, 0.9921568627450981, 0.22745098039215686, 0.3176470588235294, 0.5764705882352941, 0.12549019607843137, 0.2, 0.35294117647058826, 0.9607843137254902, 0.10588235294117647, 0.8352941176470589, 0.6274509803921569, 0.4117647058823529, 0.5450980392156862, 0.6588235294117647, 0.8549019607843137, 0.4, 0.15294117647058825, 0.4745098039215686, 0.8666666666666667, 0.34509803921568627, 0.2, 0.5058823529411764, 0.11764705882352941, 0.3607843137254902, 0.7215686274509804, 0.5254901960784314, 0.8274509803921568, 0.08235294117647059, 0.5490196078431373, 0.4627450980392157, 0.0, 0.2823529411764706, 0.7215686274509804, 0.19215686274509805, 0.48627450980392156, 0.10980392156862745, 0.5568627450980392, 0.8509803921568627, 0.07450980392156863, 0.40784313725490196, 0.27058823529411763, 0.9686274509803922, 0.3607843137254902, 0.6588235294117647, 0.4196078431372549, 0.08235294117647059, 0.5725490196078431, 0.4549019607843137, 0.15294117647058825, 0.9098039215686274, 0.5843137254901961, 0.4470588235294118, 0.5215686274509804, 0.8392156862745098, 0.396078431372549, 0.16862745098039217, 0.984313725490196, 0.6745098039215687, 0.8627450980392157, 0.7176470588235294, 0.5725490196078431, 0.10196078431372549, 0.16862745098039217, 0.8745098039215686, 0.3058823529411765, 0.7411764705882353, 0.2627450980392157, 0.6313725490196078, 1.0, 0.3137254901960784, 0.1568627450980392, 0.050980392156862744, 0.2823529411764706, 0.39215686274509803, 0.18823529411764706, 0.6392156862745098, 0.984313725490196, 0.13333333333333333, 0.050980392156862744, 0.8666666666666667, 0.7019607843137254, 0.023529411764705882, 0.8235294117647058, 0.27450980392156865, 0.3607843137254902, 0.5529411764705883, 0.4549019607843137, 0.0784313725490196, 0.33725490196078434, 0.8235294117647058, 0.00784313725490196, 0.48627450980392156, 0.984313725490196, 0.6196078431372549, 0.2823529411764706, 0.17647058823529413, 0.33725490196078434, 0.08627450980392157, 0.615686274509804, 0.6705882352941176, 0.12941176470588237, 0.5686274509803921, 0.9725490196078431, 0.4470588235294118, 0.3803921568627451, 0.12156862745098039, 0.47058823529411764, 0.8784313725490196, 0.27058823529411763, 0.5137254901960784, 0.8549019607843137, 0.6549019607843137, 0.4196078431372549, 0.8941176470588236, 0.21568627450980393, 0.9764705882352941, 0.6, 0.25882352941176473, 0.8941176470588236, 0.20392156862745098, 0.9686274509803922, 0.3568627450980392, 0.12549019607843137, 0.5333333333333333, 0.9490196078431372, 0.43529411764705883, 0.7647058823529411, 0.8313725490196079, 0.5254901960784314, 0.39215686274509803, 0.9294117647058824, 0.8117647058823529, 0.43137254901960786, 0.8549019607843137, 0.48627450980392156, 0.6980392156862745, 0.30980392156862746, 0.4549019607843137, 0.0196078431372549, 0.7333333333333333, 0.9647058823529412, 0.19607843137254902, 0.5137254901960784, 0.7686274509803922, 0.7019607843137254, 0.5411764705882353, 0.10588235294117647, 0.6549019607843137, 0.7803921568627451, 0.9411764705882353, 0.8705882352941177, 0.06274509803921569, 0.9215686274509803, 0.21568627450980393, 0.40784313725490196, 0.32941176470588235, 0.7490196078431373, 0.9019607843137255, 0.38823529411764707, 0.984313725490196, 0.615686274509804, 0.050980392156862744, 0.9215686274509803, 0.29411764705882354, 0.7568627450980392, 0.1607843137254902, 0.6, 0.6705882352941176, 0.8862745098039215, 0.4588235294117647, 0.5607843137254902, 0.8509803921568627, 0.7725490196078432, 0.24705882352941178, 0.34509803921568627, 0.8705882352941177, 0.6431372549019608, 0.26666666666666666, 0.10588235294117647, 0.20784313725490197, 0.6862745098039216, 0.2549019607843137, 0.6431372549019608, 0.7450980392156863, 0.5882352941176471, 0.5058823529411764, 0.13725490196078433, 0.21176470588235294, 0.47058823529411764, 0.9098039215686274, 0.3568627450980392, 0.6039215686274509, 0.07450980392156863, 0.807843137254902, 0.4666666666666667, 0.027450980392156862, 0.7176470588235294, 0.42745098039215684, 0.8901960784313725, 0.5725490196078431, 0.6784313725490196, 0.8470588235294118, 0.011764705882352941, 0.7215686274509804, 0.27450980392156865, 0.43529411764705883, 0.6627450980392157, 0.1803921568627451, 0.3411764705882353, 0.7764705882352941, 0.09803921568627451, 0.5137254901960784, 0.7450980392156863, 0.03137254901960784, 0.20784313725490197, 0.9450980392156862, 0.403921568627451, 0.5843137254901961, 0.2549019607843137, 0.6627450980392157, 0.7686274509803922, 0.36470588235294116, 0.9058823529411765, 0.9568627450980393, 0.5843137254901961, 0.8470588235294118, 0.2196078431372549, 0.403921568627451, 0.34509803921568627, 0.5058823529411764, 0.17647058823529413, 0.7490196078431373, 0.8941176470588236, 0.5372549019607843, 0.2196078431372549, 0.9803921568627451, 0.011764705882352941, 0.6980392156862745, 0.3607843137254902, 0.9607843137254902, 0.023529411764705882, 0.3137254901960784, 0.7098039215686275, 0.15294117647058825, 0.49019607843137253, 0.7725490196078432, 0.11372549019607843, 0.5647058823529412, 0.043137254901960784, 0.42745098039215684, 0.792156862745098, 0.8588235294117647, 0.17254901960784313, 0.6627450980392157, 0.07058823529411765, 0.2784313725490196, 0.596078431372549, 0.0196078431372549, 0.7450980392156863, 0.17254901960784313, 0.28627450980392155, 0.9411764705882353, 0.03529411764705882, 0.6039215686274509, 0.7607843137254902, 0.9137254901960784, 0.23137254901960785, 0.8196078431372549, 0.2901960784313726, 0.07450980392156863, 0.9058823529411765, 0.0, 0.23529411764705882, 0.9803921568627451, 0.8196078431372549, 0.2784313725490196, 0.0196078431372549, 0.403921568627451, 0.3058823529411765, 0.6352941176470588, 0.4588235294117647, 0.12941176470588237, 0.6823529411764706, 0.9607843137254902, 0.6039215686274509, 0.17647058823529413, 0.6666666666666666, 0.2235294117647059, 0.5294117647058824, 0.8627450980392157, 0.43529411764705883, 0.6901960784313725, 0.38823529411764707, 0.996078431372549, 0.3137254901960784, 0.047058823529411764, 0.7411764705882353, 0.1803921568627451, 0.10196078431372549, 0.2980392156862745, 0.5019607843137255, 0.9490196078431372, 0.7098039215686275, 0.01568627450980392, 0.8156862745098039, 0.39215686274509803, 0.7254901960784313, 0.8588235294117647, 0.043137254901960784, 0.9568627450980393, 0.11372549019607843, 0.44313725490196076, 0.30196078431372547, 0.07450980392156863, 0.9333333333333333, 0.403921568627451, 0.8274509803921568, 0.0, 0.7725490196078432, 0.5098039215686274, 0.9725490196078431, 0.13333333333333333, 0.5764705882352941, 0.9058823529411765, 0.18823529411764706, 0.5294117647058824, 0.8235294117647058, 0.34901960784313724, 0.21568627450980393, 0.7607843137254902, 0.5215686274509804, 0.9372549019607843, 0.09411764705882353, 0.796078431372549, 0.9176470588235294, 0.5490196078431373, 0.28627450980392155, 0.4588235294117647, 0.6274509803921569, 0.9568627450980393, 0.16862745098039217, 0.8745098039215686, 0.7058823529411765, 0.6313725490196078, 0.13333333333333333, 0.8980392156862745, 0.18823529411764706, 0.3176470588235294, 0.08627450980392157, 0.45098039215686275, 0.11372549019607843, 0.6784313725490196, 0.4235294117647059, 0.5019607843137255, 0.7843137254901961, 0.8901960784313725, 0.7058823529411765, 0.0196078431372549, 0.8313725490196079, 0.26666666666666666, 0.0392156862745098, 0.6901960784313725, 0.8196078431372549, 0.5764705882352941, 0.30980392156862746, 0.7490196078431373, 0.11764705882352941, 0.24705882352941178, 0.788235294117647, 0.6313725490196078, 0.5568627450980392, 0.050980392156862744, 0.403921568627451, 0.8666666666666667, 0.3254901960784314, 0.5137254901960784, 0.7098039215686275, 0.25098039215686274, 0.6078431372549019, 0.2980392156862745, 0.03137254901960784, 0.5686274509803921, 0.7294117647058823, 0.4745098039215686, 0.8823529411764706, 0.09411764705882353, 0.6509803921568628, 0.5058823529411764, 0.7098039215686275, 0.5568627450980392, 0.08235294117647059, 0.4, 0.16470588235294117, 0.5294117647058824, 0.12156862745098039, 0.592156862745098, 0.3843137254901961, 0.6745098039215687, 0.45098039215686275, 0.3215686274509804, 0.6352941176470588, 0.3686274509803922, 0.43529411764705883, 0.17254901960784313, 0.5882352941176471, 0.7176470588235294, 0.23137254901960785, 0.5372549019607843, 0.7686274509803922, 0.8549019607843137, 0.27450980392156865, 0.047058823529411764, 0.4980392156862745, 0.09803921568627451, 0.30980392156862746, 0.7764705882352941, 0.11764705882352941, 0.34509803921568627, 0.803921568627451, 0.023529411764705882, 0.20784313725490197, 0.6431372549019608, 0.4980392156862745, 0.9176470588235294, 0.23137254901960785, 0.8235294117647058, 0.6235294117647059, 0.03529411764705882, 0.8901960784313725, 0.16470588235294117, 0.596078431372549, 0.2196078431372549, 0.5294117647058824, 0.9725490196078431, 0.32941176470588235, 0.615686274509804, 0.4823529411764706, 0.36470588235294116, 0.5529411764705883, 0.8117647058823529, 0.8823529411764706, 0.24313725490196078, 0.7686274509803922, 0.5411764705882353, 0.6627450980392157, 0.27058823529411763, 0.7019607843137254, 0.43529411764705883, 0.22745098039215686, 0.34509803921568627, 0.6862745098039216, 0.396078431372549, 0.10980392156862745, 0.25098039215686274, 0.6549019607843137, 0.09019607843137255, 0.9568627450980393, 0.41568627450980394, 0.15294117647058825, 0.3254901960784314, 0.592156862745098, 0.49019607843137253, 0.2196078431372549, 0.7490196078431373, 0.996078431372549, 0.1450980392156863, 0.8549019607843137, 0.24313725490196078, 0.42745098039215684, 0.3176470588235294, 0.49019607843137253, 0.28627450980392155, 0.7568627450980392, 0.5254901960784314, 0.8549019607843137, 0.7215686274509804, 0.5647058823529412, 0.8117647058823529, 0.23137254901960785, 0.7568627450980392, 0.058823529411764705, 0.3058823529411765, 0.16470588235294117, 0.10196078431372549, 0.9529411764705882, 0.592156862745098, 0.3176470588235294, 0.6392156862745098, 0.9333333333333333, 0.34509803921568627, 0.0784313725490196, 0.17647058823529413, 0.4, 0.9294117647058824, 0.6078431372549019, 0.4823529411764706, 0.1803921568627451, 0.3764705882352941, 0.8196078431372549, 0.27058823529411763, 0.9372549019607843, 0.6549019607843137, 0.1843137254901961, 0.8117647058823529, 0.9098039215686274, 0.08627450980392157, 1.0, 0.49411764705882355, 0.4, 0.9333333333333333, 0.3607843137254902, 0.23529411764705882, 0.9647058823529412, 0.3333333333333333, 0.792156862745098, 0.11372549019607843, 0.37254901960784315, 0.22745098039215686, 0.803921568627451, 0.9686274509803922, 0.2627450980392157, 0.8666666666666667, 0.34901960784313724, 0.7137254901960784, 0.49019607843137253, 0.16862745098039217, 0.803921568627451, 0.9411764705882353, 0.5686274509803921, 0.050980392156862744, 0.7450980392156863, 0.9333333333333333, 0.4823529411764706, 0.8313725490196079, 0.984313725490196, 0.1568627450980392, 0.027450980392156862, 0.5803921568627451, 0.37254901960784315, 0.803921568627451, 0.8901960784313725, 0.42745098039215684, 0.7098039215686275, 0.9333333333333333, 0.4745098039215686, 0.5882352941176471, 0.24705882352941178, 0.9098039215686274, 0.5411764705882353, 0.11372549019607843, 0.788235294117647, 0.3568627450980392, 0.4392156862745098, 0.5294117647058824, 0.9803921568627451, 0.39215686274509803, 0.6509803921568628, 0.4666666666666667, 0.12156862745098039, 0.7568627450980392, 0.43137254901960786, 0.058823529411764705, 0.16862745098039217, 0.788235294117647, 0.2823529411764706, 0.8941176470588236, 0.19215686274509805, 0.03529411764705882, 0.7019607843137254, 0.34901960784313724, 0.6274509803921569, 0.06274509803921569, 0.3254901960784314, 0.18823529411764706, 0.9450980392156862, 0.054901960784313725, 0.6431372549019608, 0.8549019607843137, 0.28627450980392155, 0.7647058823529411, 0.9686274509803922, 0.48627450980392156, 0.7372549019607844, 0.03529411764705882, 0.4666666666666667, 0.6235294117647059, 0.8588235294117647, 0.25098039215686274, 0.7058823529411765, 0.3686274509803922, 0.0196078431372549, 0.40784313725490196, 0.10588235294117647, 0.5843137254901961, 0.3568627450980392, 0.058823529411764705, 0.6627450980392157, 0.7843137254901961, 0.9098039215686274, 0.09019607843137255, 0.37254901960784315, 0.050980392156862744, 0.4196078431372549, 0.9333333333333333, 0.0196078431372549, 0.5098039215686274, 0.8705882352941177, 0.26666666666666666, 0.6352941176470588, 0.9921568627450981, 0.5568627450980392, 0.44313725490196076, 0.24705882352941178, 0.7411764705882353, 0.4745098039215686, 0.13725490196078433, 0.4196078431372549, 0.5058823529411764, 0.6235294117647059, 0.792156862745098, 0.4549019607843137, 0.8431372549019608, 0.5411764705882353, 0.06666666666666667, 0.8705882352941177, 0.996078431372549, 0.5098039215686274, 0.09411764705882353, 0.7333333333333333, 0.4470588235294118, 0.0196078431372549, 0.596078431372549, 0.3803921568627451, 0.1568627450980392, 0.7490196078431373, 0.6509803921568628, 0.21176470588235294, 0.8, 0.1411764705882353, 0.6941176470588235, 0.4196078431372549, 0.18823529411764706, 0.8588235294117647, 0.4470588235294118, 0.611764705882353, 0.9058823529411765, 0.3215686274509804, 0.6392156862745098, 0.6862745098039216, 0.44313725490196076, 0.09411764705882353, 1.0, 0.8901960784313725, 0.03137254901960784, 0.2627450980392157, 0.7254901960784313, 0.13725490196078433, 0.21176470588235294, 0.8705882352941177, 0.5294117647058824, 0.12156862745098039, 0.06666666666666667, 0.3411764705882353, 0.6784313725490196, 0.43529411764705883, 0.9529411764705882, 0.18823529411764706, 0.6470588235294118, 0.24313725490196078, 0.5607843137254902, 0.027450980392156862, 0.8196078431372549, 0.16470588235294117, 0.6549019607843137, 0.07058823529411765, 0.7254901960784313, 0.4588235294117647, 0.8549019607843137, 0.2784313725490196, 0.00392156862745098, 0.6901960784313725, 0.13725490196078433, 0.3254901960784314, 0.7254901960784313, 0.2627450980392157, 0.796078431372549, 0.3607843137254902, 0.2980392156862745, 0.8980392156862745, 0.6588235294117647, 0.5686274509803921, 0.9333333333333333, 0.12941176470588237, 0.7529411764705882, 0.6039215686274509, 0.4196078431372549, 0.9529411764705882, 0.49411764705882355, 0.1568627450980392, 0.996078431372549, 0.8588235294117647, 0.5882352941176471, 0.3843137254901961, 0.8117647058823529, 0.16470588235294117, 0.9254901960784314, 0.5490196078431373, 0.00784313725490196, 0.611764705882353, 0.3607843137254902, 0.8784313725490196, 0.30196078431372547, 0.803921568627451, 0.11764705882352941, 0.5450980392156862, 0.9058823529411765, 0.07450980392156863, 0.8156862745098039, 0.6352941176470588, 0.8941176470588236, 0.6862745098039216, 0.49411764705882355, 0.7333333333333333, 0.9372549019607843, 0.5411764705882353, 0.00392156862745098, 0.6, 0.23137254901960785, 1.0, 0.6705882352941176, 0.1568627450980392, 0.6274509803921569, 0.29411764705882354, 0.39215686274509803, 0.1450980392156863, 0.9176470588235294, 0.3686274509803922, 0.0, 0.8392156862745098, 0.6627450980392157, 0.3843137254901961, 0.054901960784313725, 0.8745098039215686, 0.2, 0.996078431372549, 0.7647058823529411, 0.23529411764705882, 0.9098039215686274, 0.047058823529411764, 0.1450980392156863, 0.2784313725490196, 0.6745098039215687, 0.42745098039215684, 0.7019607843137254, 0.1411764705882353, 0.615686274509804, 0.3568627450980392, 0.22745098039215686, 0.9725490196078431, 0.2823529411764706, 0.6823529411764706, 0.4627450980392157, 0.3411764705882353, 0.8862745098039215, 0.0784313725490196, 0.8470588235294118, 0.5294117647058824, 0.0, 0.6313725490196078, 0.5686274509803921, 0.26666666666666666, 0.996078431372549, 0.027450980392156862, 0.7607843137254902, 0.1411764705882353, 0.49019607843137253, 0.00392156862745098, 0.5686274509803921, 0.7568627450980392, 0.2196078431372549, 0.6235294117647059, 0.5450980392156862, 0.8509803921568627, 0.403921568627451, 0.49411764705882355, 0.788235294117647, 0.30980392156862746, 0.6705882352941176, 0.25882352941176473, 0.38823529411764707, 0.6078431372549019, 0.9058823529411765, 0.29411764705882354, 0.5137254901960784, 0.7215686274509804, 0.11764705882352941, 0.32941176470588235, 0.996078431372549, 0.6235294117647059, 0.3803921568627451, 0.2823529411764706, 0.8823529411764706, 0.41568627450980394, 0.3215686274509804, 0.9764705882352941, 0.1843137254901961, 0.6196078431372549, 0.9529411764705882, 0.7568627450980392, 0.5803921568627451, 0.20784313725490197, 0.9137254901960784, 0.08235294117647059, 0.5490196078431373, 1.0, 0.8274509803921568, 0.09803921568627451, 0.47843137254901963, 0.24705882352941178, 0.396078431372549, 0.00392156862745098, 0.5215686274509804, 0.6745098039215687, 0.22745098039215686, 0.10588235294117647, 0.792156862745098, 0.396078431372549, 0.7215686274509804, 0.4588235294117647, 0.12156862745098039, 0.5294117647058824, 0.7333333333333333, 0.48627450980392156, 0.09019607843137255, 0.4470588235294118, 0.20392156862745098, 0.8313725490196079, 0.13333333333333333, 0.5764705882352941, 0.23137254901960785, 0.9921568627450981, 0.3803921568627451, 0.6705882352941176, 0.19607843137254902, 0.4549019607843137, 0.9764705882352941, 0.16470588235294117, 0.25882352941176473, 0.8431372549019608, 0.3058823529411765, 0.19607843137254902, 0.8117647058823529, 0.3843137254901961, 0.1607843137254902, 0.4549019607843137, 0.8431372549019608, 0.5529411764705883, 0.803921568627451, 0.4745098039215686, 0.21568627450980393, 0.9725490196078431, 0.7019607843137254, 0.6039215686274509, 0.20392156862745098, 0.7137254901960784, 0.49411764705882355, 0.7647058823529411, 0.1843137254901961, 0.9215686274509803, 0.5294117647058824, 0.803921568627451, 0.6901960784313725, 0.5607843137254902, 0.10588235294117647, 0.2901960784313726, 0.6666666666666666, 0.3686274509803922, 0.7294117647058823, 0.9647058823529412, 0.3411764705882353, 0.22745098039215686, 0.0, 0.2980392156862745, 0.9215686274509803, 0.8470588235294118, 0.5647058823529412, 0.7568627450980392, 0.12156862745098039, 0.8352941176470589, 0.5450980392156862, 0.050980392156862744, 0.2627450980392157, 0.5843137254901961, 0.44313725490196076, 0.29411764705882354, 0.7411764705882353, 0.9215686274509803, 0.08235294117647059, 0.4980392156862745, 0.7215686274509804, 0.20784313725490197, 0.5372549019607843, 0.4196078431372549, 0.8784313725490196, 0.18823529411764706, 0.8274509803921568, 0.3058823529411765, 0.050980392156862744, 0.4235294117647059, 0.32941176470588235, 0.10980392156862745, 0.6078431372549019, 0.9764705882352941, 0.08235294117647059, 0.6274509803921569, 0.4235294117647059, 0.8901960784313725, 0.7098039215686275, 0.8117647058823529, 0.20784313725490197, 0.7803921568627451, 0.07450980392156863, 0.40784313725490196, 0.8666666666666667, 0.7647058823529411, 0.4666666666666667, 0.20392156862745098, 0.08235294117647059, 0.9529411764705882, 0.5529411764705883, 0.7607843137254902, 0.1411764705882353, 0.6784313725490196, 0.37254901960784315, 0.5568627450980392, 0.10196078431372549, 0.4, 0.8117647058823529, 0.050980392156862744, 0.8627450980392157, 0.44313725490196076, 0.6823529411764706, 0.011764705882352941, 0.6196078431372549, 0.19607843137254902, 0.7372549019607844, 0.8549019607843137, 0.6980392156862745, 0.4549019607843137, 0.9882352941176471, 0.33725490196078434, 0.8431372549019608, 0.2901960784313726, 0.5764705882352941, 0.011764705882352941, 0.21568627450980393, 0.27450980392156865, 0.9176470588235294, 0.027450980392156862, 0.30980392156862746, 0.24313725490196078, 0.6274509803921569, 0.788235294117647, 0.3215686274509804, 0.9411764705882353, 0.41568627450980394, 0.7058823529411765, 0.16470588235294117, 0.4980392156862745, 0.7294117647058823, 0.0, 0.2823529411764706, 0.7686274509803922, 0.5215686274509804, 0.3411764705882353, 0.5568627450980392, 0.03137254901960784, 0.44313725490196076, 0.08235294117647059, 0.6313725490196078, 0.27058823529411763, 0.9647058823529412, 0.7254901960784313, 0.3333333333333333, 0.09803921568627451, 0.27450980392156865, 0.7411764705882353, 0.34509803921568627, 0.8313725490196079, 0.054901960784313725, 0.4588235294117647, 0.08235294117647059, 0.8196078431372549, 0.32941176470588235, 0.11372549019607843, 0.2823529411764706, 0.611764705882353, 0.09019607843137255, 0.3607843137254902, 0.3058823529411765, 0.00784313725490196, 0.403921568627451, 0.8705882352941177, 0.48627450980392156, 0.5647058823529412, 0.20392156862745098, 0.6196078431372549, 0.7803921568627451, 0.8980392156862745, 0.5843137254901961, 0.7490196078431373, 0.4666666666666667, 0.16862745098039217, 0.06666666666666667, 0.5058823529411764, 0.4, 0.9490196078431372, 0.2, 0.7764705882352941, 0.9294117647058824, 0.7137254901960784, 0.11764705882352941, 0.984313725490196, 0.3411764705882353, 0.8274509803921568, 0.1568627450980392, 0.6666666666666666, 0.3607843137254902, 0.8156862745098039, 0.28627450980392155, 0.07450980392156863, 0.9764705882352941, 0.7098039215686275, 0.3843137254901961, 0.9294117647058824, 0.6549019607843137, 0.7843137254901961, 0.9529411764705882, 0.19215686274509805, 0.6941176470588235, 0.3607843137254902, 0.23529411764705882, 0.9215686274509803, 0.043137254901960784, 0.17647058823529413, 0.5372549019607843, 0.01568627450980392, 0.4745098039215686, 0.1411764705882353, 0.6, 0.9294117647058824, 0.00392156862745098, 0.5372549019607843, 0.1450980392156863, 0.8431372549019608, 0.7333333333333333, 0.5058823529411764, 0.2196078431372549, 0.011764705882352941, 0.8235294117647058, 0.4980392156862745, 0.058823529411764705, 0.24705882352941178, 0.8431372549019608, 0.4745098039215686, 0.29411764705882354, 0.5098039215686274, 0.611764705882353, 0.16862745098039217, 0.23529411764705882, 0.4, 0.5137254901960784, 0.9529411764705882, 0.35294117647058826, 0.050980392156862744, 0.3058823529411765, 0.8117647058823529, 0.1607843137254902, 0.0784313725490196, 0.9215686274509803, 0.6470588235294118, 0.5137254901960784, 0.8980392156862745, 0.8235294117647058, 0.611764705882353, 0.7725490196078432, 0.6745098039215687, 0.9647058823529412, 0.8745098039215686, 0.4, 0.7137254901960784, 0.047058823529411764, 0.6549019607843137, 0.5294117647058824, 0.9058823529411765, 0.0392156862745098, 0.788235294117647, 0.3333333333333333, 0.592156862745098, 0.9450980392156862, 0.41568627450980394, 0.058823529411764705, 0.7411764705882353, 0.611764705882353, 0.9607843137254902, 0.7725490196078432, 0.9098039215686274, 0.5215686274509804, 0.11764705882352941, 0.8745098039215686, 0.6862745098039216, 0.18823529411764706, 0.4117647058823529, 0.9411764705882353, 0.03137254901960784, 0.5803921568627451, 0.12941176470588237, 0.8901960784313725, 0.5450980392156862, 0.9607843137254902, 0.592156862745098, 0.4117647058823529, 0.9411764705882353, 0.8549019607843137, 0.4666666666666667, 0.23137254901960785, 0.9607843137254902, 0.6470588235294118, 0.8431372549019608, 0.7333333333333333, 0.16862745098039217, 0.9725490196078431, 0.027450980392156862, 0.8156862745098039, 0.11372549019607843, 0.45098039215686275, 0.0784313725490196, 0.5254901960784314, 0.8117647058823529, 0.39215686274509803, 0.6705882352941176, 0.3176470588235294, 0.8941176470588236, 0.6431372549019608, 0.00784313725490196, 0.30196078431372547, 0.6235294117647059, 0.4980392156862745, 0.3686274509803922, 0.1843137254901961, 0.615686274509804, 0.47843137254901963, 0.22745098039215686, 0.40784313725490196, 0.8823529411764706, 0.050980392156862744, 0.9450980392156862, 0.5803921568627451, 0.6470588235294118, 0.3411764705882353, 0.25098039215686274, 0.5254901960784314, 0.12549019607843137, 0.48627450980392156, 0.27450980392156865, 0.7215686274509804, 0.4588235294117647, 0.054901960784313725, 0.8313725490196079, 0.5725490196078431, 0.47058823529411764, 0.7372549019607844, 0.996078431372549, 0.3254901960784314, 0.8549019607843137, 0.25098039215686274, 0.9647058823529412, 0.6588235294117647, 0.35294117647058826, 0.22745098039215686, 0.6901960784313725, 0.28627450980392155, 0.41568627450980394, 0.6627450980392157, 0.34509803921568627, 0.6, 0.9254901960784314, 0.2901960784313726, 0.6313725490196078, 0.8980392156862745, 0.7019607843137254, 0.9333333333333333, 0.14901960784313725, 0.6549019607843137, 0.9647058823529412, 0.34901960784313724, 0.7803921568627451, 0.9333333333333333, 0.7176470588235294, 0.2823529411764706, 0.1450980392156863, 0.5843137254901961, 0.9098039215686274, 0.6392156862745098, 0.21176470588235294, 0.5568627450980392, 0.3843137254901961, 0.7411764705882353, 0.43529411764705883, 0.1803921568627451, 0.6980392156862745, 0.3215686274509804, 0.08627450980392157, 0.42745098039215684, 0.3568627450980392, 0.19215686274509805, 0.5764705882352941, 0.12549019607843137, 0.996078431372549, 0.49019607843137253, 0.2549019607843137, 0.08235294117647059, 0.29411764705882354, 0.6274509803921569, 0.4470588235294118, 0.9215686274509803, 0.11372549019607843, 0.8352941176470589, 0.6431372549019608, 0.2196078431372549, 0.8745098039215686, 0.12549019607843137, 0.3764705882352941, 0.23529411764705882, 0.6705882352941176, 0.3215686274509804, 0.4196078431372549, 0.5725490196078431, 0.058823529411764705, 0.5058823529411764, 0.615686274509804, 0.9019607843137255, 0.6627450980392157, 0.5137254901960784, 0.7803921568627451, 0.4235294117647059, 0.30980392156862746, 0.7568627450980392, 0.1450980392156863, 0.2235294117647059, 0.6823529411764706, 0.5490196078431373, 0.027450980392156862, 0.7843137254901961, 0.42745098039215684, 0.1450980392156863, 0.5098039215686274, 0.25882352941176473, 0.611764705882353, 0.43529411764705883, 0.3215686274509804, 0.9294117647058824, 0.5058823529411764, 0.3803921568627451, 0.1843137254901961, 0.9529411764705882, 0.2549019607843137, 0.043137254901960784, 0.984313725490196, 0.21176470588235294, 0.788235294117647, 0.4470588235294118, 0.7294117647058823, 0.15294117647058825, 0.8627450980392157, 0.4117647058823529, 0.03529411764705882, 0.792156862745098, 0.8980392156862745, 0.058823529411764705, 0.7686274509803922, 0.5607843137254902, 0.30980392156862746, 0.4666666666666667, 0.13725490196078433, 0.6980392156862745, 0.4392156862745098, 0.8470588235294118, 0.09803921568627451, 0.6078431372549019, 0.8, 0.17254901960784313, 0.07450980392156863, 0.8745098039215686, 0.5333333333333333, 0.9137254901960784, 0.15294117647058825, 0.2901960784313726, 0.803921568627451, 0.37254901960784315, 0.11372549019607843, 0.6509803921568628, 0.44313725490196076, 0.5647058823529412, 0.7490196078431373, 0.2980392156862745, 0.4980392156862745, 0.8274509803921568, 0.5843137254901961, 0.9019607843137255, 0.9725490196078431, 0.03529411764705882, 0.8, 0.12549019607843137, 0.4549019607843137, 0.39215686274509803, 0.19607843137254902, 0.7764705882352941, 0.4392156862745098, 0.3411764705882353, 0.21568627450980393, 0.0196078431372549, 0.7333333333333333, 0.25882352941176473, 0.10980392156862745, 0.48627450980392156, 0.0392156862745098, 0.8745098039215686, 0.792156862745098, 0.4196078431372549, 0.10588235294117647, 0.4980392156862745, 0.7686274509803922, 0.8745098039215686, 0.6196078431372549, 0.047058823529411764, 0.25882352941176473, 0.12549019607843137, 0.9764705882352941, 0.47058823529411764, 0.5568627450980392, 0.14901960784313725, 0.8431372549019608, 0.5176470588235295, 0.058823529411764705, 0.8235294117647058, 0.36470588235294116, 0.17254901960784313, 0.7686274509803922, 0.8627450980392157, 0.39215686274509803, 0.8235294117647058, 0.19215686274509805, 0.24705882352941178, 0.5607843137254902, 0.37254901960784315, 0.15294117647058825, 0.49411764705882355, 0.30980392156862746, 0.6862745098039216, 0.4627450980392157, 0.17647058823529413, 0.8352941176470589, 0.0196078431372549, 0.7411764705882353, 0.21176470588235294, 0.8196078431372549, 0.2980392156862745, 0.7686274509803922, 0.15294117647058825, 0.25098039215686274, 0.3803921568627451, 0.1843137254901961, 0.6862745098039216, 0.25882352941176473, 0.6313725490196078, 0.5019607843137255, 0.058823529411764705, 0.7411764705882353, 0.34509803921568627, 0.6392156862745098, 0.8901960784313725, 0.7058823529411765, 0.5764705882352941, 0.054901960784313725, 0.9098039215686274, 0.7725490196078432, 0.07450980392156863, 0.7058823529411765, 0.23921568627450981, 0.6549019607843137, 0.8745098039215686, 0.7254901960784313, 0.32941176470588235, 0.6392156862745098, 0.12549019607843137, 0.7137254901960784, 0.596078431372549, 0.09019607843137255, 0.25098039215686274, 0.3568627450980392, 0.5764705882352941, 0.9803921568627451, 0.6862745098039216, 0.23921568627450981, 0.5568627450980392, 0.2823529411764706, 0.6784313725490196, 0.10588235294117647, 0.9725490196078431, 0.6196078431372549, 0.792156862745098, 0.24313725490196078, 0.8980392156862745, 0.1803921568627451, 0.01568627450980392, 0.7333333333333333, 0.9058823529411765, 0.4117647058823529, 0.9764705882352941, 0.5725490196078431, 0.34509803921568627, 0.24313725490196078, 0.396078431372549, 0.6627450980392157, 0.01568627450980392, 0.5176470588235295, 0.20392156862745098, 0.592156862745098, 0.7764705882352941, 0.058823529411764705, 0.3843137254901961, 0.09019607843137255, 0.8823529411764706, 0.17254901960784313, 0.10588235294117647, 0.3803921568627451, 0.06666666666666667, 0.49019607843137253, 0.17647058823529413, 0.2627450980392157, 0.8745098039215686, 0.7098039215686275, 0.9921568627450981, 0.09803921568627451, 0.5254901960784314, 0.03529411764705882, 0.596078431372549, 0.803921568627451, 0.5450980392156862, 0.8784313725490196, 0.4196078431372549, 0.5843137254901961, 0.8470588235294118, 0.6392156862745098, 0.3215686274509804, 0.5372549019607843, 0.6745098039215687, 0.2549019607843137, 0.9803921568627451, 0.023529411764705882, 0.2823529411764706, 0.4745098039215686, 0.9450980392156862, 0.8, 0.37254901960784315, 0.8549019607843137, 0.06274509803921569, 0.6627450980392157, 0.9490196078431372, 0.2627450980392157, 0.7568627450980392, 0.4549019607843137, 0.2980392156862745, 0.6078431372549019, 0.9098039215686274, 0.5568627450980392, 0.6784313725490196, 0.12941176470588237, 0.9803921568627451, 0.5176470588235295, 0.6941176470588235, 0.050980392156862744, 0.8901960784313725, 0.7176470588235294, 1.0, 0.8156862745098039, 0.09019607843137255, 0.9333333333333333, 0.5372549019607843, 0.596078431372549, 0.9882352941176471, 0.47843137254901963, 0.8901960784313725, 0.3607843137254902, 0.9725490196078431, 0.00784313725490196, 0.4666666666666667, 0.8666666666666667, 0.07450980392156863, 0.9882352941176471, 0.8352941176470589, 0.01568627450980392, 0.9137254901960784, 0.8666666666666667, 0.4392156862745098, 1.0, 0.17647058823529413, 0.10980392156862745, 0.2901960784313726, 0.2, 0.3764705882352941, 0.9882352941176471, 0.3333333333333333, 0.21176470588235294, 0.5333333333333333, 0.8588235294117647, 0.14901960784313725, 0.5882352941176471, 0.2823529411764706, 0.01568627450980392, 0.5607843137254902, 0.8470588235294118, 0.49019607843137253, 0.4117647058823529, 0.8705882352941177, 0.5215686274509804, 0.9411764705882353, 0.8117647058823529, 0.4745098039215686, 0.07058823529411765, 0.12941176470588237, 0.8196078431372549, 0.9450980392156862, 0.42745098039215684, 0.5019607843137255, 0.37254901960784315, 0.18823529411764706, 0.7176470588235294, 0.023529411764705882, 0.5137254901960784, 0.3764705882352941, 0.7686274509803922, 0.5529411764705883, 0.4745098039215686, 0.21568627450980393, 0.2901960784313726, 0.6901960784313725, 0.6352941176470588, 0.027450980392156862, 0.796078431372549, 0.7372549019607844, 0.6039215686274509, 0.9686274509803922, 0.8666666666666667, 0.6980392156862745, 0.26666666666666666, 0.8862745098039215, 0.9450980392156862, 0.19607843137254902, 0.7058823529411765, 0.43137254901960786, 0.984313725490196, 0.796078431372549, 0.7176470588235294, 0.6352941176470588, 0.3254901960784314, 0.7529411764705882, 0.5372549019607843, 0.6431372549019608, 0.054901960784313725, 0.5686274509803921, 0.32941176470588235, 0.8509803921568627, 0.27058823529411763, 0.7176470588235294, 0.3843137254901961, 0.9882352941176471, 0.07450980392156863, 0.6862745098039216, 0.1607843137254902, 0.37254901960784315, 0.21176470588235294, 0.9607843137254902, 0.0784313725490196, 0.1803921568627451, 0.7411764705882353, 0.44313725490196076, 0.3607843137254902, 0.12156862745098039, 0.7098039215686275, 0.19215686274509805, 0.5294117647058824, 0.5843137254901961, 0.7568627450980392, 0.22745098039215686, 0.39215686274509803, 0.7254901960784313, 0.00392156862745098, 0.8862745098039215, 0.6509803921568628, 0.21568627450980393, 0.7058823529411765, 0.023529411764705882, 0.4235294117647059, 0.2196078431372549, 0.34509803921568627, 0.7411764705882353, 0.07450980392156863, 0.30980392156862746, 0.7764705882352941, 0.47058823529411764, 0.27058823529411763, 0.01568627450980392, 0.5725490196078431, 0.4117647058823529, 0.7529411764705882, 0.054901960784313725, 0.34901960784313724, 0.1411764705882353, 0.25882352941176473, 0.6235294117647059, 0.09803921568627451, 0.6745098039215687, 0.40784313725490196, 0.5882352941176471, 0.7254901960784313, 0.3254901960784314, 0.5568627450980392, 0.4745098039215686, 0.3686274509803922, 0.5725490196078431, 0.3176470588235294, 0.2627450980392157, 0.796078431372549, 0.5254901960784314, 0.4, 0.9450980392156862, 0.49019607843137253, 0.8117647058823529, 0.09803921568627451, 0.4627450980392157, 0.6784313725490196, 0.37254901960784315, 0.803921568627451, 0.4196078431372549, 0.09019607843137255, 0.996078431372549, 0.792156862745098, 0.4392156862745098, 0.1607843137254902, 0.9294117647058824, 0.27450980392156865, 0.3411764705882353, 0.17254901960784313, 0.027450980392156862, 0.6705882352941176, 0.20784313725490197, 0.29411764705882354, 0.5215686274509804, 0.34901960784313724, 0.6235294117647059, 0.00784313725490196, 0.7450980392156863, 0.8352941176470589, 0.2627450980392157, 0.9333333333333333, 0.4196078431372549, 0.09019607843137255, 0.9921568627450981, 0.3137254901960784, 0.6431372549019608, 0.9411764705882353, 0.3607843137254902, 0.043137254901960784, 0.8352941176470589, 0.45098039215686275, 0.20392156862745098, 0.9490196078431372, 0.10980392156862745, 0.4392156862745098, 0.3215686274509804, 0.07450980392156863, 0.40784313725490196, 0.1450980392156863, 0.4666666666666667, 0.3058823529411765, 0.8235294117647058, 0.6078431372549019, 0.03137254901960784, 0.5450980392156862, 0.27058823529411763, 0.4549019607843137, 0.2196078431372549, 0.8549019607843137, 0.9411764705882353, 0.42745098039215684, 0.3607843137254902, 0.23137254901960785, 0.7607843137254902, 0.16470588235294117, 0.6666666666666666, 0.9372549019607843, 0.11372549019607843, 0.17647058823529413, 0.4627450980392157, 0.30196078431372547, 0.5098039215686274, 0.9176470588235294, 0.054901960784313725, 0.7490196078431373, 0.47058823529411764, 0.3843137254901961, 0.8392156862745098, 0.9215686274509803, 0.6, 0.5450980392156862, 0.8313725490196079, 0.8980392156862745, 0.3254901960784314, 0.6509803921568628, 0.0196078431372549, 0.2980392156862745, 0.9215686274509803, 0.5019607843137255, 0.17647058823529413, 0.6, 0.34901960784313724, 0.10588235294117647, 0.9294117647058824, 0.8, 0.4745098039215686, 0.09019607843137255, 0.9372549019607843, 0.615686274509804, 0.4627450980392157, 0.5843137254901961, 0.9254901960784314, 0.403921568627451, 0.6274509803921569, 0.19215686274509805, 0.3568627450980392, 0.6549019607843137, 0.24705882352941178, 0.8862745098039215, 0.29411764705882354, 0.807843137254902, 0.7058823529411765, 0.396078431372549, 0.788235294117647, 0.17647058823529413, 0.5411764705882353, 0.2784313725490196, 0.9490196078431372, 0.20784313725490197, 0.792156862745098, 0.6470588235294118, 0.09019607843137255, 0.16470588235294117, 0.7764705882352941, 0.10588235294117647, 0.6588235294117647, 0.0196078431372549, 0.6078431372549019, 0.8549019607843137, 0.7294117647058823, 0.043137254901960784, 0.6196078431372549, 0.7568627450980392, 0.8745098039215686, 0.5803921568627451, 0.0196078431372549, 0.2901960784313726, 0.9058823529411765, 0.4823529411764706, 0.6862745098039216, 0.3607843137254902, 0.2235294117647059, 0.7529411764705882, 0.0392156862745098, 0.807843137254902, 0.5529411764705883, 0.7411764705882353, 0.615686274509804, 0.3764705882352941, 0.9215686274509803, 0.7568627450980392, 0.8823529411764706, 0.7176470588235294, 0.15294117647058825, 0.21176470588235294, 0.8901960784313725, 0.5882352941176471, 0.1411764705882353, 0.5372549019607843, 0.8588235294117647, 0.6862745098039216, 0.592156862745098, 0.14901960784313725, 0.2549019607843137, 0.8627450980392157, 0.11764705882352941, 0.5411764705882353, 0.7568627450980392, 0.1411764705882353, 0.5137254901960784, 0.8588235294117647, 0.2784313725490196, 0.17254901960784313, 0.4980392156862745, 0.7647058823529411, 0.9098039215686274, 0.5529411764705883, 0.00392156862745098, 0.6470588235294118, 0.49411764705882355, 0.23921568627450981, 0.3333333333333333, 0.6666666666666666, 0.13725490196078433, 0.9137254901960784, 0.01568627450980392, 0.5607843137254902, 0.09411764705882353, 0.1450980392156863, 0.8235294117647058, 0.9607843137254902, 0.4627450980392157, 0.8823529411764706, 0.396078431372549, 0.49019607843137253, 0.8235294117647058, 0.6352941176470588, 0.7647058823529411, 0.22745098039215686, 0.8313725490196079, 0.6039215686274509, 0.788235294117647, 0.27058823529411763, 0.5686274509803921, 0.7058823529411765, 0.0, 0.30196078431372547, 0.14901960784313725, 0.2235294117647059, 0.6823529411764706, 0.4117647058823529, 0.08235294117647059, 1.0, 0.4470588235294118, 0.1411764705882353, 0.6274509803921569, 0.807843137254902, 0.2784313725490196, 0.984313725490196, 0.5490196078431373, 0.40784313725490196, 0.1450980392156863, 0.5254901960784314, 0.3254901960784314, 0.8745098039215686, 0.27450980392156865, 0.803921568627451, 0.00784313725490196, 0.1803921568627451, 0.8274509803921568, 0.13333333333333333, 0.5411764705882353, 0.9607843137254902, 0.8, 0.11764705882352941, 0.5098039215686274, 0.1803921568627451, 0.6196078431372549, 0.45098039215686275, 0.9058823529411765, 0.07058823529411765, 0.5098039215686274, 0.9215686274509803, 0.7372549019607844, 0.8352941176470589, 0.12941176470588237, 0.054901960784313725, 0.43529411764705883, 0.9294117647058824, 0.24313725490196078, 0.7176470588235294, 0.9647058823529412, 0.20392156862745098, 0.3803921568627451, 0.9254901960784314, 0.4588235294117647, 0.23529411764705882, 0.15294117647058825, 0.5529411764705883, 0.3176470588235294, 0.2627450980392157, 0.12941176470588237, 0.1803921568627451, 0.9647058823529412, 0.7333333333333333, 0.615686274509804, 0.19607843137254902, 0.054901960784313725, 0.5294117647058824, 0.9450980392156862, 0.6039215686274509, 0.6705882352941176, 0.09411764705882353, 0.4549019607843137, 0.9764705882352941, 0.12156862745098039, 0.8352941176470589, 0.43137254901960786, 0.5803921568627451, 0.043137254901960784, 0.403921568627451, 1.0, 0.3058823529411765, 0.4627450980392157, 0.0784313725490196, 0.34509803921568627, 0.6470588235294118, 0.2901960784313726, 0.21176470588235294, 0.8156862745098039, 0.44313725490196076, 0.06666666666666667, 0.7843137254901961, 0.6705882352941176, 0.4, 0.9882352941176471, 0.3137254901960784, 0.7098039215686275, 0.3764705882352941, 0.5686274509803921, 0.6705882352941176, 0.8235294117647058, 0.23529411764705882, 0.6235294117647059, 0.34901960784313724, 0.9764705882352941, 0.7568627450980392, 0.10980392156862745, 0.8745098039215686, 0.9411764705882353, 0.7764705882352941, 0.5137254901960784, 0.4, 0.7411764705882353, 0.611764705882353, 0.28627450980392155, 0.6980392156862745, 0.5058823529411764, 0.0, 0.30196078431372547, 0.592156862745098, 0.047058823529411764, 0.24313725490196078, 0.5647058823529412, 0.34901960784313724, 0.00784313725490196, 0.9450980392156862, 0.12156862745098039, 0.3333333333333333, 0.43137254901960786, 0.9803921568627451, 0.13725490196078433, 0.8901960784313725, 0.5098039215686274, 0.6352941176470588, 0.8, 0.9725490196078431, 0.03529411764705882, 0.4980392156862745, 0.24705882352941178, 0.7843137254901961, 0.8666666666666667, 0.35294117647058826, 0.6980392156862745, 0.047058823529411764, 0.43529411764705883, 0.08235294117647059, 0.7803921568627451, 0.6862745098039216, 0.24313725490196078, 0.9686274509803922, 0.7450980392156863, 0.16862745098039217, 0.5098039215686274, 0.6705882352941176, 0.9607843137254902, 0.3764705882352941, 0.24313725490196078, 0.8862745098039215, 0.03137254901960784, 0.7372549019607844, 0.4470588235294118, 0.8549019607843137, 0.6901960784313725, 0.9725490196078431, 0.00392156862745098, 0.5490196078431373, 0.2196078431372549, 0.6666666666666666, 0.3215686274509804, 0.023529411764705882, 0.23529411764705882, 0.47843137254901963, 0.35294117647058826, 0.6980392156862745, 0.5215686274509804, 0.29411764705882354, 0.8588235294117647, 0.4117647058823529, 0.4823529411764706, 0.5411764705882353, 0.7058823529411765, 0.8235294117647058, 0.08235294117647059, 0.3411764705882353, 0.6823529411764706, 0.8862745098039215, 0.43137254901960786, 0.9254901960784314, 0.6549019607843137, 0.403921568627451, 0.5019607843137255, 0.10588235294117647, 0.3411764705882353, 0.7764705882352941, 0.26666666666666666, 0.8784313725490196, 0.11764705882352941, 0.30196078431372547, 0.39215686274509803, 0.2, 0.9019607843137255, 0.24705882352941178, 0.3176470588235294, 0.4980392156862745, 0.1803921568627451, 0.09411764705882353, 0.2549019607843137, 0.6549019607843137, 0.5450980392156862, 0.7803921568627451, 0.6784313725490196, 0.9215686274509803, 0.8, 0.4823529411764706, 0.043137254901960784, 0.7450980392156863, 0.3686274509803922, 0.9607843137254902, 0.5019607843137255, 0.6078431372549019, 0.19215686274509805, 0.8980392156862745, 0.054901960784313725, 0.25098039215686274, 0.6235294117647059, 0.00784313725490196, 0.8941176470588236, 0.09019607843137255, 0.9921568627450981, 0.0392156862745098, 0.7215686274509804, 0.19215686274509805, 0.8156862745098039, 0.4, 0.16470588235294117, 0.5686274509803921, 0.37254901960784315, 0.043137254901960784, 0.8431372549019608, 0.18823529411764706, 1.0, 0.35294117647058826, 0.792156862745098, 0.8980392156862745, 0.20392156862745098, 0.6392156862745098, 0.7372549019607844, 0.13725490196078433, 0.7843137254901961, 0.9725490196078431, 0.0784313725490196, 0.8941176470588236, 0.41568627450980394, 0.6627450980392157, 0.5490196078431373, 0.7215686274509804, 0.03137254901960784, 0.19607843137254902, 0.6666666666666666, 0.34901960784313724, 0.23921568627450981, 0.4235294117647059, 0.09803921568627451, 0.3333333333333333, 0.7607843137254902, 0.611764705882353, 0.1568627450980392, 0.7176470588235294, 0.5254901960784314, 0.20784313725490197, 0.5686274509803921, 0.8392156862745098, 0.9058823529411765, 0.49019607843137253, 0.30980392156862746, 0.8549019607843137, 0.6352941176470588, 0.07450980392156863, 0.592156862745098, 0.396078431372549, 0.7176470588235294, 0.10980392156862745, 0.3058823529411765, 0.44313725490196076, 0.6431372549019608, 0.7019607843137254, 0.33725490196078434, 0.2823529411764706, 0.596078431372549, 0.0784313725490196, 0.38823529411764707, 0.3176470588235294, 0.7450980392156863, 0.12549019607843137, 0.9490196078431372, 0.8392156862745098, 0.5843137254901961, 0.44313725490196076, 0.9921568627450981, 0.6352941176470588, 0.8980392156862745, 0.596078431372549, 0.8196078431372549, 0.1411764705882353, 0.00392156862745098, 0.611764705882353, 0.06666666666666667, 0.8901960784313725, 0.13725490196078433, 0.29411764705882354, 0.5764705882352941, 0.7490196078431373, 0.9686274509803922, 0.0, 0.20784313725490197, 0.5215686274509804, 0.8196078431372549, 0.7176470588235294, 0.23529411764705882, 0.8470588235294118, 0.5568627450980392, 0.9725490196078431, 0.6392156862745098, 0.42745098039215684, 0.7098039215686275, 0.48627450980392156, 0.8509803921568627, 0.5764705882352941, 0.7215686274509804, 0.6352941176470588, 0.0, 0.792156862745098, 0.6980392156862745, 0.9568627450980393, 0.8588235294117647, 0.4823529411764706, 0.11372549019607843, 0.3803921568627451, 0.03137254901960784, 0.24313725490196078, 0.4117647058823529, 0.9764705882352941, 0.12549019607843137, 0.8980392156862745, 0.5568627450980392, 0.0, 0.7137254901960784, 0.28627450980392155, 0.3411764705882353, 0.47058823529411764, 0.5803921568627451, 0.803921568627451, 0.9333333333333333, 0.47843137254901963, 0.7529411764705882, 0.32941176470588235, 0.396078431372549, 0.4627450980392157, 0.5254901960784314, 0.29411764705882354, 0.06274509803921569, 0.6784313725490196, 0.45098039215686275, 0.7411764705882353, 0.21176470588235294, 0.615686274509804, 0.30196078431372547, 0.6862745098039216, 0.06274509803921569, 0.24313725490196078, 0.4745098039215686, 0.40784313725490196, 0.0784313725490196, 0.5333333333333333, 0.9215686274509803, 0.3686274509803922, 0.43529411764705883, 0.7019607843137254, 0.28627450980392155, 0.5019607843137255, 0.7490196078431373, 0.1843137254901961, 0.2627450980392157, 0.8627450980392157, 0.4823529411764706, 0.615686274509804, 0.8117647058823529, 0.058823529411764705, 0.9568627450980393, 0.7294117647058823, 0.47843137254901963, 0.8862745098039215, 0.5607843137254902, 0.3803921568627451, 0.9529411764705882, 0.2901960784313726, 0.09803921568627451, 0.9372549019607843, 0.396078431372549, 0.12941176470588237, 0.23529411764705882, 0.7372549019607844, 0.1803921568627451, 0.0196078431372549, 0.45098039215686275, 0.3568627450980392, 0.8352941176470589, 0.03529411764705882, 0.23137254901960785, 0.5686274509803921, 0.8470588235294118, 0.7725490196078432, 0.5333333333333333, 0.09803921568627451, 0.49411764705882355, 0.9333333333333333, 0.16470588235294117, 0.23529411764705882, 0.8941176470588236, 0.48627450980392156, 0.8156862745098039, 0.42745098039215684, 0.27058823529411763, 0.3764705882352941, 0.1568627450980392, 0.7725490196078432, 0.11372549019607843, 0.3058823529411765, 0.03137254901960784, 0.1843137254901961, 0.38823529411764707, 0.9803921568627451, 0.6666666666666666, 0.32941176470588235, 0.803921568627451, 0.2549019607843137, 0.43529411764705883, 0.9450980392156862, 0.1803921568627451, 0.5019607843137255, 0.38823529411764707, 0.6392156862745098, 0.7803921568627451, 0.0784313725490196, 0.35294117647058826, 0.03529411764705882, 0.9372549019607843, 0.44313725490196076, 0.15294117647058825, 0.00392156862745098, 0.3764705882352941, 0.17254901960784313, 0.8196078431372549, 0.027450980392156862, 0.9607843137254902, 0.34509803921568627, 0.0784313725490196, 0.41568627450980394, 0.8745098039215686, 0.5411764705882353, 0.3568627450980392, 0.6039215686274509, 0.2901960784313726, 0.8117647058823529, 0.19607843137254902, 0.8784313725490196, 0.611764705882353, 0.7176470588235294, 0.17647058823529413, 0.5803921568627451, 0.6745098039215687, 0.3254901960784314, 0.1843137254901961, 0.4196078431372549, 0.9254901960784314, 0.10980392156862745, 0.8470588235294118, 0.7372549019607844, 0.1607843137254902, 0.4235294117647059, 0.2196078431372549, 0.12549019607843137, 0.5411764705882353, 0.1843137254901961, 0.6078431372549019, 0.8627450980392157, 0.13333333333333333, 0.9529411764705882, 0.5882352941176471, 0.9176470588235294, 0.26666666666666666, 0.0784313725490196, 0.9764705882352941, 0.4823529411764706, 0.12941176470588237, 0.43529411764705883, 0.5490196078431373, 0.8745098039215686, 0.1607843137254902, 0.6627450980392157, 0.9686274509803922, 0.32941176470588235, 0.23529411764705882, 0.8392156862745098, 0.18823529411764706, 0.6470588235294118, 0.8666666666666667, 0.13333333333333333, 0.6, 1.0, 0.3764705882352941, 0.09803921568627451, 0.9254901960784314, 0.4, 0.2980392156862745, 0.5294117647058824, 0.8509803921568627, 0.16862745098039217, 0.6549019607843137, 0.26666666666666666, 0.19607843137254902, 0.050980392156862744, 0.8313725490196079, 0.6431372549019608, 0.4745098039215686, 0.00784313725490196, 0.7607843137254902, 0.6666666666666666, 0.3333333333333333, 0.615686274509804, 0.9529411764705882, 0.5647058823529412, 0.9176470588235294, 0.27058823529411763, 0.7764705882352941, 1.0, 0.4823529411764706, 0.9098039215686274, 0.1568627450980392, 0.043137254901960784, 0.9803921568627451, 0.20392156862745098, 0.8235294117647058, 0.41568627450980394, 0.6509803921568628, 0.7803921568627451, 0.047058823529411764, 0.5803921568627451, 0.18823529411764706, 0.6352941176470588, 0.0392156862745098, 0.7176470588235294, 0.8823529411764706, 0.6588235294117647, 0.403921568627451, 0.803921568627451, 0.5411764705882353, 0.7333333333333333, 0.25882352941176473, 0.5019607843137255, 0.7529411764705882, 0.18823529411764706];
-}
\ No newline at end of file
+        // Initial binary pattern, a tenth of the pixels at random
+        let initial_ones = (n/10).max(1);
+        let mut pattern = vec![false; n];
+        let mut energy = vec![0.0; n];
+        let mut ones = 0;
+        while ones < initial_ones {
+            let p = rng.gen_range(0..n);
+            if !pattern[p] {
+                pattern[p] = true;
+                splat(&mut energy, p, 1.0);
+                ones += 1;
+            }
+        }
+        // Move points from clusters into voids until that stops changing anything
+        if ones < n {
+            loop {
+                let cluster = tightest_cluster(&pattern, &energy);
+                pattern[cluster] = false;
+                splat(&mut energy, cluster, -1.0);
+                let void = largest_void(&pattern, &energy);
+                pattern[void] = true;
+                splat(&mut energy, void, 1.0);
+                if void == cluster { break; }
+            }
+        }
+
+        let mut rank = vec![0; n];
+        let (initial_pattern, initial_energy) = (pattern.clone(), energy.clone());
+
+        // Phase 1: remove the initial points from the tightest clusters down
+        while ones > 0 {
+            let cluster = tightest_cluster(&pattern, &energy);
+            pattern[cluster] = false;
+            splat(&mut energy, cluster, -1.0);
+            ones -= 1;
+            rank[cluster] = ones;
+        }
+
+        // Phases 2 and 3: fill the largest voids until every pixel is set. Past the half way point
+        // this is the same as removing the tightest clusters of empty pixels.
+        pattern = initial_pattern;
+        energy = initial_energy;
+        ones = initial_ones;
+        while ones < n {
+            let void = largest_void(&pattern, &energy);
+            pattern[void] = true;
+            splat(&mut energy, void, 1.0);
+            rank[void] = ones;
+            ones += 1;
+        }
+
+        rank.into_iter().map(|r| r as f32/n as f32).collect()
+    }
+
+    // Mitchell's best candidate sampling in the unit disc
+    fn best_candidate_disc(count: usize) -> Vec<(f32, f32)> {
+        let mut rng = rand::thread_rng();
+        let mut points: Vec<(f32, f32)> = Vec::with_capacity(count);
+        while points.len() < count {
+            let mut best = (0.0, 0.0);
+            let mut best_dist = -1.0;
+            for _ in 0..(10*points.len() + 1) {
+                let r = rng.gen::<f32>().sqrt();
+                let theta = 2.0*PI*rng.gen::<f32>();
+                let c = (r*theta.cos(), r*theta.sin());
+                let dist = points.iter()
+                    .map(|p| (p.0 - c.0).powi(2) + (p.1 - c.1).powi(2))
+                    .fold(f32::INFINITY, f32::min);
+                if dist > best_dist {
+                    best = c;
+                    best_dist = dist;
+                }
+            }
+            points.push(best);
+        }
+        points
+    }
+}
+
+pub use self::filters::Denoiser;
+
+mod filters{
+    use crate::vector::vec3;
+    use crate::film::Film;
+    use vec3 as colour;
+
+    // Post process filters that run over the film, before tone mapping
+    #[derive(Copy, Clone, Debug)]
+    pub enum Denoiser {
+        Atrous(AtrousFilter),
+        NonLocalMeans(NlmFilter),
+        JointBilateral(BilateralFilter),
+    }
+
+    impl Denoiser {
+        pub fn from_name(name: &str) -> Option<Self> {
+            match name {
+                "atrous" => Some(Denoiser::Atrous(AtrousFilter::default())),
+                "nlm" => Some(Denoiser::NonLocalMeans(NlmFilter::default())),
+                "bilateral" => Some(Denoiser::JointBilateral(BilateralFilter::default())),
+                _ => None,
+            }
+        }
+
+        pub fn feature_weights_mut(&mut self) -> &mut FeatureWeights {
+            match self {
+                Denoiser::Atrous(f) => &mut f.features,
+                Denoiser::NonLocalMeans(f) => &mut f.features,
+                Denoiser::JointBilateral(f) => &mut f.features,
+            }
+        }
+
+        // Returns the filtered beauty, in scanline order
+        pub fn apply(&self, film: &Film) -> Vec<colour> {
+            let buffers = Buffers::from_film(film);
+            match self {
+                Denoiser::Atrous(f) => f.apply(&buffers),
+                Denoiser::NonLocalMeans(f) => f.apply(&buffers),
+                Denoiser::JointBilateral(f) => f.apply(&buffers),
+            }
+        }
+    }
+
+    // How strongly the guide buffers stop the filter from mixing two pixels
+    #[derive(Copy, Clone, Debug)]
+    pub struct FeatureWeights {
+        // Exponent applied to the normal dot product. Higher is stricter.
+        pub sigma_normal: f32,
+        // Depth tolerance. Scaled by the expected depth change, so it is unitless.
+        pub sigma_depth: f32,
+        pub sigma_albedo: f32,
+    }
+
+    impl FeatureWeights {
+        fn weight(&self, b: &Buffers, p: usize, q: usize, expected_depth_change: f32) -> f32 {
+            let w_normal = if b.normal[p].length_squared() == 0.0 && b.normal[q].length_squared() == 0.0 {
+                1.0
+            } else {
+                vec3::dot(&b.normal[p], &b.normal[q]).max(0.0).powf(self.sigma_normal)
+            };
+            let w_depth = (-(b.depth[p] - b.depth[q]).abs()/(self.sigma_depth*expected_depth_change + 1e-4)).exp();
+            let w_albedo = (-(b.albedo[p] - b.albedo[q]).length()/self.sigma_albedo).exp();
+            w_normal*w_depth*w_albedo
+        }
+    }
+
+    // Per pixel data read from the film, in scanline order
+    struct Buffers {
+        width: usize,
+        height: usize,
+        beauty: Vec<colour>,
+        normal: Vec<vec3>,
+        depth: Vec<f32>,
+        depth_gradient: Vec<f32>,
+        albedo: Vec<colour>,
+        samples: Vec<f32>,
+        variance: Vec<f32>,
+    }
+
+    impl Buffers {
+        fn from_film(film: &Film) -> Self {
+            let (w, h) = (film.width, film.height);
+            let n = w*h;
+
+            let read3 = |name: &str| -> Vec<vec3> {
+                match film.layer(name) {
+                    Some(l) => l.data.chunks(3).map(|c| vec3::new(c[0], c[1], c[2])).collect(),
+                    None => vec![vec3::new(0.0, 0.0, 0.0); n],
+                }
+            };
+            let read1 = |name: &str, default: f32| -> Vec<f32> {
+                match film.layer(name) {
+                    Some(l) => l.data.clone(),
+                    None => vec![default; n],
+                }
+            };
+
+            let depth = read1("depth", 0.0);
+            let mut depth_gradient = vec![0.0; n];
+            for y in 0..h {
+                for x in 0..w {
+                    let dx = (depth[y*w + (x+1).min(w-1)] - depth[y*w + x.saturating_sub(1)]).abs()*0.5;
+                    let dy = (depth[(y+1).min(h-1)*w + x] - depth[y.saturating_sub(1)*w + x]).abs()*0.5;
+                    depth_gradient[y*w + x] = dx.max(dy);
+                }
+            }
+
+            Buffers{
+                width: w,
+                height: h,
+                beauty: read3("beauty"),
+                normal: read3("normal").into_iter().map(|v| if v.length_squared() > 0.0 {vec3::unit_vector(v)} else {v}).collect(),
+                depth: depth,
+                depth_gradient: depth_gradient,
+                albedo: read3("albedo"),
+                samples: read1("samples", 1.0),
+                variance: read1("variance", 0.0),
+            }
+        }
+
+        // Divisor that turns the beauty into illumination, so texture detail isn't blurred.
+        // Pixels without an albedo (sky) are filtered as-is.
+        fn demodulation(&self) -> Vec<colour> {
+            self.albedo.iter().map(|a| colour::new(
+                if a.x > 0.01 {a.x} else {1.0}, if a.y > 0.01 {a.y} else {1.0}, if a.z > 0.01 {a.z} else {1.0})).collect()
+        }
+
+        // Variance of the luminance of `img`. Uses the per pixel estimate where there are enough samples for it
+        // to be reliable, otherwise the variance over a 7x7 window.
+        fn estimate_variance(&self, img: &[colour]) -> Vec<f32> {
+            let (w, h) = (self.width, self.height);
+            let mut variance = vec![0.0; w*h];
+            for y in 0..h {
+                for x in 0..w {
+                    let idx = y*w + x;
+                    if self.samples[idx] >= MIN_SAMPLES_FOR_VARIANCE {
+                        variance[idx] = self.variance[idx];
+                        continue;
+                    }
+                    let (mut m1, mut m2, mut count) = (0.0, 0.0, 0.0);
+                    for yy in y.saturating_sub(3)..(y+4).min(h) {
+                        for xx in x.saturating_sub(3)..(x+4).min(w) {
+                            let l = img[yy*w + xx].luminance();
+                            m1 += l;
+                            m2 += l*l;
+                            count += 1.0;
+                        }
+                    }
+                    m1 /= count;
+                    m2 /= count;
+                    variance[idx] = (m2 - m1*m1).max(0.0);
+                }
+            }
+            variance
+        }
+    }
+
+    // Below this many samples the per pixel variance is too unreliable, so it is estimated spatially
+    const MIN_SAMPLES_FOR_VARIANCE: f32 = 4.0;
+
+    /////////////////////////// A-trous /////////////////////////
+
+    // Edge avoiding a-trous wavelet filter, as used in SVGF (Schied et al. 2017).
+    // Filters the illumination with a 5x5 B3 spline kernel of growing stride, weighted by
+    // how similar neighbours are in luminance, normal, depth and albedo.
+    #[derive(Copy, Clone, Debug)]
+    pub struct AtrousFilter {
+        pub iterations: usize,
+        // Luminance tolerance, in standard deviations of the noise
+        pub sigma_luminance: f32,
+        pub features: FeatureWeights,
+    }
+
+    impl Default for AtrousFilter {
+        fn default() -> Self {
+            AtrousFilter{iterations: 5, sigma_luminance: 4.0,
+                features: FeatureWeights{sigma_normal: 128.0, sigma_depth: 1.0, sigma_albedo: 0.1}}
+        }
+    }
+
+    const KERNEL: [f32; 5] = [1.0/16.0, 1.0/4.0, 3.0/8.0, 1.0/4.0, 1.0/16.0];
+
+    impl AtrousFilter {
+        fn apply(&self, b: &Buffers) -> Vec<colour> {
+            let demod = b.demodulation();
+            let mut illum: Vec<colour> = b.beauty.iter().zip(demod.iter())
+                .map(|(c, a)| colour::new(c.x/a.x, c.y/a.y, c.z/a.z)).collect();
+            let mut variance = b.estimate_variance(&illum);
+
+            for i in 0..self.iterations {
+                let (next_illum, next_variance) = self.iteration(&illum, &variance, b, 1 << i);
+                illum = next_illum;
+                variance = next_variance;
+            }
+
+            illum.iter().zip(demod.iter()).map(|(l, a)| *l*(*a)).collect()
+        }
+
+        fn iteration(&self, illum: &[colour], variance: &[f32], b: &Buffers, step: usize) -> (Vec<colour>, Vec<f32>) {
+            let (w, h) = (b.width, b.height);
+            let mut out_illum = vec![colour::new(0.0, 0.0, 0.0); w*h];
+            let mut out_variance = vec![0.0; w*h];
+            let step_i = step as isize;
+
+            for y in 0..h {
+                for x in 0..w {
+                    let p = y*w + x;
+
+                    // 3x3 blurred variance makes the luminance weight less noisy
+                    let mut blurred_var = 0.0;
+                    let mut total_k = 0.0;
+                    for dy in -1..=1isize {
+                        for dx in -1..=1isize {
+                            let (qx, qy) = (x as isize + dx, y as isize + dy);
+                            if qx < 0 || qy < 0 || qx >= w as isize || qy >= h as isize { continue; }
+                            let k = KERNEL[(dx+2) as usize]*KERNEL[(dy+2) as usize];
+                            blurred_var += variance[qy as usize*w + qx as usize]*k;
+                            total_k += k;
+                        }
+                    }
+                    let lum_tolerance = self.sigma_luminance*(blurred_var/total_k).sqrt() + 1e-6;
+
+                    let lum_p = illum[p].luminance();
+
+                    let mut sum = colour::new(0.0, 0.0, 0.0);
+                    let mut sum_var = 0.0;
+                    let mut sum_w = 0.0;
+                    for ky in 0..5 {
+                        for kx in 0..5 {
+                            let qx = x as isize + (kx as isize - 2)*step_i;
+                            let qy = y as isize + (ky as isize - 2)*step_i;
+                            if qx < 0 || qy < 0 || qx >= w as isize || qy >= h as isize { continue; }
+                            let q = qy as usize*w + qx as usize;
+
+                            // Expected depth change is the local gradient times the distance to the tap
+                            let dist = (kx as isize - 2).abs().max((ky as isize - 2).abs()) as f32*step as f32;
+                            let w_lum = (-(lum_p - illum[q].luminance()).abs()/lum_tolerance).exp();
+                            let weight = KERNEL[kx]*KERNEL[ky]*w_lum*self.features.weight(b, p, q, b.depth_gradient[p]*dist);
+
+                            sum += illum[q]*weight;
+                            sum_var += variance[q]*weight*weight;
+                            sum_w += weight;
+                        }
+                    }
+
+                    // The center pixel always has weight, so sum_w > 0
+                    out_illum[p] = sum/sum_w;
+                    out_variance[p] = sum_var/(sum_w*sum_w);
+                }
+            }
+            (out_illum, out_variance)
+        }
+    }
+
+    /////////////////////////// Non-local means /////////////////////////
+
+    // Variance aware non-local means (Rousselle et al. 2012). Pixels are averaged with the pixels in the search
+    // window whose surrounding patch looks alike, after accounting for how noisy both patches are.
+    #[derive(Copy, Clone, Debug)]
+    pub struct NlmFilter {
+        pub search_radius: usize,
+        pub patch_radius: usize,
+        // Damping factor. Higher blurs more.
+        pub k: f32,
+        pub features: FeatureWeights,
+    }
+
+    impl Default for NlmFilter {
+        fn default() -> Self {
+            NlmFilter{search_radius: 5, patch_radius: 1, k: 0.45,
+                features: FeatureWeights{sigma_normal: 32.0, sigma_depth: 0.05, sigma_albedo: 0.2}}
+        }
+    }
+
+    impl NlmFilter {
+        fn apply(&self, b: &Buffers) -> Vec<colour> {
+            let (w, h) = (b.width, b.height);
+            let demod = b.demodulation();
+            let illum: Vec<colour> = b.beauty.iter().zip(demod.iter())
+                .map(|(c, a)| colour::new(c.x/a.x, c.y/a.y, c.z/a.z)).collect();
+            let variance = b.estimate_variance(&illum);
+
+            let mut sum = vec![colour::new(0.0, 0.0, 0.0); w*h];
+            let mut sum_w = vec![0.0; w*h];
+            let r = self.search_radius as isize;
+            let k2 = self.k*self.k;
+
+            // Loop over offsets rather than pixels, so each patch distance is a box filter over a per pixel distance image
+            let mut dist = vec![0.0; w*h];
+            for oy in -r..=r {
+                for ox in -r..=r {
+                    for y in 0..h {
+                        for x in 0..w {
+                            let p = y*w + x;
+                            let (qx, qy) = ((x as isize + ox).max(0).min(w as isize - 1) as usize, (y as isize + oy).max(0).min(h as isize - 1) as usize);
+                            let q = qy*w + qx;
+                            let (vp, vq) = (variance[p], variance[q]);
+                            let diff = illum[p] - illum[q];
+                            let d2 = (diff.x*diff.x + diff.y*diff.y + diff.z*diff.z)/3.0;
+                            dist[p] = (d2 - (vp + vp.min(vq)))/(1e-10 + k2*(vp + vq));
+                        }
+                    }
+                    let patch_dist = box_filter(&dist, w, h, self.patch_radius);
+
+                    for y in 0..h {
+                        for x in 0..w {
+                            let (qx, qy) = (x as isize + ox, y as isize + oy);
+                            if qx < 0 || qy < 0 || qx >= w as isize || qy >= h as isize { continue; }
+                            let p = y*w + x;
+                            let q = qy as usize*w + qx as usize;
+                            let weight = (-patch_dist[p].max(0.0)).exp()*self.features.weight(b, p, q, b.depth[p]);
+                            sum[p] += illum[q]*weight;
+                            sum_w[p] += weight;
+                        }
+                    }
+                }
+            }
+
+            // Zero offset always has weight 1, so sum_w > 0
+            (0..w*h).map(|p| (sum[p]/sum_w[p])*demod[p]).collect()
+        }
+    }
+
+    // Mean over a (2r+1)^2 window, clamped at the borders. Separable, so the cost doesn't depend on r.
+    fn box_filter(img: &[f32], w: usize, h: usize, r: usize) -> Vec<f32> {
+        let mut tmp = vec![0.0; w*h];
+        let mut out = vec![0.0; w*h];
+        for y in 0..h {
+            for x in 0..w {
+                let (x0, x1) = (x.saturating_sub(r), (x+r).min(w-1));
+                tmp[y*w + x] = img[y*w + x0..=y*w + x1].iter().sum::<f32>()/(x1 - x0 + 1) as f32;
+            }
+        }
+        for y in 0..h {
+            let (y0, y1) = (y.saturating_sub(r), (y+r).min(h-1));
+            for x in 0..w {
+                out[y*w + x] = (y0..=y1).map(|yy| tmp[yy*w + x]).sum::<f32>()/(y1 - y0 + 1) as f32;
+            }
+        }
+        out
+    }
+
+    /////////////////////////// Joint bilateral /////////////////////////
+
+    // Gaussian blur that doesn't cross edges in the colour, normal, depth or albedo buffers
+    #[derive(Copy, Clone, Debug)]
+    pub struct BilateralFilter {
+        pub radius: usize,
+        pub sigma_spatial: f32,
+        pub sigma_colour: f32,
+        pub features: FeatureWeights,
+    }
+
+    impl Default for BilateralFilter {
+        fn default() -> Self {
+            BilateralFilter{radius: 4, sigma_spatial: 2.0, sigma_colour: 1.0,
+                features: FeatureWeights{sigma_normal: 32.0, sigma_depth: 0.05, sigma_albedo: 0.2}}
+        }
+    }
+
+    impl BilateralFilter {
+        fn apply(&self, b: &Buffers) -> Vec<colour> {
+            let (w, h) = (b.width, b.height);
+            let demod = b.demodulation();
+            let illum: Vec<colour> = b.beauty.iter().zip(demod.iter())
+                .map(|(c, a)| colour::new(c.x/a.x, c.y/a.y, c.z/a.z)).collect();
+
+            let r = self.radius as isize;
+            let mut out = vec![colour::new(0.0, 0.0, 0.0); w*h];
+            for y in 0..h {
+                for x in 0..w {
+                    let p = y*w + x;
+                    let mut sum = colour::new(0.0, 0.0, 0.0);
+                    let mut sum_w = 0.0;
+                    for dy in -r..=r {
+                        for dx in -r..=r {
+                            let (qx, qy) = (x as isize + dx, y as isize + dy);
+                            if qx < 0 || qy < 0 || qx >= w as isize || qy >= h as isize { continue; }
+                            let q = qy as usize*w + qx as usize;
+
+                            let w_spatial = (-((dx*dx + dy*dy) as f32)/(2.0*self.sigma_spatial*self.sigma_spatial)).exp();
+                            let w_colour = (-(illum[p] - illum[q]).length_squared()/(2.0*self.sigma_colour*self.sigma_colour)).exp();
+                            let weight = w_spatial*w_colour*self.features.weight(b, p, q, b.depth[p]);
+                            sum += illum[q]*weight;
+                            sum_w += weight;
+                        }
+                    }
+                    out[p] = (sum/sum_w)*demod[p];
+                }
+            }
+            out
+        }
+    }
+}
@@ -0,0 +1,160 @@
+pub use self::film::Film;
+pub use self::film::Layer;
+pub use self::film::Aovs;
+
+mod film{
+    use crate::vector::vec3;
+    use crate::ray::Ray;
+    use crate::geometry::HitRecord;
+    use vec3 as colour;
+    use vec3 as point3;
+
+    // A named group of float channels, interleaved per pixel. Row 0 is the top of the image.
+    pub struct Layer {
+        pub name: String,
+        pub channels: Vec<String>,
+        pub data: Vec<f32>,
+    }
+
+    impl Layer {
+        pub fn new(name: &str, channels: &[&str], width: usize, height: usize) -> Self {
+            Self {
+                name: name.to_string(),
+                channels: channels.iter().map(|c| c.to_string()).collect(),
+                data: vec![0.0; width*height*channels.len()],
+            }
+        }
+
+        pub fn num_channels(&self) -> usize {
+            self.channels.len()
+        }
+
+        // All values of one channel, in scanline order
+        pub fn channel(&self, c: usize) -> Vec<f32> {
+            self.data.iter().skip(c).step_by(self.num_channels()).cloned().collect()
+        }
+    }
+
+    // Floating point frame buffer. Holds the linear beauty image plus any number of extra layers.
+    pub struct Film {
+        pub width: usize,
+        pub height: usize,
+        pub layers: Vec<Layer>,
+    }
+
+    impl Film {
+        pub fn new(width: usize, height: usize) -> Self {
+            Self {width: width, height: height, layers: vec![Layer::new("beauty", &["R", "G", "B"], width, height)]}
+        }
+
+        pub fn add_layer(&mut self, name: &str, channels: &[&str]) {
+            if self.layer(name).is_none() {
+                self.layers.push(Layer::new(name, channels, self.width, self.height));
+            }
+        }
+
+        pub fn layer(&self, name: &str) -> Option<&Layer> {
+            self.layers.iter().find(|l| l.name == name)
+        }
+
+        pub fn layer_mut(&mut self, name: &str) -> Option<&mut Layer> {
+            self.layers.iter_mut().find(|l| l.name == name)
+        }
+
+        pub fn get(&self, name: &str, x: usize, y: usize) -> &[f32] {
+            let layer = self.layer(name).unwrap_or_else(|| panic!("Film has no layer '{}'", name));
+            let n = layer.num_channels();
+            let idx = (y*self.width + x)*n;
+            &layer.data[idx..idx+n]
+        }
+
+        pub fn set(&mut self, name: &str, x: usize, y: usize, values: &[f32]) {
+            let width = self.width;
+            let layer = self.layer_mut(name).unwrap_or_else(|| panic!("Film has no layer '{}'", name));
+            let n = layer.num_channels();
+            let idx = (y*width + x)*n;
+            layer.data[idx..idx+n].copy_from_slice(&values[..n]);
+        }
+
+        pub fn beauty(&self, x: usize, y: usize) -> colour {
+            let px = self.get("beauty", x, y);
+            colour::new(px[0], px[1], px[2])
+        }
+
+        pub fn set_beauty(&mut self, x: usize, y: usize, c: colour) {
+            self.set("beauty", x, y, &[c.x, c.y, c.z]);
+        }
+    }
+
+    // Arbitrary output variables, taken from the first hit of each camera ray
+    #[derive(Copy, Clone)]
+    pub struct Aovs {
+        // Distance along the camera ray. 0 if nothing was hit.
+        pub depth: f32,
+        pub normal: vec3,
+        pub albedo: colour,
+        pub position: point3,
+        // IDs are offset by one so that 0 means background
+        pub material_id: usize,
+        pub object_id: usize,
+    }
+
+    impl Default for Aovs {
+        fn default() -> Self {
+            Aovs{depth: 0.0, normal: vec3::new(0.0,0.0,0.0), albedo: colour::new(0.0,0.0,0.0),
+                 position: point3::new(0.0,0.0,0.0), material_id: 0, object_id: 0}
+        }
+    }
+
+    impl Aovs {
+        pub const LAYERS: [(&'static str, &'static [&'static str]); 6] = [
+            ("depth", &["Z"]),
+            ("normal", &["X", "Y", "Z"]),
+            ("albedo", &["R", "G", "B"]),
+            ("position", &["X", "Y", "Z"]),
+            ("material_id", &["id"]),
+            ("object_id", &["id"]),
+        ];
+
+        pub fn from_hit(ray: &Ray, hit_record: &HitRecord, albedo: colour) -> Self {
+            Aovs{
+                depth: hit_record.t*ray.dir.length(),
+                normal: hit_record.normal,
+                albedo: albedo,
+                position: hit_record.p,
+                material_id: hit_record.material_id + 1,
+                object_id: hit_record.object_id + 1,
+            }
+        }
+
+        // Sums the continuous values so they can be averaged over all samples of a pixel.
+        // IDs can't be averaged, so the first non-background one is kept.
+        pub fn accumulate(&mut self, other: &Aovs) {
+            self.depth += other.depth;
+            self.normal += other.normal;
+            self.albedo += other.albedo;
+            self.position += other.position;
+            if self.object_id == 0 {
+                self.material_id = other.material_id;
+                self.object_id = other.object_id;
+            }
+        }
+
+        pub fn add_layers(film: &mut Film) {
+            for (name, channels) in Aovs::LAYERS.iter() {
+                film.add_layer(name, channels);
+            }
+        }
+
+        pub fn write_to_film(&self, film: &mut Film, x: usize, y: usize, num_samples: usize) {
+            let scale = 1.0/num_samples.max(1) as f32;
+            let (n, a, p) = (self.normal*scale, self.albedo*scale, self.position*scale);
+            film.set("depth", x, y, &[self.depth*scale]);
+            film.set("normal", x, y, &[n.x, n.y, n.z]);
+            film.set("albedo", x, y, &[a.x, a.y, a.z]);
+            film.set("position", x, y, &[p.x, p.y, p.z]);
+            film.set("material_id", x, y, &[self.material_id as f32]);
+            film.set("object_id", x, y, &[self.object_id as f32]);
+        }
+    }
+}
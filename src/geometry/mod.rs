@@ -1,29 +1,49 @@
 pub use self::geometry::HitRecord;
 pub use self::geometry::Hittable;
+pub use self::geometry::Aabb;
 pub use self::geometry::Sphere;
 pub use self::geometry::Cube;
 pub use self::geometry::Plane;
+pub use self::geometry::{Quad, Disk};
+pub use self::geometry::{Mesh, TriangleIntersector};
+pub use self::shapes::{Cylinder, Cone, Torus, Capsule};
 pub use self::geometry::HittableList;
 
 pub use self::geometry::Material;
 pub use self::geometry::Metal;
 pub use self::geometry::Lambertian;
 pub use self::geometry::Dielectric;
+pub use self::geometry::DiffuseLight;
 
 mod geometry{
-    use rand::Rng;
     use crate::vector::vec3;
     use crate::ray::Ray;
-    use crate::denoising::BlueNoise;
+    use crate::sampling::{Sampler, IndependentSampler, cosine_hemisphere, uniform_sphere, tangent_frame};
+    use crate::lights::{Light, AreaLight};
+    use crate::sky::{Sky, PhysicalSky};
     use vec3 as point3;
     use vec3 as colour;
 
     ///////////////////////// Store information about ray hits /////////////////////////
+    #[derive(Copy, Clone)]
     pub struct HitRecord {
         pub p: point3,
+        // Shading normal, flipped to face the incoming ray
         pub normal: vec3,
+        // True surface normal, always pointing out of the object
+        pub geometric_normal: vec3,
         pub t: f32,
         pub front_face: bool,
+        // Surface parametrisation. In [0,1] except for planes, which use distances along the plane.
+        pub uv: (f32, f32),
+        // Only set for triangles
+        pub barycentrics: Option<(f32, f32)>,
+        // Number of ray-object intersection tests done to find this hit
+        pub intersection_tests: usize,
+
+        // Index of the object in the HittableList, and of its material (objects can share materials)
+        pub object_id: usize,
+        pub material_id: usize,
     }
 
     impl HitRecord{
@@ -34,13 +54,80 @@ mod geometry{
     }
 
     impl Default for HitRecord{
-        fn default() -> Self {HitRecord{p: point3::new(0.0,0.0,0.0), normal: vec3::new(0.0,0.0,0.0),
-                              t: 0.0, front_face: true}}
+        fn default() -> Self {HitRecord{p: point3::new(0.0,0.0,0.0), normal: vec3::new(0.0,0.0,0.0), geometric_normal: vec3::new(0.0,0.0,0.0),
+                              t: 0.0, front_face: true, uv: (0.0, 0.0), barycentrics: None, intersection_tests: 0, object_id: 0, material_id: 0}}
+    }
+
+    ///////////////////////// Axis aligned bounding box /////////////////////////
+    #[derive(Copy, Clone, Debug)]
+    pub struct Aabb {
+        pub min: point3,
+        pub max: point3,
+    }
+
+    impl Aabb{
+        // Smallest box containing both points
+        pub fn new(a: point3, b: point3) -> Self {
+            Aabb{min: point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)), max: point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z))}
+        }
+
+        pub fn from_points(points: &[point3]) -> Option<Self> {
+            let first = Aabb::new(*points.first()?, *points.first()?);
+            Some(points.iter().fold(first, |b, &p| b.union(&Aabb::new(p, p))))
+        }
+
+        pub fn union(&self, other: &Aabb) -> Self {
+            Aabb::new(point3::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y), self.min.z.min(other.min.z)),
+                point3::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y), self.max.z.max(other.max.z)))
+        }
+
+        // Tight box around a circle, from how far it reaches along each axis
+        pub fn around_disk(center: point3, normal: vec3, radius: f32) -> Self {
+            let reach = |n: f32| radius*(1.0 - n*n).max(0.0).sqrt();
+            let extent = vec3::new(reach(normal.x), reach(normal.y), reach(normal.z));
+            Aabb::new(center - extent, center + extent)
+        }
+
+        // Whether the ray passes through the box somewhere between t_min and t_max (slab test)
+        pub fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> bool {
+            let (mut t0, mut t1) = (t_min, t_max);
+            for (min, max, o, d) in [(self.min.x, self.max.x, ray.origin.x, ray.dir.x),
+                (self.min.y, self.max.y, ray.origin.y, ray.dir.y), (self.min.z, self.max.z, ray.origin.z, ray.dir.z)] {
+                let inv_d = 1.0/d;
+                let (near, far) = ((min - o)*inv_d, (max - o)*inv_d);
+                let (near, far) = if inv_d < 0.0 {(far, near)} else {(near, far)};
+                // min/max skip the NaN of a ray lying in the slab's boundary plane
+                t0 = t0.max(near);
+                t1 = t1.min(far);
+                if t1 < t0 {
+                    return false;
+                }
+            }
+            true
+        }
     }
 
     ///////////////////////// Parent trait for all hittable geometry /////////////////////////
     pub trait Hittable: Sync + Send {
-        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, pixel_data: (usize,usize,usize)) -> Option<Ray>;
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>;
+        fn material(&self) -> &dyn Material;
+
+        // Surface area and uniformly distributed points on the surface (with the outward normal).
+        // Only shapes that implement these can be used as area lights.
+        fn area(&self) -> f32 {
+            0.0
+        }
+        fn sample_surface(&self, _u: (f32, f32)) -> Option<(point3, vec3)> {
+            None
+        }
+        // Centre of the shape and its area projected towards `from`, for treating it as a point light
+        fn point_approximation(&self, _from: point3) -> Option<(point3, f32)> {
+            None
+        }
+        // None for unbounded shapes
+        fn bounding_box(&self) -> Option<Aabb> {
+            None
+        }
     }
 
     /////////////////////////// Sphere /////////////////////////
@@ -57,7 +144,7 @@ mod geometry{
     }
 
     impl Hittable for Sphere{
-        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, pixel_data: (usize,usize,usize)) -> Option<Ray>{
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
             let oc: vec3 = ray.origin - self.center;
 
             let a = ray.dir.length_squared();
@@ -84,21 +171,65 @@ mod geometry{
 
             let outward_normal = (hit_record.p - self.center)/self.radius;
             hit_record.set_face_normal(ray, &outward_normal);
+            hit_record.geometric_normal = outward_normal;
+
+            // Longitude and latitude, v=0 at the bottom
+            let theta = (-outward_normal.y).max(-1.0).min(1.0).acos();
+            let phi = (-outward_normal.z).atan2(outward_normal.x) + std::f32::consts::PI;
+            hit_record.uv = (phi/(2.0*std::f32::consts::PI), theta/std::f32::consts::PI);
 
             // TODO: Optimize unnecessary cloning
             let mut r_out = ray.clone();
-            self.material.scatter(ray, &mut r_out, hit_record, attenuation, pixel_data);
+            self.material.scatter(ray, &mut r_out, hit_record, attenuation, sampler);
             
             Some(r_out)            
         }
+
+        fn material(&self) -> &dyn Material {
+            &*self.material
+        }
+
+        fn area(&self) -> f32 {
+            4.0*std::f32::consts::PI*self.radius*self.radius
+        }
+
+        fn sample_surface(&self, u: (f32, f32)) -> Option<(point3, vec3)> {
+            let dir = uniform_sphere(u);
+            // Negative radii turn the sphere inside out
+            Some((self.center + dir*self.radius.abs(), dir*self.radius.signum()))
+        }
+
+        fn point_approximation(&self, _from: point3) -> Option<(point3, f32)> {
+            Some((self.center, std::f32::consts::PI*self.radius*self.radius))
+        }
+
+        fn bounding_box(&self) -> Option<Aabb> {
+            let r = self.radius.abs();
+            Some(Aabb::new(self.center - vec3::new(r, r, r), self.center + vec3::new(r, r, r)))
+        }
     }
 
     /////////////////////////// Plane /////////////////////////
+    // Distance to where the ray meets the plane. Single sided planes can only be hit from the side the normal points to.
+    fn hit_plane(ray: &Ray, point: point3, normal: vec3, single_sided: bool, t_min: f32, t_max: f32) -> Option<f32> {
+        let cos = vec3::dot(&normal, &ray.dir);
+        if cos == 0.0 || (single_sided && cos > 0.0) {
+            return None;
+        }
+        let t = vec3::dot(&(point - ray.origin), &normal)/cos;
+        if t < t_min || t > t_max {
+            return None;
+        }
+        Some(t)
+    }
+
+    // Infinite plane
     pub struct Plane{
         pub normal: vec3,
         pub point: point3,
         pub material: Box<dyn Material>,
 
+        // Rays pass through the back of the plane
         pub single_sided: bool
     }
 
@@ -109,25 +240,177 @@ mod geometry{
     }
 
     impl Hittable for Plane{
-        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, pixel_data: (usize,usize,usize)) -> Option<Ray>{
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
             
-            hit_record.t = vec3::dot(&(self.point-ray.origin), &self.normal)/vec3::dot(&self.normal, &ray.dir);
-
-            if hit_record.t < t_min || hit_record.t > t_max {
-                return None;
-            }
+            hit_record.t = hit_plane(ray, self.point, self.normal, self.single_sided, t_min, t_max)?;
 
             hit_record.p = ray.at(hit_record.t);
             hit_record.normal = self.normal;
 
             hit_record.set_face_normal(ray, &hit_record.normal.clone());
+            hit_record.geometric_normal = self.normal;
+
+            // Distances along two axes lying in the plane
+            let (tangent, bitangent) = tangent_frame(self.normal);
+            let d = hit_record.p - self.point;
+            hit_record.uv = (vec3::dot(&d, &tangent), vec3::dot(&d, &bitangent));
 
             // TODO: Optimize unnecessary cloning
             let mut r_out = ray.clone();
-            self.material.scatter(ray, &mut r_out, hit_record, attenuation, pixel_data);
+            self.material.scatter(ray, &mut r_out, hit_record, attenuation, sampler);
 
             Some(r_out)            
         }
+
+        fn material(&self) -> &dyn Material {
+            &*self.material
+        }
+    }
+
+    /////////////////////////// Quad /////////////////////////
+    // Parallelogram spanned by two edges from a corner. The front is the side cross(edge_u, edge_v) points to.
+    pub struct Quad{
+        pub corner: point3,
+        pub edge_u: vec3,
+        pub edge_v: vec3,
+        pub material: Box<dyn Material>,
+
+        pub single_sided: bool,
+        normal: vec3,
+        // Turns a point on the plane into coordinates along the edges
+        w: vec3,
+    }
+
+    impl Quad{
+        pub fn new(corner: point3, edge_u: vec3, edge_v: vec3, material: Box<dyn Material>, single_sided: bool) -> Self {
+            let n = vec3::cross(&edge_u, &edge_v);
+            Self {corner: corner, edge_u: edge_u, edge_v: edge_v, material: material, single_sided: single_sided,
+                normal: vec3::unit_vector(n), w: n/vec3::dot(&n, &n)}
+        }
+    }
+
+    impl Hittable for Quad{
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
+            let t = hit_plane(ray, self.corner, self.normal, self.single_sided, t_min, t_max)?;
+            let p = ray.at(t);
+            let d = p - self.corner;
+            let a = vec3::dot(&self.w, &vec3::cross(&d, &self.edge_v));
+            let b = vec3::dot(&self.w, &vec3::cross(&self.edge_u, &d));
+            if !(0.0..=1.0).contains(&a) || !(0.0..=1.0).contains(&b) {
+                return None;
+            }
+
+            hit_record.t = t;
+            hit_record.p = p;
+            hit_record.set_face_normal(ray, &self.normal);
+            hit_record.geometric_normal = self.normal;
+            hit_record.uv = (a, b);
+
+            // TODO: Optimize unnecessary cloning
+            let mut r_out = ray.clone();
+            self.material.scatter(ray, &mut r_out, hit_record, attenuation, sampler);
+
+            Some(r_out)
+        }
+
+        fn material(&self) -> &dyn Material {
+            &*self.material
+        }
+
+        fn area(&self) -> f32 {
+            vec3::cross(&self.edge_u, &self.edge_v).length()
+        }
+
+        fn sample_surface(&self, u: (f32, f32)) -> Option<(point3, vec3)> {
+            Some((self.corner + self.edge_u*u.0 + self.edge_v*u.1, self.normal))
+        }
+
+        fn point_approximation(&self, from: point3) -> Option<(point3, f32)> {
+            let center = self.corner + (self.edge_u + self.edge_v)*0.5;
+            let cos = vec3::dot(&self.normal, &vec3::unit_vector(from - center)).abs();
+            Some((center, self.area()*cos))
+        }
+
+        fn bounding_box(&self) -> Option<Aabb> {
+            let c = self.corner;
+            Aabb::from_points(&[c, c + self.edge_u, c + self.edge_v, c + self.edge_u + self.edge_v])
+        }
+    }
+
+    /////////////////////////// Disk /////////////////////////
+    // Disk, or annulus when inner_radius is above zero. u goes around the normal, v outwards from the inner edge.
+    pub struct Disk{
+        pub center: point3,
+        pub normal: vec3,
+        pub radius: f32,
+        pub inner_radius: f32,
+        pub material: Box<dyn Material>,
+
+        pub single_sided: bool,
+    }
+
+    impl Disk{
+        pub fn new(center: point3, normal: vec3, radius: f32, material: Box<dyn Material>, single_sided: bool) -> Self {
+            Self::annulus(center, normal, 0.0, radius, material, single_sided)
+        }
+
+        pub fn annulus(center: point3, normal: vec3, inner_radius: f32, radius: f32, material: Box<dyn Material>, single_sided: bool) -> Self {
+            assert!(0.0 <= inner_radius && inner_radius < radius, "Disk: need 0 <= inner radius < radius");
+            Self {center: center, normal: vec3::unit_vector(normal), radius: radius, inner_radius: inner_radius, material: material, single_sided: single_sided}
+        }
+    }
+
+    impl Hittable for Disk{
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
+            let t = hit_plane(ray, self.center, self.normal, self.single_sided, t_min, t_max)?;
+            let p = ray.at(t);
+            let d = p - self.center;
+            let r_sq = d.length_squared();
+            if r_sq > self.radius*self.radius || r_sq < self.inner_radius*self.inner_radius {
+                return None;
+            }
+
+            hit_record.t = t;
+            hit_record.p = p;
+            hit_record.set_face_normal(ray, &self.normal);
+            hit_record.geometric_normal = self.normal;
+
+            let (tangent, bitangent) = tangent_frame(self.normal);
+            let phi = vec3::dot(&d, &bitangent).atan2(vec3::dot(&d, &tangent));
+            let phi = if phi < 0.0 {phi + 2.0*std::f32::consts::PI} else {phi};
+            hit_record.uv = (phi/(2.0*std::f32::consts::PI), (r_sq.sqrt() - self.inner_radius)/(self.radius - self.inner_radius));
+
+            // TODO: Optimize unnecessary cloning
+            let mut r_out = ray.clone();
+            self.material.scatter(ray, &mut r_out, hit_record, attenuation, sampler);
+
+            Some(r_out)
+        }
+
+        fn material(&self) -> &dyn Material {
+            &*self.material
+        }
+
+        fn area(&self) -> f32 {
+            std::f32::consts::PI*(self.radius*self.radius - self.inner_radius*self.inner_radius)
+        }
+
+        fn sample_surface(&self, u: (f32, f32)) -> Option<(point3, vec3)> {
+            let r_inner_sq = self.inner_radius*self.inner_radius;
+            let r = (r_inner_sq + u.0*(self.radius*self.radius - r_inner_sq)).sqrt();
+            let phi = 2.0*std::f32::consts::PI*u.1;
+            let (tangent, bitangent) = tangent_frame(self.normal);
+            Some((self.center + (tangent*phi.cos() + bitangent*phi.sin())*r, self.normal))
+        }
+
+        fn point_approximation(&self, from: point3) -> Option<(point3, f32)> {
+            let cos = vec3::dot(&self.normal, &vec3::unit_vector(from - self.center)).abs();
+            Some((self.center, self.area()*cos))
+        }
+
+        fn bounding_box(&self) -> Option<Aabb> {
+            Some(Aabb::around_disk(self.center, self.normal, self.radius))
+        }
     }
 
     /////////////////////////// Cube /////////////////////////
@@ -155,7 +438,7 @@ mod geometry{
     }
 
     impl Hittable for Cube{
-        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, pixel_data: (usize,usize,usize)) -> Option<Ray>{
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
             // Uses Smit's Algorithm
             let mut tmin;
             let mut tmax;
@@ -214,55 +497,428 @@ mod geometry{
                 hit_record.normal.y = (eps*(hit_record.p-self.center).y/(self.h/2.0)) as i32 as f32;
                 hit_record.normal.z = (eps*(hit_record.p-self.center).z/(self.d/2.0)) as i32 as f32;
                 hit_record.normal = vec3::unit_vector(hit_record.normal);
-                self.material.scatter(ray, &mut r_out, hit_record, attenuation, pixel_data);
+                hit_record.geometric_normal = hit_record.normal;
+
+                // Position on the face, from the two axes the normal is not along
+                let local = hit_record.p - self.corner0;
+                let (lx, ly, lz) = (local.x/self.w, local.y/self.h, local.z/self.d);
+                hit_record.uv = if hit_record.normal.x.abs() > 0.5 { (lz, ly) }
+                    else if hit_record.normal.y.abs() > 0.5 { (lx, lz) }
+                    else { (lx, ly) };
+                self.material.scatter(ray, &mut r_out, hit_record, attenuation, sampler);
 
                 return Some(r_out);
             }
 
             None            
         }
+
+        fn material(&self) -> &dyn Material {
+            &*self.material
+        }
+
+        fn bounding_box(&self) -> Option<Aabb> {
+            Some(Aabb::new(self.corner0, self.corner1))
+        }
+    }
+
+    /////////////////////////// Triangle mesh /////////////////////////
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum TriangleIntersector {
+        // Fast, but rays through a shared edge or vertex can slip between the triangles
+        MollerTrumbore,
+        // Woop, Benthin and Wald. No ray gets through between triangles that share an edge, although
+        // one exactly through the edge or a vertex may hit more than one of them.
+        Watertight,
+    }
+
+    impl TriangleIntersector {
+        pub fn from_name(name: &str) -> Option<TriangleIntersector> {
+            match name {
+                "moller-trumbore" => Some(TriangleIntersector::MollerTrumbore),
+                "watertight" => Some(TriangleIntersector::Watertight),
+                _ => None,
+            }
+        }
+    }
+
+    // Counter-clockwise triangles are front facing
+    pub struct Mesh{
+        pub positions: Vec<point3>,
+        // Per vertex, interpolated for shading. Without them the mesh is flat shaded.
+        pub normals: Option<Vec<vec3>>,
+        // Per vertex. Without them uv is the barycentrics of the second and third vertex.
+        pub uvs: Option<Vec<(f32, f32)>>,
+        pub indices: Vec<[usize; 3]>,
+        pub material: Box<dyn Material>,
+
+        // Rays pass through the back of the triangles
+        pub backface_culling: bool,
+        pub intersector: TriangleIntersector,
+        // Running sum of triangle areas, for sampling the surface
+        area_cdf: Vec<f32>,
+    }
+
+    impl Mesh{
+        pub fn new(positions: Vec<point3>, indices: Vec<[usize; 3]>, material: Box<dyn Material>) -> Self {
+            for tri in &indices {
+                assert!(tri.iter().all(|&i| i < positions.len()), "Mesh: vertex index out of range");
+            }
+            let mut area_cdf = Vec::with_capacity(indices.len());
+            let mut total = 0.0;
+            for tri in &indices {
+                let [p0, p1, p2] = tri.map(|i| positions[i]);
+                total += 0.5*vec3::cross(&(p1 - p0), &(p2 - p0)).length();
+                area_cdf.push(total);
+            }
+            Self {positions: positions, normals: None, uvs: None, indices: indices, material: material,
+                backface_culling: false, intersector: TriangleIntersector::Watertight, area_cdf: area_cdf}
+        }
+
+        pub fn with_normals(mut self, normals: Vec<vec3>) -> Self {
+            assert_eq!(normals.len(), self.positions.len(), "Mesh: need one normal per vertex");
+            self.normals = Some(normals.into_iter().map(vec3::unit_vector).collect());
+            self
+        }
+
+        pub fn with_uvs(mut self, uvs: Vec<(f32, f32)>) -> Self {
+            assert_eq!(uvs.len(), self.positions.len(), "Mesh: need one uv per vertex");
+            self.uvs = Some(uvs);
+            self
+        }
+
+        pub fn with_intersector(mut self, intersector: TriangleIntersector) -> Self {
+            self.intersector = intersector;
+            self
+        }
+
+        // Distance along the ray and barycentrics of the second and third vertex
+        fn moller_trumbore(ray: &Ray, [p0, p1, p2]: [point3; 3]) -> Option<(f32, f32, f32)> {
+            let e1 = p1 - p0;
+            let e2 = p2 - p0;
+            let pvec = vec3::cross(&ray.dir, &e2);
+            let det = vec3::dot(&e1, &pvec);
+            if det.abs() < 1e-12 {
+                return None;
+            }
+            let inv_det = 1.0/det;
+            let tvec = ray.origin - p0;
+            let b1 = vec3::dot(&tvec, &pvec)*inv_det;
+            if !(0.0..=1.0).contains(&b1) {
+                return None;
+            }
+            let qvec = vec3::cross(&tvec, &e1);
+            let b2 = vec3::dot(&ray.dir, &qvec)*inv_det;
+            if b2 < 0.0 || b1 + b2 > 1.0 {
+                return None;
+            }
+            Some((vec3::dot(&e2, &qvec)*inv_det, b1, b2))
+        }
+
+        fn watertight(ray: &Ray, [p0, p1, p2]: [point3; 3]) -> Option<(f32, f32, f32)> {
+            let axis = |v: vec3, k: usize| [v.x, v.y, v.z][k];
+            // Shear and permute so the ray goes along +z from the origin
+            let abs_dir = [ray.dir.x.abs(), ray.dir.y.abs(), ray.dir.z.abs()];
+            let kz = if abs_dir[0] > abs_dir[1] {if abs_dir[0] > abs_dir[2] {0} else {2}} else if abs_dir[1] > abs_dir[2] {1} else {2};
+            let mut kx = (kz + 1)%3;
+            let mut ky = (kx + 1)%3;
+            // Keep the winding
+            if axis(ray.dir, kz) < 0.0 {
+                std::mem::swap(&mut kx, &mut ky);
+            }
+            let dz = axis(ray.dir, kz);
+            if dz == 0.0 {
+                return None;
+            }
+            let (sx, sy, sz) = (axis(ray.dir, kx)/dz, axis(ray.dir, ky)/dz, 1.0/dz);
+
+            let sheared = |p: point3| {
+                let a = p - ray.origin;
+                (axis(a, kx) - sx*axis(a, kz), axis(a, ky) - sy*axis(a, kz), sz*axis(a, kz))
+            };
+            let (ax, ay, az) = sheared(p0);
+            let (bx, by, bz) = sheared(p1);
+            let (cx, cy, cz) = sheared(p2);
+
+            // Scaled barycentrics from 2D edge functions, redone in double precision when on an edge
+            let mut u = cx*by - cy*bx;
+            let mut v = ax*cy - ay*cx;
+            let mut w = bx*ay - by*ax;
+            if u == 0.0 || v == 0.0 || w == 0.0 {
+                u = (cx as f64*by as f64 - cy as f64*bx as f64) as f32;
+                v = (ax as f64*cy as f64 - ay as f64*cx as f64) as f32;
+                w = (bx as f64*ay as f64 - by as f64*ax as f64) as f32;
+            }
+            if (u < 0.0 || v < 0.0 || w < 0.0) && (u > 0.0 || v > 0.0 || w > 0.0) {
+                return None;
+            }
+            let det = u + v + w;
+            if det == 0.0 {
+                return None;
+            }
+            let t = (u*az + v*bz + w*cz)/det;
+            Some((t, v/det, w/det))
+        }
+
+        fn triangle(&self, i: usize) -> [point3; 3] {
+            self.indices[i].map(|v| self.positions[v])
+        }
+    }
+
+    impl Hittable for Mesh{
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
+            let mut closest: Option<(usize, f32, f32, f32, vec3)> = None;
+            let mut closest_so_far = t_max;
+            for i in 0..self.indices.len() {
+                let tri = self.triangle(i);
+                let outward = vec3::cross(&(tri[1] - tri[0]), &(tri[2] - tri[0]));
+                if self.backface_culling && vec3::dot(&ray.dir, &outward) >= 0.0 {
+                    continue;
+                }
+                let found = match self.intersector {
+                    TriangleIntersector::MollerTrumbore => Self::moller_trumbore(ray, tri),
+                    TriangleIntersector::Watertight => Self::watertight(ray, tri),
+                };
+                if let Some((t, b1, b2)) = found {
+                    if t >= t_min && t <= closest_so_far {
+                        closest_so_far = t;
+                        closest = Some((i, t, b1, b2, outward));
+                    }
+                }
+            }
+            let (i, t, b1, b2, outward) = closest?;
+
+            hit_record.t = t;
+            hit_record.p = ray.at(t);
+            hit_record.barycentrics = Some((b1, b2));
+            let b0 = 1.0 - b1 - b2;
+            let [i0, i1, i2] = self.indices[i];
+
+            let outward_normal = vec3::unit_vector(outward);
+            hit_record.geometric_normal = outward_normal;
+            hit_record.front_face = vec3::dot(&ray.dir, &outward_normal) < 0.0;
+            let shading_normal = match &self.normals {
+                Some(n) => vec3::unit_vector(n[i0]*b0 + n[i1]*b1 + n[i2]*b2),
+                None => outward_normal,
+            };
+            hit_record.normal = if hit_record.front_face {shading_normal} else {-shading_normal};
+
+            hit_record.uv = match &self.uvs {
+                Some(uv) => (uv[i0].0*b0 + uv[i1].0*b1 + uv[i2].0*b2, uv[i0].1*b0 + uv[i1].1*b1 + uv[i2].1*b2),
+                None => (b1, b2),
+            };
+
+            // TODO: Optimize unnecessary cloning
+            let mut r_out = ray.clone();
+            self.material.scatter(ray, &mut r_out, hit_record, attenuation, sampler);
+
+            Some(r_out)
+        }
+
+        fn material(&self) -> &dyn Material {
+            &*self.material
+        }
+
+        fn area(&self) -> f32 {
+            self.area_cdf.last().copied().unwrap_or(0.0)
+        }
+
+        // Pick a triangle by area, then a uniform point on it
+        fn sample_surface(&self, u: (f32, f32)) -> Option<(point3, vec3)> {
+            let total = self.area();
+            if total <= 0.0 {
+                return None;
+            }
+            let target = u.0*total;
+            let i = self.area_cdf.partition_point(|&c| c <= target).min(self.indices.len() - 1);
+            let start = if i == 0 {0.0} else {self.area_cdf[i - 1]};
+            let u0 = ((target - start)/(self.area_cdf[i] - start)).clamp(0.0, 1.0);
+
+            let [p0, p1, p2] = self.triangle(i);
+            let su = u0.sqrt();
+            let (b1, b2) = (su*(1.0 - u.1), su*u.1);
+            let p = p0*(1.0 - b1 - b2) + p1*b1 + p2*b2;
+            Some((p, vec3::unit_vector(vec3::cross(&(p1 - p0), &(p2 - p0)))))
+        }
+
+        // A closed convex shape looks a quarter of its surface area big on average
+        fn point_approximation(&self, _from: point3) -> Option<(point3, f32)> {
+            let total = self.area();
+            if total <= 0.0 {
+                return None;
+            }
+            let mut centroid = point3::new(0.0, 0.0, 0.0);
+            let mut previous = 0.0;
+            for (i, &c) in self.area_cdf.iter().enumerate() {
+                let [p0, p1, p2] = self.triangle(i);
+                centroid += (p0 + p1 + p2)*((c - previous)/(3.0*total));
+                previous = c;
+            }
+            Some((centroid, 0.25*total))
+        }
+
+        fn bounding_box(&self) -> Option<Aabb> {
+            Aabb::from_points(&self.positions)
+        }
     }
 
     ///////////////////////////// Hittable List ///////////////////////////////
     
     pub struct HittableList{
         pub list: Vec<Box<dyn Hittable>>,
+        pub material_ids: Vec<usize>,
+        // Emissive objects are added as area lights automatically
+        pub lights: Vec<Box<dyn Light>>,
+        pub sky: Sky,
+        // Bounding box of each object, None for unbounded ones like planes
+        boxes: Vec<Option<Aabb>>,
     }
 
     impl HittableList{
         pub fn new() -> Self {
-            Self {list: Vec::new()}
+            Self {list: Vec::new(), material_ids: Vec::new(), lights: Vec::new(), sky: Sky::Gradient, boxes: Vec::new()}
+        }
+        // Gives the object's material a new ID for the material ID AOV, and returns it
+        pub fn add(&mut self, hittable: Box<dyn Hittable + Send + Sync>) -> usize {
+            let material_id = self.material_ids.iter().max().map_or(0, |m| m+1);
+            self.add_with_material_id(hittable, material_id);
+            material_id
         }
-        pub fn add(&mut self, hittable: Box<dyn Hittable + Send + Sync>){
+        // Every object owns its material, so objects made of the same material are marked as such by sharing an ID
+        pub fn add_with_material_id(&mut self, hittable: Box<dyn Hittable + Send + Sync>, material_id: usize){
+            let emission = hittable.material().emission();
+            if emission.max_component() > 0.0 && hittable.area() > 0.0 {
+                self.lights.push(Box::new(AreaLight{object_id: self.list.len(), emission: emission}));
+            }
+
+            self.material_ids.push(material_id);
+            self.boxes.push(hittable.bounding_box());
             self.list.push(hittable);
         }
+        // Light leaving the hit point towards where the ray came from. Emitters only emit from their front face.
+        pub fn emitted(&self, hit_record: &HitRecord) -> colour {
+            if hit_record.front_face {self.list[hit_record.object_id].material().emission()} else {colour::new(0.0, 0.0, 0.0)}
+        }
+        // Index in `lights` of the area light attached to an object
+        pub fn light_index(&self, object_id: usize) -> Option<usize> {
+            self.lights.iter().position(|l| l.object_id() == Some(object_id))
+        }
+        // Point, spot and directional lights, which aren't attached to any object
+        pub fn add_light(&mut self, light: Box<dyn Light>){
+            self.lights.push(light);
+        }
+        // Box around all bounded objects, None if there aren't any
+        pub fn bounds(&self) -> Option<Aabb> {
+            self.boxes.iter().flatten().fold(None, |acc: Option<Aabb>, b| Some(acc.map_or(*b, |a| a.union(b))))
+        }
+        // Lets the lights adapt to the finished scene. Call after the last object and light are added.
+        pub fn preprocess(&mut self){
+            let mut lights = std::mem::take(&mut self.lights);
+            for light in lights.iter_mut(){
+                light.preprocess(self);
+            }
+            self.lights = lights;
+        }
+        // Replaces the sky gradient, and adds the sun as a light
+        pub fn set_sky(&mut self, sky: PhysicalSky){
+            if let Some(sun) = sky.sun(){
+                self.add_light(Box::new(sun));
+            }
+            self.sky = Sky::Physical(sky);
+        }
+        // Seen by rays that leave the scene
+        pub fn background(&self, ray: &Ray) -> colour {
+            self.sky.radiance(ray.dir)
+        }
+        // Shadow ray test between two points, excluding both ends
+        pub fn occluded(&self, from: point3, to: point3) -> bool {
+            let d = to - from;
+            let dist = d.length();
+            let mut hr = HitRecord::default();
+            let mut attenuation = colour::new(0.0, 0.0, 0.0);
+            // Materials still scatter on hit, so keep them away from the caller's sampler
+            let ray = Ray::new(from, d/dist);
+            self.hit(&ray, &mut attenuation, 0.001, dist - 0.001, &mut hr, &mut IndependentSampler::new(0)).is_some()
+        }
         // Hit is not derived from Hittable trait, it's just another method called that
-        pub fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, pixel_data: (usize,usize,usize)) -> Option<Ray>{
+        pub fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
             let mut temp_hr = HitRecord{..Default::default()};
             let mut closest_so_far = t_max;
             let mut current_ray = None;
+            let mut tests = 0;
 
-            for object in self.list.iter(){
-                if let Some(r) = object.hit(ray, attenuation, t_min, closest_so_far, &mut temp_hr, pixel_data){
+            for (idx, object) in self.list.iter().enumerate(){
+                // Cheap test against the bounding box first
+                if let Some(bounds) = &self.boxes[idx] {
+                    if !bounds.hit(ray, t_min, closest_so_far) {
+                        continue;
+                    }
+                }
+                tests += 1;
+                // Only triangles set these
+                temp_hr.barycentrics = None;
+                if let Some(r) = object.hit(ray, attenuation, t_min, closest_so_far, &mut temp_hr, sampler){
                     closest_so_far = temp_hr.t;
 
                     hit_record.p = temp_hr.p;
                     hit_record.normal = temp_hr.normal;
+                    hit_record.geometric_normal = temp_hr.geometric_normal;
                     hit_record.t = temp_hr.t;
                     hit_record.front_face = temp_hr.front_face;
+                    hit_record.uv = temp_hr.uv;
+                    hit_record.barycentrics = temp_hr.barycentrics;
+                    hit_record.object_id = idx;
+                    hit_record.material_id = self.material_ids[idx];
 
                     current_ray = Some(r);
                 }
             }
+            // Objects whose bounding box the ray went through, there is no acceleration structure beyond that
+            hit_record.intersection_tests = tests;
             current_ray
         }
     }
     unsafe impl Send for HittableList {}
     unsafe impl Sync for HittableList {}
 
+    // Direction sampled from a BSDF. weight is f*cos/pdf. Specular samples have no meaningful pdf.
+    pub struct BsdfSample{
+        pub wi: vec3,
+        pub weight: colour,
+        pub pdf: f32,
+        pub specular: bool,
+    }
+
+    // Deterministic stand-in for a material, for preview integrators: a diffuse albedo lit by direct light,
+    // plus perfectly sharp directions to follow with their weights
+    pub struct PreviewResponse{
+        pub diffuse: colour,
+        pub specular: Vec<(vec3, colour)>,
+    }
+
     // Material Class
+    // scatter is what the path tracer follows. sample, eval and pdf are the same BSDF with explicit densities,
+    // for integrators that connect paths. Directions are unit vectors pointing away from the hit point,
+    // wo back along the incoming ray.
     pub trait Material: Send + Sync{
-        fn scatter(&self, r_in: &Ray, r_out: &mut Ray, hit_record: &HitRecord, attenuation: &mut colour, pixel_data: (usize,usize,usize));
+        fn scatter(&self, r_in: &Ray, r_out: &mut Ray, hit_record: &HitRecord, attenuation: &mut colour, sampler: &mut dyn Sampler);
+
+        fn sample(&self, wo: vec3, hit_record: &HitRecord, sampler: &mut dyn Sampler) -> Option<BsdfSample>;
+        // BSDF value for a pair of directions, without the cosine. Zero for specular materials.
+        fn eval(&self, _wo: vec3, _wi: vec3, _hit_record: &HitRecord) -> colour {
+            colour::new(0.0, 0.0, 0.0)
+        }
+        // Solid angle density of sample returning wi
+        fn pdf(&self, _wo: vec3, _wi: vec3, _hit_record: &HitRecord) -> f32 {
+            0.0
+        }
+        // Radiance leaving the front face
+        fn emission(&self) -> colour {
+            colour::new(0.0, 0.0, 0.0)
+        }
+        fn preview(&self, _wo: vec3, _hit_record: &HitRecord) -> PreviewResponse {
+            PreviewResponse{diffuse: colour::new(0.0, 0.0, 0.0), specular: Vec::new()}
+        }
     }
 
     pub struct Metal{
@@ -272,11 +928,24 @@ mod geometry{
     }
 
     impl Material for Metal{
-        fn scatter(&self, r_in: &Ray, r_out: &mut Ray, hit_record: &HitRecord, attenuation: &mut colour, pixel_data: (usize,usize,usize)) {
+        fn scatter(&self, r_in: &Ray, r_out: &mut Ray, hit_record: &HitRecord, attenuation: &mut colour, sampler: &mut dyn Sampler) {
             let reflected = vec3::reflect(vec3::unit_vector(r_in.dir), hit_record.normal);
 
             *attenuation = self.albedo;
-            *r_out = Ray::new(hit_record.p, reflected + BlueNoise::random_in_unit_sphere(pixel_data)*self.fuzz );//+ BlueNoise::blue_noise_cleanup(pixel_data)*self.fuzz);
+            *r_out = Ray::new(hit_record.p, reflected + sampler.in_unit_sphere()*self.fuzz );//+ BlueNoise::blue_noise_cleanup(pixel_data)*self.fuzz);
+        }
+
+        // Fuzzy reflections are treated as specular too, there is no density for them
+        fn sample(&self, wo: vec3, hit_record: &HitRecord, sampler: &mut dyn Sampler) -> Option<BsdfSample> {
+            let reflected = vec3::reflect(-wo, hit_record.normal);
+            let wi = vec3::unit_vector(reflected + sampler.in_unit_sphere()*self.fuzz);
+            Some(BsdfSample{wi: wi, weight: self.albedo, pdf: 0.0, specular: true})
+        }
+
+        // A mirror. Fuzzy reflections can't be blurred without sampling, so fuzz is ignored.
+        fn preview(&self, wo: vec3, hit_record: &HitRecord) -> PreviewResponse {
+            let reflected = vec3::reflect(-wo, hit_record.normal);
+            PreviewResponse{diffuse: colour::new(0.0, 0.0, 0.0), specular: vec![(reflected, self.albedo)]}
         }
     }
 
@@ -285,9 +954,9 @@ mod geometry{
     }
 
     impl Material for Lambertian{
-        fn scatter(&self, _r_in: &Ray, r_out: &mut Ray,hit_record: &HitRecord, attenuation: &mut colour, pixel_data: (usize,usize,usize)){
+        fn scatter(&self, _r_in: &Ray, r_out: &mut Ray,hit_record: &HitRecord, attenuation: &mut colour, sampler: &mut dyn Sampler){
 
-            let  mut scatter_dir = hit_record.normal + BlueNoise::random_in_unit_sphere(pixel_data);// + BlueNoise::blue_noise_cleanup(pixel_data);
+            let  mut scatter_dir = hit_record.normal + sampler.in_unit_sphere();// + BlueNoise::blue_noise_cleanup(pixel_data);
 
             if scatter_dir.is_near_zero(){
                 scatter_dir = hit_record.normal;
@@ -296,6 +965,34 @@ mod geometry{
             *attenuation = self.albedo;
             *r_out = Ray::new(hit_record.p, scatter_dir);               
         }
+
+        fn sample(&self, _wo: vec3, hit_record: &HitRecord, sampler: &mut dyn Sampler) -> Option<BsdfSample> {
+            let wi = cosine_hemisphere(hit_record.normal, sampler.get_2d());
+            let pdf = vec3::dot(&wi, &hit_record.normal)/std::f32::consts::PI;
+            if pdf <= 0.0 {
+                return None;
+            }
+            Some(BsdfSample{wi: wi, weight: self.albedo, pdf: pdf, specular: false})
+        }
+
+        fn eval(&self, wo: vec3, wi: vec3, hit_record: &HitRecord) -> colour {
+            // Reflection only, both directions on the side the normal faces
+            if vec3::dot(&wo, &hit_record.normal) <= 0.0 || vec3::dot(&wi, &hit_record.normal) <= 0.0 {
+                return colour::new(0.0, 0.0, 0.0);
+            }
+            self.albedo/std::f32::consts::PI
+        }
+
+        fn pdf(&self, wo: vec3, wi: vec3, hit_record: &HitRecord) -> f32 {
+            if vec3::dot(&wo, &hit_record.normal) <= 0.0 {
+                return 0.0;
+            }
+            vec3::dot(&wi, &hit_record.normal).max(0.0)/std::f32::consts::PI
+        }
+
+        fn preview(&self, _wo: vec3, _hit_record: &HitRecord) -> PreviewResponse {
+            PreviewResponse{diffuse: self.albedo, specular: Vec::new()}
+        }
     }
 
     pub struct Dielectric{
@@ -315,34 +1012,487 @@ mod geometry{
             let r0 = ((1.0-ref_idx)/(1.0+ref_idx)).powi(2);
             r0 + (1.0-r0)*((1.0-cosine)).powi(5)
         }
-        fn should_reflect(&self, cosine: f32, ref_idx: f32) ->bool{
-            self.reflectance(cosine, ref_idx) > rand::thread_rng().gen()
+        fn should_reflect(&self, cosine: f32, ref_idx: f32, sampler: &mut dyn Sampler) ->bool{
+            self.reflectance(cosine, ref_idx) > sampler.get_1d()
         }
-    }
-
-    impl Material for Dielectric{
-        fn scatter(&self, r_in: &Ray, r_out: &mut Ray,hit_record: &HitRecord, attenuation: &mut colour, pixel_data: (usize,usize,usize)){
-
-            *attenuation = self.albedo;
-
+        // Picks between reflection and refraction with the Fresnel reflectance as probability
+        fn scatter_dir(&self, unit_dir: vec3, hit_record: &HitRecord, sampler: &mut dyn Sampler) -> vec3{
             let refraction_ratio = if hit_record.front_face {1.0/self.index_of_refraction} else{self.index_of_refraction};
-            let unit_dir = vec3::unit_vector((*r_in).dir);
 
             // Calculate total internal reflection
             let cos_theta = vec3::dot(&-unit_dir, &hit_record.normal).min(1.0);
             let sin_theta = (1.0-cos_theta*cos_theta).sqrt();
 
             let dir;
-            if (refraction_ratio*sin_theta > 1.0) || (self.should_reflect(cos_theta, refraction_ratio)) {
+            if (refraction_ratio*sin_theta > 1.0) || (self.should_reflect(cos_theta, refraction_ratio, sampler)) {
                 //Reflect, internally or externally (cannot refract)
                 dir = vec3::reflect(unit_dir, hit_record.normal);
             } else {
                 // Refract
                 dir = self.refract(unit_dir, hit_record.normal, refraction_ratio);
             }
+            dir
+        }
+    }
+
+    impl Material for Dielectric{
+        fn scatter(&self, r_in: &Ray, r_out: &mut Ray,hit_record: &HitRecord, attenuation: &mut colour, sampler: &mut dyn Sampler){
+
+            *attenuation = self.albedo;
+            let dir = self.scatter_dir(vec3::unit_vector((*r_in).dir), hit_record, sampler);
 
             *r_out = Ray::new(hit_record.p, dir);       
         }
+
+        fn sample(&self, wo: vec3, hit_record: &HitRecord, sampler: &mut dyn Sampler) -> Option<BsdfSample> {
+            let wi = vec3::unit_vector(self.scatter_dir(-wo, hit_record, sampler));
+            Some(BsdfSample{wi: wi, weight: self.albedo, pdf: 0.0, specular: true})
+        }
+
+        // Both the reflection and the refraction, split by the Fresnel reflectance
+        fn preview(&self, wo: vec3, hit_record: &HitRecord) -> PreviewResponse {
+            let refraction_ratio = if hit_record.front_face {1.0/self.index_of_refraction} else{self.index_of_refraction};
+            let cos_theta = vec3::dot(&wo, &hit_record.normal).min(1.0);
+            let sin_theta = (1.0-cos_theta*cos_theta).sqrt();
+            let reflected = vec3::reflect(-wo, hit_record.normal);
+
+            let specular = if refraction_ratio*sin_theta > 1.0 {
+                vec![(reflected, self.albedo)]
+            } else {
+                let r = self.reflectance(cos_theta, refraction_ratio);
+                vec![(reflected, self.albedo*r), (self.refract(-wo, hit_record.normal, refraction_ratio), self.albedo*(1.0-r))]
+            };
+            PreviewResponse{diffuse: colour::new(0.0, 0.0, 0.0), specular: specular}
+        }
+    }
+
+    // Emits uniformly from the front face and absorbs everything that hits it
+    pub struct DiffuseLight{
+        pub emit: colour,
+    }
+
+    impl Material for DiffuseLight{
+        fn scatter(&self, _r_in: &Ray, _r_out: &mut Ray, _hit_record: &HitRecord, attenuation: &mut colour, _sampler: &mut dyn Sampler){
+            *attenuation = colour::new(0.0, 0.0, 0.0);
+        }
+
+        fn sample(&self, _wo: vec3, _hit_record: &HitRecord, _sampler: &mut dyn Sampler) -> Option<BsdfSample> {
+            None
+        }
+
+        fn emission(&self) -> colour {
+            self.emit
+        }
+    }
+
+}
+
+mod shapes{
+    use crate::vector::vec3;
+    use crate::ray::Ray;
+    use crate::sampling::{Sampler, tangent_frame};
+    use super::{HitRecord, Hittable, Material, Aabb};
+    use vec3 as point3;
+    use vec3 as colour;
+
+    const TWO_PI: f32 = 2.0*std::f32::consts::PI;
+
+    // Coordinates with y along an axis, for shapes that are symmetric around it
+    struct Frame {
+        origin: point3,
+        tangent: vec3,
+        axis: vec3,
+        bitangent: vec3,
+    }
+
+    impl Frame {
+        fn new(origin: point3, axis: vec3) -> Self {
+            let axis = vec3::unit_vector(axis);
+            let (tangent, bitangent) = tangent_frame(axis);
+            Frame{origin: origin, tangent: tangent, axis: axis, bitangent: bitangent}
+        }
+
+        fn vector_to_local(&self, v: vec3) -> vec3 {
+            vec3::new(vec3::dot(&v, &self.tangent), vec3::dot(&v, &self.axis), vec3::dot(&v, &self.bitangent))
+        }
+
+        fn to_local(&self, p: point3) -> point3 {
+            self.vector_to_local(p - self.origin)
+        }
+
+        fn vector_to_world(&self, v: vec3) -> vec3 {
+            self.tangent*v.x + self.axis*v.y + self.bitangent*v.z
+        }
+    }
+
+    // Angle around the axis as a fraction of a turn
+    fn around(p: point3) -> f32 {
+        let phi = p.z.atan2(p.x);
+        (if phi < 0.0 {phi + TWO_PI} else {phi})/TWO_PI
     }
 
-}
\ No newline at end of file
+    // Roots of a t^2 + b t + c, avoiding cancellation
+    fn quadratic(a: f32, b: f32, c: f32) -> Option<(f32, f32)> {
+        if a == 0.0 {
+            return None;
+        }
+        let discriminant = b*b - 4.0*a*c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let q = -0.5*(b + b.signum()*discriminant.sqrt());
+        if q == 0.0 {
+            return Some((0.0, 0.0));
+        }
+        let (t0, t1) = (q/a, c/q);
+        Some((t0.min(t1), t0.max(t1)))
+    }
+
+    // Candidate hit in local coordinates, with the outward normal and uv
+    type LocalHit = (f32, vec3, (f32, f32));
+
+    fn nearest(candidates: impl Iterator<Item = LocalHit>, t_min: f32, t_max: f32) -> Option<LocalHit> {
+        candidates.filter(|c| c.0 >= t_min && c.0 <= t_max).min_by(|a, b| a.0.total_cmp(&b.0))
+    }
+
+    // Fills in the hit record from a local hit and lets the material scatter
+    fn finish_hit(ray: &Ray, frame: &Frame, (t, local_normal, uv): LocalHit, material: &dyn Material,
+        attenuation: &mut colour, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray> {
+        hit_record.t = t;
+        hit_record.p = ray.at(t);
+        let outward_normal = vec3::unit_vector(frame.vector_to_world(local_normal));
+        hit_record.set_face_normal(ray, &outward_normal);
+        hit_record.geometric_normal = outward_normal;
+        hit_record.uv = uv;
+
+        // TODO: Optimize unnecessary cloning
+        let mut r_out = ray.clone();
+        material.scatter(ray, &mut r_out, hit_record, attenuation, sampler);
+        Some(r_out)
+    }
+
+    // Side of a cone cut off at y=0 and y=height, with the radius going linearly from r0 to r1, and optionally its caps.
+    // u goes around the axis. v goes up the side, and out from the middle of the caps.
+    fn frustum_hits(o: point3, d: vec3, r0: f32, r1: f32, height: f32, capped: bool) -> Vec<LocalHit> {
+        let mut hits = Vec::with_capacity(4);
+        let k = (r1 - r0)/height;
+        let r_o = r0 + k*o.y;
+        let a = d.x*d.x + d.z*d.z - k*k*d.y*d.y;
+        let b = 2.0*(o.x*d.x + o.z*d.z - k*r_o*d.y);
+        let c = o.x*o.x + o.z*o.z - r_o*r_o;
+        if let Some((t0, t1)) = quadratic(a, b, c) {
+            for t in [t0, t1] {
+                let p = o + d*t;
+                if p.y >= 0.0 && p.y <= height {
+                    hits.push((t, vec3::new(p.x, -k*(r0 + k*p.y), p.z), (around(p), p.y/height)));
+                }
+            }
+        }
+        if capped && d.y != 0.0 {
+            for (y, r, normal_y) in [(0.0, r0, -1.0), (height, r1, 1.0)] {
+                let t = (y - o.y)/d.y;
+                let p = o + d*t;
+                let r_sq = p.x*p.x + p.z*p.z;
+                if r > 0.0 && r_sq <= r*r {
+                    hits.push((t, vec3::new(0.0, normal_y, 0.0), (around(p), r_sq.sqrt()/r)));
+                }
+            }
+        }
+        hits
+    }
+
+    /////////////////////////// Cylinder /////////////////////////
+    // Round cylinder between the centres of its two ends. Uncapped cylinders are open tubes.
+    pub struct Cylinder{
+        pub base: point3,
+        pub top: point3,
+        pub radius: f32,
+        pub capped: bool,
+        pub material: Box<dyn Material>,
+        frame: Frame,
+        height: f32,
+    }
+
+    impl Cylinder{
+        pub fn new(base: point3, top: point3, radius: f32, capped: bool, material: Box<dyn Material>) -> Self {
+            let height = (top - base).length();
+            assert!(height > 0.0 && radius > 0.0, "Cylinder: need a positive height and radius");
+            Self {base: base, top: top, radius: radius, capped: capped, material: material, frame: Frame::new(base, top - base), height: height}
+        }
+    }
+
+    impl Hittable for Cylinder{
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
+            let (o, d) = (self.frame.to_local(ray.origin), self.frame.vector_to_local(ray.dir));
+            let hits = frustum_hits(o, d, self.radius, self.radius, self.height, self.capped);
+            let hit = nearest(hits.into_iter(), t_min, t_max)?;
+            finish_hit(ray, &self.frame, hit, &*self.material, attenuation, hit_record, sampler)
+        }
+
+        fn material(&self) -> &dyn Material {
+            &*self.material
+        }
+
+        fn bounding_box(&self) -> Option<Aabb> {
+            Some(Aabb::around_disk(self.base, self.frame.axis, self.radius).union(&Aabb::around_disk(self.top, self.frame.axis, self.radius)))
+        }
+    }
+
+    /////////////////////////// Cone /////////////////////////
+    // Cone, or frustum when both radii are above zero, between the centres of its two ends
+    pub struct Cone{
+        pub base: point3,
+        pub top: point3,
+        pub base_radius: f32,
+        pub top_radius: f32,
+        pub capped: bool,
+        pub material: Box<dyn Material>,
+        frame: Frame,
+        height: f32,
+    }
+
+    impl Cone{
+        pub fn new(base: point3, top: point3, base_radius: f32, top_radius: f32, capped: bool, material: Box<dyn Material>) -> Self {
+            let height = (top - base).length();
+            assert!(height > 0.0 && base_radius >= 0.0 && top_radius >= 0.0 && base_radius + top_radius > 0.0,
+                "Cone: need a positive height and non-negative radii, not both zero");
+            Self {base: base, top: top, base_radius: base_radius, top_radius: top_radius, capped: capped, material: material,
+                frame: Frame::new(base, top - base), height: height}
+        }
+    }
+
+    impl Hittable for Cone{
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
+            let (o, d) = (self.frame.to_local(ray.origin), self.frame.vector_to_local(ray.dir));
+            let hits = frustum_hits(o, d, self.base_radius, self.top_radius, self.height, self.capped);
+            // The normal at the tip is undefined
+            let hit = nearest(hits.into_iter().filter(|h| h.1.length_squared() > 0.0), t_min, t_max)?;
+            finish_hit(ray, &self.frame, hit, &*self.material, attenuation, hit_record, sampler)
+        }
+
+        fn material(&self) -> &dyn Material {
+            &*self.material
+        }
+
+        fn bounding_box(&self) -> Option<Aabb> {
+            Some(Aabb::around_disk(self.base, self.frame.axis, self.base_radius).union(&Aabb::around_disk(self.top, self.frame.axis, self.top_radius)))
+        }
+    }
+
+    /////////////////////////// Capsule /////////////////////////
+    // Cylinder with hemispheres on both ends. u goes around the axis, v along the surface from the bottom pole to the top one.
+    pub struct Capsule{
+        pub a: point3,
+        pub b: point3,
+        pub radius: f32,
+        pub material: Box<dyn Material>,
+        frame: Frame,
+        height: f32,
+    }
+
+    impl Capsule{
+        pub fn new(a: point3, b: point3, radius: f32, material: Box<dyn Material>) -> Self {
+            assert!(radius > 0.0, "Capsule: need a positive radius");
+            let height = (b - a).length();
+            // The axis doesn't matter for a sphere
+            let axis = if height > 0.0 {b - a} else {vec3::new(0.0, 1.0, 0.0)};
+            Self {a: a, b: b, radius: radius, material: material, frame: Frame::new(a, axis), height: height}
+        }
+    }
+
+    impl Hittable for Capsule{
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
+            let (o, d) = (self.frame.to_local(ray.origin), self.frame.vector_to_local(ray.dir));
+            let r = self.radius;
+            let length = std::f32::consts::PI*r + self.height;
+            let mut hits = if self.height > 0.0 {frustum_hits(o, d, r, r, self.height, false)} else {Vec::new()};
+            for hit in hits.iter_mut() {
+                hit.2.1 = (0.5*std::f32::consts::PI*r + hit.2.1*self.height)/length;
+            }
+
+            // Hemispheres, each only counting on its own side of the body
+            let dd = vec3::dot(&d, &d);
+            for (centre_y, below) in [(0.0, true), (self.height, false)] {
+                let oc = o - vec3::new(0.0, centre_y, 0.0);
+                if let Some((t0, t1)) = quadratic(dd, 2.0*vec3::dot(&oc, &d), vec3::dot(&oc, &oc) - r*r) {
+                    for t in [t0, t1] {
+                        let n = oc + d*t;
+                        if (below && n.y <= 0.0) || (!below && n.y >= 0.0) {
+                            // Arc length from the bottom pole
+                            let s = if below {r*(-n.y/r).clamp(-1.0, 1.0).acos()}
+                                else {0.5*std::f32::consts::PI*r + self.height + r*(n.y/r).clamp(-1.0, 1.0).asin()};
+                            hits.push((t, n, (around(n), s/length)));
+                        }
+                    }
+                }
+            }
+            let hit = nearest(hits.into_iter(), t_min, t_max)?;
+            finish_hit(ray, &self.frame, hit, &*self.material, attenuation, hit_record, sampler)
+        }
+
+        fn material(&self) -> &dyn Material {
+            &*self.material
+        }
+
+        fn bounding_box(&self) -> Option<Aabb> {
+            let r = vec3::new(self.radius, self.radius, self.radius);
+            Some(Aabb::new(self.a - r, self.a + r).union(&Aabb::new(self.b - r, self.b + r)))
+        }
+    }
+
+    /////////////////////////// Torus /////////////////////////
+    // Real roots of a quadratic, cubic or quartic with leading coefficient 1 (Graphics Gems I, "Cubic and Quartic Roots")
+    fn is_zero(x: f64) -> bool {
+        x.abs() < 1e-12
+    }
+
+    fn solve_quadratic(p: f64, q: f64) -> Vec<f64> {
+        let d = p*p/4.0 - q;
+        if is_zero(d) {
+            vec![-p/2.0]
+        } else if d < 0.0 {
+            Vec::new()
+        } else {
+            vec![d.sqrt() - p/2.0, -d.sqrt() - p/2.0]
+        }
+    }
+
+    fn solve_cubic(a: f64, b: f64, c: f64) -> Vec<f64> {
+        // Substitute x = y - a/3 to get y^3 + 3p y + 2q = 0
+        let sq_a = a*a;
+        let p = (-sq_a/3.0 + b)/3.0;
+        let q = (2.0/27.0*a*sq_a - a*b/3.0 + c)/2.0;
+        let cb_p = p*p*p;
+        let d = q*q + cb_p;
+
+        let roots = if is_zero(d) {
+            if is_zero(q) {
+                vec![0.0]
+            } else {
+                let u = (-q).cbrt();
+                vec![2.0*u, -u]
+            }
+        } else if d < 0.0 {
+            // Three real roots
+            let phi = (-q/(-cb_p).sqrt()).clamp(-1.0, 1.0).acos()/3.0;
+            let t = 2.0*(-p).sqrt();
+            vec![t*phi.cos(), -t*(phi + std::f64::consts::PI/3.0).cos(), -t*(phi - std::f64::consts::PI/3.0).cos()]
+        } else {
+            let sqrt_d = d.sqrt();
+            vec![(sqrt_d - q).cbrt() - (sqrt_d + q).cbrt()]
+        };
+        roots.into_iter().map(|y| y - a/3.0).collect()
+    }
+
+    fn solve_quartic(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
+        // Substitute x = y - a/4 to get y^4 + p y^2 + q y + r = 0
+        let sq_a = a*a;
+        let p = -3.0/8.0*sq_a + b;
+        let q = sq_a*a/8.0 - a*b/2.0 + c;
+        let r = -3.0/256.0*sq_a*sq_a + sq_a*b/16.0 - a*c/4.0 + d;
+
+        let roots = if is_zero(r) {
+            let mut roots = solve_cubic(0.0, p, q);
+            roots.push(0.0);
+            roots
+        } else {
+            // One root of the resolvent cubic splits the quartic into two quadratics
+            let z = solve_cubic(-p/2.0, -r, r*p/2.0 - q*q/8.0)[0];
+            let u = z*z - r;
+            let v = 2.0*z - p;
+            let u = if is_zero(u) {0.0} else if u > 0.0 {u.sqrt()} else {return Vec::new()};
+            let v = if is_zero(v) {0.0} else if v > 0.0 {v.sqrt()} else {return Vec::new()};
+            let mut roots = solve_quadratic(if q < 0.0 {-v} else {v}, z - u);
+            roots.extend(solve_quadratic(if q < 0.0 {v} else {-v}, z + u));
+            roots
+        };
+
+        // Polish with Newton's method, the closed form loses a lot of precision
+        roots.into_iter().map(|y| {
+            let mut x = y - a/4.0;
+            for _ in 0..2 {
+                let f = (((x + a)*x + b)*x + c)*x + d;
+                let df = ((4.0*x + 3.0*a)*x + 2.0*b)*x + c;
+                if df != 0.0 {
+                    x -= f/df;
+                }
+            }
+            x
+        }).collect()
+    }
+
+    // Ring around the axis through center. u goes around the axis, v around the tube starting from the outside.
+    pub struct Torus{
+        pub center: point3,
+        pub axis: vec3,
+        // From the centre to the middle of the tube
+        pub major_radius: f32,
+        // Of the tube
+        pub minor_radius: f32,
+        pub material: Box<dyn Material>,
+        frame: Frame,
+    }
+
+    impl Torus{
+        pub fn new(center: point3, axis: vec3, major_radius: f32, minor_radius: f32, material: Box<dyn Material>) -> Self {
+            assert!(major_radius > 0.0 && minor_radius > 0.0, "Torus: need positive radii");
+            let frame = Frame::new(center, axis);
+            Self {center: center, axis: frame.axis, major_radius: major_radius, minor_radius: minor_radius, material: material, frame: frame}
+        }
+    }
+
+    impl Hittable for Torus{
+        fn hit(&self, ray: &Ray, attenuation: &mut colour, t_min: f32, t_max: f32, hit_record: &mut HitRecord, sampler: &mut dyn Sampler) -> Option<Ray>{
+            let dir_length = ray.dir.length();
+            let o = self.frame.to_local(ray.origin);
+            let d = self.frame.vector_to_local(ray.dir)/dir_length;
+
+            // Skip rays that miss the bounding sphere, and start the others at it to keep the quartic well conditioned
+            let bound = self.major_radius + self.minor_radius;
+            let half_b = vec3::dot(&o, &d);
+            let discriminant = half_b*half_b - (vec3::dot(&o, &o) - bound*bound);
+            if discriminant < 0.0 {
+                return None;
+            }
+            let s0 = (-half_b - discriminant.sqrt()).max(0.0);
+            let start = o + d*s0;
+
+            // (|p|^2 + R^2 - r^2)^2 = 4 R^2 (x^2 + z^2) along the ray, as a polynomial in distance
+            let (ox, oy, oz) = (start.x as f64, start.y as f64, start.z as f64);
+            let (dx, dy, dz) = (d.x as f64, d.y as f64, d.z as f64);
+            let (big_r, small_r) = (self.major_radius as f64, self.minor_radius as f64);
+            let m = ox*ox + oy*oy + oz*oz;
+            let n = ox*dx + oy*dy + oz*dz;
+            let k = m + big_r*big_r - small_r*small_r;
+            let four_r_sq = 4.0*big_r*big_r;
+            let roots = solve_quartic(4.0*n, 4.0*n*n + 2.0*k - four_r_sq*(dx*dx + dz*dz),
+                4.0*n*k - 2.0*four_r_sq*(ox*dx + oz*dz), k*k - four_r_sq*(ox*ox + oz*oz));
+
+            let candidates = roots.into_iter().map(|s| {
+                let s = s0 + s as f32;
+                let p = o + d*s;
+                // Away from the circle running through the middle of the tube
+                let ring = vec3::new(p.x, 0.0, p.z);
+                let ring_length = ring.length();
+                let ring_point = if ring_length > 0.0 {ring*(self.major_radius/ring_length)} else {ring};
+                let normal = p - ring_point;
+                let tube_angle = normal.y.atan2(ring_length - self.major_radius);
+                let v = (if tube_angle < 0.0 {tube_angle + TWO_PI} else {tube_angle})/TWO_PI;
+                (s/dir_length, normal, (around(p), v))
+            });
+            let hit = nearest(candidates, t_min, t_max)?;
+            finish_hit(ray, &self.frame, hit, &*self.material, attenuation, hit_record, sampler)
+        }
+
+        fn material(&self) -> &dyn Material {
+            &*self.material
+        }
+
+        fn bounding_box(&self) -> Option<Aabb> {
+            // The ring through the middle of the tube, grown by the tube radius
+            let ring = Aabb::around_disk(self.center, self.axis, self.major_radius);
+            let r = vec3::new(self.minor_radius, self.minor_radius, self.minor_radius);
+            Some(Aabb::new(ring.min - r, ring.max + r))
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use std::path::Path;
-use std::io::Write;
+use std::io::{Read, Write};
 use std::fs::File;
+use crate::film::{Film, Layer};
+use crate::tonemapping::{ToneMapper, srgb_oetf};
  
 pub struct RGB {
     pub r: u8,
@@ -21,6 +23,14 @@ impl PPM {
         PPM { height: height, width: width, data: buffer }
     }
  
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
     fn buffer_size(&self) -> u32 {
         3 * self.height * self.width
     }
@@ -66,4 +76,157 @@ impl PPM {
         file.write(&self.data)?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    // Reads a binary PPM (P6) or PGM (P5) file with 8 bit channels. Greyscale images are expanded to RGB.
+    pub fn read_file(filename: &str) -> std::io::Result<PPM> {
+        let mut bytes = Vec::new();
+        File::open(Path::new(filename))?.read_to_end(&mut bytes)?;
+
+        let invalid = |msg: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{}: {}", filename, msg));
+
+        // Header is four whitespace separated tokens (magic, width, height, maxval), with optional # comments
+        let mut tokens = Vec::new();
+        let mut pos = 0;
+        while tokens.len() < 4 {
+            while pos < bytes.len() && (bytes[pos].is_ascii_whitespace() || bytes[pos] == b'#') {
+                if bytes[pos] == b'#' {
+                    while pos < bytes.len() && bytes[pos] != b'\n' { pos += 1; }
+                }
+                pos += 1;
+            }
+            let start = pos;
+            while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() { pos += 1; }
+            if start == pos { return Err(invalid("truncated header")); }
+            tokens.push(String::from_utf8_lossy(&bytes[start..pos]).to_string());
+        }
+        // Exactly one whitespace byte separates the header from the raster
+        pos += 1;
+
+        let channels = match tokens[0].as_str() {
+            "P5" => 1,
+            "P6" => 3,
+            _ => return Err(invalid("only binary PGM (P5) and PPM (P6) are supported")),
+        };
+        let width: u32 = tokens[1].parse().map_err(|_| invalid("bad width"))?;
+        let height: u32 = tokens[2].parse().map_err(|_| invalid("bad height"))?;
+        if tokens[3] != "255" {
+            return Err(invalid("only 8 bit images are supported"));
+        }
+
+        let raster = &bytes[pos.min(bytes.len())..];
+        if raster.len() < (width * height) as usize * channels {
+            return Err(invalid("truncated raster"));
+        }
+
+        let mut ppm = PPM::new(height, width);
+        for (px, rgb) in raster.chunks(channels).take((width * height) as usize).zip(ppm.data.chunks_mut(3)) {
+            for c in 0..3 {
+                rgb[c] = px[c % channels];
+            }
+        }
+        Ok(ppm)
+    }
+}
+
+
+// Precision of the samples written to OpenEXR files
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ExrPrecision {
+    Half,
+    Float,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ExrCompression {
+    None,
+    Zip,
+    Piz,
+}
+
+// Writes the film to disk. The format is picked from the extension:
+// .exr (all layers), .pfm (one file per layer) or .ppm (beauty only, tone mapped and sRGB encoded).
+pub fn write_film(film: &Film, filename: &str, precision: ExrPrecision, compression: ExrCompression, tone_mapper: ToneMapper) -> std::io::Result<()> {
+    let extension = Path::new(filename).extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
+    match extension.as_str() {
+        "exr" => write_exr(film, filename, precision, compression),
+        "pfm" => {
+            for layer in film.layers.iter() {
+                // Beauty keeps the given name, other layers get theirs added before the extension
+                let layer_filename = if layer.name == "beauty" {
+                    filename.to_string()
+                } else {
+                    format!("{}.{}.pfm", &filename[..filename.len()-4], layer.name)
+                };
+                write_pfm(layer, film.width, film.height, &layer_filename)?;
+            }
+            Ok(())
+        },
+        "ppm" => {
+            let mut ppm = PPM::new(film.height as u32, film.width as u32);
+            for y in 0..film.height {
+                for x in 0..film.width {
+                    let c = tone_mapper.apply(film.beauty(x, y));
+                    let to_u8 = |v: f32| (255.0*srgb_oetf(v)).round() as u8;
+                    ppm.set_pixel(x as u32, y as u32, RGB{r: to_u8(c.x), g: to_u8(c.y), b: to_u8(c.z)});
+                }
+            }
+            ppm.write_file(filename)
+        },
+        _ => Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("{}: unsupported output format", filename))),
+    }
+}
+
+// Portable float map. Layers with one channel are written as greyscale, anything else as RGB (extra channels are dropped).
+pub fn write_pfm(layer: &Layer, width: usize, height: usize, filename: &str) -> std::io::Result<()> {
+    let n = layer.num_channels();
+    let (magic, out_channels) = if n == 1 {("Pf", 1)} else {("PF", 3)};
+
+    // Negative scale means little endian. Rows are stored bottom to top.
+    let mut bytes = format!("{}\n{} {}\n-1.0\n", magic, width, height).into_bytes();
+    for y in (0..height).rev() {
+        for x in 0..width {
+            let px = &layer.data[(y*width + x)*n..(y*width + x + 1)*n];
+            for c in 0..out_channels {
+                let v = if c < n {px[c]} else {0.0};
+                bytes.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+    }
+
+    let mut file = File::create(Path::new(filename))?;
+    file.write_all(&bytes)
+}
+
+// Single part OpenEXR. The beauty layer becomes the R, G, B channels, every other layer is
+// written as "<layer>.<channel>" so compositing packages pick them up as separate layers.
+pub fn write_exr(film: &Film, filename: &str, precision: ExrPrecision, compression: ExrCompression) -> std::io::Result<()> {
+    use exr::prelude as exr;
+    use exr::WritableImage;
+
+    let mut channels = exr::SmallVec::new();
+    for layer in film.layers.iter() {
+        for (c, channel_name) in layer.channels.iter().enumerate() {
+            let name = if layer.name == "beauty" {channel_name.clone()} else {format!("{}.{}", layer.name, channel_name)};
+            let values = layer.channel(c);
+            let samples = match precision {
+                ExrPrecision::Half => exr::FlatSamples::F16(values.into_iter().map(exr::f16::from_f32).collect()),
+                ExrPrecision::Float => exr::FlatSamples::F32(values),
+            };
+            channels.push(exr::AnyChannel::new(name.as_str(), samples));
+        }
+    }
+
+    let encoding = exr::Encoding{
+        compression: match compression {
+            ExrCompression::None => exr::Compression::Uncompressed,
+            ExrCompression::Zip => exr::Compression::ZIP16,
+            ExrCompression::Piz => exr::Compression::PIZ,
+        },
+        blocks: exr::Blocks::ScanLines,
+        line_order: exr::LineOrder::Increasing,
+    };
+
+    let layer = exr::Layer::new((film.width, film.height), exr::LayerAttributes::named("beauty"), encoding, exr::AnyChannels::sort(channels));
+    exr::Image::from_layer(layer).write().to_file(filename)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, format!("{}: {}", filename, e)))
+}
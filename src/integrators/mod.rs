@@ -0,0 +1,1299 @@
+pub use self::integrators::Integrator;
+pub use self::integrators::IntegratorKind;
+pub use self::integrators::DebugMode;
+pub use self::integrators::SampleRecord;
+
+mod integrators{
+    use std::sync::{Arc, Mutex, RwLock};
+    use crate::vector::vec3;
+    use crate::ray::Ray;
+    use crate::camera::Camera;
+    use crate::geometry::{HitRecord, HittableList};
+    use crate::sampling::{Sampler, RussianRoulette, cosine_hemisphere};
+    use crate::film::Aovs;
+    use crate::photons::PhotonMap;
+    use crate::settings::Settings;
+    use crate::tonemapping::heatmap;
+    use super::bdpt::Bdpt;
+    use super::mlt::Mlt;
+    use vec3 as colour;
+
+    // What an integrator reports besides the radiance of a camera sample
+    #[derive(Default)]
+    pub struct SampleRecord {
+        // Number of rays traced
+        pub path_len: usize,
+        // Light reaching other pixels, as screen coordinates (s, t) in [0,1]^2 and unnormalised radiance.
+        // Added to the film once per frame, divided by the number of samples taken.
+        pub splats: Vec<(f32, f32, colour)>,
+    }
+
+    // Computes the light arriving along a camera ray.
+    // aovs is filled in with the first hit if given.
+    pub trait Integrator: Send + Sync {
+        fn li(&self, ray: &Ray, scene: &HittableList, cam: &Camera, sampler: &mut dyn Sampler, record: &mut SampleRecord, aovs: Option<&mut Aovs>) -> colour;
+
+        // Called by every render thread before it starts on a frame, for work shared by the whole frame
+        fn begin_frame(&self, _scene: &HittableList, _cam: &Camera, _frame: usize) {}
+
+        // False colour output should reach the screen as-is
+        fn tone_mapped(&self) -> bool {
+            true
+        }
+
+        // Frames are independent estimates that get better over time, so they should be averaged while the camera stands still
+        fn progressive(&self) -> bool {
+            false
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum IntegratorKind {
+        Path,
+        Bdpt,
+        PhotonMapping,
+        Mlt,
+        AmbientOcclusion,
+        Whitted,
+        Debug(DebugMode),
+    }
+
+    impl IntegratorKind {
+        pub fn from_name(name: &str) -> Option<IntegratorKind> {
+            match name {
+                "path" => Some(IntegratorKind::Path),
+                "bdpt" => Some(IntegratorKind::Bdpt),
+                "photon" => Some(IntegratorKind::PhotonMapping),
+                "mlt" => Some(IntegratorKind::Mlt),
+                "ao" => Some(IntegratorKind::AmbientOcclusion),
+                "whitted" => Some(IntegratorKind::Whitted),
+                _ => DebugMode::ALL.iter().find(|m| m.name() == name).map(|&m| IntegratorKind::Debug(m)),
+            }
+        }
+
+        pub fn create(&self, settings: &Settings) -> Arc<dyn Integrator> {
+            match self {
+                IntegratorKind::Path => Arc::new(PathTracer{max_bounces: settings.max_bounces, roulette: settings.russian_roulette}),
+                IntegratorKind::Bdpt => Arc::new(Bdpt::new(settings)),
+                IntegratorKind::PhotonMapping => Arc::new(PhotonMapper::new(settings)),
+                IntegratorKind::Mlt => Arc::new(Mlt::new(PathTracer{max_bounces: settings.max_bounces, roulette: settings.russian_roulette},
+                    settings.mlt_bootstrap, settings.mlt_large_step, settings.mlt_sigma)),
+                IntegratorKind::AmbientOcclusion => Arc::new(AmbientOcclusion{max_distance: settings.ao_distance, samples: settings.ao_samples}),
+                IntegratorKind::Whitted => Arc::new(Whitted{max_depth: settings.max_bounces}),
+                IntegratorKind::Debug(mode) => Arc::new(DebugIntegrator{
+                    mode: *mode,
+                    path: PathTracer{max_bounces: settings.max_bounces, roulette: settings.russian_roulette},
+                }),
+            }
+        }
+
+        pub fn name(&self) -> &'static str {
+            match self {
+                IntegratorKind::Path => "path",
+                IntegratorKind::Bdpt => "bdpt",
+                IntegratorKind::PhotonMapping => "photon",
+                IntegratorKind::Mlt => "mlt",
+                IntegratorKind::AmbientOcclusion => "ao",
+                IntegratorKind::Whitted => "whitted",
+                IntegratorKind::Debug(mode) => mode.name(),
+            }
+        }
+    }
+
+    // Light reaching a hit point straight from the point, spot and directional lights, with shadow rays.
+    // Paths can never hit these lights, so this is the only way they are found.
+    fn delta_lights(scene: &HittableList, hr: &HitRecord, wo: vec3, sampler: &mut dyn Sampler, record: &mut SampleRecord) -> colour {
+        let mut radiance = colour::new(0.0, 0.0, 0.0);
+        let material = scene.list[hr.object_id].material();
+        for light in scene.lights.iter().filter(|l| l.is_delta()) {
+            let ls = match light.sample_li(scene, hr.p, sampler.get_2d()) {
+                Some(ls) if ls.pdf > 0.0 => ls,
+                _ => continue,
+            };
+            let f = material.eval(wo, ls.wi, hr)*vec3::dot(&ls.wi, &hr.normal).abs();
+            if f.max_component() <= 0.0 {
+                continue;
+            }
+            record.path_len += 1;
+            if !scene.occluded(hr.p, ls.p) {
+                radiance += f*ls.radiance/ls.pdf;
+            }
+        }
+        radiance
+    }
+
+    // Unidirectional path tracer. Follows the scattered ray of every hit, keeping the product
+    // of the attenuations as the path throughput, until the path leaves the scene,
+    // reaches max_bounces or is ended by Russian roulette. Light is only picked up when a path
+    // happens to hit an emitter or the sky, except for delta lights, which get a shadow ray at every hit.
+    pub struct PathTracer {
+        pub max_bounces: usize,
+        pub roulette: Option<RussianRoulette>,
+    }
+
+    impl Integrator for PathTracer {
+        fn li(&self, ray: &Ray, scene: &HittableList, _cam: &Camera, sampler: &mut dyn Sampler, record: &mut SampleRecord, mut aovs: Option<&mut Aovs>) -> colour {
+            let mut radiance = colour::new(0.0, 0.0, 0.0);
+            let mut throughput = colour::new(1.0, 1.0, 1.0);
+            let mut ray = *ray;
+
+            for depth in 0..self.max_bounces {
+                record.path_len += 1;
+
+                let mut hr = HitRecord::default();
+                let mut attenuation = colour::new(0.0, 0.0, 0.0);
+                let scattered = match scene.hit(&ray, &mut attenuation, 0.001, f32::INFINITY, &mut hr, sampler) {
+                    Some(r) => r,
+                    None => {
+                        radiance += throughput*scene.background(&ray);
+                        break;
+                    }
+                };
+                if let Some(aovs) = aovs.take() {
+                    *aovs = Aovs::from_hit(&ray, &hr, attenuation);
+                }
+                radiance += throughput*scene.emitted(&hr);
+                radiance += throughput*delta_lights(scene, &hr, -vec3::unit_vector(ray.dir), sampler, record);
+
+                throughput = throughput*attenuation;
+                if let Some(roulette) = self.roulette {
+                    let p = roulette.survival_probability(depth + 1, throughput);
+                    if p <= 0.0 || sampler.get_1d() >= p {
+                        break;
+                    }
+                    throughput = throughput/p;
+                }
+                ray = scattered;
+            }
+            radiance
+        }
+    }
+
+    // Path tracer that takes caustics from a photon map at the first diffuse hit, instead of waiting for
+    // paths to find the light through glass. Camera paths that go from that hit through specular bounces
+    // to a light are not counted, the photon map already has them. Only scene lights emit photons, the sky doesn't.
+    // max_bounces applies to photon and camera paths separately, so paths can be longer than with the other integrators.
+    //
+    // Progressive (Knaus and Zwicker): every frame is a pass with a new photon map and a smaller lookup radius
+    // than the last, and the viewer averages the passes, which converges. Both start over when the camera moves.
+    pub struct PhotonMapper {
+        pub max_bounces: usize,
+        pub roulette: Option<RussianRoulette>,
+        pub photons: usize,
+        // Lookup radius of the first pass
+        pub radius: f32,
+        // Fraction of the photons kept from one pass to the next, in (0,1). Smaller shrinks the radius faster.
+        pub alpha: f32,
+
+        pass: RwLock<Option<PhotonPass>>,
+        tracing: Mutex<()>,
+    }
+
+    struct PhotonPass {
+        frame: usize,
+        // Passes since the camera last moved, which sets the radius
+        index: usize,
+        cam: Camera,
+        map: Arc<PhotonMap>,
+    }
+
+    impl PhotonMapper {
+        pub fn new(settings: &Settings) -> Self {
+            PhotonMapper{max_bounces: settings.max_bounces, roulette: settings.russian_roulette,
+                photons: settings.photons, radius: settings.photon_radius, alpha: settings.ppm_alpha,
+                pass: RwLock::new(None), tracing: Mutex::new(())}
+        }
+
+        // r_{i+1}^2 = r_i^2 (i + alpha)/(i + 1)
+        fn radius(&self, pass: usize) -> f32 {
+            let mut radius_sq = self.radius*self.radius;
+            for i in 1..=pass {
+                radius_sq *= (i as f32 + self.alpha)/(i as f32 + 1.0);
+            }
+            radius_sq.sqrt()
+        }
+
+        fn has_pass(&self, frame: usize) -> bool {
+            self.pass.read().unwrap().as_ref().map_or(false, |pass| pass.frame >= frame)
+        }
+
+        fn photon_map(&self, scene: &HittableList, cam: &Camera) -> Arc<PhotonMap> {
+            if let Some(pass) = self.pass.read().unwrap().as_ref() {
+                return pass.map.clone();
+            }
+            // Switched to in the middle of a frame
+            self.begin_frame(scene, cam, 0);
+            self.pass.read().unwrap().as_ref().unwrap().map.clone()
+        }
+    }
+
+    impl Integrator for PhotonMapper {
+        fn begin_frame(&self, scene: &HittableList, cam: &Camera, frame: usize) {
+            if self.has_pass(frame) {
+                return;
+            }
+            let _tracing = self.tracing.lock().unwrap();
+            // Another thread may have traced it while this one was waiting
+            if self.has_pass(frame) {
+                return;
+            }
+            let index = match self.pass.read().unwrap().as_ref() {
+                Some(pass) if pass.cam.same_view(cam) => pass.index + 1,
+                _ => 0,
+            };
+            let map = PhotonMap::trace(scene, self.photons, self.max_bounces, self.radius(index), frame as u64);
+            println!("INFO: Photon pass {}: {} caustic photons, radius {:.4}", index, map.len(), map.radius);
+            *self.pass.write().unwrap() = Some(PhotonPass{frame: frame, index: index, cam: cam.clone(), map: Arc::new(map)});
+        }
+
+        fn progressive(&self) -> bool {
+            true
+        }
+
+        fn li(&self, ray: &Ray, scene: &HittableList, cam: &Camera, sampler: &mut dyn Sampler, record: &mut SampleRecord, mut aovs: Option<&mut Aovs>) -> colour {
+            let map = self.photon_map(scene, cam);
+            let mut radiance = colour::new(0.0, 0.0, 0.0);
+            let mut throughput = colour::new(1.0, 1.0, 1.0);
+            let mut ray = *ray;
+            // Specular bounces since the first diffuse hit, None before it or once the path hits another diffuse surface
+            let mut specular_chain: Option<usize> = None;
+            let mut diffuse_seen = false;
+
+            for depth in 0..self.max_bounces {
+                record.path_len += 1;
+
+                let mut hr = HitRecord::default();
+                let mut attenuation = colour::new(0.0, 0.0, 0.0);
+                if scene.hit(&ray, &mut attenuation, 0.001, f32::INFINITY, &mut hr, sampler).is_none() {
+                    radiance += throughput*scene.background(&ray);
+                    break;
+                }
+                if let Some(aovs) = aovs.take() {
+                    *aovs = Aovs::from_hit(&ray, &hr, attenuation);
+                }
+                if !matches!(specular_chain, Some(n) if n > 0) {
+                    radiance += throughput*scene.emitted(&hr);
+                }
+
+                let wo = -vec3::unit_vector(ray.dir);
+                radiance += throughput*delta_lights(scene, &hr, wo, sampler, record);
+                let bs = match scene.list[hr.object_id].material().sample(wo, &hr, sampler) {
+                    Some(bs) => bs,
+                    None => break,
+                };
+                if bs.specular {
+                    specular_chain = specular_chain.map(|n| n + 1);
+                } else if !diffuse_seen {
+                    radiance += throughput*map.estimate(scene, &hr, wo);
+                    diffuse_seen = true;
+                    specular_chain = Some(0);
+                } else {
+                    specular_chain = None;
+                }
+
+                throughput = throughput*bs.weight;
+                if let Some(roulette) = self.roulette {
+                    let p = roulette.survival_probability(depth + 1, throughput);
+                    if p <= 0.0 || sampler.get_1d() >= p {
+                        break;
+                    }
+                    throughput = throughput/p;
+                }
+                ray = Ray::new(hr.p, bs.wi);
+            }
+            radiance
+        }
+    }
+
+    // Fraction of cosine weighted rays from the first hit that travel max_distance without hitting anything.
+    // Only one bounce and no materials, so it is a quick preview of the geometry.
+    pub struct AmbientOcclusion {
+        pub max_distance: f32,
+        pub samples: usize,
+    }
+
+    impl Integrator for AmbientOcclusion {
+        fn li(&self, ray: &Ray, scene: &HittableList, _cam: &Camera, sampler: &mut dyn Sampler, record: &mut SampleRecord, aovs: Option<&mut Aovs>) -> colour {
+            record.path_len += 1;
+            let mut hr = HitRecord::default();
+            let mut attenuation = colour::new(0.0, 0.0, 0.0);
+            if scene.hit(ray, &mut attenuation, 0.001, f32::INFINITY, &mut hr, sampler).is_none() {
+                return colour::new(1.0, 1.0, 1.0);
+            }
+            if let Some(aovs) = aovs {
+                *aovs = Aovs::from_hit(ray, &hr, attenuation);
+            }
+
+            let samples = self.samples.max(1);
+            let mut unoccluded = 0;
+            for _ in 0..samples {
+                record.path_len += 1;
+                let dir = cosine_hemisphere(hr.normal, sampler.get_2d());
+                // occluded() keeps the occluders' materials from drawing on the pixel's sampler
+                if !scene.occluded(hr.p, hr.p + dir*self.max_distance) {
+                    unoccluded += 1;
+                }
+            }
+            let ao = unoccluded as f32/samples as f32;
+            colour::new(ao, ao, ao)
+        }
+
+        // Already in [0,1]
+        fn tone_mapped(&self) -> bool {
+            false
+        }
+    }
+
+    // Deterministic ray tracer for previews: direct light from every light with hard shadows, plus perfect
+    // mirror reflection and Fresnel weighted reflection and refraction, followed up to max_depth.
+    // Area lights are treated as points at their centre. Diffuse surfaces also get the sky colour
+    // along their normal as ambient light, otherwise scenes lit only by the sky would be black.
+    pub struct Whitted {
+        pub max_depth: usize,
+    }
+
+    impl Whitted {
+        fn trace(&self, ray: &Ray, depth: usize, scene: &HittableList, sampler: &mut dyn Sampler, record: &mut SampleRecord, aovs: Option<&mut Aovs>) -> colour {
+            record.path_len += 1;
+            let mut hr = HitRecord::default();
+            let mut attenuation = colour::new(0.0, 0.0, 0.0);
+            if scene.hit(ray, &mut attenuation, 0.001, f32::INFINITY, &mut hr, sampler).is_none() {
+                return scene.background(ray);
+            }
+            if let Some(aovs) = aovs {
+                *aovs = Aovs::from_hit(ray, &hr, attenuation);
+            }
+
+            let wo = -vec3::unit_vector(ray.dir);
+            let response = scene.list[hr.object_id].material().preview(wo, &hr);
+            let mut radiance = scene.emitted(&hr);
+
+            if response.diffuse.length_squared() > 0.0 {
+                radiance += response.diffuse*scene.background(&Ray::new(hr.p, hr.normal));
+                for light in scene.lights.iter() {
+                    // Emitters don't light themselves
+                    if light.object_id() == Some(hr.object_id) {
+                        continue;
+                    }
+                    let ls = match light.sample_li_preview(scene, hr.p) {
+                        Some(ls) => ls,
+                        None => continue,
+                    };
+                    let cos = vec3::dot(&hr.normal, &ls.wi);
+                    if cos <= 0.0 || ls.pdf <= 0.0 {
+                        continue;
+                    }
+                    record.path_len += 1;
+                    // The light's own shape is in the way of its centre
+                    let to_light = ls.p - hr.p;
+                    let mut occluder = HitRecord::default();
+                    let shadow_ray = Ray::new(hr.p, ls.wi);
+                    if scene.hit(&shadow_ray, &mut attenuation, 0.001, to_light.length() - 0.001, &mut occluder, sampler).is_some()
+                        && Some(occluder.object_id) != light.object_id() {
+                        continue;
+                    }
+                    radiance += response.diffuse*ls.radiance*(cos/(std::f32::consts::PI*ls.pdf));
+                }
+            }
+
+            if depth + 1 < self.max_depth {
+                for (dir, weight) in response.specular {
+                    radiance += weight*self.trace(&Ray::new(hr.p, dir), depth + 1, scene, sampler, record, None);
+                }
+            }
+            radiance
+        }
+    }
+
+    impl Integrator for Whitted {
+        fn li(&self, ray: &Ray, scene: &HittableList, _cam: &Camera, sampler: &mut dyn Sampler, record: &mut SampleRecord, aovs: Option<&mut Aovs>) -> colour {
+            self.trace(ray, 0, scene, sampler, record, aovs)
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum DebugMode {
+        ShadingNormals,
+        GeometricNormals,
+        Uv,
+        Depth,
+        Barycentrics,
+        Facing,
+        IntersectionCost,
+        Bounces,
+    }
+
+    impl DebugMode {
+        // In hotkey order, 1 to 8 in the viewer
+        pub const ALL: [DebugMode; 8] = [
+            DebugMode::ShadingNormals,
+            DebugMode::GeometricNormals,
+            DebugMode::Uv,
+            DebugMode::Depth,
+            DebugMode::Barycentrics,
+            DebugMode::Facing,
+            DebugMode::IntersectionCost,
+            DebugMode::Bounces,
+        ];
+
+        pub fn name(&self) -> &'static str {
+            match self {
+                DebugMode::ShadingNormals => "normals",
+                DebugMode::GeometricNormals => "geometric-normals",
+                DebugMode::Uv => "uv",
+                DebugMode::Depth => "depth",
+                DebugMode::Barycentrics => "barycentrics",
+                DebugMode::Facing => "facing",
+                DebugMode::IntersectionCost => "cost",
+                DebugMode::Bounces => "bounces",
+            }
+        }
+    }
+
+    // Visualises one property of the first hit, or of the whole path for cost and bounces.
+    // Rays that miss everything are black.
+    pub struct DebugIntegrator {
+        pub mode: DebugMode,
+        // Traces the paths for the bounce count and the intersection cost
+        pub path: PathTracer,
+    }
+
+    impl Integrator for DebugIntegrator {
+        fn li(&self, ray: &Ray, scene: &HittableList, cam: &Camera, sampler: &mut dyn Sampler, record: &mut SampleRecord, aovs: Option<&mut Aovs>) -> colour {
+            let black = colour::new(0.0, 0.0, 0.0);
+            let max_bounces = self.path.max_bounces.max(1) as f32;
+
+            if self.mode == DebugMode::Bounces {
+                let mut path = SampleRecord::default();
+                self.path.li(ray, scene, cam, sampler, &mut path, aovs);
+                record.path_len += path.path_len;
+                return heatmap(path.path_len as f32/max_bounces);
+            }
+
+            record.path_len += 1;
+            let mut hr = HitRecord::default();
+            let mut attenuation = colour::new(0.0, 0.0, 0.0);
+            let scattered = match scene.hit(ray, &mut attenuation, 0.001, f32::INFINITY, &mut hr, sampler) {
+                Some(r) => r,
+                None => return black,
+            };
+            if let Some(aovs) = aovs {
+                *aovs = Aovs::from_hit(ray, &hr, attenuation);
+            }
+
+            let fract = |x: f32| x - x.floor();
+            match self.mode {
+                DebugMode::ShadingNormals => hr.normal*0.5 + colour::new(0.5, 0.5, 0.5),
+                DebugMode::GeometricNormals => hr.geometric_normal*0.5 + colour::new(0.5, 0.5, 0.5),
+                // Wrapped, so that planes show a grid
+                DebugMode::Uv => colour::new(fract(hr.uv.0), fract(hr.uv.1), 0.0),
+                DebugMode::Depth => {
+                    let d = 1.0/(1.0 + hr.t*ray.dir.length());
+                    colour::new(d, d, d)
+                },
+                // Dark grey for anything that isn't a triangle
+                DebugMode::Barycentrics => match hr.barycentrics {
+                    Some((b1, b2)) => colour::new(1.0 - b1 - b2, b1, b2),
+                    None => colour::new(0.1, 0.1, 0.1),
+                },
+                DebugMode::Facing => if hr.front_face {colour::new(0.0, 1.0, 0.0)} else {colour::new(1.0, 0.0, 0.0)},
+                // Intersection tests along the whole path, relative to testing every object on every bounce.
+                // There is no BVH yet, only a bounding box test per object, so this mostly varies with the path length.
+                DebugMode::IntersectionCost => {
+                    let mut tests = hr.intersection_tests;
+                    let mut ray = scattered;
+                    for _ in 1..self.path.max_bounces {
+                        let mut hr = HitRecord::default();
+                        record.path_len += 1;
+                        match scene.hit(&ray, &mut attenuation, 0.001, f32::INFINITY, &mut hr, sampler) {
+                            Some(r) => { tests += hr.intersection_tests; ray = r; },
+                            None => { tests += scene.list.len(); break; },
+                        }
+                    }
+                    heatmap(tests as f32/(scene.list.len().max(1) as f32*max_bounces))
+                },
+                DebugMode::Bounces => black,
+            }
+        }
+
+        fn tone_mapped(&self) -> bool {
+            false
+        }
+    }
+}
+
+mod bdpt{
+    use std::sync::{Arc, RwLock};
+    use crate::vector::vec3;
+    use crate::ray::Ray;
+    use crate::camera::Camera;
+    use crate::geometry::{HitRecord, HittableList};
+    use crate::lights::LightDistribution;
+    use crate::sampling::Sampler;
+    use crate::film::Aovs;
+    use crate::settings::Settings;
+    use super::integrators::{Integrator, SampleRecord};
+    use vec3 as point3;
+    use vec3 as colour;
+
+    #[derive(Copy, Clone, PartialEq)]
+    enum VertexKind {
+        Camera,
+        // Index into the scene's lights
+        Light(usize),
+        Surface,
+    }
+
+    #[derive(Copy, Clone)]
+    struct Vertex {
+        kind: VertexKind,
+        p: point3,
+        // Surface normal, viewing direction for the camera, zero for lights that aren't surfaces
+        n: vec3,
+        // Unit direction towards the previous vertex of the subpath
+        wo: vec3,
+        hr: HitRecord,
+        // Product of the sampling weights up to this vertex
+        beta: colour,
+        // Area densities of sampling this vertex from the previous vertex of its subpath, and in the reverse direction
+        pdf_fwd: f32,
+        pdf_rev: f32,
+        // Only reachable by sampling a specular BSDF, so it can't be connected to
+        delta: bool,
+    }
+
+    impl Vertex {
+        fn camera(cam: &Camera, p: point3, beta: colour) -> Self {
+            Vertex{kind: VertexKind::Camera, p: p, n: -cam.w, wo: vec3::new(0.0, 0.0, 0.0), hr: HitRecord::default(),
+                beta: beta, pdf_fwd: 0.0, pdf_rev: 0.0,
+                // Light paths can only be connected to a pinhole
+                delta: cam.lens_radius > 0.0}
+        }
+
+        fn light(idx: usize, p: point3, n: vec3, beta: colour, pdf: f32) -> Self {
+            Vertex{kind: VertexKind::Light(idx), p: p, n: n, wo: vec3::new(0.0, 0.0, 0.0), hr: HitRecord::default(),
+                beta: beta, pdf_fwd: pdf, pdf_rev: 0.0, delta: false}
+        }
+
+        fn surface(hr: HitRecord, wo: vec3, beta: colour) -> Self {
+            Vertex{kind: VertexKind::Surface, p: hr.p, n: hr.normal, wo: wo, hr: hr, beta: beta, pdf_fwd: 0.0, pdf_rev: 0.0, delta: false}
+        }
+
+        fn on_surface(&self) -> bool {
+            match self.kind {
+                VertexKind::Camera => false,
+                VertexKind::Light(_) => self.n.length_squared() > 0.0,
+                VertexKind::Surface => true,
+            }
+        }
+
+        // The light this vertex is on, and the normal it emits along
+        fn light_index(&self, scene: &HittableList) -> Option<(usize, vec3)> {
+            match self.kind {
+                VertexKind::Camera => None,
+                VertexKind::Light(idx) => Some((idx, self.n)),
+                VertexKind::Surface => scene.light_index(self.hr.object_id).map(|idx| (idx, self.hr.geometric_normal)),
+            }
+        }
+
+        fn is_delta_light(&self, scene: &HittableList) -> bool {
+            match self.kind {
+                VertexKind::Light(idx) => scene.lights[idx].is_delta(),
+                _ => false,
+            }
+        }
+
+        // BSDF towards wi, times the cosine there
+        fn f_cos(&self, scene: &HittableList, wi: vec3) -> colour {
+            let f = scene.list[self.hr.object_id].material().eval(self.wo, wi, &self.hr);
+            f*vec3::dot(&wi, &self.n).abs()
+        }
+
+        // Area density at next of sampling it from this vertex, coming from prev
+        fn pdf(&self, scene: &HittableList, cam: &Camera, prev: Option<&Vertex>, next: &Vertex) -> f32 {
+            if let VertexKind::Light(_) = self.kind {
+                return self.pdf_light(scene, next);
+            }
+            let d = next.p - self.p;
+            if d.length_squared() <= 0.0 {
+                return 0.0;
+            }
+            let wn = vec3::unit_vector(d);
+            let pdf = match self.kind {
+                VertexKind::Camera => {
+                    match cam.project(next.p) {
+                        Some((s, t)) if (0.0..=1.0).contains(&s) && (0.0..=1.0).contains(&t) => camera_importance(cam, wn).1,
+                        _ => 0.0,
+                    }
+                },
+                _ => {
+                    let wp = match prev {
+                        Some(prev) => vec3::unit_vector(prev.p - self.p),
+                        None => return 0.0,
+                    };
+                    scene.list[self.hr.object_id].material().pdf(wp, wn, &self.hr)
+                },
+            };
+            convert_density(pdf, self, next)
+        }
+
+        // Area density at v of a light path leaving this (emitting) vertex towards it
+        fn pdf_light(&self, scene: &HittableList, v: &Vertex) -> f32 {
+            let (idx, n) = match self.light_index(scene) {
+                Some(l) => l,
+                None => return 0.0,
+            };
+            let d = v.p - self.p;
+            let dist_sq = d.length_squared();
+            if dist_sq <= 0.0 {
+                return 0.0;
+            }
+            let w = d/dist_sq.sqrt();
+            // Infinite lights start paths on a disc perpendicular to them, there is no falloff
+            let mut pdf = match scene.lights[idx].infinite_pdf(v.p) {
+                Some(pdf) => pdf,
+                None => scene.lights[idx].pdf_le(scene, n, w).1/dist_sq,
+            };
+            if v.on_surface() {
+                pdf *= vec3::dot(&v.n, &w).abs();
+            }
+            pdf
+        }
+
+        // Area density of a light path starting at this vertex, including the choice of light
+        fn pdf_light_origin(&self, scene: &HittableList, lights: &LightDistribution, v: &Vertex) -> f32 {
+            let (idx, n) = match self.light_index(scene) {
+                Some(l) => l,
+                None => return 0.0,
+            };
+            let w = vec3::unit_vector(v.p - self.p);
+            let (pdf_pos, _) = scene.lights[idx].pdf_le(scene, n, w);
+            pdf_pos*lights.pdf(idx)
+        }
+    }
+
+    // Solid angle density at `from` to area density at `to`
+    fn convert_density(pdf: f32, from: &Vertex, to: &Vertex) -> f32 {
+        let d = to.p - from.p;
+        let dist_sq = d.length_squared();
+        if dist_sq <= 0.0 {
+            return 0.0;
+        }
+        let mut pdf = pdf/dist_sq;
+        if to.on_surface() {
+            pdf *= vec3::dot(&to.n, &(d/dist_sq.sqrt())).abs();
+        }
+        pdf
+    }
+
+    // Importance and direction density of a pinhole camera for a unit direction.
+    // The image is a rectangle of area A at distance 1, so the density is 1/(A cos^3) and the importance 1/(A cos^4).
+    fn camera_importance(cam: &Camera, dir: vec3) -> (f32, f32) {
+        let cos = vec3::dot(&dir, &-cam.w);
+        if cos <= 0.0 {
+            return (0.0, 0.0);
+        }
+        let pdf = 1.0/(cam.viewport_width*cam.viewport_height*cos*cos*cos);
+        (pdf/cos, pdf)
+    }
+
+    // Extends a subpath by sampling BSDFs until it leaves the scene, is absorbed or has max_vertices.
+    // Returns the ray that left the scene and its throughput, if any.
+    fn random_walk(scene: &HittableList, mut ray: Ray, sampler: &mut dyn Sampler, mut beta: colour, pdf: f32, max_vertices: usize,
+        path: &mut Vec<Vertex>, record: &mut SampleRecord, mut aovs: Option<&mut Aovs>) -> Option<(Ray, colour)> {
+
+        let mut pdf_fwd = pdf;
+        while path.len() < max_vertices {
+            record.path_len += 1;
+            let mut hr = HitRecord::default();
+            let mut attenuation = colour::new(0.0, 0.0, 0.0);
+            if scene.hit(&ray, &mut attenuation, 0.001, f32::INFINITY, &mut hr, sampler).is_none() {
+                return Some((ray, beta));
+            }
+            if let Some(aovs) = aovs.take() {
+                *aovs = Aovs::from_hit(&ray, &hr, attenuation);
+            }
+
+            let wo = -vec3::unit_vector(ray.dir);
+            let prev = path.len() - 1;
+            let mut vertex = Vertex::surface(hr, wo, beta);
+            vertex.pdf_fwd = convert_density(pdf_fwd, &path[prev], &vertex);
+            path.push(vertex);
+            if path.len() >= max_vertices {
+                break;
+            }
+
+            let material = scene.list[hr.object_id].material();
+            let bs = match material.sample(wo, &hr, sampler) {
+                Some(bs) => bs,
+                None => break,
+            };
+            let pdf_rev;
+            if bs.specular {
+                path[prev + 1].delta = true;
+                pdf_fwd = 0.0;
+                pdf_rev = 0.0;
+            } else {
+                pdf_fwd = bs.pdf;
+                pdf_rev = material.pdf(bs.wi, wo, &hr);
+            }
+            beta = beta*bs.weight;
+            path[prev].pdf_rev = convert_density(pdf_rev, &path[prev + 1], &path[prev]);
+            ray = Ray::new(hr.p, bs.wi);
+        }
+        None
+    }
+
+    // Bidirectional path tracer. Traces a subpath from the camera and one from a light, and connects
+    // every pair of their vertices, weighting each connection strategy with the balance heuristic.
+    // Connections straight to the camera land on other pixels and are returned as splats.
+    // The sky is not a light, so it is only found by camera subpaths.
+    pub struct Bdpt {
+        pub max_bounces: usize,
+
+        // Frame number and light selection probabilities it was built for
+        lights: RwLock<Option<(usize, Arc<LightDistribution>)>>,
+    }
+
+    impl Bdpt {
+        pub fn new(settings: &Settings) -> Self {
+            Bdpt{max_bounces: settings.max_bounces, lights: RwLock::new(None)}
+        }
+
+        fn light_distribution(&self, scene: &HittableList, cam: &Camera) -> Arc<LightDistribution> {
+            if let Some((_, lights)) = self.lights.read().unwrap().as_ref() {
+                return lights.clone();
+            }
+            // Switched to in the middle of a frame
+            self.begin_frame(scene, cam, 0);
+            self.lights.read().unwrap().as_ref().unwrap().1.clone()
+        }
+    }
+
+    impl Integrator for Bdpt {
+        fn begin_frame(&self, scene: &HittableList, _cam: &Camera, frame: usize) {
+            let mut lights = self.lights.write().unwrap();
+            if lights.as_ref().map_or(true, |(f, _)| *f < frame) {
+                *lights = Some((frame, Arc::new(LightDistribution::new(scene))));
+            }
+        }
+
+        fn li(&self, ray: &Ray, scene: &HittableList, cam: &Camera, sampler: &mut dyn Sampler, record: &mut SampleRecord, aovs: Option<&mut Aovs>) -> colour {
+            let lights = self.light_distribution(scene, cam);
+            let mut radiance = colour::new(0.0, 0.0, 0.0);
+
+            let dir = vec3::unit_vector(ray.dir);
+            let mut camera_path = vec![Vertex::camera(cam, ray.origin, colour::new(1.0, 1.0, 1.0))];
+            let escaped = random_walk(scene, Ray::new(ray.origin, dir), sampler, colour::new(1.0, 1.0, 1.0), camera_importance(cam, dir).1,
+                self.max_bounces + 2, &mut camera_path, record, aovs);
+            if let Some((r, beta)) = escaped {
+                radiance += beta*scene.background(&r);
+            }
+
+            let mut light_path = Vec::new();
+            if let Some((idx, light_pdf)) = lights.sample(sampler.get_1d()) {
+                let (u_pos, u_dir) = (sampler.get_2d(), sampler.get_2d());
+                if let Some(e) = scene.lights[idx].sample_le(scene, u_pos, u_dir) {
+                    let start = Vertex::light(idx, e.ray.origin, e.n, e.radiance, e.pdf_pos*light_pdf);
+                    let cos = if start.on_surface() {vec3::dot(&e.n, &e.ray.dir).abs()} else {1.0};
+                    let beta = e.radiance*(cos/(light_pdf*e.pdf_pos*e.pdf_dir));
+                    light_path.push(start);
+                    random_walk(scene, e.ray, sampler, beta, e.pdf_dir, self.max_bounces + 1, &mut light_path, record, None);
+                    if scene.lights[idx].infinite_pdf(e.ray.origin).is_some() && light_path.len() > 1 {
+                        light_path[1].pdf_fwd = light_path[0].pdf_light(scene, &light_path[1]);
+                    }
+                }
+            }
+
+            for t in 1..=camera_path.len() {
+                for s in 0..=light_path.len() {
+                    // Direct hits on the light already cover s = 1, t = 1
+                    if (s == 1 && t == 1) || s + t < 2 || s + t - 2 > self.max_bounces {
+                        continue;
+                    }
+                    let (l, raster) = self.connect(scene, cam, &lights, &light_path, &camera_path, s, t, sampler, record);
+                    match raster {
+                        Some((u, v)) => if l.max_component() > 0.0 { record.splats.push((u, v, l)); },
+                        None => radiance += l,
+                    }
+                }
+            }
+            radiance
+        }
+    }
+
+    impl Bdpt {
+        // Contribution of the path made of the first s light and first t camera vertices, with its MIS weight.
+        // Also returns the screen position for t = 1, where the contribution belongs to another pixel.
+        fn connect(&self, scene: &HittableList, cam: &Camera, lights: &LightDistribution, light_path: &[Vertex], camera_path: &[Vertex],
+            s: usize, t: usize, sampler: &mut dyn Sampler, record: &mut SampleRecord) -> (colour, Option<(f32, f32)>) {
+
+            let black = colour::new(0.0, 0.0, 0.0);
+            let mut sampled = None;
+            let mut raster = None;
+            let l;
+
+            if s == 0 {
+                // Camera subpath ends on an emitter
+                let pt = &camera_path[t - 1];
+                if pt.kind != VertexKind::Surface {
+                    return (black, None);
+                }
+                l = pt.beta*scene.emitted(&pt.hr);
+            } else if t == 1 {
+                // Light subpath connected to the camera
+                let qs = &light_path[s - 1];
+                if qs.delta || qs.kind != VertexKind::Surface || cam.lens_radius > 0.0 {
+                    return (black, None);
+                }
+                let st = match cam.project(qs.p) {
+                    Some((u, v)) if (0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v) => (u, v),
+                    _ => return (black, None),
+                };
+                let d = qs.p - cam.origin;
+                let dist_sq = d.length_squared();
+                let dir = d/dist_sq.sqrt();
+                let (importance, _) = camera_importance(cam, dir);
+                let camera = Vertex::camera(cam, cam.origin, colour::new(1.0, 1.0, 1.0)*(importance*vec3::dot(&dir, &-cam.w)/dist_sq));
+
+                l = qs.beta*qs.f_cos(scene, -dir)*camera.beta;
+                if l.max_component() <= 0.0 {
+                    return (black, None);
+                }
+                record.path_len += 1;
+                if scene.occluded(qs.p, cam.origin) {
+                    return (black, None);
+                }
+                sampled = Some(camera);
+                raster = Some(st);
+            } else if s == 1 {
+                // Camera subpath connected to a new point on a light
+                let pt = &camera_path[t - 1];
+                if pt.delta || pt.kind != VertexKind::Surface {
+                    return (black, None);
+                }
+                let (idx, light_pdf) = match lights.sample(sampler.get_1d()) {
+                    Some(choice) => choice,
+                    None => return (black, None),
+                };
+                let ls = match scene.lights[idx].sample_li(scene, pt.p, sampler.get_2d()) {
+                    Some(ls) if ls.pdf > 0.0 => ls,
+                    _ => return (black, None),
+                };
+                let mut light = Vertex::light(idx, ls.p, ls.n, ls.radiance/(ls.pdf*light_pdf), 0.0);
+                light.pdf_fwd = light.pdf_light_origin(scene, lights, pt);
+
+                l = pt.beta*pt.f_cos(scene, ls.wi)*light.beta;
+                if l.max_component() <= 0.0 {
+                    return (black, None);
+                }
+                record.path_len += 1;
+                if scene.occluded(pt.p, ls.p) {
+                    return (black, None);
+                }
+                sampled = Some(light);
+            } else {
+                let (qs, pt) = (&light_path[s - 1], &camera_path[t - 1]);
+                if qs.delta || pt.delta {
+                    return (black, None);
+                }
+                let d = qs.p - pt.p;
+                let dist_sq = d.length_squared();
+                if dist_sq <= 0.0 {
+                    return (black, None);
+                }
+                let w = d/dist_sq.sqrt();
+                // Cosines are in f_cos, the rest of the geometry term is the inverse square
+                l = qs.beta*qs.f_cos(scene, -w)*pt.f_cos(scene, w)*pt.beta/dist_sq;
+                if l.max_component() <= 0.0 {
+                    return (black, None);
+                }
+                record.path_len += 1;
+                if scene.occluded(pt.p, qs.p) {
+                    return (black, None);
+                }
+            }
+
+            if l.max_component() <= 0.0 {
+                return (black, None);
+            }
+            (l*self.mis_weight(scene, cam, lights, light_path, camera_path, sampled, s, t), raster)
+        }
+
+        // Balance heuristic weight of strategy (s, t), from the ratios of the densities the other
+        // strategies would have had for the same path
+        fn mis_weight(&self, scene: &HittableList, cam: &Camera, lights: &LightDistribution, light_path: &[Vertex], camera_path: &[Vertex],
+            sampled: Option<Vertex>, s: usize, t: usize) -> f32 {
+
+            if s + t == 2 {
+                return 1.0;
+            }
+            let mut qs_path = light_path[..s].to_vec();
+            let mut pt_path = camera_path[..t].to_vec();
+            if let Some(v) = sampled {
+                if s == 1 { qs_path[0] = v; } else { pt_path[0] = v; }
+            }
+
+            // The connection vertices are not specular
+            pt_path[t - 1].delta = false;
+            if s > 0 {
+                qs_path[s - 1].delta = false;
+            }
+
+            // Reverse densities around the connection
+            let pt = pt_path[t - 1];
+            let pt_minus = if t > 1 {Some(pt_path[t - 2])} else {None};
+            let qs = if s > 0 {Some(qs_path[s - 1])} else {None};
+            let qs_minus = if s > 1 {Some(qs_path[s - 2])} else {None};
+
+            pt_path[t - 1].pdf_rev = match (&qs, &pt_minus) {
+                (Some(qs), _) => qs.pdf(scene, cam, qs_minus.as_ref(), &pt),
+                (None, Some(pt_minus)) => pt.pdf_light_origin(scene, lights, pt_minus),
+                (None, None) => 0.0,
+            };
+            // No light subpath reaches pt, e.g. it is outside the disc of a directional light
+            if s == 1 && pt_path[t - 1].pdf_rev == 0.0 {
+                return 1.0;
+            }
+            if let Some(pt_minus) = pt_minus {
+                pt_path[t - 2].pdf_rev = match &qs {
+                    Some(qs) => pt.pdf(scene, cam, Some(qs), &pt_minus),
+                    None => pt.pdf_light(scene, &pt_minus),
+                };
+            }
+            if let Some(qs) = qs {
+                qs_path[s - 1].pdf_rev = pt.pdf(scene, cam, pt_minus.as_ref(), &qs);
+                if let Some(qs_minus) = qs_minus {
+                    qs_path[s - 2].pdf_rev = qs.pdf(scene, cam, Some(&pt), &qs_minus);
+                }
+            }
+
+            // Zero densities belong to specular vertices, which are skipped anyway
+            let remap = |f: f32| if f != 0.0 {f} else {1.0};
+            let mut sum_ri = 0.0;
+
+            let mut ri = 1.0;
+            for i in (1..t).rev() {
+                ri *= remap(pt_path[i].pdf_rev)/remap(pt_path[i].pdf_fwd);
+                if !pt_path[i].delta && !pt_path[i - 1].delta {
+                    sum_ri += ri;
+                }
+            }
+
+            let mut ri = 1.0;
+            for i in (0..s).rev() {
+                ri *= remap(qs_path[i].pdf_rev)/remap(qs_path[i].pdf_fwd);
+                let delta_before = if i > 0 {qs_path[i - 1].delta} else {qs_path[0].is_delta_light(scene)};
+                if !qs_path[i].delta && !delta_before {
+                    sum_ri += ri;
+                }
+            }
+            1.0/(1.0 + sum_ri)
+        }
+    }
+}
+
+mod mlt{
+    use std::cell::RefCell;
+    use std::sync::{Arc, Mutex, RwLock};
+    use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+    use crate::vector::vec3;
+    use crate::ray::Ray;
+    use crate::camera::Camera;
+    use crate::geometry::{HitRecord, HittableList};
+    use crate::sampling::{Sampler, IndependentSampler};
+    use crate::film::Aovs;
+    use super::integrators::{Integrator, SampleRecord, PathTracer};
+    use vec3 as colour;
+
+    // One coordinate of primary sample space, with what it was before the current mutation
+    #[derive(Copy, Clone, Default)]
+    struct PrimarySample {
+        value: f32,
+        backup: f32,
+        last_modified: u64,
+        modify_backup: u64,
+    }
+
+    // Hands the path tracer a point in primary sample space, mutating each coordinate lazily the first
+    // time it is used in an iteration (Kelemen et al., with the bookkeeping from pbrt).
+    struct MltSampler {
+        rng: IndependentSampler,
+        x: Vec<PrimarySample>,
+        sigma: f32,
+        large_step_probability: f32,
+        iteration: u64,
+        large_step: bool,
+        last_large_step: u64,
+        index: usize,
+    }
+
+    impl MltSampler {
+        fn new(seed: u64, sigma: f32, large_step_probability: f32) -> Self {
+            // Starts with a large step, so the first iteration is a plain random sample
+            MltSampler{rng: IndependentSampler::new(seed), x: Vec::new(), sigma: sigma, large_step_probability: large_step_probability,
+                iteration: 0, large_step: true, last_large_step: 0, index: 0}
+        }
+
+        fn start_iteration(&mut self) {
+            self.iteration += 1;
+            self.large_step = self.rng.get_1d() < self.large_step_probability;
+            self.index = 0;
+        }
+
+        fn accept(&mut self) {
+            if self.large_step {
+                self.last_large_step = self.iteration;
+            }
+        }
+
+        fn reject(&mut self) {
+            for xi in self.x.iter_mut() {
+                if xi.last_modified == self.iteration {
+                    xi.value = xi.backup;
+                    xi.last_modified = xi.modify_backup;
+                }
+            }
+            self.iteration -= 1;
+        }
+
+        fn next(&mut self) -> f32 {
+            let i = self.index;
+            self.index += 1;
+            if i >= self.x.len() {
+                self.x.resize(i + 1, PrimarySample::default());
+            }
+
+            let iteration = self.iteration;
+            let xi = &mut self.x[i];
+            // Coordinates untouched since the last accepted large step start from a fresh random value
+            if xi.last_modified < self.last_large_step {
+                xi.value = self.rng.get_1d();
+                xi.last_modified = self.last_large_step;
+            }
+            xi.backup = xi.value;
+            xi.modify_backup = xi.last_modified;
+
+            if self.large_step {
+                xi.value = self.rng.get_1d();
+            } else {
+                // All the small steps missed since the coordinate was last used, at once
+                let (u1, u2) = self.rng.get_2d();
+                let normal = (-2.0*(1.0 - u1).ln()).sqrt()*(2.0*std::f32::consts::PI*u2).cos();
+                let sigma = self.sigma*((iteration - xi.last_modified) as f32).sqrt();
+                xi.value += normal*sigma;
+                xi.value -= xi.value.floor();
+            }
+            xi.last_modified = iteration;
+            xi.value
+        }
+    }
+
+    impl Sampler for MltSampler {
+        fn start_pixel_sample(&mut self, _x: usize, _y: usize, _sample_index: usize) {}
+        fn get_1d(&mut self) -> f32 {
+            self.next()
+        }
+        fn get_2d(&mut self) -> (f32, f32) {
+            (self.next(), self.next())
+        }
+    }
+
+    // Image position and radiance of a path
+    #[derive(Copy, Clone)]
+    struct PathSample {
+        s: f32,
+        t: f32,
+        l: colour,
+    }
+
+    // Importance function the chains are distributed by
+    fn importance(l: colour) -> f32 {
+        l.luminance().max(0.0)
+    }
+
+    struct Chain {
+        integrator: u64,
+        frame: usize,
+        sampler: MltSampler,
+        // For acceptance decisions, kept separate from primary sample space
+        rng: IndependentSampler,
+        current: PathSample,
+    }
+
+    thread_local! {
+        // Every render thread runs its own chain
+        static CHAIN: RefCell<Option<Chain>> = RefCell::new(None);
+    }
+
+    // Average importance over primary sample space, and the samples it was estimated from,
+    // which the chains pick their starting points from
+    struct Bootstrap {
+        frame: usize,
+        b: f32,
+        cdf: Vec<f32>,
+    }
+
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+    // Primary sample space Metropolis light transport (Kelemen et al.) on top of the path tracer.
+    // Every camera sample advances the thread's Markov chain by one mutation, either a small step
+    // around the current path or, with large_step_probability, an independent new path. Both the
+    // proposed and the current path are splatted, weighted by the acceptance probability.
+    // Chains restart every frame from a bootstrap estimate, as the camera may have moved.
+    pub struct Mlt {
+        pub path: PathTracer,
+        pub bootstrap_samples: usize,
+        pub large_step_probability: f32,
+        // Standard deviation of small steps
+        pub sigma: f32,
+
+        id: u64,
+        frame: AtomicUsize,
+        bootstrap: RwLock<Option<Arc<Bootstrap>>>,
+        bootstrapping: Mutex<()>,
+        next_chain: AtomicU64,
+        // Acceptance statistics of the current frame
+        mutations: AtomicU64,
+        accepted: AtomicU64,
+        large_steps: AtomicU64,
+        large_accepted: AtomicU64,
+    }
+
+    impl Mlt {
+        pub fn new(path: PathTracer, bootstrap_samples: usize, large_step_probability: f32, sigma: f32) -> Self {
+            Mlt{path: path, bootstrap_samples: bootstrap_samples.max(1), large_step_probability: large_step_probability, sigma: sigma,
+                id: NEXT_ID.fetch_add(1, Ordering::Relaxed), frame: AtomicUsize::new(0),
+                bootstrap: RwLock::new(None), bootstrapping: Mutex::new(()), next_chain: AtomicU64::new(0),
+                mutations: AtomicU64::new(0), accepted: AtomicU64::new(0), large_steps: AtomicU64::new(0), large_accepted: AtomicU64::new(0)}
+        }
+
+        fn evaluate(&self, scene: &HittableList, cam: &Camera, sampler: &mut MltSampler, record: &mut SampleRecord) -> PathSample {
+            let (s, t) = sampler.get_2d();
+            let lens = sampler.in_unit_disc();
+            let l = match cam.get_ray(s, t, lens) {
+                Some(r) => self.path.li(&r, scene, cam, sampler, record, None),
+                None => colour::new(0.0, 0.0, 0.0),
+            };
+            PathSample{s: s, t: t, l: l}
+        }
+
+        fn seed(frame: usize, index: u64) -> u64 {
+            ((frame as u64) << 40) ^ index
+        }
+
+        fn bootstrap(&self, scene: &HittableList, cam: &Camera, frame: usize) -> Arc<Bootstrap> {
+            if let Some(b) = self.bootstrap.read().unwrap().as_ref().filter(|b| b.frame == frame) {
+                return b.clone();
+            }
+            let _bootstrapping = self.bootstrapping.lock().unwrap();
+            if let Some(b) = self.bootstrap.read().unwrap().as_ref().filter(|b| b.frame == frame) {
+                return b.clone();
+            }
+
+            let mut record = SampleRecord::default();
+            let mut cdf = Vec::with_capacity(self.bootstrap_samples);
+            let mut sum = 0.0;
+            for i in 0..self.bootstrap_samples {
+                let mut sampler = MltSampler::new(Self::seed(frame, i as u64), self.sigma, self.large_step_probability);
+                sum += importance(self.evaluate(scene, cam, &mut sampler, &mut record).l);
+                cdf.push(sum);
+            }
+            let bootstrap = Arc::new(Bootstrap{frame: frame, b: sum/self.bootstrap_samples as f32, cdf: cdf});
+            *self.bootstrap.write().unwrap() = Some(bootstrap.clone());
+            bootstrap
+        }
+
+        // Starts a chain at one of the bootstrap samples, picked in proportion to its importance
+        fn start_chain(&self, scene: &HittableList, cam: &Camera, bootstrap: &Bootstrap, record: &mut SampleRecord) -> Chain {
+            let mut rng = IndependentSampler::new(Self::seed(bootstrap.frame, self.next_chain.fetch_add(1, Ordering::Relaxed)) ^ 0x5bd1e995);
+            let total = bootstrap.cdf[bootstrap.cdf.len() - 1];
+            let u = rng.get_1d()*total;
+            let idx = bootstrap.cdf.partition_point(|&c| c <= u).min(bootstrap.cdf.len() - 1);
+
+            // Replays the bootstrap sample, the sampler generates the same numbers from the same seed
+            let mut sampler = MltSampler::new(Self::seed(bootstrap.frame, idx as u64), self.sigma, self.large_step_probability);
+            let current = self.evaluate(scene, cam, &mut sampler, record);
+            Chain{integrator: self.id, frame: bootstrap.frame, sampler: sampler, rng: rng, current: current}
+        }
+
+        fn report(&self) {
+            let mutations = self.mutations.swap(0, Ordering::Relaxed);
+            let accepted = self.accepted.swap(0, Ordering::Relaxed);
+            let large_steps = self.large_steps.swap(0, Ordering::Relaxed);
+            let large_accepted = self.large_accepted.swap(0, Ordering::Relaxed);
+            if mutations == 0 {
+                return;
+            }
+            let small_steps = mutations - large_steps;
+            println!("INFO: MLT acceptance rate: {:.1}% (small steps {:.1}%, large steps {:.1}%)",
+                100.0*accepted as f32/mutations as f32,
+                100.0*(accepted - large_accepted) as f32/small_steps.max(1) as f32,
+                100.0*large_accepted as f32/large_steps.max(1) as f32);
+        }
+    }
+
+    impl Integrator for Mlt {
+        fn begin_frame(&self, _scene: &HittableList, _cam: &Camera, frame: usize) {
+            // Only the first thread to get to a frame reports on the previous one
+            if self.frame.fetch_max(frame, Ordering::SeqCst) < frame {
+                self.report();
+            }
+        }
+
+        // Returns black, everything goes through splats
+        fn li(&self, ray: &Ray, scene: &HittableList, cam: &Camera, sampler: &mut dyn Sampler, record: &mut SampleRecord, aovs: Option<&mut Aovs>) -> colour {
+            let black = colour::new(0.0, 0.0, 0.0);
+            if let Some(aovs) = aovs {
+                let mut hr = HitRecord::default();
+                let mut attenuation = black;
+                if scene.hit(ray, &mut attenuation, 0.001, f32::INFINITY, &mut hr, sampler).is_some() {
+                    *aovs = Aovs::from_hit(ray, &hr, attenuation);
+                }
+            }
+
+            let bootstrap = self.bootstrap(scene, cam, self.frame.load(Ordering::SeqCst));
+            if bootstrap.b <= 0.0 {
+                return black;
+            }
+
+            CHAIN.with(|chain| {
+                let mut chain = chain.borrow_mut();
+                if !matches!(chain.as_ref(), Some(c) if c.integrator == self.id && c.frame == bootstrap.frame) {
+                    *chain = Some(self.start_chain(scene, cam, &bootstrap, record));
+                }
+                let chain = chain.as_mut().unwrap();
+
+                chain.sampler.start_iteration();
+                let large_step = chain.sampler.large_step;
+                let proposed = self.evaluate(scene, cam, &mut chain.sampler, record);
+                let (current_i, proposed_i) = (importance(chain.current.l), importance(proposed.l));
+                let a = if current_i > 0.0 {(proposed_i/current_i).min(1.0)} else {1.0};
+
+                // Expected values: both paths contribute, in proportion to how likely the chain is to be at each
+                if a > 0.0 && proposed_i > 0.0 {
+                    record.splats.push((proposed.s, proposed.t, proposed.l*(a*bootstrap.b/proposed_i)));
+                }
+                if a < 1.0 {
+                    record.splats.push((chain.current.s, chain.current.t, chain.current.l*((1.0 - a)*bootstrap.b/current_i)));
+                }
+
+                self.mutations.fetch_add(1, Ordering::Relaxed);
+                if large_step {
+                    self.large_steps.fetch_add(1, Ordering::Relaxed);
+                }
+                if chain.rng.get_1d() < a {
+                    chain.sampler.accept();
+                    chain.current = proposed;
+                    self.accepted.fetch_add(1, Ordering::Relaxed);
+                    if large_step {
+                        self.large_accepted.fetch_add(1, Ordering::Relaxed);
+                    }
+                } else {
+                    chain.sampler.reject();
+                }
+            });
+            black
+        }
+    }
+}
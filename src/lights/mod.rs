@@ -0,0 +1,570 @@
+pub use self::lights::Light;
+pub use self::lights::LightDistribution;
+pub use self::lights::AreaLight;
+pub use self::lights::{PointLight, SpotLight, DirectionalLight};
+pub use self::ies::IesProfile;
+
+mod lights{
+    use std::sync::Arc;
+    use crate::vector::vec3;
+    use crate::ray::Ray;
+    use crate::geometry::HittableList;
+    use crate::sampling::{cosine_hemisphere, uniform_sphere, uniform_cone, concentric_disc, tangent_frame};
+    use super::ies::IesProfile;
+    use vec3 as point3;
+    use vec3 as colour;
+
+    // A point on a light as seen from a shading point
+    pub struct LightSample {
+        pub p: point3,
+        // Zero for lights that aren't surfaces
+        pub n: vec3,
+        // Unit direction from the shading point to p
+        pub wi: vec3,
+        // Radiance arriving at the shading point, ignoring occlusion
+        pub radiance: colour,
+        // Density over solid angle at the shading point. 1 for delta lights.
+        pub pdf: f32,
+    }
+
+    // A ray leaving a light, for tracing paths starting at the lights
+    pub struct LightEmission {
+        pub ray: Ray,
+        pub n: vec3,
+        pub radiance: colour,
+        // Density of the ray origin over area, and of its direction over solid angle
+        pub pdf_pos: f32,
+        pub pdf_dir: f32,
+    }
+
+    // Lights get the scene passed in, so area lights can refer to their object by index
+    pub trait Light: Send + Sync {
+        fn sample_li(&self, scene: &HittableList, p: point3, u: (f32, f32)) -> Option<LightSample>;
+        fn sample_le(&self, scene: &HittableList, u_pos: (f32, f32), u_dir: (f32, f32)) -> Option<LightEmission>;
+        // Densities sample_le has for a ray leaving a point with normal n in direction dir
+        fn pdf_le(&self, scene: &HittableList, n: vec3, dir: vec3) -> (f32, f32);
+        // Total emitted power, only used to pick between lights
+        fn power(&self, scene: &HittableList) -> f32;
+
+        // A single point standing in for the whole light, so previews get hard shadows without noise.
+        // Delta lights are a single point already.
+        fn sample_li_preview(&self, scene: &HittableList, p: point3) -> Option<LightSample> {
+            self.sample_li(scene, p, (0.5, 0.5))
+        }
+
+        // Lights at a single point or from a single direction can't be hit by rays
+        fn is_delta(&self) -> bool {
+            false
+        }
+        // Lights infinitely far away start their light paths on a disc facing them. This is the density
+        // over area, perpendicular to the light, of those paths arriving at p. None for other lights.
+        fn infinite_pdf(&self, _p: point3) -> Option<f32> {
+            None
+        }
+        // Object the light is attached to, for area lights
+        fn object_id(&self) -> Option<usize> {
+            None
+        }
+        // Called once the scene is complete, for lights that need to know its size
+        fn preprocess(&mut self, _scene: &HittableList) {}
+    }
+
+    // Emissive object, sampled uniformly over its area
+    pub struct AreaLight {
+        pub object_id: usize,
+        pub emission: colour,
+    }
+
+    impl Light for AreaLight {
+        fn sample_li(&self, scene: &HittableList, p: point3, u: (f32, f32)) -> Option<LightSample> {
+            let shape = &scene.list[self.object_id];
+            let (q, n) = shape.sample_surface(u)?;
+            let d = q - p;
+            let dist_sq = d.length_squared();
+            if dist_sq <= 0.0 {
+                return None;
+            }
+            let wi = d/dist_sq.sqrt();
+            // Back faces don't emit
+            let cos = vec3::dot(&n, &-wi);
+            if cos <= 0.0 {
+                return None;
+            }
+            Some(LightSample{p: q, n: n, wi: wi, radiance: self.emission, pdf: dist_sq/(cos*shape.area())})
+        }
+
+        fn sample_le(&self, scene: &HittableList, u_pos: (f32, f32), u_dir: (f32, f32)) -> Option<LightEmission> {
+            let shape = &scene.list[self.object_id];
+            let (q, n) = shape.sample_surface(u_pos)?;
+            let dir = cosine_hemisphere(n, u_dir);
+            let (pdf_pos, pdf_dir) = self.pdf_le(scene, n, dir);
+            if pdf_dir <= 0.0 {
+                return None;
+            }
+            Some(LightEmission{ray: Ray::new(q, dir), n: n, radiance: self.emission, pdf_pos: pdf_pos, pdf_dir: pdf_dir})
+        }
+
+        // Point light at the centre, as bright as the shape looks from p
+        fn sample_li_preview(&self, scene: &HittableList, p: point3) -> Option<LightSample> {
+            let (c, projected_area) = scene.list[self.object_id].point_approximation(p)?;
+            let d = c - p;
+            let dist_sq = d.length_squared();
+            if dist_sq <= 0.0 {
+                return None;
+            }
+            let wi = d/dist_sq.sqrt();
+            Some(LightSample{p: c, n: vec3::new(0.0, 0.0, 0.0), wi: wi, radiance: self.emission*(projected_area/dist_sq), pdf: 1.0})
+        }
+
+        fn pdf_le(&self, scene: &HittableList, n: vec3, dir: vec3) -> (f32, f32) {
+            (1.0/scene.list[self.object_id].area(), vec3::dot(&n, &dir).max(0.0)/std::f32::consts::PI)
+        }
+
+        fn power(&self, scene: &HittableList) -> f32 {
+            self.emission.luminance()*scene.list[self.object_id].area()*std::f32::consts::PI
+        }
+
+        fn object_id(&self) -> Option<usize> {
+            Some(self.object_id)
+        }
+    }
+
+    // Integral over all directions, for the power of lights with a photometric profile
+    fn integrate_sphere(f: impl Fn(vec3) -> f32) -> f32 {
+        let (n_theta, n_phi) = (64, 64);
+        let d_theta = std::f32::consts::PI/n_theta as f32;
+        let d_phi = 2.0*std::f32::consts::PI/n_phi as f32;
+        let mut sum = 0.0;
+        for i in 0..n_theta {
+            let theta = (i as f32 + 0.5)*d_theta;
+            for j in 0..n_phi {
+                let phi = (j as f32 + 0.5)*d_phi;
+                sum += f(vec3::new(theta.sin()*phi.cos(), theta.cos(), theta.sin()*phi.sin()))*theta.sin()*d_theta*d_phi;
+            }
+        }
+        sum
+    }
+
+    // Shines from a single point. Light falls off with the inverse square of the distance.
+    pub struct PointLight {
+        pub position: point3,
+        // Radiant intensity, power per solid angle. With a profile, the multiplier from candela to radiant intensity.
+        pub intensity: colour,
+        // Intensity by direction, with vertical angle 0 pointing down
+        pub profile: Option<Arc<IesProfile>>,
+    }
+
+    impl PointLight {
+        pub fn new(position: point3, intensity: colour) -> Self {
+            PointLight{position: position, intensity: intensity, profile: None}
+        }
+
+        pub fn with_profile(mut self, profile: Arc<IesProfile>) -> Self {
+            self.profile = Some(profile);
+            self
+        }
+
+        // Intensity towards a unit direction leaving the light
+        fn intensity(&self, dir: vec3) -> colour {
+            match &self.profile {
+                Some(profile) => self.intensity*profile.candela(dir, vec3::new(0.0, -1.0, 0.0)),
+                None => self.intensity,
+            }
+        }
+    }
+
+    impl Light for PointLight {
+        fn sample_li(&self, _scene: &HittableList, p: point3, _u: (f32, f32)) -> Option<LightSample> {
+            let d = self.position - p;
+            let dist_sq = d.length_squared();
+            if dist_sq <= 0.0 {
+                return None;
+            }
+            let wi = d/dist_sq.sqrt();
+            Some(LightSample{p: self.position, n: vec3::new(0.0, 0.0, 0.0), wi: wi, radiance: self.intensity(-wi)/dist_sq, pdf: 1.0})
+        }
+
+        fn sample_le(&self, scene: &HittableList, _u_pos: (f32, f32), u_dir: (f32, f32)) -> Option<LightEmission> {
+            let dir = uniform_sphere(u_dir);
+            let (pdf_pos, pdf_dir) = self.pdf_le(scene, vec3::new(0.0, 0.0, 0.0), dir);
+            Some(LightEmission{ray: Ray::new(self.position, dir), n: vec3::new(0.0, 0.0, 0.0), radiance: self.intensity(dir), pdf_pos: pdf_pos, pdf_dir: pdf_dir})
+        }
+
+        fn pdf_le(&self, _scene: &HittableList, _n: vec3, _dir: vec3) -> (f32, f32) {
+            (1.0, 1.0/(4.0*std::f32::consts::PI))
+        }
+
+        fn power(&self, _scene: &HittableList) -> f32 {
+            match &self.profile {
+                Some(_) => integrate_sphere(|dir| self.intensity(dir).luminance()),
+                None => 4.0*std::f32::consts::PI*self.intensity.luminance(),
+            }
+        }
+
+        fn is_delta(&self) -> bool {
+            true
+        }
+    }
+
+    // Point light that only shines into a cone. Full intensity up to falloff_start from the axis,
+    // fading out smoothly to nothing at the edge of the cone.
+    pub struct SpotLight {
+        pub position: point3,
+        // Unit vector along the middle of the cone
+        pub direction: vec3,
+        // As for PointLight
+        pub intensity: colour,
+        // Intensity by direction, with vertical angle 0 along the axis of the cone
+        pub profile: Option<Arc<IesProfile>>,
+        cos_total_width: f32,
+        cos_falloff_start: f32,
+    }
+
+    impl SpotLight {
+        // Angles are in degrees, measured from the axis
+        pub fn new(position: point3, look_at: point3, intensity: colour, cone_angle: f32, falloff_start: f32) -> Self {
+            let falloff_start = falloff_start.min(cone_angle);
+            SpotLight{position: position, direction: vec3::unit_vector(look_at - position), intensity: intensity, profile: None,
+                cos_total_width: cone_angle.to_radians().cos(), cos_falloff_start: falloff_start.to_radians().cos()}
+        }
+
+        pub fn with_profile(mut self, profile: Arc<IesProfile>) -> Self {
+            self.profile = Some(profile);
+            self
+        }
+
+        // Intensity towards a unit direction leaving the light, including the fade at the edge of the cone
+        fn intensity(&self, dir: vec3) -> colour {
+            let falloff = self.falloff(dir);
+            match &self.profile {
+                Some(profile) if falloff > 0.0 => self.intensity*(falloff*profile.candela(dir, self.direction)),
+                _ => self.intensity*falloff,
+            }
+        }
+
+        fn falloff(&self, dir: vec3) -> f32 {
+            let cos = vec3::dot(&dir, &self.direction);
+            if cos < self.cos_total_width {
+                return 0.0;
+            }
+            if cos >= self.cos_falloff_start {
+                return 1.0;
+            }
+            let delta = (cos - self.cos_total_width)/(self.cos_falloff_start - self.cos_total_width);
+            delta*delta*delta*delta
+        }
+    }
+
+    impl Light for SpotLight {
+        fn sample_li(&self, _scene: &HittableList, p: point3, _u: (f32, f32)) -> Option<LightSample> {
+            let d = self.position - p;
+            let dist_sq = d.length_squared();
+            if dist_sq <= 0.0 {
+                return None;
+            }
+            let wi = d/dist_sq.sqrt();
+            let intensity = self.intensity(-wi);
+            if intensity.max_component() <= 0.0 {
+                return None;
+            }
+            Some(LightSample{p: self.position, n: vec3::new(0.0, 0.0, 0.0), wi: wi, radiance: intensity/dist_sq, pdf: 1.0})
+        }
+
+        // Directions are sampled uniformly in the cone
+        fn sample_le(&self, scene: &HittableList, _u_pos: (f32, f32), u_dir: (f32, f32)) -> Option<LightEmission> {
+            let dir = uniform_cone(self.direction, self.cos_total_width, u_dir);
+            let (pdf_pos, pdf_dir) = self.pdf_le(scene, vec3::new(0.0, 0.0, 0.0), dir);
+            Some(LightEmission{ray: Ray::new(self.position, dir), n: vec3::new(0.0, 0.0, 0.0),
+                radiance: self.intensity(dir), pdf_pos: pdf_pos, pdf_dir: pdf_dir})
+        }
+
+        fn pdf_le(&self, _scene: &HittableList, _n: vec3, dir: vec3) -> (f32, f32) {
+            if vec3::dot(&dir, &self.direction) < self.cos_total_width {
+                return (1.0, 0.0);
+            }
+            (1.0, 1.0/(2.0*std::f32::consts::PI*(1.0 - self.cos_total_width)))
+        }
+
+        // Without a profile, the fade is approximated as half way between the two cones
+        fn power(&self, _scene: &HittableList) -> f32 {
+            match &self.profile {
+                Some(_) => integrate_sphere(|dir| self.intensity(dir).luminance()),
+                None => 2.0*std::f32::consts::PI*(1.0 - 0.5*(self.cos_falloff_start + self.cos_total_width))*self.intensity.luminance(),
+            }
+        }
+
+        fn is_delta(&self) -> bool {
+            true
+        }
+    }
+
+    // Parallel light from far away, like the sun. With a non-zero angular diameter every shadow ray
+    // goes towards a random point on the disc of the source, which softens the shadows. Rays can't hit
+    // the source either way.
+    pub struct DirectionalLight {
+        // Unit vector pointing towards the light
+        pub to_light: vec3,
+        // Irradiance on a surface facing the light
+        pub irradiance: colour,
+        cos_half_angle: f32,
+        // Light paths start on a disc this size facing the light, so it has to cover the scene. Set by preprocess.
+        pub world_center: point3,
+        pub world_radius: f32,
+    }
+
+    impl DirectionalLight {
+        // direction is where the light travels, angular_diameter is in degrees (about 0.53 for the sun)
+        pub fn new(direction: vec3, irradiance: colour, angular_diameter: f32) -> Self {
+            DirectionalLight{to_light: -vec3::unit_vector(direction), irradiance: irradiance,
+                cos_half_angle: (0.5*angular_diameter).to_radians().cos(),
+                world_center: point3::new(0.0, 0.0, 0.0), world_radius: 10.0}
+        }
+
+        fn sample_direction(&self, u: (f32, f32)) -> vec3 {
+            if self.cos_half_angle < 1.0 {uniform_cone(self.to_light, self.cos_half_angle, u)} else {self.to_light}
+        }
+
+        // Stand-in for the light's position seen from p, past anything in the scene that could shadow p
+        fn beyond_scene(&self, p: point3, wi: vec3) -> point3 {
+            p + wi*((p - self.world_center).length() + 2.0*self.world_radius)
+        }
+    }
+
+    impl Light for DirectionalLight {
+        fn sample_li(&self, _scene: &HittableList, p: point3, u: (f32, f32)) -> Option<LightSample> {
+            let wi = self.sample_direction(u);
+            Some(LightSample{p: self.beyond_scene(p, wi), n: vec3::new(0.0, 0.0, 0.0), wi: wi, radiance: self.irradiance, pdf: 1.0})
+        }
+
+        fn sample_li_preview(&self, _scene: &HittableList, p: point3) -> Option<LightSample> {
+            Some(LightSample{p: self.beyond_scene(p, self.to_light), n: vec3::new(0.0, 0.0, 0.0), wi: self.to_light, radiance: self.irradiance, pdf: 1.0})
+        }
+
+        fn sample_le(&self, scene: &HittableList, u_pos: (f32, f32), u_dir: (f32, f32)) -> Option<LightEmission> {
+            let wi = self.sample_direction(u_dir);
+            let (tangent, bitangent) = tangent_frame(wi);
+            let (x, y) = concentric_disc(u_pos);
+            let origin = self.world_center + (tangent*x + bitangent*y + wi)*self.world_radius;
+            let (pdf_pos, pdf_dir) = self.pdf_le(scene, vec3::new(0.0, 0.0, 0.0), -wi);
+            Some(LightEmission{ray: Ray::new(origin, -wi), n: vec3::new(0.0, 0.0, 0.0), radiance: self.irradiance, pdf_pos: pdf_pos, pdf_dir: pdf_dir})
+        }
+
+        // The direction counts as a delta, even when it is jittered over the disc of the source
+        fn pdf_le(&self, _scene: &HittableList, _n: vec3, _dir: vec3) -> (f32, f32) {
+            (1.0/(std::f32::consts::PI*self.world_radius*self.world_radius), 1.0)
+        }
+
+        fn power(&self, _scene: &HittableList) -> f32 {
+            std::f32::consts::PI*self.world_radius*self.world_radius*self.irradiance.luminance()
+        }
+
+        fn is_delta(&self) -> bool {
+            true
+        }
+
+        // Sphere around everything with a bounding box. Unbounded objects like planes only get
+        // light paths where they pass through it, infinite_pdf tells the integrators as much.
+        fn preprocess(&mut self, scene: &HittableList) {
+            if let Some(bounds) = scene.bounds() {
+                self.world_center = (bounds.min + bounds.max)/2.0;
+                self.world_radius = ((bounds.max - bounds.min).length()/2.0).max(0.001);
+            }
+        }
+
+        // Points outside the disc can't be reached by light paths
+        fn infinite_pdf(&self, p: point3) -> Option<f32> {
+            let d = p - self.world_center;
+            let along = vec3::dot(&d, &self.to_light);
+            if (d - self.to_light*along).length_squared() > self.world_radius*self.world_radius {
+                return Some(0.0);
+            }
+            Some(1.0/(std::f32::consts::PI*self.world_radius*self.world_radius))
+        }
+    }
+
+    // Picks lights with probability proportional to their power
+    pub struct LightDistribution {
+        cdf: Vec<f32>,
+    }
+
+    impl LightDistribution {
+        pub fn new(scene: &HittableList) -> Self {
+            let powers: Vec<f32> = scene.lights.iter().map(|l| l.power(scene).max(0.0)).collect();
+            let total: f32 = powers.iter().sum();
+
+            let mut cdf = Vec::with_capacity(powers.len());
+            let mut sum = 0.0;
+            for (idx, p) in powers.iter().enumerate() {
+                // Uniform if no light has any power
+                sum += if total > 0.0 {p/total} else {1.0/powers.len() as f32};
+                cdf.push(if idx + 1 == powers.len() {1.0} else {sum});
+            }
+            LightDistribution{cdf: cdf}
+        }
+
+        // Index of the chosen light and the probability of choosing it
+        pub fn sample(&self, u: f32) -> Option<(usize, f32)> {
+            if self.cdf.is_empty() {
+                return None;
+            }
+            let idx = self.cdf.partition_point(|&c| c <= u).min(self.cdf.len() - 1);
+            let pdf = self.pdf(idx);
+            if pdf <= 0.0 {
+                return None;
+            }
+            Some((idx, pdf))
+        }
+
+        pub fn pdf(&self, idx: usize) -> f32 {
+            let lo = if idx == 0 {0.0} else {self.cdf[idx - 1]};
+            self.cdf.get(idx).map_or(0.0, |c| c - lo)
+        }
+    }
+}
+
+mod ies{
+    use crate::vector::vec3;
+
+    // Photometric data of a luminaire from an IES LM-63 file: luminous intensity in candela over a grid of
+    // vertical and horizontal angles. Only type C photometry, by far the most common, is supported.
+    // Vertical angles go from 0 (the nadir, straight down from the fitting) to 180, horizontal angles
+    // go around the vertical axis. Lamp tilt data is ignored.
+    pub struct IesProfile {
+        vertical: Vec<f32>,
+        horizontal: Vec<f32>,
+        // One row of vertical samples per horizontal angle, already multiplied by the file's scale factors
+        candela: Vec<Vec<f32>>,
+    }
+
+    // Linear interpolation in sorted sample positions. None outside them.
+    fn interpolate(xs: &[f32], x: f32) -> Option<(usize, usize, f32)> {
+        if x < xs[0] || x > xs[xs.len() - 1] {
+            return None;
+        }
+        if xs.len() == 1 {
+            return Some((0, 0, 0.0));
+        }
+        let hi = xs.partition_point(|&v| v < x).clamp(1, xs.len() - 1);
+        let lo = hi - 1;
+        let span = xs[hi] - xs[lo];
+        Some((lo, hi, if span > 0.0 {(x - xs[lo])/span} else {0.0}))
+    }
+
+    impl IesProfile {
+        pub fn from_file(filename: &str) -> std::io::Result<Self> {
+            let text = std::fs::read_to_string(filename)?;
+            Self::parse(&text).map_err(|msg| std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{}: {}", filename, msg)))
+        }
+
+        pub fn parse(text: &str) -> Result<Self, String> {
+            // Keyword lines up to TILT, then whitespace or comma separated numbers
+            let mut lines = text.lines();
+            let tilt = lines.find(|l| l.trim_start().starts_with("TILT=")).ok_or("no TILT line")?;
+            let mut numbers = Vec::new();
+            for token in lines.flat_map(|l| l.split(|c: char| c.is_whitespace() || c == ',')).filter(|t| !t.is_empty()) {
+                numbers.push(token.parse::<f32>().map_err(|_| format!("invalid number '{}'", token))?);
+            }
+            let mut numbers = numbers.into_iter();
+            let mut next = || numbers.next().ok_or("truncated photometric data");
+
+            if tilt.trim() == "TILT=INCLUDE" {
+                let _geometry = next()?;
+                let n = next()? as usize;
+                for _ in 0..2*n {
+                    next()?;
+                }
+            }
+
+            let _lamps = next()?;
+            let _lumens_per_lamp = next()?;
+            let multiplier = next()?;
+            let n_vertical = next()? as usize;
+            let n_horizontal = next()? as usize;
+            let photometric_type = next()? as i32;
+            for _ in 0..4 {
+                // Units and luminous opening size
+                next()?;
+            }
+            let ballast_factor = next()?;
+            let ballast_lamp_factor = next()?;
+            let _input_watts = next()?;
+
+            if photometric_type != 1 {
+                return Err(format!("photometric type {} is not supported, only type C (1)", photometric_type));
+            }
+            if n_vertical == 0 || n_horizontal == 0 {
+                return Err("no angles".to_string());
+            }
+
+            let vertical = (0..n_vertical).map(|_| next()).collect::<Result<Vec<f32>, _>>()?;
+            let horizontal = (0..n_horizontal).map(|_| next()).collect::<Result<Vec<f32>, _>>()?;
+            let scale = multiplier*ballast_factor*ballast_lamp_factor;
+            let mut candela = Vec::with_capacity(n_horizontal);
+            for _ in 0..n_horizontal {
+                candela.push((0..n_vertical).map(|_| next().map(|c| c*scale)).collect::<Result<Vec<f32>, _>>()?);
+            }
+
+            let sorted = |a: &[f32]| a.windows(2).all(|w| w[0] <= w[1]);
+            if !sorted(&vertical) || !sorted(&horizontal) {
+                return Err("angles are not in increasing order".to_string());
+            }
+            Ok(IesProfile{vertical: vertical, horizontal: horizontal, candela: candela})
+        }
+
+        // Folds a horizontal angle in [0,360) into the range the file covers, using the symmetry its range implies
+        fn fold_horizontal(&self, phi: f32) -> f32 {
+            let first = self.horizontal[0];
+            let last = self.horizontal[self.horizontal.len() - 1];
+            if last <= 90.0 && first >= 0.0 {
+                // Quadrant symmetry
+                let phi = if phi > 180.0 {360.0 - phi} else {phi};
+                if phi > 90.0 {180.0 - phi} else {phi}
+            } else if first >= 90.0 && last <= 270.0 {
+                // Symmetric about the 90-270 plane
+                if phi < 90.0 {180.0 - phi} else if phi > 270.0 {540.0 - phi} else {phi}
+            } else if last <= 180.0 {
+                // Symmetric about the 0-180 plane
+                if phi > 180.0 {360.0 - phi} else {phi}
+            } else {
+                phi
+            }
+        }
+
+        // Intensity in candela at vertical angle theta and horizontal angle phi, in degrees
+        pub fn candela_at(&self, theta: f32, phi: f32) -> f32 {
+            let (v0, v1, tv) = match interpolate(&self.vertical, theta) {
+                Some(v) => v,
+                None => return 0.0,
+            };
+            let along = |row: &Vec<f32>| row[v0]*(1.0 - tv) + row[v1]*tv;
+            if self.horizontal.len() == 1 {
+                return along(&self.candela[0]);
+            }
+
+            let phi = self.fold_horizontal(phi.rem_euclid(360.0));
+            // Full circle files may stop short of 360, which is the same as 0
+            let (h0, h1, th) = match interpolate(&self.horizontal, phi) {
+                Some(h) => h,
+                None => {
+                    let last = self.horizontal.len() - 1;
+                    let gap = 360.0 - self.horizontal[last] + self.horizontal[0];
+                    let t = if gap > 0.0 {(phi - self.horizontal[last]).rem_euclid(360.0)/gap} else {0.0};
+                    (last, 0, t)
+                },
+            };
+            along(&self.candela[h0])*(1.0 - th) + along(&self.candela[h1])*th
+        }
+
+        // Intensity towards a unit direction, for a fitting whose vertical angle 0 points along nadir.
+        // Horizontal angle 0 faces -z, or +x when nadir is along z, and 90 is a quarter turn clockwise
+        // looking along -nadir, so +x for a fitting pointing down.
+        pub fn candela(&self, dir: vec3, nadir: vec3) -> f32 {
+            let cos_theta = vec3::dot(&dir, &nadir).clamp(-1.0, 1.0);
+            let reference = if nadir.z.abs() > 0.999 {vec3::new(1.0, 0.0, 0.0)} else {vec3::new(0.0, 0.0, -1.0)};
+            let zero = vec3::unit_vector(reference - nadir*vec3::dot(&reference, &nadir));
+            let ninety = vec3::cross(&nadir, &zero);
+            let phi = vec3::dot(&dir, &ninety).atan2(vec3::dot(&dir, &zero)).to_degrees();
+            self.candela_at(cos_theta.acos().to_degrees(), phi)
+        }
+    }
+}
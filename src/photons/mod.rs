@@ -0,0 +1,156 @@
+pub use self::photons::PhotonMap;
+
+mod photons{
+    use crate::vector::vec3;
+    use crate::ray::Ray;
+    use crate::geometry::{HitRecord, HittableList};
+    use crate::lights::LightDistribution;
+    use crate::sampling::{Sampler, IndependentSampler};
+    use vec3 as point3;
+    use vec3 as colour;
+
+    #[derive(Copy, Clone)]
+    pub struct Photon {
+        pub p: point3,
+        // Unit direction the photon came from
+        pub wi: vec3,
+        // Flux carried by the photon
+        pub power: colour,
+    }
+
+    // Caustic photons (light that went through at least one specular bounce before landing on a
+    // diffuse surface) in a kd-tree. The tree is implicit: every slice is split at its middle element.
+    pub struct PhotonMap {
+        photons: Vec<Photon>,
+        // Split axis of the node stored at the same index
+        axes: Vec<u8>,
+        // Lookup radius for density estimation
+        pub radius: f32,
+    }
+
+    fn component(v: vec3, axis: u8) -> f32 {
+        match axis {
+            0 => v.x,
+            1 => v.y,
+            _ => v.z,
+        }
+    }
+
+    impl PhotonMap {
+        // Shoots photons from the scene's lights, following specular bounces only
+        pub fn trace(scene: &HittableList, num_photons: usize, max_bounces: usize, radius: f32, seed: u64) -> Self {
+            let lights = LightDistribution::new(scene);
+            let mut sampler = IndependentSampler::new(seed);
+            let mut photons = Vec::new();
+
+            for _ in 0..num_photons {
+                let (idx, light_pdf) = match lights.sample(sampler.get_1d()) {
+                    Some(choice) => choice,
+                    None => break,
+                };
+                let (u_pos, u_dir) = (sampler.get_2d(), sampler.get_2d());
+                let e = match scene.lights[idx].sample_le(scene, u_pos, u_dir) {
+                    Some(e) => e,
+                    None => continue,
+                };
+                let cos = if e.n.length_squared() > 0.0 {vec3::dot(&e.n, &e.ray.dir).abs()} else {1.0};
+                let mut power = e.radiance*(cos/(light_pdf*e.pdf_pos*e.pdf_dir*num_photons as f32));
+                let mut ray = e.ray;
+
+                for bounce in 0..max_bounces {
+                    let mut hr = HitRecord::default();
+                    let mut attenuation = colour::new(0.0, 0.0, 0.0);
+                    if scene.hit(&ray, &mut attenuation, 0.001, f32::INFINITY, &mut hr, &mut sampler).is_none() {
+                        break;
+                    }
+                    let wo = -vec3::unit_vector(ray.dir);
+                    let bs = match scene.list[hr.object_id].material().sample(wo, &hr, &mut sampler) {
+                        Some(bs) => bs,
+                        None => break,
+                    };
+                    if !bs.specular {
+                        // Light reaching diffuse surfaces directly is left to the camera paths
+                        // Grazing refractions can leave NaNs behind, which would poison the kd-tree and the estimates
+                        let finite = [hr.p.x, hr.p.y, hr.p.z, power.x, power.y, power.z].iter().all(|c| c.is_finite());
+                        if bounce > 0 && finite {
+                            photons.push(Photon{p: hr.p, wi: wo, power: power});
+                        }
+                        break;
+                    }
+                    power = power*bs.weight;
+                    ray = Ray::new(hr.p, bs.wi);
+                }
+            }
+
+            let mut axes = vec![0; photons.len()];
+            Self::build(&mut photons, &mut axes);
+            PhotonMap{photons: photons, axes: axes, radius: radius}
+        }
+
+        pub fn len(&self) -> usize {
+            self.photons.len()
+        }
+
+        fn build(photons: &mut [Photon], axes: &mut [u8]) {
+            if photons.len() <= 1 {
+                return;
+            }
+            // Split along the axis the photons are most spread out on
+            let mut lo = photons[0].p;
+            let mut hi = photons[0].p;
+            for ph in photons.iter() {
+                lo = vec3::new(lo.x.min(ph.p.x), lo.y.min(ph.p.y), lo.z.min(ph.p.z));
+                hi = vec3::new(hi.x.max(ph.p.x), hi.y.max(ph.p.y), hi.z.max(ph.p.z));
+            }
+            let extent = hi - lo;
+            let axis = if extent.x >= extent.y && extent.x >= extent.z {0} else if extent.y >= extent.z {1} else {2};
+
+            let mid = photons.len()/2;
+            photons.select_nth_unstable_by(mid, |a, b| component(a.p, axis).total_cmp(&component(b.p, axis)));
+            axes[mid] = axis;
+
+            let (left, right) = photons.split_at_mut(mid);
+            let (left_axes, right_axes) = axes.split_at_mut(mid);
+            Self::build(left, left_axes);
+            Self::build(&mut right[1..], &mut right_axes[1..]);
+        }
+
+        // Calls f for every photon within radius of p
+        pub fn for_each_near(&self, p: point3, radius: f32, mut f: impl FnMut(&Photon)) {
+            Self::query(&self.photons, &self.axes, p, radius*radius, &mut f);
+        }
+
+        fn query(photons: &[Photon], axes: &[u8], p: point3, radius_sq: f32, f: &mut impl FnMut(&Photon)) {
+            if photons.is_empty() {
+                return;
+            }
+            let mid = photons.len()/2;
+            let node = &photons[mid];
+            if (node.p - p).length_squared() <= radius_sq {
+                f(node);
+            }
+            if photons.len() == 1 {
+                return;
+            }
+
+            let d = component(p, axes[mid]) - component(node.p, axes[mid]);
+            let (left, right) = (&photons[..mid], &photons[mid+1..]);
+            let (left_axes, right_axes) = (&axes[..mid], &axes[mid+1..]);
+            let (near, near_axes, far, far_axes) = if d < 0.0 {(left, left_axes, right, right_axes)} else {(right, right_axes, left, left_axes)};
+            Self::query(near, near_axes, p, radius_sq, f);
+            if d*d <= radius_sq {
+                Self::query(far, far_axes, p, radius_sq, f);
+            }
+        }
+
+        // Reflected radiance from the photons around a hit point, towards wo
+        pub fn estimate(&self, scene: &HittableList, hit_record: &HitRecord, wo: vec3) -> colour {
+            let material = scene.list[hit_record.object_id].material();
+            let mut flux = colour::new(0.0, 0.0, 0.0);
+            self.for_each_near(hit_record.p, self.radius, |ph| {
+                flux += material.eval(wo, ph.wi, hit_record)*ph.power;
+            });
+            flux/(std::f32::consts::PI*self.radius*self.radius)
+        }
+    }
+}
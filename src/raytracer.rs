@@ -1,8 +1,8 @@
 extern crate minifb;
-use minifb::{Key, Window, WindowOptions};
+use minifb::{Key, KeyRepeat, Window, WindowOptions};
 
 use std::thread;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use crossbeam::{bounded, TryRecvError};
 
 use scene::Scene;
@@ -14,15 +14,38 @@ mod denoising;
 use vector::vec3;
 mod vector;
 
-use ray::Ray;
 mod ray;
 
 use camera::Camera;
 mod camera;
 
+use tonemapping::{ToneMapper, srgb_oetf};
+mod tonemapping;
+
+use settings::Settings;
+mod settings;
+
 use geometry::*;
 mod geometry;
 
+mod image;
+
+use film::{Film, Aovs};
+mod film;
+
+use temporal::TemporalAccumulator;
+mod temporal;
+
+use sampling::{AdaptiveSampling, RunningStats, Sampler, SamplerKind};
+mod sampling;
+use integrators::{Integrator, IntegratorKind, DebugMode, SampleRecord};
+mod integrators;
+mod lights;
+mod photons;
+
+use sky::PhysicalSky;
+mod sky;
+
 use vec3 as colour;
 use vec3 as point3;
 
@@ -32,24 +55,18 @@ const USE_BUFFER: bool = true;
 const NUM_FRAMES_TO_RENDER: u32 = 10;
 /// COLOUR
 
-fn clamp(x: f32, min: f32, max: f32) -> f32{
-    if x<min {return min;}
-    if x>max {return max;}
-    return x;
-}
-
 fn from_u8_rgb(r: u8, g: u8, b: u8) -> u32 {
     let (r, g, b) = (r as u32, g as u32, b as u32);
     (r << 16) | (g << 8) | b
 }
 
-fn write_colour(mut colour: colour, samples_per_px: usize, buffer: &mut Vec<u32>, i: usize, row: usize, image_width: usize, image_height: usize){
-    let scale = 1.0/samples_per_px as f32;
-    colour = colour*scale;
-    
-    let ir = (256.0*clamp(colour.x, 0.0, 0.999)) as u8;
-    let ig = (256.0*clamp(colour.y, 0.0, 0.999)) as u8;
-    let ib = (256.0*clamp(colour.z, 0.0, 0.999)) as u8;
+fn write_colour(mut colour: colour, tone_mapper: ToneMapper, buffer: &mut Vec<u32>, i: usize, row: usize, image_width: usize, image_height: usize){
+    colour = tone_mapper.apply(colour);
+
+    // Everything up to here is linear. Encode for display.
+    let ir = (255.0*srgb_oetf(colour.x)).round() as u8;
+    let ig = (255.0*srgb_oetf(colour.y)).round() as u8;
+    let ib = (255.0*srgb_oetf(colour.z)).round() as u8;
 
     buffer[row*image_width + i] = from_u8_rgb(ir, ig, ib);
 }
@@ -63,57 +80,10 @@ fn write_to_window(window: &mut minifb::Window, buffer: &mut Vec<u32>, width: us
     }
 }
 
-/// RAY
-
-fn ray_colour(&ray: &Ray, scene: &HittableList, ray_bounces: usize, gamma_correction: bool, pixel_data: (usize,usize,usize)) -> colour{
-    if ray_bounces <=0{ return colour::new(0.0, 0.0, 0.0);}
-
-    let mut hr = geometry::HitRecord::default();
-
-    let mut attenuation = colour::new(0.0,0.0,0.0);
-    let max_ray_len = f32::INFINITY;
-    if let Some(r) = scene.hit(&ray, &mut attenuation, 0.001, max_ray_len, &mut hr, pixel_data) { //hit anything in scene
-        // Compute Lambertian reflection
-        // TODO: Use r instead of recalculating it
-        // let target: point3 = hr.p + hr.normal + vec3::random_unit_vector();
-        return attenuation*ray_colour(&r, scene, ray_bounces-1, gamma_correction, pixel_data);
-    }
-    let unit_dir: vec3 = vec3::unit_vector(ray.dir);
-    let t = 0.5*unit_dir.y+1.0;
-
-    let mut colour = colour::new(1.0, 1.0, 1.0)*(1.0-t) + colour::new(0.5, 0.7, 1.0)*t;
-
-    if gamma_correction{
-        colour.x = colour.x.sqrt();
-        colour.y = colour.y.sqrt();
-        colour.z = colour.z.sqrt();
-    }
-    colour
-}
-
 //////////////////////////////////////////////////////////////////////////////
 
 fn main(){
 
-    // use image::*;
-    // let im = image::open("data/128_128_tile.png").unwrap();
-    // let pxls = im.raw_pixels().to_vec();
-    // let mut out = vec![];
-    // // print!("{}", pxls.len());
-    // for p in pxls{
-    //     // println!("{:?}", p);
-    //     out.push(p as f32/255.0);
-    // }
-    // println!("{:?}", out);
-    // return ();
-
-    // use spherical_blue_noise::*;
-
-    // let blue_noise_vec: Vec<(f32, f32, f32)> = BlueNoiseSphere::new(256, &mut rand::thread_rng()).into_iter().collect();
-    // println!("{:?}", blue_noise_vec);
-    // return ();
-
-
     // IMAGE
     let aspect_ratio = 16.0/9.0 as f32;
     let image_width: usize = 600;
@@ -121,19 +91,38 @@ fn main(){
 
     /////////// SET UP DISPAY /////////////
     let mut img_buffer: Vec<u32> = vec![0; image_width * image_height];
+    let mut film = Film::new(image_width, image_height);
     let mut window = Window::new("Test - ESC to exit", image_width as usize, image_height as usize, WindowOptions::default())
     .unwrap_or_else(|e| {
         panic!("{}", e);
     });
     ///////////////////////////////////////
 
-    let mut cam = Scene::get_camera();
-
-    let max_ray_bounces = 10;
-    let gamma_correction = true;
+    let settings = Settings::from_args();
+    // The scene's integrator unless another one was picked on the command line
+    let default_integrator = settings.integrator
+        .unwrap_or_else(|| IntegratorKind::from_name(Scene::integrator()).unwrap_or_else(|| panic!("Unknown integrator '{}'", Scene::integrator())));
+    // Threads pick up the current integrator at the start of every row, so it can be switched from the viewer
+    let integrator = Arc::new(RwLock::new(default_integrator.create(&settings)));
+    let mut tone_mapper = if integrator.read().unwrap().tone_mapped() {settings.tone_mapper} else {ToneMapper::Clamp};
+    BlueNoise::init(&settings.blue_noise);
+    if settings.aovs{
+        Aovs::add_layers(&mut film);
+    }
+    // Per pixel statistics for the denoisers and the sample count heatmap
+    let track_samples = settings.denoiser.is_some() || settings.adaptive.is_some();
+    if track_samples{
+        film.add_layer("variance", &["V"]);
+        film.add_layer("samples", &["N"]);
+    }
+    let mut temporal = if settings.temporal {Some(TemporalAccumulator::new(image_width, image_height))} else {None};
 
     // Scene
-    let mut scene = Scene::get_scene();
+    let mut scene = Scene::get_scene(&settings);
+    if let Some(sky) = settings.sky{
+        scene.set_sky(PhysicalSky::new(sky));
+    }
+    scene.preprocess();
 
     if !USE_BUFFER{ print!("P3\n{} {}\n255\n", image_width, image_height);}
 
@@ -143,41 +132,106 @@ fn main(){
     let scene_arc = Arc::new(scene);
     let mut thread_handles = Vec::with_capacity(num_threads);
 
+    // The camera is built and focused once per frame, and handed to every thread before it starts on the frame
+    let frame_camera = |scene: &HittableList| {
+        let mut cam = Scene::get_camera();
+        settings.lens.apply(&mut cam);
+        cam.apply_autofocus(scene);
+        Arc::new(cam)
+    };
+    let mut cam = frame_camera(&scene_arc);
+    let mut cam_senders = Vec::with_capacity(num_threads);
+
     for i in 0..num_threads{
         let sender_clone = sender.clone();
         let scene_clone = scene_arc.clone();
+        let (cam_sender, cam_receiver) = bounded(NUM_FRAMES_TO_RENDER as usize);
+        cam_sender.send(cam.clone()).unwrap();
+        cam_senders.push(cam_sender);
 
         let context = ThreadContext{
             thread_id: i,
             num_threads: num_threads,
             sender: sender_clone,
             scene: scene_clone,
-            cam: cam.clone(),
+            cams: cam_receiver,
             image_height: image_height,
             image_width: image_width,
             samples_per_px: cam.samples_per_px as usize,
-            max_ray_bounces: max_ray_bounces,
-            gamma_correction: gamma_correction,
-            blue_noise_disc: BlueNoise::get_disc().clone()
+            integrator: integrator.clone(),
+            adaptive: settings.adaptive,
+            sampler: settings.sampler,
         };
 
         let h = thread::spawn(move || {
+            // The stratified sampler needs to know how many samples a pixel can get
+            let max_samples = context.adaptive.map_or(context.samples_per_px, |a| a.max_samples);
             for i in 0..NUM_FRAMES_TO_RENDER {
-                calculate_some_pxls(context.thread_id, context.num_threads,  &(*context.scene), &context.cam, &context.sender, context.image_height,context.image_width,
-                    context.samples_per_px, context.max_ray_bounces, context.gamma_correction, &context.blue_noise_disc);
+                // Waits for the main thread to finish the previous frame and move the camera
+                let cam = context.cams.recv().unwrap();
+                // Seeded per frame, so that temporal accumulation sees different samples every frame
+                let mut sampler = context.sampler.create(max_samples, context.image_width, i as u64);
+                context.integrator.read().unwrap().begin_frame(&context.scene, &cam, i as usize);
+                let frame = Frame{scene: &context.scene, cam: &cam, integrator: &context.integrator, adaptive: context.adaptive,
+                    samples_per_px: context.samples_per_px, image_width: context.image_width, image_height: context.image_height};
+                calculate_some_pxls(context.thread_id, context.num_threads, &frame, &context.sender, &mut *sampler);
             }
         });
         thread_handles.push(h);
     }
+    let mut frames_started = 1;
 
     let total_num_pxls = image_width*image_height;
     let mut ctr=0;
     let mut start_time = std::time::SystemTime::now();
+    // Rays traced and camera samples taken this frame, for the average path length
+    let (mut frame_path_len, mut frame_samples) = (0, 0);
+    // Light traced straight to the camera this frame, summed over all samples
+    let mut splats = vec![colour::new(0.0, 0.0, 0.0); total_num_pxls];
+    let mut frame_has_splats = false;
+    // Sum of the frames a progressive integrator rendered since the camera last moved, how many there are, and their camera
+    let mut passes: Option<(Vec<colour>, usize, Arc<Camera>)> = None;
     loop{
+        let mut frame_done = false;
         match receiver.try_recv() {
             Ok(received) => {
-                write_colour(received.c, received.num_samples, &mut img_buffer, received.col, received.row, image_width, image_height);
+                // Film keeps the unclamped linear value, the window gets the tone mapped one
+                let c = received.c*(cam.exposure_scale()/received.num_samples as f32);
+                film.set_beauty(received.col, received.row, c);
+                if settings.aovs{
+                    received.aovs.write_to_film(&mut film, received.col, received.row, received.num_samples);
+                }
+                if track_samples{
+                    // Variance of the pixel mean, from the luminance moments of its samples
+                    let n = received.num_samples as f32;
+                    let mean_lum_sq = received.lum_sq*cam.exposure_scale()*cam.exposure_scale()/n;
+                    let variance = (mean_lum_sq - c.luminance()*c.luminance()).max(0.0)/n;
+                    film.set("variance", received.col, received.row, &[variance]);
+                    film.set("samples", received.col, received.row, &[n]);
+                }
+                write_colour(c, tone_mapper, &mut img_buffer, received.col, received.row, image_width, image_height);
+                for (s, t, c) in received.splats{
+                    if !(0.0..=1.0).contains(&s) || !(0.0..=1.0).contains(&t){
+                        continue;
+                    }
+                    // Camera samples of a pixel are spread over a disc one pixel in radius around its centre,
+                    // so a splat belongs to every pixel whose disc it falls in
+                    let x = s*(image_width-1) as f32;
+                    let y = t*(image_height-1) as f32;
+                    for j in (y - 1.0).ceil().max(0.0) as usize..=((y + 1.0).floor() as usize).min(image_height-1){
+                        for col in (x - 1.0).ceil().max(0.0) as usize..=((x + 1.0).floor() as usize).min(image_width-1){
+                            let (dx, dy) = (x - col as f32, y - j as f32);
+                            if dx*dx + dy*dy <= 1.0{
+                                splats[(image_height-1-j)*image_width + col] += c;
+                                frame_has_splats = true;
+                            }
+                        }
+                    }
+                }
+                frame_path_len += received.path_len;
+                frame_samples += received.num_samples;
                 ctr += 1;
+                frame_done = ctr%(image_width*image_height)==0;
             }
             Err(TryRecvError::Disconnected)  =>{ println!("\nINFO: Thread disconnected or finished."); }
             Err(TryRecvError::Empty)=> { }
@@ -185,12 +239,89 @@ fn main(){
         }
         
 
-        if ctr%(image_width*image_height)==0{
+        if frame_done{
+            if frame_has_splats{
+                // Every sample's splats could have landed on any pixel, so divide by the samples of the
+                // whole frame, and by the area of a pixel's disc as a fraction of the screen
+                let disc_area = std::f32::consts::PI/((image_width-1)*(image_height-1)) as f32;
+                let scale = cam.exposure_scale()/(disc_area*frame_samples.max(1) as f32);
+                for (idx, splat) in splats.iter_mut().enumerate(){
+                    let (col, row) = (idx%image_width, idx/image_width);
+                    film.set_beauty(col, row, film.beauty(col, row) + *splat*scale);
+                    *splat = colour::new(0.0, 0.0, 0.0);
+                }
+            }
+            if integrator.read().unwrap().progressive(){
+                if !passes.as_ref().map_or(false, |(_, _, pass_cam)| pass_cam.same_view(&cam)){
+                    passes = Some((vec![colour::new(0.0, 0.0, 0.0); total_num_pxls], 0, cam.clone()));
+                }
+                let (sum, n, _) = passes.as_mut().unwrap();
+                *n += 1;
+                for (idx, s) in sum.iter_mut().enumerate(){
+                    let (col, row) = (idx%image_width, idx/image_width);
+                    *s += film.beauty(col, row);
+                    film.set_beauty(col, row, *s/(*n as f32));
+                }
+            }else{
+                passes = None;
+            }
+            if let Some(temporal) = temporal.as_mut(){
+                temporal.accumulate(&mut film, &cam);
+            }
+            if settings.temporal || settings.denoiser.is_some() || frame_has_splats || passes.is_some(){
+                let frame = match settings.denoiser{
+                    Some(denoiser) => denoiser.apply(&film),
+                    None => (0..total_num_pxls).map(|idx| film.beauty(idx%image_width, idx/image_width)).collect(),
+                };
+                for row in 0..image_height{
+                    for col in 0..image_width{
+                        write_colour(frame[row*image_width + col], tone_mapper, &mut img_buffer, col, row, image_width, image_height);
+                    }
+                }
+            }
             write_to_window(&mut window, &mut img_buffer, image_width, image_height);
-            Scene::move_cam();
+
+            // 1-8 switch to the debug views, 9 to the Whitted preview, 0 back to the normal integrator
+            if let Some(keys) = window.get_keys_pressed(KeyRepeat::No){
+                for key in keys{
+                    let kind = match key{
+                        Key::Key0 => Some(default_integrator),
+                        Key::Key1 => Some(IntegratorKind::Debug(DebugMode::ALL[0])),
+                        Key::Key2 => Some(IntegratorKind::Debug(DebugMode::ALL[1])),
+                        Key::Key3 => Some(IntegratorKind::Debug(DebugMode::ALL[2])),
+                        Key::Key4 => Some(IntegratorKind::Debug(DebugMode::ALL[3])),
+                        Key::Key5 => Some(IntegratorKind::Debug(DebugMode::ALL[4])),
+                        Key::Key6 => Some(IntegratorKind::Debug(DebugMode::ALL[5])),
+                        Key::Key7 => Some(IntegratorKind::Debug(DebugMode::ALL[6])),
+                        Key::Key8 => Some(IntegratorKind::Debug(DebugMode::ALL[7])),
+                        Key::Key9 => Some(IntegratorKind::Whitted),
+                        _ => None,
+                    };
+                    if let Some(kind) = kind{
+                        let new_integrator = kind.create(&settings);
+                        tone_mapper = if new_integrator.tone_mapped() {settings.tone_mapper} else {ToneMapper::Clamp};
+                        *integrator.write().unwrap() = new_integrator;
+                        passes = None;
+                        println!("INFO: Integrator: {}", kind.name());
+                    }
+                }
+            }
+            if settings.animate{
+                Scene::move_cam();
+            }
+            if frames_started < NUM_FRAMES_TO_RENDER{
+                cam = frame_camera(&scene_arc);
+                for cam_sender in cam_senders.iter(){
+                    cam_sender.send(cam.clone()).unwrap();
+                }
+                frames_started += 1;
+            }
             if let Ok(elapsed) = start_time.elapsed(){
-                println!("FPS: {}", 1000.0/elapsed.as_millis() as f32);
+                println!("FPS: {}, avg path length: {:.2}", 1000.0/elapsed.as_millis() as f32, frame_path_len as f32/frame_samples.max(1) as f32);
             }
+            frame_path_len = 0;
+            frame_samples = 0;
+            frame_has_splats = false;
             start_time = std::time::SystemTime::now();
         }
         if ctr == total_num_pxls*NUM_FRAMES_TO_RENDER as usize{
@@ -204,6 +335,31 @@ fn main(){
         t.join().unwrap();
     }
 
+    if let Some(adaptive) = settings.adaptive{
+        film.add_layer("heatmap", &["R", "G", "B"]);
+        for row in 0..image_height{
+            for col in 0..image_width{
+                let c = adaptive.heatmap(film.get("samples", col, row)[0]);
+                film.set("heatmap", col, row, &[c.x, c.y, c.z]);
+            }
+        }
+    }
+
+    if let Some(denoiser) = settings.denoiser{
+        let denoised = denoiser.apply(&film);
+        film.add_layer("denoised", &["R", "G", "B"]);
+        for (idx, c) in denoised.iter().enumerate(){
+            film.set("denoised", idx%image_width, idx/image_width, &[c.x, c.y, c.z]);
+        }
+    }
+
+    for output in settings.outputs.iter(){
+        match image::write_film(&film, output, settings.exr_precision, settings.exr_compression, tone_mapper) {
+            Ok(()) => println!("INFO: Wrote {}", output),
+            Err(e) => println!("ERROR: Could not write {}: {}", output, e),
+        }
+    }
+
     // while  window.is_open() && !window.is_key_down(Key::Escape) {}
 }
 
@@ -213,58 +369,86 @@ struct ThreadContext{
     sender: crossbeam::Sender<PxData>,
     
     scene: Arc<HittableList>,
-    cam: Camera,
+    // Camera of every frame, in order
+    cams: crossbeam::Receiver<Arc<Camera>>,
     image_height: usize,
     image_width: usize,
     samples_per_px: usize,
-    max_ray_bounces: usize,
-    gamma_correction: bool,
-    blue_noise_disc: Vec<(f32, f32)>
+    integrator: Arc<RwLock<Arc<dyn Integrator>>>,
+    adaptive: Option<AdaptiveSampling>,
+    sampler: SamplerKind,
+}
+
+// What stays the same for every pixel of a frame
+struct Frame<'a>{
+    scene: &'a HittableList,
+    cam: &'a Camera,
+    // Read again for every row, so it can be switched from the viewer
+    integrator: &'a RwLock<Arc<dyn Integrator>>,
+    adaptive: Option<AdaptiveSampling>,
+    samples_per_px: usize,
+    image_width: usize,
+    image_height: usize,
 }
 
 struct PxData{
     c: colour,
     row: usize,
     col: usize,
-    num_samples: usize
+    num_samples: usize,
+    // Sum of the squared luminance of all samples, for variance estimation
+    lum_sq: f32,
+    // Total number of rays traced for this pixel
+    path_len: usize,
+    // Contributions to other pixels, see SampleRecord
+    splats: Vec<(f32, f32, colour)>,
+    aovs: Aovs
 }
 
 fn calculate_some_pxls(thread_id: usize,
     num_threads: usize,
-    _scene: &HittableList,
-    _cam: &Camera,
+    frame: &Frame,
     sender: &crossbeam::Sender<PxData>,
-    image_height: usize,
-    image_width: usize,
-    samples_per_px: usize,
-    max_ray_bounces: usize,
-    gamma_correction: bool,
-    blue_noise_disc: &Vec<(f32,f32)>){
+    sampler: &mut dyn Sampler){
 
-    
+    let Frame{scene, cam, integrator, adaptive, samples_per_px, image_width, image_height} = *frame;
     for j in (thread_id .. image_height).step_by(num_threads){
-        let scene = Scene::get_scene();
-        let cam = Scene::get_camera();
+        let integrator = integrator.read().unwrap().clone();
         for i in 0..image_width{
             let mut px_colour = colour::new(0.0, 0.0, 0.0);
+            let mut px_aovs = Aovs::default();
+            let mut px_lum_sq = 0.0;
+            let mut px_stats = RunningStats::default();
+            let mut px_record = SampleRecord::default();
+
+            // Without adaptive sampling every pixel gets exactly samples_per_px
+            let fixed = AdaptiveSampling{min_samples: samples_per_px, max_samples: samples_per_px, threshold: 0.0};
+            let sampling = adaptive.unwrap_or(fixed);
+
             // TODO: Make anti-aliasing be a second stage process (i.e. have non-aliased preliminary result, then anti-alias).
-            for s in 0..cam.samples_per_px {
-                // let offset_x = blue_noise_disc[(s%blue_noise_disc.len() as u32) as usize].0;
-                // let offset_y = blue_noise_disc[(s%blue_noise_disc.len() as u32) as usize].1;
-                let (offset_x, offset_y) = BlueNoise::random_in_disc();
+            while sampling.needs_more(&px_stats) {
+                sampler.start_pixel_sample(i, j, px_stats.n);
+                let (offset_x, offset_y) = sampler.in_unit_disc();
                 let u = (i as f32 + offset_x) / (image_width-1) as f32;
                 let v = (j as f32 + offset_y) / (image_height-1) as f32;
 
-                let (a,b) = BlueNoise::random_in_disc();
-                let for_depth_of_field = vec3::new(a, b, 0.0);
-
-                let r = cam.get_ray(u, v, for_depth_of_field);
-                px_colour += ray_colour(&r, &scene, max_ray_bounces, gamma_correction, (i,j,image_width));
+                let for_depth_of_field = sampler.in_unit_disc();
+
+                // Samples blocked by the lens barrel contribute black
+                let mut c = colour::new(0.0, 0.0, 0.0);
+                if let Some(r) = cam.get_ray(u, v, for_depth_of_field){
+                    let mut aovs = Aovs::default();
+                    c = integrator.li(&r, scene, cam, sampler, &mut px_record, Some(&mut aovs));
+                    px_aovs.accumulate(&aovs);
+                }
+                px_colour += c;
+                px_lum_sq += c.luminance()*c.luminance();
+                px_stats.push(c.luminance());
             }
             let row;
             if USE_BUFFER{ row = image_height-1-j; }else{ row = j;}
 
-            let px_data = PxData{c: px_colour, row: row, col: i, num_samples: samples_per_px};
+            let px_data = PxData{c: px_colour, row: row, col: i, num_samples: px_stats.n, lum_sq: px_lum_sq, path_len: px_record.path_len, splats: px_record.splats, aovs: px_aovs};
             sender.send(px_data).unwrap();
         }
     }
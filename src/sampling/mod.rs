@@ -0,0 +1,557 @@
+pub use self::sampling::AdaptiveSampling;
+pub use self::sampling::RunningStats;
+pub use self::sampling::RussianRoulette;
+pub use self::samplers::{Sampler, SamplerKind, IndependentSampler, cosine_hemisphere, uniform_sphere, uniform_cone, concentric_disc, tangent_frame};
+
+mod sampling{
+    use crate::vector::vec3;
+    use crate::tonemapping::heatmap;
+    use vec3 as colour;
+
+    // Welford's online mean and variance
+    #[derive(Copy, Clone, Debug, Default)]
+    pub struct RunningStats {
+        pub n: usize,
+        pub mean: f32,
+        m2: f32,
+    }
+
+    impl RunningStats {
+        pub fn push(&mut self, x: f32) {
+            self.n += 1;
+            let delta = x - self.mean;
+            self.mean += delta/self.n as f32;
+            self.m2 += delta*(x - self.mean);
+        }
+
+        // Unbiased sample variance
+        pub fn variance(&self) -> f32 {
+            if self.n < 2 {0.0} else {self.m2/(self.n-1) as f32}
+        }
+
+        // Standard error of the mean, relative to the mean. Almost black pixels are measured
+        // against a floor, otherwise they would all run up to the maximum sample count.
+        pub fn relative_error(&self) -> f32 {
+            if self.n == 0 { return f32::INFINITY; }
+            (self.variance()/self.n as f32).sqrt()/self.mean.abs().max(0.01)
+        }
+    }
+
+    // Takes min_samples in every pixel, then keeps sampling until the relative error
+    // of the pixel's luminance is below threshold or max_samples is reached
+    #[derive(Copy, Clone, Debug)]
+    pub struct AdaptiveSampling {
+        pub min_samples: usize,
+        pub max_samples: usize,
+        pub threshold: f32,
+    }
+
+    impl Default for AdaptiveSampling {
+        fn default() -> Self {
+            AdaptiveSampling{min_samples: 4, max_samples: 64, threshold: 0.05}
+        }
+    }
+
+    impl AdaptiveSampling {
+        pub fn needs_more(&self, stats: &RunningStats) -> bool {
+            if stats.n < self.min_samples { return true; }
+            if stats.n >= self.max_samples { return false; }
+            stats.relative_error() > self.threshold
+        }
+
+        // False colour for a sample count, from blue (min_samples) through green to red (max_samples)
+        pub fn heatmap(&self, samples: f32) -> colour {
+            let range = self.max_samples.saturating_sub(self.min_samples).max(1) as f32;
+            heatmap((samples - self.min_samples as f32)/range)
+        }
+    }
+
+    // Randomly ends paths after min_depth bounces, with a survival probability that follows the
+    // path throughput. Surviving paths are divided by that probability, so the estimate stays unbiased.
+    #[derive(Copy, Clone, Debug)]
+    pub struct RussianRoulette {
+        pub min_depth: usize,
+    }
+
+    impl Default for RussianRoulette {
+        fn default() -> Self {
+            RussianRoulette{min_depth: 3}
+        }
+    }
+
+    impl RussianRoulette {
+        // Probability of continuing a path that has already bounced depth times
+        pub fn survival_probability(&self, depth: usize, throughput: colour) -> f32 {
+            if depth < self.min_depth { return 1.0; }
+            throughput.max_component().min(1.0)
+        }
+    }
+}
+
+mod samplers{
+    use std::f32::consts::PI;
+    use std::sync::OnceLock;
+    use rand::Rng;
+    use crate::vector::vec3;
+    use crate::denoising::BlueNoise;
+
+    const ONE_MINUS_EPSILON: f32 = 0.99999994;
+
+    // A sampler is reset at the start of every pixel sample and then hands out one dimension
+    // after the other: pixel jitter, lens, then whatever each bounce asks for
+    pub trait Sampler {
+        fn start_pixel_sample(&mut self, x: usize, y: usize, sample_index: usize);
+        fn get_1d(&mut self) -> f32;
+        fn get_2d(&mut self) -> (f32, f32);
+
+        // Point in the unit disc, used for pixel jitter and lens samples
+        fn in_unit_disc(&mut self) -> (f32, f32) {
+            concentric_disc(self.get_2d())
+        }
+
+        // Point in the unit ball, used by the diffuse and fuzzy metal materials
+        fn in_unit_sphere(&mut self) -> vec3 {
+            let (u1, u2) = self.get_2d();
+            let r = self.get_1d().cbrt();
+            let z = 1.0 - 2.0*u1;
+            let s = (1.0 - z*z).max(0.0).sqrt();
+            let phi = 2.0*PI*u2;
+            vec3::new(s*phi.cos(), s*phi.sin(), z)*r
+        }
+    }
+
+    // Shirley-Chiu mapping, keeps the stratification of the square sample
+    pub fn concentric_disc((u, v): (f32, f32)) -> (f32, f32) {
+        let (a, b) = (2.0*u - 1.0, 2.0*v - 1.0);
+        if a == 0.0 && b == 0.0 { return (0.0, 0.0); }
+        let (r, theta) = if a.abs() > b.abs() {
+            (a, PI/4.0*(b/a))
+        } else {
+            (b, PI/2.0 - PI/4.0*(a/b))
+        };
+        (r*theta.cos(), r*theta.sin())
+    }
+
+    // Cosine weighted direction around normal (Malley's method), pdf is cos(theta)/pi
+    pub fn cosine_hemisphere(normal: vec3, u: (f32, f32)) -> vec3 {
+        let (x, y) = concentric_disc(u);
+        let z = (1.0 - x*x - y*y).max(0.0).sqrt();
+
+        let (tangent, bitangent) = tangent_frame(normal);
+        tangent*x + bitangent*y + normal*z
+    }
+
+    // Two unit vectors completing an orthonormal basis with the unit vector n
+    pub fn tangent_frame(n: vec3) -> (vec3, vec3) {
+        let helper = if n.x.abs() > 0.9 {vec3::new(0.0, 1.0, 0.0)} else {vec3::new(1.0, 0.0, 0.0)};
+        let tangent = vec3::unit_vector(vec3::cross(&helper, &n));
+        (tangent, vec3::cross(&n, &tangent))
+    }
+
+    // Uniform direction, pdf is 1/(4 pi)
+    pub fn uniform_sphere(u: (f32, f32)) -> vec3 {
+        let z = 1.0 - 2.0*u.0;
+        let r = (1.0 - z*z).max(0.0).sqrt();
+        let phi = 2.0*PI*u.1;
+        vec3::new(r*phi.cos(), r*phi.sin(), z)
+    }
+
+    // Uniform direction within cos_max of axis, pdf is 1/(2 pi (1 - cos_max))
+    pub fn uniform_cone(axis: vec3, cos_max: f32, u: (f32, f32)) -> vec3 {
+        let z = 1.0 - u.0*(1.0 - cos_max);
+        let r = (1.0 - z*z).max(0.0).sqrt();
+        let phi = 2.0*PI*u.1;
+        let (tangent, bitangent) = tangent_frame(axis);
+        tangent*(r*phi.cos()) + bitangent*(r*phi.sin()) + axis*z
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum SamplerKind {
+        BlueNoise,
+        Independent,
+        Stratified,
+        Halton,
+        Sobol,
+        Pmj02,
+    }
+
+    impl SamplerKind {
+        pub fn from_name(name: &str) -> Option<SamplerKind> {
+            match name {
+                "bluenoise" => Some(SamplerKind::BlueNoise),
+                "independent" => Some(SamplerKind::Independent),
+                "stratified" => Some(SamplerKind::Stratified),
+                "halton" => Some(SamplerKind::Halton),
+                "sobol" => Some(SamplerKind::Sobol),
+                "pmj02" => Some(SamplerKind::Pmj02),
+                _ => None,
+            }
+        }
+
+        // samples_per_pixel is only used by the stratified sampler to size its grid,
+        // the seed decorrelates frames
+        pub fn create(&self, samples_per_pixel: usize, image_width: usize, seed: u64) -> Box<dyn Sampler> {
+            match self {
+                SamplerKind::BlueNoise => Box::new(BlueNoiseSampler{image_width, x: 0, y: 0}),
+                SamplerKind::Independent => Box::new(IndependentSampler::new(seed)),
+                SamplerKind::Stratified => Box::new(StratifiedSampler{samples_per_pixel: samples_per_pixel.max(1) as u32, seed, pixel_seed: 0, index: 0, dim: 0}),
+                SamplerKind::Halton => Box::new(HaltonSampler{seed, pixel_seed: 0, index: 0, dim: 0}),
+                SamplerKind::Sobol => Box::new(SobolSampler{seed, pixel_seed: 0, index: 0, dim: 0}),
+                SamplerKind::Pmj02 => Box::new(Pmj02Sampler{seed, pixel_seed: 0, index: 0, dim: 0, tables: pmj02_tables()}),
+            }
+        }
+    }
+
+    // splitmix64 finaliser
+    fn mix_bits(mut v: u64) -> u64 {
+        v ^= v >> 31;
+        v = v.wrapping_mul(0x7fb5d329728ea185);
+        v ^= v >> 27;
+        v = v.wrapping_mul(0x81dadef4bc2dd44d);
+        v ^= v >> 33;
+        v
+    }
+
+    fn hash(a: u64, b: u64) -> u64 {
+        mix_bits(a ^ mix_bits(b).wrapping_add(0x9e3779b97f4a7c15))
+    }
+
+    fn pixel_hash(seed: u64, x: usize, y: usize) -> u64 {
+        hash(seed, ((x as u64) << 32) | y as u64)
+    }
+
+    fn to_float(bits: u32) -> f32 {
+        (bits >> 8) as f32*(1.0/16777216.0)
+    }
+
+    // The original behaviour: cycles through the precomputed blue noise tables,
+    // with thread_rng for anything else
+    pub struct BlueNoiseSampler {
+        image_width: usize,
+        x: usize,
+        y: usize,
+    }
+
+    impl Sampler for BlueNoiseSampler {
+        fn start_pixel_sample(&mut self, x: usize, y: usize, _sample_index: usize) {
+            self.x = x;
+            self.y = y;
+        }
+        fn get_1d(&mut self) -> f32 {
+            rand::thread_rng().gen()
+        }
+        fn get_2d(&mut self) -> (f32, f32) {
+            (rand::thread_rng().gen(), rand::thread_rng().gen())
+        }
+        fn in_unit_disc(&mut self) -> (f32, f32) {
+            BlueNoise::random_in_disc()
+        }
+        fn in_unit_sphere(&mut self) -> vec3 {
+            BlueNoise::random_in_unit_sphere((self.x, self.y, self.image_width))
+        }
+    }
+
+    // Uncorrelated random numbers, deterministic per pixel sample
+    pub struct IndependentSampler {
+        seed: u64,
+        state: u64,
+    }
+
+    impl IndependentSampler {
+        pub fn new(seed: u64) -> Self {
+            IndependentSampler{seed, state: seed}
+        }
+        fn next(&mut self) -> u32 {
+            self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+            (mix_bits(self.state) >> 32) as u32
+        }
+    }
+
+    impl Sampler for IndependentSampler {
+        fn start_pixel_sample(&mut self, x: usize, y: usize, sample_index: usize) {
+            self.state = hash(pixel_hash(self.seed, x, y), sample_index as u64);
+        }
+        fn get_1d(&mut self) -> f32 {
+            to_float(self.next())
+        }
+        fn get_2d(&mut self) -> (f32, f32) {
+            (to_float(self.next()), to_float(self.next()))
+        }
+    }
+
+    // Kensler's hash based permutation of 0..len, from "Correlated Multi-Jittered Sampling"
+    fn permutation_element(mut i: u32, len: u32, p: u32) -> u32 {
+        let mut w = len - 1;
+        w |= w >> 1;
+        w |= w >> 2;
+        w |= w >> 4;
+        w |= w >> 8;
+        w |= w >> 16;
+        loop {
+            i ^= p; i = i.wrapping_mul(0xe170893d);
+            i ^= p >> 16; i ^= (i & w) >> 4;
+            i ^= p >> 8; i = i.wrapping_mul(0x0929eb3f);
+            i ^= p >> 23; i ^= (i & w) >> 1;
+            i = i.wrapping_mul(1 | p >> 27); i = i.wrapping_mul(0x6935fa69);
+            i ^= (i & w) >> 11; i = i.wrapping_mul(0x74dcb303);
+            i ^= (i & w) >> 2; i = i.wrapping_mul(0x9e501cc3);
+            i ^= (i & w) >> 2; i = i.wrapping_mul(0xc860a3df);
+            i &= w;
+            i ^= i >> 5;
+            if i < len { break; }
+        }
+        (i.wrapping_add(p)) % len
+    }
+
+    // Jittered strata, shuffled independently for every dimension.
+    // 2D samples use a sqrt(spp) x sqrt(spp) grid.
+    pub struct StratifiedSampler {
+        samples_per_pixel: u32,
+        seed: u64,
+        pixel_seed: u64,
+        index: u32,
+        dim: u64,
+    }
+
+    impl Sampler for StratifiedSampler {
+        fn start_pixel_sample(&mut self, x: usize, y: usize, sample_index: usize) {
+            self.pixel_seed = pixel_hash(self.seed, x, y);
+            self.index = sample_index as u32;
+            self.dim = 0;
+        }
+        fn get_1d(&mut self) -> f32 {
+            let h = hash(self.pixel_seed, self.dim);
+            self.dim += 1;
+            let n = self.samples_per_pixel;
+            let stratum = permutation_element(self.index % n, n, h as u32);
+            let jitter = to_float(hash(h, self.index as u64) as u32);
+            ((stratum as f32 + jitter)/n as f32).min(ONE_MINUS_EPSILON)
+        }
+        fn get_2d(&mut self) -> (f32, f32) {
+            let h = hash(self.pixel_seed, self.dim);
+            self.dim += 2;
+            let nx = (self.samples_per_pixel as f32).sqrt().ceil() as u32;
+            let ny = (self.samples_per_pixel + nx - 1)/nx;
+            let stratum = permutation_element(self.index % (nx*ny), nx*ny, h as u32);
+            let jitter = hash(h, self.index as u64);
+            let (jx, jy) = (to_float(jitter as u32), to_float((jitter >> 32) as u32));
+            (
+                (((stratum % nx) as f32 + jx)/nx as f32).min(ONE_MINUS_EPSILON),
+                (((stratum / nx) as f32 + jy)/ny as f32).min(ONE_MINUS_EPSILON),
+            )
+        }
+    }
+
+    const PRIMES: [u32; 32] = [
+        2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53,
+        59, 61, 67, 71, 73, 79, 83, 89, 97, 101, 103, 107, 109, 113, 127, 131,
+    ];
+
+    fn radical_inverse(base: u32, mut i: u64) -> f32 {
+        let inv_base = 1.0/base as f64;
+        let mut f = inv_base;
+        let mut r = 0.0;
+        while i > 0 {
+            r += (i % base as u64) as f64*f;
+            i /= base as u64;
+            f *= inv_base;
+        }
+        r as f32
+    }
+
+    // Halton sequence over the samples of a pixel, one prime base per dimension,
+    // with a per pixel Cranley-Patterson rotation. Dimensions past the 32nd reuse the bases.
+    pub struct HaltonSampler {
+        seed: u64,
+        pixel_seed: u64,
+        index: u64,
+        dim: u64,
+    }
+
+    impl Sampler for HaltonSampler {
+        fn start_pixel_sample(&mut self, x: usize, y: usize, sample_index: usize) {
+            self.pixel_seed = pixel_hash(self.seed, x, y);
+            self.index = sample_index as u64;
+            self.dim = 0;
+        }
+        fn get_1d(&mut self) -> f32 {
+            let v = radical_inverse(PRIMES[self.dim as usize % PRIMES.len()], self.index);
+            let v = v + to_float(hash(self.pixel_seed, self.dim) as u32);
+            self.dim += 1;
+            (v - v.floor()).min(ONE_MINUS_EPSILON)
+        }
+        fn get_2d(&mut self) -> (f32, f32) {
+            (self.get_1d(), self.get_1d())
+        }
+    }
+
+    // Burley, "Practical Hash-based Owen Scrambling"
+    fn laine_karras_permutation(mut x: u32, seed: u32) -> u32 {
+        x = x.wrapping_add(seed);
+        x ^= x.wrapping_mul(0x6c50b47c);
+        x ^= x.wrapping_mul(0xb82f1e52);
+        x ^= x.wrapping_mul(0xc7afe638);
+        x ^= x.wrapping_mul(0x8d22f6e6);
+        x
+    }
+
+    fn nested_uniform_scramble(x: u32, seed: u32) -> u32 {
+        laine_karras_permutation(x.reverse_bits(), seed).reverse_bits()
+    }
+
+    // First two Sobol dimensions: van der Corput, and the Pascal matrix one
+    fn sobol_2d(index: u32) -> (u32, u32) {
+        let mut y = 0;
+        let mut v = 1u32 << 31;
+        let mut i = index;
+        while i != 0 {
+            if i & 1 != 0 { y ^= v; }
+            i >>= 1;
+            v ^= v >> 1;
+        }
+        (index.reverse_bits(), y)
+    }
+
+    // Owen scrambled Sobol, padded: every 1D or 2D request uses the first Sobol dimensions
+    // with its own index shuffle and scramble, so there is no limit on the dimension count
+    pub struct SobolSampler {
+        seed: u64,
+        pixel_seed: u64,
+        index: u32,
+        dim: u64,
+    }
+
+    impl SobolSampler {
+        fn sample(&mut self) -> (u32, u32) {
+            let h = hash(self.pixel_seed, self.dim);
+            self.dim += 1;
+            let (x, y) = sobol_2d(nested_uniform_scramble(self.index, h as u32));
+            (nested_uniform_scramble(x, (h >> 32) as u32), nested_uniform_scramble(y, mix_bits(h) as u32))
+        }
+    }
+
+    impl Sampler for SobolSampler {
+        fn start_pixel_sample(&mut self, x: usize, y: usize, sample_index: usize) {
+            self.pixel_seed = pixel_hash(self.seed, x, y);
+            self.index = sample_index as u32;
+            self.dim = 0;
+        }
+        fn get_1d(&mut self) -> f32 {
+            to_float(self.sample().0)
+        }
+        fn get_2d(&mut self) -> (f32, f32) {
+            let (x, y) = self.sample();
+            (to_float(x), to_float(y))
+        }
+    }
+
+    const PMJ02_SETS: usize = 32;
+    const PMJ02_SAMPLES: usize = 1024;
+
+    fn pmj02_tables() -> &'static [Vec<(u32, u32)>] {
+        static TABLES: OnceLock<Vec<Vec<(u32, u32)>>> = OnceLock::new();
+        TABLES.get_or_init(|| (0..PMJ02_SETS).map(|i| generate_pmj02(i as u64, PMJ02_SAMPLES)).collect())
+    }
+
+    fn top_bits(v: u32, bits: usize) -> u32 {
+        if bits == 0 {0} else {v >> (32 - bits)}
+    }
+
+    // Progressive multi-jittered (0,2) points: every power of two prefix has exactly one point
+    // in each elementary interval. Built one point at a time, picking a free x stratum and then
+    // searching the y bits against the occupied intervals (Pharr, "Efficient Generation of Points
+    // that Satisfy Two-Dimensional Elementary Intervals"). Starts over on the rare dead end.
+    fn generate_pmj02(seed: u64, n: usize) -> Vec<(u32, u32)> {
+        let mut rng = IndependentSampler::new(hash(seed, 0x706d6a3032));
+        'attempt: loop {
+            let mut points = vec![(rng.next(), rng.next())];
+            let mut k = 0;
+            // occupied[a] covers the 2^a x 2^(k-a) intervals
+            let mut occupied: Vec<Vec<bool>> = Vec::new();
+
+            while points.len() < n {
+                if points.len() == 1 << k {
+                    k += 1;
+                    occupied = vec![vec![false; 1 << k]; k + 1];
+                    for &(x, y) in &points {
+                        for (a, cells) in occupied.iter_mut().enumerate() {
+                            cells[((top_bits(x, a) << (k - a)) | top_bits(y, k - a)) as usize] = true;
+                        }
+                    }
+                }
+
+                let mut candidates: Vec<u32> = (0..1u32 << k).filter(|&xs| !occupied[k][xs as usize]).collect();
+                let mut found = None;
+                while !candidates.is_empty() && found.is_none() {
+                    let xs = candidates.swap_remove(rng.next() as usize % candidates.len());
+                    let x = (xs << (32 - k)) | (rng.next() >> k);
+                    if let Some(ys) = search_y(&occupied, k, x, 0, 0, &mut rng) {
+                        found = Some((x, (ys << (32 - k)) | (rng.next() >> k)));
+                    }
+                }
+
+                match found {
+                    Some((x, y)) => {
+                        for (a, cells) in occupied.iter_mut().enumerate() {
+                            cells[((top_bits(x, a) << (k - a)) | top_bits(y, k - a)) as usize] = true;
+                        }
+                        points.push((x, y));
+                    }
+                    None => continue 'attempt,
+                }
+            }
+            return points;
+        }
+    }
+
+    // Picks the y bits from the top down, checking the interval shape that the next bit completes
+    fn search_y(occupied: &[Vec<bool>], k: usize, x: u32, prefix: u32, bits: usize, rng: &mut IndependentSampler) -> Option<u32> {
+        if bits == k { return Some(prefix); }
+        let first = rng.next() & 1;
+        for bit in [first, first ^ 1] {
+            let p = (prefix << 1) | bit;
+            let a = k - (bits + 1);
+            if !occupied[a][((top_bits(x, a) << (bits + 1)) | p) as usize] {
+                if let Some(y) = search_y(occupied, k, x, p, bits + 1, rng) {
+                    return Some(y);
+                }
+            }
+        }
+        None
+    }
+
+    // Per pixel and dimension a table is picked by hash and given a random xor shift,
+    // which keeps the elementary interval stratification
+    pub struct Pmj02Sampler {
+        seed: u64,
+        pixel_seed: u64,
+        index: usize,
+        dim: u64,
+        tables: &'static [Vec<(u32, u32)>],
+    }
+
+    impl Pmj02Sampler {
+        fn sample(&mut self) -> (u32, u32) {
+            let h = hash(hash(self.pixel_seed, self.dim), (self.index/PMJ02_SAMPLES) as u64);
+            self.dim += 1;
+            let (x, y) = self.tables[h as usize % PMJ02_SETS][self.index % PMJ02_SAMPLES];
+            let shift = mix_bits(h);
+            (x ^ shift as u32, y ^ (shift >> 32) as u32)
+        }
+    }
+
+    impl Sampler for Pmj02Sampler {
+        fn start_pixel_sample(&mut self, x: usize, y: usize, sample_index: usize) {
+            self.pixel_seed = pixel_hash(self.seed, x, y);
+            self.index = sample_index;
+            self.dim = 0;
+        }
+        fn get_1d(&mut self) -> f32 {
+            to_float(self.sample().0)
+        }
+        fn get_2d(&mut self) -> (f32, f32) {
+            let (x, y) = self.sample();
+            (to_float(x), to_float(y))
+        }
+    }
+}
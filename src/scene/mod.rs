@@ -4,7 +4,9 @@ pub use self::scene::Scene;
 mod scene{
     use crate::vector::vec3;
     use crate::geometry::*;
-    use crate::camera::Camera;
+    use crate::lights::{PointLight, SpotLight, DirectionalLight};
+    use crate::camera::{Camera, Aperture, Exposure};
+    use crate::settings::Settings;
 
     use vec3 as colour;
     use vec3 as point3;
@@ -12,7 +14,7 @@ mod scene{
 
     pub struct Scene {}
     impl Scene{
-        pub fn get_scene() -> HittableList{
+        pub fn get_scene(settings: &Settings) -> HittableList{
             let mut scene = HittableList::new();
 
 
@@ -38,15 +40,69 @@ mod scene{
 
             // Hollow glass sphere
             let m5: Box<dyn Material> = Box::new( Dielectric{albedo: colour::new(0.95,0.95,1.0), index_of_refraction: 1.5});
-            scene.add(Box::new(Sphere::new(point3::new(-0.25, 0.75, -0.42), 0.14, m5)));
+            let glass = scene.add(Box::new(Sphere::new(point3::new(-0.25, 0.75, -0.42), 0.14, m5)));
             let m5: Box<dyn Material> = Box::new( Dielectric{albedo: colour::new(0.95,0.95,1.0), index_of_refraction: 1.5});
-            scene.add(Box::new(Sphere::new(point3::new(-0.25, 0.75, -0.42), -0.13, m5)));
+            scene.add_with_material_id(Box::new(Sphere::new(point3::new(-0.25, 0.75, -0.42), -0.13, m5)), glass);
+            if LAMP {
+                let m: Box<dyn Material> = Box::new( DiffuseLight{emit: colour::new(40.0, 34.0, 26.0)});
+                scene.add(Box::new(Sphere::new(point3::new(-0.25, 0.75, -0.42), 0.04, m)));
+            }
 
             // Cube!
             let m6: Box<dyn Material> = Box::new( Lambertian{albedo: colour::new(0.7, 0.3, 0.7)});
             let w = 0.50; let h = 0.50; let d = 0.50;
             scene.add(Box::new(Cube::new(point3::new(0.0, 0.5, -1.0), w,h,d, m6)));
 
+            // Smooth shaded octahedron
+            if MESH {
+                let c = point3::new(0.75, 0.25, -0.6);
+                let dirs = vec![vec3::new(1.0, 0.0, 0.0), vec3::new(0.0, 0.0, -1.0), vec3::new(-1.0, 0.0, 0.0),
+                    vec3::new(0.0, 0.0, 1.0), vec3::new(0.0, 1.0, 0.0), vec3::new(0.0, -1.0, 0.0)];
+                let indices = vec![[0, 1, 4], [1, 2, 4], [2, 3, 4], [3, 0, 4], [1, 0, 5], [2, 1, 5], [3, 2, 5], [0, 3, 5]];
+                let m: Box<dyn Material> = Box::new( Lambertian{albedo: colour::new(0.2, 0.6, 0.3)});
+                // Projected from above
+                let uvs = dirs.iter().map(|d| (0.5 + 0.5*d.x, 0.5 - 0.5*d.z)).collect();
+                let mesh = Mesh::new(dirs.iter().map(|&d| c + d*0.25).collect(), indices, m).with_normals(dirs).with_uvs(uvs)
+                    .with_intersector(settings.triangle_intersector);
+                scene.add(Box::new(mesh));
+            }
+
+            // Rectangular light panel over the scene, with a disk and a ring on the ground
+            if PLANAR_SHAPES {
+                let m: Box<dyn Material> = Box::new( DiffuseLight{emit: colour::new(6.0, 6.0, 6.0)});
+                scene.add(Box::new(Quad::new(point3::new(-0.5, 2.0, -1.2), vec3::new(1.0, 0.0, 0.0), vec3::new(0.0, 0.0, 0.6), m, true)));
+                let m: Box<dyn Material> = Box::new( Metal{albedo: colour::new(0.8, 0.8, 0.8), fuzz: 0.05});
+                scene.add(Box::new(Disk::new(point3::new(-0.8, 0.001, -0.3), vec3::new(0.0, 1.0, 0.0), 0.3, m, true)));
+                let m: Box<dyn Material> = Box::new( Lambertian{albedo: colour::new(0.8, 0.5, 0.1)});
+                scene.add(Box::new(Disk::annulus(point3::new(0.8, 0.001, 0.2), vec3::new(0.0, 1.0, 0.0), 0.15, 0.25, m, true)));
+            }
+
+            // Glass torus, a capsule, an open tube and a cone
+            if ANALYTIC_SHAPES {
+                let m: Box<dyn Material> = Box::new( Dielectric{albedo: colour::new(1.0, 1.0, 1.0), index_of_refraction: 1.5});
+                scene.add(Box::new(Torus::new(point3::new(-0.9, 0.25, -0.9), vec3::new(0.3, 1.0, 0.2), 0.25, 0.08, m)));
+                let m: Box<dyn Material> = Box::new( Lambertian{albedo: colour::new(0.2, 0.4, 0.8)});
+                scene.add(Box::new(Capsule::new(point3::new(0.7, 0.1, -0.2), point3::new(1.1, 0.1, -0.6), 0.1, m)));
+                let m: Box<dyn Material> = Box::new( Metal{albedo: colour::new(0.9, 0.6, 0.3), fuzz: 0.2});
+                scene.add(Box::new(Cylinder::new(point3::new(-0.6, 0.0, 0.3), point3::new(-0.6, 0.3, 0.3), 0.12, false, m)));
+                let m: Box<dyn Material> = Box::new( Lambertian{albedo: colour::new(0.8, 0.8, 0.2)});
+                scene.add(Box::new(Cone::new(point3::new(0.5, 0.0, 0.4), point3::new(0.5, 0.35, 0.4), 0.15, 0.0, true, m)));
+            }
+
+            // With --ies, the point and spot lights are there to show the profile, whose candela are scaled by --ies-scale
+            if let Some(profile) = &settings.ies {
+                let intensity = colour::new(1.0, 1.0, 1.0)*settings.ies_scale;
+                scene.add_light(Box::new(PointLight::new(point3::new(-0.6, 1.4, 0.2), intensity).with_profile(profile.clone())));
+                scene.add_light(Box::new(SpotLight::new(point3::new(0.6, 1.8, 0.3), point3::new(0.0, 0.5, -1.0), intensity, 25.0, 15.0)
+                    .with_profile(profile.clone())));
+            } else if DELTA_LIGHTS {
+                scene.add_light(Box::new(PointLight::new(point3::new(-0.6, 1.4, 0.2), colour::new(0.6, 0.5, 0.4))));
+                scene.add_light(Box::new(SpotLight::new(point3::new(0.6, 1.8, 0.3), point3::new(0.0, 0.5, -1.0), colour::new(3.0, 3.0, 3.0), 25.0, 15.0)));
+            }
+            if DELTA_LIGHTS {
+                scene.add_light(Box::new(DirectionalLight::new(vec3::new(-1.0, -0.6, -0.4), colour::new(1.5, 1.4, 1.2), 0.53)));
+            }
+
             // Plane
             let m6: Box<dyn Material> = Box::new( Lambertian{albedo: colour::new(0.3, 0.3, 0.3)});
             let single_sided = true;
@@ -64,7 +120,7 @@ mod scene{
             let look_at = vec3::new(0.25,0.60,-0.50);
             let samples_per_px = 1;
 
-            Camera::new(
+            let mut cam = Camera::new(
                 27.0,
                 16.0/9.0 as f32,
                 0.0,//0.1,
@@ -72,7 +128,23 @@ mod scene{
                 cam_origin,
                 look_at,
                 vec3::new(0.0,1.0,0.0),
-                samples_per_px as u32)
+                samples_per_px as u32);
+
+            // Bokeh shape, vignetting and autofocus. Can be changed with --aperture-blades, --aperture-image, --cat-eye and --autofocus.
+            cam.aperture = Aperture::Circular;
+            cam.cat_eye = 0.0;
+            cam.autofocus = None;
+
+            // Physical exposure. Overrides the aperture passed to Camera::new. Can be changed with --f-number, --shutter, --iso and --ev.
+            if PHYSICAL_CAMERA {
+                cam.set_exposure(Exposure{f_number: 2.8, shutter_speed: 1.0/60.0, iso: 100.0, ev_compensation: 0.0});
+            }
+            cam
+        }
+
+        // Name of the integrator this scene is meant to be rendered with, can be overridden with --integrator
+        pub fn integrator() -> &'static str {
+            "path"
         }
 
         pub fn move_cam() {
@@ -85,4 +157,23 @@ mod scene{
     }
 
     static mut COUNTER: u32 = 0;
+
+    // The default sky gradient is not in physical units, so this is off by default. Goes with --sky preetham --sky-scale 1.
+    // Giving any of the exposure options on the command line also turns it on.
+    const PHYSICAL_CAMERA: bool = false;
+
+    // Small lamp inside the hollow glass sphere. Mostly lit through glass, which needs --integrator bdpt to converge.
+    const LAMP: bool = false;
+
+    // A point light, a spot light on the cube and a low sun, on top of the sky
+    const DELTA_LIGHTS: bool = false;
+
+    // Triangle mesh next to the cube
+    const MESH: bool = false;
+
+    // Quad light, disk and annulus
+    const PLANAR_SHAPES: bool = false;
+
+    // Torus, capsule, cylinder and cone in front of the cube
+    const ANALYTIC_SHAPES: bool = false;
 }
\ No newline at end of file
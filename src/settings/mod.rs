@@ -0,0 +1,397 @@
+pub use self::settings::Settings;
+
+mod settings{
+    use crate::tonemapping::ToneMapper;
+    use crate::image::{ExrPrecision, ExrCompression};
+    use crate::denoising::{Denoiser, BlueNoiseConfig};
+    use crate::sampling::{AdaptiveSampling, RussianRoulette, SamplerKind};
+    use crate::integrators::IntegratorKind;
+    use crate::sky::SkyParameters;
+    use crate::camera::{Camera, Aperture, ApertureImage, Exposure};
+    use crate::lights::IesProfile;
+    use crate::geometry::TriangleIntersector;
+    use std::sync::Arc;
+
+    // Depth of field, bokeh and exposure on top of the scene's camera. Unset options keep what the scene asks for.
+    #[derive(Clone, Debug, Default)]
+    pub struct LensSettings {
+        // Physical camera. Sets the lens radius from the f-number and scales the image by the exposure.
+        pub exposure: Option<Exposure>,
+        // Lens diameter in scene units, replacing the one from the scene (and from a physical exposure)
+        pub aperture_size: Option<f32>,
+        pub aperture: Option<Aperture>,
+        pub cat_eye: Option<f32>,
+        pub autofocus: Option<(f32, f32)>,
+    }
+
+    impl LensSettings {
+        pub fn apply(&self, cam: &mut Camera) {
+            if let Some(exposure) = self.exposure {
+                cam.set_exposure(exposure);
+            }
+            if let Some(size) = self.aperture_size {
+                cam.lens_radius = size/2.0;
+            }
+            if let Some(aperture) = &self.aperture {
+                cam.aperture = aperture.clone();
+            }
+            if let Some(cat_eye) = self.cat_eye {
+                cam.cat_eye = cat_eye;
+            }
+            if self.autofocus.is_some() {
+                cam.autofocus = self.autofocus;
+            }
+        }
+    }
+
+    // Render options that can be changed from the command line, e.g. `raytracer --tonemap aces`
+    pub struct Settings {
+        pub tone_mapper: ToneMapper,
+
+        // Files the final film is written to. Format is picked from the extension (.exr, .pfm or .ppm).
+        pub outputs: Vec<String>,
+        pub exr_precision: ExrPrecision,
+        pub exr_compression: ExrCompression,
+
+        // Record depth, normal, albedo, position and IDs of the first hit as extra film layers
+        pub aovs: bool,
+
+        // Applied to the film every frame and before writing outputs. Needs the AOVs, so it turns them on.
+        pub denoiser: Option<Denoiser>,
+        // Reproject and blend previous frames in the viewer. Also needs the AOVs.
+        pub temporal: bool,
+        // Move the camera a little every frame. Progressive integrators only converge while it stands still.
+        pub animate: bool,
+
+        // Replaces the fixed samples per pixel. Also writes "samples" and "heatmap" layers.
+        pub adaptive: Option<AdaptiveSampling>,
+        // Overrides the scene's integrator
+        pub integrator: Option<IntegratorKind>,
+        // Hard limit on the number of bounces, and random termination after a minimum depth
+        pub max_bounces: usize,
+        pub russian_roulette: Option<RussianRoulette>,
+        // Ambient occlusion integrator
+        pub ao_distance: f32,
+        pub ao_samples: usize,
+        // Photon mapping: photons per pass, lookup radius of the first pass and how fast it shrinks
+        pub photons: usize,
+        pub photon_radius: f32,
+        pub ppm_alpha: f32,
+        // Metropolis light transport: paths used to normalise and seed the chains, chance of a
+        // completely new path per mutation, and size of small mutations
+        pub mlt_bootstrap: usize,
+        pub mlt_large_step: f32,
+        pub mlt_sigma: f32,
+        // Physical sky and sun instead of the scene's sky gradient
+        pub sky: Option<SkyParameters>,
+        // Depth of field, bokeh and physical exposure
+        pub lens: LensSettings,
+        // Photometric profile for the scene's point and spot lights, and the multiplier from its candela to scene units
+        pub ies: Option<Arc<IesProfile>>,
+        pub ies_scale: f32,
+        // Ray/triangle test used by the scene's meshes
+        pub triangle_intersector: TriangleIntersector,
+
+        // Where pixel jitter, lens and BSDF samples come from
+        pub sampler: SamplerKind,
+        // Sizes of the blue noise generated at startup, and where it is cached
+        pub blue_noise: BlueNoiseConfig,
+    }
+
+    impl Default for Settings {
+        fn default() -> Self {
+            Settings{
+                tone_mapper: ToneMapper::Clamp,
+
+                outputs: Vec::new(),
+                exr_precision: ExrPrecision::Half,
+                exr_compression: ExrCompression::Zip,
+
+                aovs: false,
+
+                denoiser: None,
+                temporal: false,
+                animate: true,
+
+                adaptive: None,
+                integrator: None,
+                max_bounces: 10,
+                russian_roulette: Some(RussianRoulette::default()),
+                ao_distance: 0.5,
+                ao_samples: 4,
+                photons: 100000,
+                photon_radius: 0.02,
+                ppm_alpha: 0.7,
+                mlt_bootstrap: 100000,
+                mlt_large_step: 0.3,
+                mlt_sigma: 0.01,
+                sky: None,
+                lens: LensSettings::default(),
+                ies: None,
+                ies_scale: 0.001,
+                triangle_intersector: TriangleIntersector::Watertight,
+
+                sampler: SamplerKind::BlueNoise,
+                blue_noise: BlueNoiseConfig::default(),
+            }
+        }
+    }
+
+    impl Settings {
+        pub fn from_args() -> Self {
+            let mut settings = Settings::default();
+            let mut args = std::env::args().skip(1);
+
+            while let Some(arg) = args.next() {
+                // Options are either flags or take a single value
+                let mut next_value = || args.next().unwrap_or_else(|| panic!("Missing value for '{}'", arg));
+                match arg.as_str() {
+                    "--tonemap" => {
+                        let value = next_value();
+                        settings.tone_mapper = ToneMapper::from_name(&value)
+                            .unwrap_or_else(|| panic!("Unknown tone mapper '{}'. Options: clamp, reinhard, extended-reinhard, aces, agx", value));
+                    },
+                    "--white-point" => {
+                        let value = next_value();
+                        let white_point = value.parse().unwrap_or_else(|_| panic!("Invalid white point '{}'", value));
+                        settings.tone_mapper = ToneMapper::ExtendedReinhard{white_point: white_point};
+                    },
+                    "--output" => settings.outputs.push(next_value()),
+                    "--aovs" => settings.aovs = true,
+                    "--exr-precision" => {
+                        settings.exr_precision = match next_value().as_str() {
+                            "half" => ExrPrecision::Half,
+                            "float" => ExrPrecision::Float,
+                            other => panic!("Unknown EXR precision '{}'. Options: half, float", other),
+                        };
+                    },
+                    "--exr-compression" => {
+                        settings.exr_compression = match next_value().as_str() {
+                            "none" => ExrCompression::None,
+                            "zip" => ExrCompression::Zip,
+                            "piz" => ExrCompression::Piz,
+                            other => panic!("Unknown EXR compression '{}'. Options: none, zip, piz", other),
+                        };
+                    },
+                    "--denoise" => {
+                        let value = next_value();
+                        settings.denoiser = match value.as_str() {
+                            "none" => None,
+                            name => Some(Denoiser::from_name(name)
+                                .unwrap_or_else(|| panic!("Unknown denoiser '{}'. Options: none, atrous, nlm, bilateral", name))),
+                        };
+                    },
+                    "--denoise-iterations" => {
+                        let value = next_value();
+                        let iterations = value.parse().unwrap_or_else(|_| panic!("Invalid number of iterations '{}'", value));
+                        match settings.denoiser.as_mut() {
+                            Some(Denoiser::Atrous(f)) => f.iterations = iterations,
+                            _ => panic!("--denoise-iterations has to come after --denoise atrous"),
+                        }
+                    },
+                    "--denoise-radius" => {
+                        let value = next_value();
+                        let radius = value.parse().unwrap_or_else(|_| panic!("Invalid radius '{}'", value));
+                        match settings.denoiser.as_mut() {
+                            Some(Denoiser::NonLocalMeans(f)) => f.search_radius = radius,
+                            Some(Denoiser::JointBilateral(f)) => f.radius = radius,
+                            _ => panic!("--denoise-radius has to come after --denoise nlm or --denoise bilateral"),
+                        }
+                    },
+                    "--denoise-patch-radius" => {
+                        let value = next_value();
+                        let radius = value.parse().unwrap_or_else(|_| panic!("Invalid patch radius '{}'", value));
+                        match settings.denoiser.as_mut() {
+                            Some(Denoiser::NonLocalMeans(f)) => f.patch_radius = radius,
+                            _ => panic!("--denoise-patch-radius has to come after --denoise nlm"),
+                        }
+                    },
+                    "--denoise-feature-weights" => {
+                        let value = next_value();
+                        let sigmas: Vec<f32> = value.split(',').map(|v| v.parse().unwrap_or_else(|_| panic!("Invalid feature weights '{}'", value))).collect();
+                        if sigmas.len() != 3 {
+                            panic!("--denoise-feature-weights takes <normal>,<depth>,<albedo>");
+                        }
+                        let features = settings.denoiser.as_mut()
+                            .unwrap_or_else(|| panic!("--denoise-feature-weights has to come after --denoise"))
+                            .feature_weights_mut();
+                        features.sigma_normal = sigmas[0];
+                        features.sigma_depth = sigmas[1];
+                        features.sigma_albedo = sigmas[2];
+                    },
+                    "--temporal" => settings.temporal = true,
+                    "--no-animation" => settings.animate = false,
+                    "--adaptive" => settings.adaptive = Some(AdaptiveSampling::default()),
+                    "--adaptive-min" | "--adaptive-max" | "--adaptive-threshold" => {
+                        let value = next_value();
+                        let adaptive = settings.adaptive.get_or_insert(AdaptiveSampling::default());
+                        match arg.as_str() {
+                            "--adaptive-min" => adaptive.min_samples = value.parse().unwrap_or_else(|_| panic!("Invalid sample count '{}'", value)),
+                            "--adaptive-max" => adaptive.max_samples = value.parse().unwrap_or_else(|_| panic!("Invalid sample count '{}'", value)),
+                            _ => adaptive.threshold = value.parse().unwrap_or_else(|_| panic!("Invalid threshold '{}'", value)),
+                        }
+                    },
+                    "--integrator" => {
+                        let name = next_value();
+                        settings.integrator = Some(IntegratorKind::from_name(&name).unwrap_or_else(|| panic!("Unknown integrator '{}'", name)));
+                    },
+                    "--max-bounces" => {
+                        let value = next_value();
+                        settings.max_bounces = value.parse().unwrap_or_else(|_| panic!("Invalid bounce count '{}'", value));
+                    },
+                    "--rr-min-depth" => {
+                        let value = next_value();
+                        let min_depth = value.parse().unwrap_or_else(|_| panic!("Invalid depth '{}'", value));
+                        settings.russian_roulette = Some(RussianRoulette{min_depth});
+                    },
+                    "--no-russian-roulette" => settings.russian_roulette = None,
+                    "--ao-distance" => {
+                        let value = next_value();
+                        settings.ao_distance = value.parse().unwrap_or_else(|_| panic!("Invalid distance '{}'", value));
+                    },
+                    "--ao-samples" => {
+                        let value = next_value();
+                        settings.ao_samples = value.parse().unwrap_or_else(|_| panic!("Invalid sample count '{}'", value));
+                    },
+                    "--photons" => {
+                        let value = next_value();
+                        settings.photons = value.parse().unwrap_or_else(|_| panic!("Invalid photon count '{}'", value));
+                    },
+                    "--photon-radius" => {
+                        let value = next_value();
+                        settings.photon_radius = value.parse().ok().filter(|&r: &f32| r > 0.0).unwrap_or_else(|| panic!("Invalid radius '{}'", value));
+                    },
+                    "--ppm-alpha" => {
+                        let value = next_value();
+                        settings.ppm_alpha = value.parse().ok().filter(|&a: &f32| a > 0.0 && a < 1.0).unwrap_or_else(|| panic!("Invalid alpha '{}', has to be between 0 and 1", value));
+                    },
+                    "--mlt-bootstrap" => {
+                        let value = next_value();
+                        settings.mlt_bootstrap = value.parse().ok().filter(|&n| n > 0).unwrap_or_else(|| panic!("Invalid sample count '{}'", value));
+                    },
+                    "--mlt-large-step" => {
+                        let value = next_value();
+                        settings.mlt_large_step = value.parse().ok().filter(|&p: &f32| (0.0..=1.0).contains(&p)).unwrap_or_else(|| panic!("Invalid probability '{}'", value));
+                    },
+                    "--mlt-sigma" => {
+                        let value = next_value();
+                        settings.mlt_sigma = value.parse().ok().filter(|&s: &f32| s > 0.0).unwrap_or_else(|| panic!("Invalid step size '{}'", value));
+                    },
+                    "--sky" => {
+                        settings.sky = match next_value().as_str() {
+                            "gradient" => None,
+                            "preetham" => Some(settings.sky.unwrap_or_default()),
+                            other => panic!("Unknown sky '{}'. Options: gradient, preetham", other),
+                        };
+                    },
+                    "--sun-elevation" | "--sun-azimuth" | "--turbidity" | "--ground-albedo" | "--sky-scale" => {
+                        let value = next_value();
+                        let sky = settings.sky.get_or_insert(SkyParameters::default());
+                        match arg.as_str() {
+                            "--sun-elevation" => sky.sun_elevation = value.parse().ok().filter(|e: &f32| (-90.0..=90.0).contains(e))
+                                .unwrap_or_else(|| panic!("Invalid elevation '{}', has to be between -90 and 90 degrees", value)),
+                            "--sun-azimuth" => sky.sun_azimuth = value.parse().unwrap_or_else(|_| panic!("Invalid azimuth '{}'", value)),
+                            "--turbidity" => sky.turbidity = value.parse().ok().filter(|&t: &f32| (1.7..=10.0).contains(&t))
+                                .unwrap_or_else(|| panic!("Invalid turbidity '{}', has to be between 1.7 and 10", value)),
+                            "--ground-albedo" => sky.ground_albedo = value.parse().ok().filter(|a: &f32| (0.0..=1.0).contains(a))
+                                .unwrap_or_else(|| panic!("Invalid albedo '{}', has to be between 0 and 1", value)),
+                            _ => sky.scale = value.parse().ok().filter(|&s: &f32| s > 0.0).unwrap_or_else(|| panic!("Invalid scale '{}'", value)),
+                        }
+                    },
+                    "--aperture" => {
+                        let value = next_value();
+                        settings.lens.aperture_size = Some(value.parse().ok().filter(|&d: &f32| d >= 0.0).unwrap_or_else(|| panic!("Invalid aperture diameter '{}'", value)));
+                    },
+                    "--aperture-blades" => {
+                        let value = next_value();
+                        let blades = value.parse().ok().filter(|&n: &u32| n >= 3).unwrap_or_else(|| panic!("Invalid number of blades '{}', needs at least 3", value));
+                        let rotation = match settings.lens.aperture {
+                            Some(Aperture::Polygon{rotation, ..}) => rotation,
+                            _ => 0.0,
+                        };
+                        settings.lens.aperture = Some(Aperture::Polygon{blades: blades, rotation: rotation});
+                    },
+                    "--aperture-rotation" => {
+                        let value = next_value();
+                        let degrees: f32 = value.parse().unwrap_or_else(|_| panic!("Invalid rotation '{}'", value));
+                        match settings.lens.aperture.as_mut() {
+                            Some(Aperture::Polygon{rotation, ..}) => *rotation = degrees.to_radians(),
+                            _ => panic!("--aperture-rotation has to come after --aperture-blades"),
+                        }
+                    },
+                    "--aperture-image" => {
+                        let image = ApertureImage::from_file(&next_value()).unwrap_or_else(|e| panic!("{}", e));
+                        settings.lens.aperture = Some(Aperture::Image(Arc::new(image)));
+                    },
+                    "--f-number" | "--shutter" | "--iso" | "--ev" => {
+                        let value = next_value();
+                        let exposure = settings.lens.exposure.get_or_insert(Exposure::default());
+                        match arg.as_str() {
+                            "--f-number" => exposure.f_number = value.parse().ok().filter(|&n: &f32| n > 0.0)
+                                .unwrap_or_else(|| panic!("Invalid f-number '{}'", value)),
+                            // Either seconds or a fraction like 1/60
+                            "--shutter" => exposure.shutter_speed = match value.split_once('/') {
+                                Some((num, den)) => num.parse::<f32>().ok().zip(den.parse::<f32>().ok()).map(|(n, d)| n/d),
+                                None => value.parse().ok(),
+                            }.filter(|t| t.is_finite() && *t > 0.0).unwrap_or_else(|| panic!("Invalid shutter speed '{}'", value)),
+                            "--iso" => exposure.iso = value.parse().ok().filter(|&iso: &f32| iso > 0.0)
+                                .unwrap_or_else(|| panic!("Invalid ISO '{}'", value)),
+                            _ => exposure.ev_compensation = value.parse().unwrap_or_else(|_| panic!("Invalid exposure compensation '{}'", value)),
+                        }
+                    },
+                    "--cat-eye" => {
+                        let value = next_value();
+                        settings.lens.cat_eye = Some(value.parse().ok().filter(|&c: &f32| c >= 0.0).unwrap_or_else(|| panic!("Invalid cat's eye strength '{}'", value)));
+                    },
+                    "--autofocus" => {
+                        let value = next_value();
+                        let point: Vec<f32> = value.split(',').map(|v| v.parse().ok().filter(|c: &f32| (0.0..=1.0).contains(c))
+                            .unwrap_or_else(|| panic!("Invalid autofocus point '{}', coordinates have to be between 0 and 1", value))).collect();
+                        if point.len() != 2 {
+                            panic!("--autofocus takes <x>,<y>");
+                        }
+                        settings.lens.autofocus = Some((point[0], point[1]));
+                    },
+                    "--ies" => {
+                        let profile = IesProfile::from_file(&next_value()).unwrap_or_else(|e| panic!("{}", e));
+                        settings.ies = Some(Arc::new(profile));
+                    },
+                    "--ies-scale" => {
+                        let value = next_value();
+                        settings.ies_scale = value.parse().ok().filter(|&s: &f32| s > 0.0).unwrap_or_else(|| panic!("Invalid scale '{}'", value));
+                    },
+                    "--triangle-intersector" => {
+                        let name = next_value();
+                        settings.triangle_intersector = TriangleIntersector::from_name(&name)
+                            .unwrap_or_else(|| panic!("Unknown triangle intersector '{}'. Options: watertight, moller-trumbore", name));
+                    },
+                    "--sampler" => {
+                        let name = next_value();
+                        settings.sampler = SamplerKind::from_name(&name).unwrap_or_else(|| panic!("Unknown sampler '{}'", name));
+                    },
+                    "--blue-noise-size" | "--blue-noise-sphere-points" | "--blue-noise-disc-points" => {
+                        let value = next_value();
+                        let n: usize = value.parse().ok().filter(|&n| n > 0).unwrap_or_else(|| panic!("Invalid size '{}'", value));
+                        match arg.as_str() {
+                            "--blue-noise-size" => settings.blue_noise.screenspace_size = n,
+                            "--blue-noise-sphere-points" => settings.blue_noise.sphere_points = n,
+                            _ => settings.blue_noise.disc_points = n,
+                        }
+                    },
+                    "--blue-noise-cache" => settings.blue_noise.cache_dir = Some(next_value().into()),
+                    "--no-blue-noise-cache" => settings.blue_noise.cache_dir = None,
+                    other => panic!("Unknown option '{}'", other),
+                }
+            }
+            if let Some(adaptive) = settings.adaptive {
+                if adaptive.min_samples == 0 || adaptive.min_samples > adaptive.max_samples {
+                    panic!("Invalid adaptive sample range {} to {}, needs 0 < min <= max", adaptive.min_samples, adaptive.max_samples);
+                }
+            }
+            if settings.denoiser.is_some() || settings.temporal {
+                settings.aovs = true;
+            }
+            settings
+        }
+    }
+}
@@ -0,0 +1,189 @@
+pub use self::sky::Sky;
+pub use self::sky::{PhysicalSky, SkyParameters};
+
+mod sky{
+    use crate::vector::vec3;
+    use crate::lights::DirectionalLight;
+    use vec3 as colour;
+
+    // What rays that leave the scene see
+    pub enum Sky {
+        // White at the horizon to light blue at the top, not in physical units
+        Gradient,
+        Physical(PhysicalSky),
+    }
+
+    impl Sky {
+        pub fn radiance(&self, dir: vec3) -> colour {
+            match self {
+                Sky::Gradient => {
+                    let unit_dir: vec3 = vec3::unit_vector(dir);
+                    let t = 0.5*unit_dir.y+1.0;
+
+                    colour::new(1.0, 1.0, 1.0)*(1.0-t) + colour::new(0.5, 0.7, 1.0)*t
+                },
+                Sky::Physical(sky) => sky.radiance(dir),
+            }
+        }
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    pub struct SkyParameters {
+        // Degrees above the horizon
+        pub sun_elevation: f32,
+        // Degrees clockwise from -z (into the screen for the default camera) when looking down, so 90 is +x
+        pub sun_azimuth: f32,
+        // Haziness of the atmosphere, from about 2 (very clear) to 10 (hazy)
+        pub turbidity: f32,
+        // Reflectance of the ground, which is what rays below the horizon see
+        pub ground_albedo: f32,
+        // Multiplier from cd/m^2 to scene radiance. 1 for use with a physical camera exposure.
+        pub scale: f32,
+    }
+
+    impl Default for SkyParameters {
+        fn default() -> Self {
+            SkyParameters{sun_elevation: 45.0, sun_azimuth: 30.0, turbidity: 3.0, ground_albedo: 0.3, scale: 5e-5}
+        }
+    }
+
+    // Preetham, Shirley and Smits' analytic daylight model. Colours come from the model's CIE xyY and are converted to linear sRGB.
+    // The model doesn't cover the sun below the horizon, so there the sky is evaluated for a sun on the horizon and faded out
+    // by the end of civil twilight.
+    pub struct PhysicalSky {
+        pub parameters: SkyParameters,
+        // Unit vector towards the sun
+        pub sun_dir: vec3,
+        theta_sun: f32,
+        // Perez coefficients A to E for Y, x and y
+        perez: [[f32; 5]; 3],
+        // Y in cd/m^2, x and y at the zenith
+        zenith: [f32; 3],
+        twilight: f32,
+        ground: colour,
+    }
+
+    // Size of the sun seen from earth, in degrees
+    const SUN_ANGULAR_DIAMETER: f32 = 0.53;
+    // Illuminance from the sun at the top of the atmosphere, in lux
+    const SOLAR_ILLUMINANCE: f32 = 127000.0;
+
+    impl PhysicalSky {
+        pub fn new(parameters: SkyParameters) -> Self {
+            let t = parameters.turbidity;
+            let elevation = parameters.sun_elevation.to_radians();
+            let azimuth = parameters.sun_azimuth.to_radians();
+            let sun_dir = vec3::new(elevation.cos()*azimuth.sin(), elevation.sin(), -elevation.cos()*azimuth.cos());
+
+            let theta_sun = std::f32::consts::FRAC_PI_2 - elevation.max(0.0);
+            let perez = [
+                [0.1787*t - 1.4630, -0.3554*t + 0.4275, -0.0227*t + 5.3251, 0.1206*t - 2.5771, -0.0670*t + 0.3703],
+                [-0.0193*t - 0.2592, -0.0665*t + 0.0008, -0.0004*t + 0.2125, -0.0641*t - 0.8989, -0.0033*t + 0.0452],
+                [-0.0167*t - 0.2608, -0.0950*t + 0.0092, -0.0079*t + 0.2102, -0.0441*t - 1.6537, -0.0109*t + 0.0529],
+            ];
+
+            let chi = (4.0/9.0 - t/120.0)*(std::f32::consts::PI - 2.0*theta_sun);
+            let zenith_luminance = ((4.0453*t - 4.9710)*chi.tan() - 0.2155*t + 2.4192)*1000.0;
+            let polynomial = |c: [[f32; 4]; 3]| {
+                let th = [theta_sun*theta_sun*theta_sun, theta_sun*theta_sun, theta_sun, 1.0];
+                let row = |r: [f32; 4]| r.iter().zip(th.iter()).map(|(a, b)| a*b).sum::<f32>();
+                t*t*row(c[0]) + t*row(c[1]) + row(c[2])
+            };
+            let zenith_x = polynomial([
+                [0.00166, -0.00375, 0.00209, 0.0],
+                [-0.02903, 0.06377, -0.03202, 0.00394],
+                [0.11693, -0.21196, 0.06052, 0.25886],
+            ]);
+            let zenith_y = polynomial([
+                [0.00275, -0.00610, 0.00317, 0.0],
+                [-0.04214, 0.08970, -0.04153, 0.00516],
+                [0.15346, -0.26756, 0.06670, 0.26688],
+            ]);
+
+            // Sky brightness drops to nothing by the time the sun is 6 degrees below the horizon
+            let twilight = (1.0 + parameters.sun_elevation/6.0).clamp(0.0, 1.0);
+
+            let mut sky = PhysicalSky{parameters: parameters, sun_dir: sun_dir, theta_sun: theta_sun, perez: perez,
+                zenith: [zenith_luminance.max(0.0), zenith_x, zenith_y], twilight: twilight, ground: colour::new(0.0, 0.0, 0.0)};
+
+            // The ground is a diffuse surface lit by the sky and the sun
+            let mut irradiance = sky.sky_irradiance();
+            if let Some(sun) = sky.sun() {
+                irradiance += sun.irradiance*sun_dir.y;
+            }
+            sky.ground = irradiance*(parameters.ground_albedo/std::f32::consts::PI);
+            sky
+        }
+
+        fn perez(&self, channel: usize, cos_theta: f32, gamma: f32) -> f32 {
+            let [a, b, c, d, e] = self.perez[channel];
+            (1.0 + a*(b/cos_theta).exp())*(1.0 + c*(d*gamma).exp() + e*gamma.cos()*gamma.cos())
+        }
+
+        // Sky without the sun disc, which is a light instead
+        fn sky_radiance(&self, dir: vec3) -> colour {
+            let cos_theta = dir.y.max(1e-3);
+            let gamma = vec3::dot(&dir, &self.sun_dir).clamp(-1.0, 1.0).acos();
+
+            let mut xyy = [0.0; 3];
+            for channel in 0..3 {
+                xyy[channel] = self.zenith[channel]*self.perez(channel, cos_theta, gamma)/self.perez(channel, 1.0, self.theta_sun);
+            }
+            let (luminance, x, y) = (xyy[0]*self.twilight*self.parameters.scale, xyy[1], xyy[2]);
+            if y <= 0.0 {
+                return colour::new(0.0, 0.0, 0.0);
+            }
+
+            // CIE XYZ to linear sRGB
+            let big_x = x*luminance/y;
+            let big_z = (1.0 - x - y)*luminance/y;
+            colour::new(
+                (3.2406*big_x - 1.5372*luminance - 0.4986*big_z).max(0.0),
+                (-0.9689*big_x + 1.8758*luminance + 0.0415*big_z).max(0.0),
+                (0.0557*big_x - 0.2040*luminance + 1.0570*big_z).max(0.0))
+        }
+
+        // Integrated over the upper hemisphere, for lighting the ground
+        fn sky_irradiance(&self) -> colour {
+            let (n_theta, n_phi) = (16, 64);
+            let d_theta = std::f32::consts::FRAC_PI_2/n_theta as f32;
+            let d_phi = 2.0*std::f32::consts::PI/n_phi as f32;
+            let mut irradiance = colour::new(0.0, 0.0, 0.0);
+            for i in 0..n_theta {
+                let theta = (i as f32 + 0.5)*d_theta;
+                for j in 0..n_phi {
+                    let phi = (j as f32 + 0.5)*d_phi;
+                    let dir = vec3::new(theta.sin()*phi.cos(), theta.cos(), theta.sin()*phi.sin());
+                    irradiance += self.sky_radiance(dir)*(theta.cos()*theta.sin()*d_theta*d_phi);
+                }
+            }
+            irradiance
+        }
+
+        pub fn radiance(&self, dir: vec3) -> colour {
+            let dir = vec3::unit_vector(dir);
+            if dir.y < 0.0 {self.ground} else {self.sky_radiance(dir)}
+        }
+
+        // Light from the sun disc, dimmed by Rayleigh and aerosol scattering on the way through the atmosphere.
+        // None once the sun has set.
+        pub fn sun(&self) -> Option<DirectionalLight> {
+            if self.parameters.sun_elevation <= 0.0 {
+                return None;
+            }
+            let zenith_degrees = 90.0 - self.parameters.sun_elevation;
+            // Relative optical air mass (Kasten and Young)
+            let air_mass = 1.0/(zenith_degrees.to_radians().cos() + 0.50572*(96.07995 - zenith_degrees).powf(-1.6364));
+            let beta = 0.04608*self.parameters.turbidity - 0.04586;
+            // Wavelengths in micrometres standing in for red, green and blue
+            let transmittance = |lambda: f32| {
+                let rayleigh = (-0.008735*lambda.powf(-4.08)*air_mass).exp();
+                let aerosol = (-beta*lambda.powf(-1.3)*air_mass).exp();
+                rayleigh*aerosol
+            };
+            let irradiance = colour::new(transmittance(0.680), transmittance(0.550), transmittance(0.440))
+                *(SOLAR_ILLUMINANCE*self.parameters.scale);
+            Some(DirectionalLight::new(-self.sun_dir, irradiance, SUN_ANGULAR_DIAMETER))
+        }
+    }
+}
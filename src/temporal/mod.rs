@@ -0,0 +1,143 @@
+pub use self::temporal::TemporalAccumulator;
+
+mod temporal{
+    use crate::vector::vec3;
+    use crate::camera::Camera;
+    use crate::film::Film;
+    use vec3 as colour;
+    use vec3 as point3;
+
+    // Blends each new frame with the reprojected history of previous frames, so the moving
+    // viewer doesn't start from scratch every time the camera moves.
+    pub struct TemporalAccumulator {
+        width: usize,
+        height: usize,
+        // Smallest weight given to the new frame. Lower is smoother but ghosts more.
+        pub alpha_min: f32,
+        // History is dropped when its depth differs by more than this fraction
+        pub depth_tolerance: f32,
+        // History is dropped when the normals' dot product is below this
+        pub normal_tolerance: f32,
+
+        prev_cam: Option<Camera>,
+        colour: Vec<colour>,
+        depth: Vec<f32>,
+        normal: Vec<vec3>,
+        history_len: Vec<f32>,
+    }
+
+    impl TemporalAccumulator {
+        pub fn new(width: usize, height: usize) -> Self {
+            Self {
+                width: width,
+                height: height,
+                alpha_min: 0.1,
+                depth_tolerance: 0.1,
+                normal_tolerance: 0.9,
+
+                prev_cam: None,
+                colour: vec![colour::new(0.0,0.0,0.0); width*height],
+                depth: vec![0.0; width*height],
+                normal: vec![vec3::new(0.0,0.0,0.0); width*height],
+                history_len: vec![0.0; width*height],
+            }
+        }
+
+        // Film row/column of a point as seen by the previous camera. Matches how calculate_some_pxls maps pixels to (u, v).
+        fn reproject(&self, cam: &Camera, p: point3) -> Option<(f32, f32)> {
+            let (s, t) = cam.project(p)?;
+            let col = s*(self.width-1) as f32;
+            let row = (self.height-1) as f32 - t*(self.height-1) as f32;
+            if col < 0.0 || row < 0.0 || col > (self.width-1) as f32 || row > (self.height-1) as f32 {
+                return None;
+            }
+            Some((col, row))
+        }
+
+        // Replaces the film's beauty with the temporally accumulated one. Needs the position, depth and normal AOVs.
+        pub fn accumulate(&mut self, film: &mut Film, cam: &Camera) {
+            let (w, h) = (self.width, self.height);
+            let current: Vec<colour> = (0..w*h).map(|idx| film.beauty(idx%w, idx/w)).collect();
+
+            let mut out_colour = current.clone();
+            let mut out_history = vec![1.0; w*h];
+            let mut out_depth = vec![0.0; w*h];
+            let mut out_normal = vec![vec3::new(0.0,0.0,0.0); w*h];
+
+            for row in 0..h {
+                for col in 0..w {
+                    let idx = row*w + col;
+                    let depth = film.get("depth", col, row)[0];
+                    let n = film.get("normal", col, row);
+                    let normal = vec3::new(n[0], n[1], n[2]);
+                    let normal = if normal.length_squared() > 0.0 {vec3::unit_vector(normal)} else {normal};
+                    out_depth[idx] = depth;
+                    out_normal[idx] = normal;
+
+                    // Sky has no position to reproject
+                    if depth <= 0.0 { continue; }
+                    let prev_cam = match &self.prev_cam {
+                        Some(c) => c,
+                        None => continue,
+                    };
+
+                    let p = film.get("position", col, row);
+                    let p = point3::new(p[0], p[1], p[2]);
+                    let (hx, hy) = match self.reproject(prev_cam, p) {
+                        Some(xy) => xy,
+                        None => continue,
+                    };
+                    let expected_depth = (p - prev_cam.origin).length();
+
+                    // Bilinear fetch of the history, skipping taps that belong to a different surface
+                    let (x0, y0) = (hx.floor() as usize, hy.floor() as usize);
+                    let (fx, fy) = (hx - x0 as f32, hy - y0 as f32);
+                    let mut history = colour::new(0.0,0.0,0.0);
+                    let mut history_len = 0.0;
+                    let mut total_w = 0.0;
+                    for (dx, dy, bw) in [(0, 0, (1.0-fx)*(1.0-fy)), (1, 0, fx*(1.0-fy)), (0, 1, (1.0-fx)*fy), (1, 1, fx*fy)].iter() {
+                        let (x, y) = ((x0+dx).min(w-1), (y0+dy).min(h-1));
+                        let q = y*w + x;
+                        if self.history_len[q] <= 0.0 || *bw <= 0.0 { continue; }
+                        if (self.depth[q] - expected_depth).abs() > self.depth_tolerance*expected_depth { continue; }
+                        if vec3::dot(&self.normal[q], &normal) < self.normal_tolerance { continue; }
+
+                        history += self.colour[q]*(*bw);
+                        history_len += self.history_len[q]*(*bw);
+                        total_w += *bw;
+                    }
+                    if total_w < 1e-3 { continue; }
+                    let history = history/total_w;
+                    let history_len = history_len/total_w;
+
+                    // Clamp the history to the colour range of the current neighbourhood to limit ghosting
+                    let mut lo = current[idx];
+                    let mut hi = current[idx];
+                    for y in row.saturating_sub(1)..(row+2).min(h) {
+                        for x in col.saturating_sub(1)..(col+2).min(w) {
+                            let c = current[y*w + x];
+                            lo = colour::new(lo.x.min(c.x), lo.y.min(c.y), lo.z.min(c.z));
+                            hi = colour::new(hi.x.max(c.x), hi.y.max(c.y), hi.z.max(c.z));
+                        }
+                    }
+                    let history = colour::new(history.x.max(lo.x).min(hi.x), history.y.max(lo.y).min(hi.y), history.z.max(lo.z).min(hi.z));
+
+                    // Running average until the history is long enough, then an exponential moving average
+                    let alpha = (1.0/(history_len + 1.0)).max(self.alpha_min);
+                    out_colour[idx] = history*(1.0-alpha) + current[idx]*alpha;
+                    out_history[idx] = history_len + 1.0;
+                }
+            }
+
+            for (idx, c) in out_colour.iter().enumerate() {
+                film.set_beauty(idx%w, idx/w, *c);
+            }
+
+            self.colour = out_colour;
+            self.history_len = out_history;
+            self.depth = out_depth;
+            self.normal = out_normal;
+            self.prev_cam = Some(cam.clone());
+        }
+    }
+}
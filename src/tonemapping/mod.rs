@@ -0,0 +1,127 @@
+pub use self::tonemapping::ToneMapper;
+pub use self::tonemapping::srgb_oetf;
+pub use self::tonemapping::heatmap;
+
+mod tonemapping{
+    use crate::vector::vec3;
+    use vec3 as colour;
+
+    // Maps linear HDR radiance to linear display values in [0,1]. The sRGB transform is applied afterwards.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum ToneMapper {
+        Clamp,
+        Reinhard,
+        // Luminance at or above `white_point` maps to pure white
+        ExtendedReinhard{white_point: f32},
+        // Stephen Hill's fit of the ACES RRT+ODT
+        Aces,
+        // Minimal AgX, using the polynomial fit of the default contrast curve
+        AgX,
+    }
+
+    // Multiplies c by a 3x3 matrix given as rows
+    fn mat_mul(m: &[[f32; 3]; 3], c: colour) -> colour {
+        colour::new(
+            m[0][0]*c.x + m[0][1]*c.y + m[0][2]*c.z,
+            m[1][0]*c.x + m[1][1]*c.y + m[1][2]*c.z,
+            m[2][0]*c.x + m[2][1]*c.y + m[2][2]*c.z)
+    }
+
+    fn map_channels(c: colour, f: impl Fn(f32) -> f32) -> colour {
+        colour::new(f(c.x), f(c.y), f(c.z))
+    }
+
+    impl ToneMapper {
+        pub fn from_name(name: &str) -> Option<Self> {
+            match name {
+                "clamp" => Some(ToneMapper::Clamp),
+                "reinhard" => Some(ToneMapper::Reinhard),
+                "extended-reinhard" => Some(ToneMapper::ExtendedReinhard{white_point: 4.0}),
+                "aces" => Some(ToneMapper::Aces),
+                "agx" => Some(ToneMapper::AgX),
+                _ => None,
+            }
+        }
+
+        pub fn apply(&self, c: colour) -> colour {
+            let c = map_channels(c, |x| x.max(0.0));
+            let mapped = match *self {
+                ToneMapper::Clamp => c,
+                ToneMapper::Reinhard => {
+                    // Applied to luminance so hues are preserved
+                    let l = c.luminance();
+                    c/(1.0 + l)
+                },
+                ToneMapper::ExtendedReinhard{white_point} => {
+                    let l = c.luminance();
+                    if l <= 0.0 { return colour::new(0.0, 0.0, 0.0); }
+                    let l_out = l*(1.0 + l/(white_point*white_point))/(1.0 + l);
+                    c*(l_out/l)
+                },
+                ToneMapper::Aces => {
+                    const INPUT: [[f32; 3]; 3] = [
+                        [0.59719, 0.35458, 0.04823],
+                        [0.07600, 0.90834, 0.01566],
+                        [0.02840, 0.13383, 0.83777]];
+                    const OUTPUT: [[f32; 3]; 3] = [
+                        [ 1.60475, -0.53108, -0.07367],
+                        [-0.10208,  1.10813, -0.00605],
+                        [-0.00327, -0.07276,  1.07602]];
+
+                    let v = mat_mul(&INPUT, c);
+                    let v = map_channels(v, |x| {
+                        let a = x*(x + 0.0245786) - 0.000090537;
+                        let b = x*(0.983729*x + 0.4329510) + 0.238081;
+                        a/b
+                    });
+                    mat_mul(&OUTPUT, v)
+                },
+                ToneMapper::AgX => {
+                    const INSET: [[f32; 3]; 3] = [
+                        [0.842479062253094, 0.0784335999999992, 0.0792237451477643],
+                        [0.0423282422610123, 0.878468636469772, 0.0791661274605434],
+                        [0.0423756549057051, 0.0784336, 0.879142973793104]];
+                    const OUTSET: [[f32; 3]; 3] = [
+                        [ 1.19687900512017, -0.0980208811401368, -0.0990297440797205],
+                        [-0.0528968517574562, 1.15190312990417, -0.0989611768448433],
+                        [-0.0529716355144438, -0.0980434501171241, 1.15107367264116]];
+                    const MIN_EV: f32 = -12.47393;
+                    const MAX_EV: f32 = 4.026069;
+
+                    let v = mat_mul(&INSET, c);
+                    // Log2 encoding between MIN_EV and MAX_EV stops around middle grey
+                    let v = map_channels(v, |x| (x.max(1e-10).log2().max(MIN_EV).min(MAX_EV) - MIN_EV)/(MAX_EV - MIN_EV));
+                    let v = map_channels(v, |x| {
+                        let x2 = x*x;
+                        let x4 = x2*x2;
+                        15.5*x4*x2 - 40.14*x4*x + 31.96*x4 - 6.868*x2*x + 0.4298*x2 + 0.1191*x - 0.00232
+                    });
+                    // The curve outputs display encoded values, bring them back to linear
+                    let v = mat_mul(&OUTSET, v);
+                    map_channels(v, |x| x.max(0.0).powf(2.2))
+                },
+            };
+            map_channels(mapped, |x| x.max(0.0).min(1.0))
+        }
+    }
+
+    // Exact sRGB opto-electronic transfer function. Input is linear, clamped to [0,1].
+    pub fn srgb_oetf(x: f32) -> f32 {
+        let x = x.max(0.0).min(1.0);
+        if x <= 0.0031308 {
+            12.92*x
+        } else {
+            1.055*x.powf(1.0/2.4) - 0.055
+        }
+    }
+
+    // False colour for t in [0,1], from blue through green to red
+    pub fn heatmap(t: f32) -> colour {
+        let t = t.max(0.0).min(1.0);
+        if t < 0.5 {
+            colour::new(0.0, 2.0*t, 1.0 - 2.0*t)
+        } else {
+            colour::new(2.0*t - 1.0, 2.0 - 2.0*t, 0.0)
+        }
+    }
+}
@@ -26,6 +26,13 @@ mod vector{
         pub fn length(self) -> f32{
             self.length_squared().sqrt()
         }
+        // Rec. 709 luminance, for colours
+        pub fn luminance(self) -> f32{
+            0.2126*self.x + 0.7152*self.y + 0.0722*self.z
+        }
+        pub fn max_component(self) -> f32{
+            self.x.max(self.y).max(self.z)
+        }
         pub fn is_near_zero(&self) -> bool{
             let eps = 1e-8;
             self.x < 0.0 && self.y < 0.0 && self.z < 0.0
@@ -57,12 +64,12 @@ mod vector{
         pub fn random_in_unit_sphere() -> vec3{
             // let rand_rotation = rand::thread_rng().gen_range(0.0 .. 2.0*3.141592);
             //TODO: Get rid of this random number generator
-            let random_seed = rand::thread_rng().gen_range(0 .. 64);
-            let spherical_blue_noise_64 = BlueNoise::get_spherical_64();
+            let spherical_blue_noise = BlueNoise::get_spherical();
+            let random_seed = rand::thread_rng().gen_range(0 .. spherical_blue_noise.len());
             vec3::new(
-                spherical_blue_noise_64[random_seed%spherical_blue_noise_64.len()].0,
-                spherical_blue_noise_64[random_seed%spherical_blue_noise_64.len()].1,
-                spherical_blue_noise_64[random_seed%spherical_blue_noise_64.len()].2)
+                spherical_blue_noise[random_seed].0,
+                spherical_blue_noise[random_seed].1,
+                spherical_blue_noise[random_seed].2)
             // TODO: Very inefficient right now. Fix.
             // loop{
             //     let p = vec3::random_in_range(-1.0, 1.0);